@@ -22,7 +22,8 @@
 //! the native runtimes.
 #![allow(missing_docs)]
 
-use cord_primitives::{AccountId, Balance, Block, Nonce};
+use cord_primitives::{AccountId, Balance, Block, BlockNumber, DidIdentifier, Hash, Nonce};
+use identifier::Ss58Identifier;
 use pallet_transaction_payment::{FeeDetails, RuntimeDispatchInfo};
 pub use sp_authority_discovery::AuthorityId as AuthorityDiscoveryId;
 use sp_consensus_grandpa::AuthorityId as GrandpaId;
@@ -213,6 +214,142 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_statement_runtime_api::StatementApi<
+		Block,
+		Ss58Identifier,
+		Hash,
+		DidIdentifier,
+		BlockNumber,
+	> for Runtime {
+		fn query(
+			_: Ss58Identifier,
+		) -> Option<
+			pallet_statement_runtime_api::StatementInfo<Ss58Identifier, Hash, DidIdentifier, BlockNumber>,
+		> {
+			unimplemented!()
+		}
+		fn query_by_digest(
+			_: Hash,
+			_: Ss58Identifier,
+		) -> Option<
+			pallet_statement_runtime_api::StatementInfo<Ss58Identifier, Hash, DidIdentifier, BlockNumber>,
+		> {
+			unimplemented!()
+		}
+		fn digest_status(
+			_: Ss58Identifier,
+			_: Hash,
+		) -> Option<pallet_statement_runtime_api::StatementStatus> {
+			unimplemented!()
+		}
+		fn presentations(
+			_: Ss58Identifier,
+			_: Option<pallet_statement_runtime_api::PresentationTypeOf>,
+		) -> Vec<pallet_statement_runtime_api::StatementPresentationInfo<Hash, DidIdentifier>> {
+			unimplemented!()
+		}
+	}
+
+	impl cord_identifier_runtime_api::IdentifierApi<Block, Ss58Identifier> for Runtime {
+		fn timeline(
+			_: Ss58Identifier,
+			_: cord_identifier_runtime_api::IdentifierTypeOf,
+			_: Option<u64>,
+			_: u32,
+		) -> cord_identifier_runtime_api::TimelinePageOf {
+			unimplemented!()
+		}
+	}
+
+	#[api_version(3)]
+	impl pallet_did_runtime_api::DidApi<
+		Block,
+		DidIdentifier,
+		AccountId,
+		Hash,
+		BlockNumber
+	> for Runtime {
+		fn query(
+			_: DidIdentifier,
+		) -> Option<pallet_did_runtime_api::RawDidLinkedInfo<DidIdentifier, AccountId, Hash, BlockNumber>> {
+			unimplemented!()
+		}
+
+		fn query_by_name(
+			_: Vec<u8>,
+		) -> Option<pallet_did_runtime_api::RawDidLinkedInfo<DidIdentifier, AccountId, Hash, BlockNumber>> {
+			unimplemented!()
+		}
+
+		fn resolve(
+			_: DidIdentifier,
+		) -> pallet_did_runtime_api::RawDidState<DidIdentifier, AccountId, Hash, BlockNumber> {
+			unimplemented!()
+		}
+
+		fn verify_signature_at(
+			_: DidIdentifier,
+			_: pallet_did_runtime_api::DidVerificationKeyRelationship,
+			_: Vec<u8>,
+			_: pallet_did_runtime_api::DidSignature,
+			_: BlockNumber,
+		) -> bool {
+			unimplemented!()
+		}
+	}
+
+	impl pallet_chain_space_runtime_api::ChainSpaceApi<Block, Ss58Identifier, DidIdentifier> for Runtime {
+		fn space_tree(
+			_: Ss58Identifier,
+		) -> Option<Vec<pallet_chain_space_runtime_api::SpaceTreeNode<Ss58Identifier, DidIdentifier, bool>>> {
+			unimplemented!()
+		}
+	}
+
+	impl pallet_registries_runtime_api::RegistriesApi<
+		Block,
+		Ss58Identifier,
+		Hash,
+		DidIdentifier,
+		BlockNumber
+	> for Runtime {
+		fn registry_blob(_: Hash) -> Option<Vec<u8>> {
+			unimplemented!()
+		}
+
+		fn registry_entry_blob(_: Hash) -> Option<Vec<u8>> {
+			unimplemented!()
+		}
+
+		fn entry_state_at(
+			_: Ss58Identifier,
+			_: BlockNumber,
+		) -> Option<pallet_registries_runtime_api::RegistryEntryVersion<Hash, pallet_registries_runtime_api::StatusOf, DidIdentifier>> {
+			unimplemented!()
+		}
+
+		fn entry_schema(_: Ss58Identifier) -> Option<Ss58Identifier> {
+			unimplemented!()
+		}
+	}
+
+	impl pallet_network_score_runtime_api::NetworkScoreApi<Block, Vec<u8>> for Runtime {
+		fn score(
+			_: Vec<u8>,
+			_: pallet_network_score_runtime_api::RatingTypeOf,
+			_: pallet_network_score_runtime_api::ScoreWindow,
+		) -> Option<pallet_network_score_runtime_api::AggregatedEntryOf> {
+			unimplemented!()
+		}
+
+		fn combined_score(
+			_: Vec<u8>,
+			_: pallet_network_score_runtime_api::ScoreWindow,
+		) -> Option<pallet_network_score_runtime_api::AggregatedEntryOf> {
+			unimplemented!()
+		}
+	}
+
 	impl crate::fake_runtime_api::GetLastTimestamp<Block> for Runtime {
 		fn get_last_timestamp() -> u64 {
 			unimplemented!()
@@ -0,0 +1,465 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC interface for resolving DIDs.
+//!
+//! `did_resolve` turns the on-chain details of a `did:cord` identifier into a
+//! W3C DID Core document, so that clients do not have to map the SCALE encoded
+//! `DidApi` response onto a DID document themselves. DID URLs carrying a
+//! `versionTime` query are resolved at the last block produced at or before
+//! that time, and are not found if that time precedes the chain.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Decode;
+use cord_primitives::{AccountId, Block, BlockNumber, DidIdentifier, Hash};
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{ErrorObject, ErrorObjectOwned},
+};
+use pallet_did_runtime_api::{
+	DidApi as DidRuntimeApi, DidEncryptionKey, DidPublicKey, DidState, DidVerificationKey,
+	RawDidLinkedInfo,
+};
+use sc_client_api::{Backend, StorageProvider};
+use serde::{Deserialize, Serialize};
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_core::{crypto::Ss58Codec, hashing::twox_128, storage::StorageKey};
+
+/// The method prefix of CORD DIDs.
+const DID_PREFIX: &str = "did:cord:";
+/// The JSON-LD context of DID resolution results.
+const RESOLUTION_CONTEXT: &str = "https://w3id.org/did-resolution/v1";
+/// The JSON-LD context of DID documents.
+const DID_CONTEXT: &str = "https://www.w3.org/ns/did/v1";
+/// The JSON-LD context of `Multikey` verification methods.
+const MULTIKEY_CONTEXT: &str = "https://w3id.org/security/multikey/v1";
+/// The JSON-LD context of verification methods bound to an account.
+const BLOCKCHAIN_CONTEXT: &str = "https://w3id.org/security/suites/blockchain-2021/v1";
+/// The media type of the returned DID documents.
+const DID_CONTENT_TYPE: &str = "application/did+ld+json";
+
+/// A public key of the DID, expressed as a verification method.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationMethod {
+	pub id: String,
+	#[serde(rename = "type")]
+	pub method_type: String,
+	pub controller: String,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub public_key_multibase: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub blockchain_account_id: Option<String>,
+}
+
+/// A service endpoint of the DID.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Service {
+	pub id: String,
+	#[serde(rename = "type")]
+	pub service_type: Vec<String>,
+	pub service_endpoint: Vec<String>,
+}
+
+/// A W3C DID Core document in its JSON-LD representation.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DidDocument {
+	#[serde(rename = "@context")]
+	pub context: Vec<String>,
+	pub id: String,
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub also_known_as: Vec<String>,
+	pub verification_method: Vec<VerificationMethod>,
+	pub authentication: Vec<String>,
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub assertion_method: Vec<String>,
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub capability_delegation: Vec<String>,
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub key_agreement: Vec<String>,
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub service: Vec<Service>,
+}
+
+/// Metadata about the resolution process.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DidResolutionMetadata {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub content_type: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub error: Option<String>,
+}
+
+/// Metadata about the resolved DID document.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DidDocumentMetadata {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub deactivated: Option<bool>,
+}
+
+/// The result of resolving a DID, as defined by W3C DID Resolution.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DidResolution {
+	#[serde(rename = "@context")]
+	pub context: String,
+	pub did_document: Option<DidDocument>,
+	pub did_resolution_metadata: DidResolutionMetadata,
+	pub did_document_metadata: DidDocumentMetadata,
+}
+
+impl DidResolution {
+	fn resolved(document: DidDocument) -> Self {
+		Self {
+			context: RESOLUTION_CONTEXT.into(),
+			did_document: Some(document),
+			did_resolution_metadata: DidResolutionMetadata {
+				content_type: Some(DID_CONTENT_TYPE.into()),
+				error: None,
+			},
+			did_document_metadata: Default::default(),
+		}
+	}
+
+	fn deactivated() -> Self {
+		Self {
+			context: RESOLUTION_CONTEXT.into(),
+			did_document: None,
+			did_resolution_metadata: Default::default(),
+			did_document_metadata: DidDocumentMetadata { deactivated: Some(true) },
+		}
+	}
+
+	fn failed(error: &str) -> Self {
+		Self {
+			context: RESOLUTION_CONTEXT.into(),
+			did_document: None,
+			did_resolution_metadata: DidResolutionMetadata {
+				content_type: None,
+				error: Some(error.into()),
+			},
+			did_document_metadata: Default::default(),
+		}
+	}
+}
+
+#[rpc(server)]
+pub trait DidApi<BlockHash> {
+	/// Resolves a `did:cord` DID, optionally carrying a `versionTime` query,
+	/// into a W3C DID Core document along with its resolution metadata.
+	#[method(name = "did_resolve")]
+	fn resolve(&self, did: String, at: Option<BlockHash>) -> RpcResult<DidResolution>;
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+	/// Reading a block or its state failed.
+	BlockchainError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::BlockchainError => 2,
+		}
+	}
+}
+
+/// Provides RPC methods to resolve DIDs.
+pub struct Did<C, B> {
+	client: Arc<C>,
+	_marker: PhantomData<B>,
+}
+
+impl<C, B> Did<C, B> {
+	/// Creates a new instance of the Did RPC handler.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+impl<C, B> Did<C, B>
+where
+	C: HeaderBackend<Block> + StorageProvider<Block, B>,
+	B: Backend<Block>,
+{
+	/// Returns the hash of the last block up to `upper` whose timestamp is not
+	/// later than `time`, in milliseconds since the Unix epoch.
+	///
+	/// The genesis block carries no timestamp, so a time before the first
+	/// block is taken to precede the chain and yields `None`.
+	fn block_at_time(&self, upper: Hash, time: u64) -> RpcResult<Option<Hash>> {
+		let mut low: BlockNumber = 0;
+		let mut high = self
+			.client
+			.number(upper)
+			.map_err(blockchain_error)?
+			.ok_or_else(|| blockchain_error("Unknown block."))?;
+
+		while low < high {
+			let mid = low + (high - low + 1) / 2;
+			if self.timestamp(self.block_hash(mid)?)? <= time {
+				low = mid;
+			} else {
+				high = mid - 1;
+			}
+		}
+
+		if low == 0 {
+			return Ok(None);
+		}
+
+		self.block_hash(low).map(Some)
+	}
+
+	fn block_hash(&self, number: BlockNumber) -> RpcResult<Hash> {
+		self.client
+			.hash(number)
+			.map_err(blockchain_error)?
+			.ok_or_else(|| blockchain_error("Unknown block."))
+	}
+
+	/// Reads `Timestamp::Now` at the given block. The genesis block carries
+	/// no timestamp and is reported at the Unix epoch.
+	fn timestamp(&self, at: Hash) -> RpcResult<u64> {
+		let key = StorageKey([twox_128(b"Timestamp"), twox_128(b"Now")].concat());
+		let data = self.client.storage(at, &key).map_err(blockchain_error)?;
+
+		Ok(data.and_then(|data| u64::decode(&mut &data.0[..]).ok()).unwrap_or_default())
+	}
+}
+
+impl<C, B> DidApiServer<Hash> for Did<C, B>
+where
+	C: ProvideRuntimeApi<Block>
+		+ HeaderBackend<Block>
+		+ StorageProvider<Block, B>
+		+ Send
+		+ Sync
+		+ 'static,
+	C::Api: DidRuntimeApi<Block, DidIdentifier, AccountId, Hash, BlockNumber>,
+	B: Backend<Block> + Send + Sync + 'static,
+{
+	fn resolve(&self, did: String, at: Option<Hash>) -> RpcResult<DidResolution> {
+		let Some((identifier, version_time)) = parse_did_url(&did) else {
+			return Ok(DidResolution::failed("invalidDid"));
+		};
+
+		let info = self.client.info();
+		let mut at_hash = at.unwrap_or(info.best_hash);
+		if let Some(time) = version_time {
+			let Some(hash) = self.block_at_time(at_hash, time)? else {
+				return Ok(DidResolution::failed("notFound"));
+			};
+			at_hash = hash;
+		}
+
+		let api = self.client.runtime_api();
+		let version = api
+			.api_version::<dyn DidRuntimeApi<Block, DidIdentifier, AccountId, Hash, BlockNumber>>(
+				at_hash,
+			)
+			.map_err(|e| runtime_error("Unable to resolve DID.", e))?;
+
+		// Runtimes predating `resolve` can not tell a deleted DID apart from an
+		// unknown one.
+		let state = if version.map_or(false, |version| version >= 2) {
+			api.resolve(at_hash, identifier)
+		} else {
+			api.query(at_hash, identifier)
+				.map(|info| info.map_or(DidState::NotFound, DidState::Active))
+		}
+		.map_err(|e| runtime_error("Unable to resolve DID.", e))?;
+
+		Ok(match state {
+			DidState::Active(linked_info) =>
+				DidResolution::resolved(did_document(linked_info, &chain_id(&info.genesis_hash))),
+			DidState::Deactivated => DidResolution::deactivated(),
+			DidState::NotFound => DidResolution::failed("notFound"),
+		})
+	}
+}
+
+/// Splits a DID URL into the DID identifier and the optional `versionTime`.
+fn parse_did_url(did_url: &str) -> Option<(DidIdentifier, Option<u64>)> {
+	let (did, query) = match did_url.split_once('?') {
+		Some((did, query)) => (did, Some(query)),
+		None => (did_url, None),
+	};
+	let identifier = DidIdentifier::from_ss58check(did.strip_prefix(DID_PREFIX)?).ok()?;
+
+	let version_time = match query {
+		Some(query) => {
+			let value = query.strip_prefix("versionTime=")?;
+			Some(parse_version_time(&value.replace("%3A", ":").replace("%3a", ":"))?)
+		},
+		None => None,
+	};
+
+	Some((identifier, version_time))
+}
+
+/// Parses a UTC timestamp of the form `YYYY-MM-DDTHH:MM:SS[.fff]Z` into
+/// milliseconds since the Unix epoch.
+fn parse_version_time(value: &str) -> Option<u64> {
+	let (date, time) = value.strip_suffix('Z')?.split_once('T')?;
+
+	let mut date = date.splitn(3, '-').map(|part| part.parse::<u64>().ok());
+	let (year, month, day) = (date.next()??, date.next()??, date.next()??);
+	let mut time = time.splitn(3, ':');
+	let hour = time.next()?.parse::<u64>().ok()?;
+	let minute = time.next()?.parse::<u64>().ok()?;
+	let second = time.next()?.split('.').next()?.parse::<u64>().ok()?;
+
+	if !(1970..=9999).contains(&year) ||
+		!(1..=12).contains(&month) ||
+		!(1..=31).contains(&day) ||
+		hour > 23 ||
+		minute > 59 ||
+		second > 59
+	{
+		return None;
+	}
+
+	// Days since the Unix epoch in the proleptic Gregorian calendar, counting
+	// years from March so that the leap day falls at the end of the year.
+	let (year, month) = if month <= 2 { (year - 1, month + 9) } else { (year, month - 3) };
+	let era = year / 400;
+	let year_of_era = year - era * 400;
+	let day_of_year = (153 * month + 2) / 5 + day - 1;
+	let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+	let days = era * 146_097 + day_of_era - 719_468;
+
+	Some((days * 86_400 + hour * 3_600 + minute * 60 + second) * 1_000)
+}
+
+/// Returns the CAIP-2 reference of the chain, made of the first 16 bytes of
+/// the genesis hash.
+fn chain_id(genesis_hash: &Hash) -> String {
+	genesis_hash.as_bytes()[..16].iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+/// Encodes a public key as a base58btc multibase string, prefixed by its
+/// multicodec code. All codes used here lie in `0x80..=0xff`, whose unsigned
+/// varint is the code followed by `0x01`.
+fn multibase(codec: u8, key: &[u8]) -> String {
+	let mut bytes = vec![codec, 0x01];
+	bytes.extend_from_slice(key);
+	format!("z{}", bs58::encode(bytes).into_string())
+}
+
+fn verification_method(
+	id: String,
+	controller: &str,
+	key: &DidPublicKey<AccountId>,
+	chain_id: &str,
+) -> VerificationMethod {
+	let (method_type, public_key_multibase, blockchain_account_id) = match key {
+		DidPublicKey::PublicVerificationKey(DidVerificationKey::Ed25519(key)) =>
+			("Multikey", Some(multibase(0xed, key.as_ref())), None),
+		DidPublicKey::PublicVerificationKey(DidVerificationKey::Sr25519(key)) =>
+			("Multikey", Some(multibase(0xef, key.as_ref())), None),
+		DidPublicKey::PublicVerificationKey(DidVerificationKey::Ecdsa(key)) =>
+			("Multikey", Some(multibase(0xe7, key.as_ref())), None),
+		DidPublicKey::PublicVerificationKey(DidVerificationKey::Account(account)) => (
+			"BlockchainVerificationMethod2021",
+			None,
+			Some(format!("polkadot:{}:{}", chain_id, account.to_ss58check())),
+		),
+		DidPublicKey::PublicEncryptionKey(DidEncryptionKey::X25519(key)) =>
+			("Multikey", Some(multibase(0xec, key)), None),
+	};
+
+	VerificationMethod {
+		id,
+		method_type: method_type.into(),
+		controller: controller.into(),
+		public_key_multibase,
+		blockchain_account_id,
+	}
+}
+
+/// Builds the DID document of an active DID.
+fn did_document(
+	info: RawDidLinkedInfo<DidIdentifier, AccountId, Hash, BlockNumber>,
+	chain_id: &str,
+) -> DidDocument {
+	let did = format!("{}{}", DID_PREFIX, info.identifier.to_ss58check());
+	let key_id = |key: &Hash| format!("{}#{:#x}", did, key);
+	let details = info.details;
+
+	let verification_method: Vec<_> = details
+		.public_keys
+		.iter()
+		.map(|(key, key_details)| {
+			verification_method(key_id(key), &did, &key_details.key, chain_id)
+		})
+		.collect();
+
+	let mut context = vec![DID_CONTEXT.to_string(), MULTIKEY_CONTEXT.to_string()];
+	if verification_method.iter().any(|method| method.blockchain_account_id.is_some()) {
+		context.push(BLOCKCHAIN_CONTEXT.into());
+	}
+
+	let service = info
+		.service_endpoints
+		.into_iter()
+		.map(|endpoint| Service {
+			id: format!("{}#{}", did, bytes_to_string(&endpoint.id).trim_start_matches('#')),
+			service_type: endpoint.service_types.iter().map(|t| bytes_to_string(t)).collect(),
+			service_endpoint: endpoint.urls.iter().map(|url| bytes_to_string(url)).collect(),
+		})
+		.collect();
+
+	let also_known_as =
+		info.name.iter().map(|name| format!("w3n:{}", bytes_to_string(name))).collect();
+
+	DidDocument {
+		context,
+		also_known_as,
+		verification_method,
+		authentication: vec![key_id(&details.authentication_key)],
+		assertion_method: details.assertion_key.iter().map(key_id).collect(),
+		capability_delegation: details.delegation_key.iter().map(key_id).collect(),
+		key_agreement: details.key_agreement_keys.iter().map(key_id).collect(),
+		service,
+		id: did,
+	}
+}
+
+fn bytes_to_string(bytes: &[u8]) -> String {
+	String::from_utf8_lossy(bytes).into_owned()
+}
+
+fn runtime_error(message: &'static str, e: sp_api::ApiError) -> ErrorObjectOwned {
+	ErrorObject::owned(Error::RuntimeError.into(), message, Some(e.to_string()))
+}
+
+fn blockchain_error(e: impl ToString) -> ErrorObjectOwned {
+	ErrorObject::owned(Error::BlockchainError.into(), "Unable to read the chain.", Some(e.to_string()))
+}
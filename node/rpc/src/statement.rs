@@ -0,0 +1,289 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC interface for querying statements.
+//!
+//! Verifying a credential requires the statement details, the revocation
+//! status of its digests and the attached presentations. This module exposes
+//! all of them through a single call backed by the `StatementApi` runtime API.
+
+use std::sync::Arc;
+
+use cord_primitives::{Block, BlockNumber, DidIdentifier, Hash};
+use identifier::Ss58Identifier;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{ErrorCode, ErrorObject, ErrorObjectOwned},
+};
+use pallet_statement_runtime_api::{
+	PresentationLocation, PresentationTypeOf, StatementApi as StatementRuntimeApi, StatementInfo,
+	StatementPresentationInfo, StatementStatus,
+};
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+
+/// A digest anchored against a statement.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct StatementEntry {
+	pub digest: Hash,
+	pub creator: DidIdentifier,
+	pub status: StatementStatus,
+}
+
+/// A presentation attached to a statement.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct StatementPresentation {
+	pub presentation_digest: Hash,
+	pub creator: DidIdentifier,
+	pub presentation_type: PresentationTypeOf,
+	pub digest: Hash,
+	pub mime_type: Option<String>,
+	pub language: Option<String>,
+	pub uri: Option<String>,
+	pub content_hash: Option<Hash>,
+}
+
+impl From<StatementPresentationInfo<Hash, DidIdentifier>> for StatementPresentation {
+	fn from(info: StatementPresentationInfo<Hash, DidIdentifier>) -> Self {
+		let (mime_type, language, location) = match info.metadata {
+			Some(metadata) => (metadata.mime_type, metadata.language, metadata.location),
+			None => (None, None, None),
+		};
+		let (uri, content_hash) = match location {
+			Some(PresentationLocation::Uri(uri)) => (Some(bytes_to_string(&uri)), None),
+			Some(PresentationLocation::ContentHash(hash)) => (None, Some(hash)),
+			None => (None, None),
+		};
+		Self {
+			presentation_digest: info.presentation_digest,
+			creator: info.creator,
+			presentation_type: info.presentation_type,
+			digest: info.digest,
+			mime_type: mime_type.as_deref().map(bytes_to_string),
+			language: language.as_deref().map(bytes_to_string),
+			uri,
+			content_hash,
+		}
+	}
+}
+
+/// A temporary hold placed on a statement.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct StatementSuspension {
+	pub reason: u16,
+	pub author: DidIdentifier,
+	pub suspended_at: BlockNumber,
+	pub lift_at: Option<BlockNumber>,
+}
+
+/// The JSON representation of a statement returned to verifiers.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct StatementDetails {
+	pub identifier: String,
+	pub digest: Hash,
+	pub space: String,
+	pub schema: Option<String>,
+	pub status: StatementStatus,
+	pub valid_from: Option<BlockNumber>,
+	pub valid_until: Option<BlockNumber>,
+	pub suspension: Option<StatementSuspension>,
+	pub entries: Vec<StatementEntry>,
+	pub presentations: Vec<StatementPresentation>,
+}
+
+impl From<StatementInfo<Ss58Identifier, Hash, DidIdentifier, BlockNumber>> for StatementDetails {
+	fn from(info: StatementInfo<Ss58Identifier, Hash, DidIdentifier, BlockNumber>) -> Self {
+		Self {
+			identifier: identifier_to_string(&info.identifier),
+			digest: info.digest,
+			space: identifier_to_string(&info.space),
+			schema: info.schema.as_ref().map(identifier_to_string),
+			status: info.status,
+			valid_from: info.validity.as_ref().and_then(|v| v.valid_from),
+			valid_until: info.validity.as_ref().and_then(|v| v.valid_until),
+			suspension: info.suspension.map(|s| StatementSuspension {
+				reason: s.reason,
+				author: s.author,
+				suspended_at: s.suspended_at,
+				lift_at: s.lift_at,
+			}),
+			entries: info
+				.entries
+				.into_iter()
+				.map(|e| StatementEntry { digest: e.digest, creator: e.creator, status: e.status })
+				.collect(),
+			presentations: info.presentations.into_iter().map(Into::into).collect(),
+		}
+	}
+}
+
+fn identifier_to_string(identifier: &Ss58Identifier) -> String {
+	bytes_to_string(identifier.inner())
+}
+
+fn bytes_to_string(bytes: &[u8]) -> String {
+	String::from_utf8_lossy(bytes).into_owned()
+}
+
+#[rpc(server)]
+pub trait StatementApi<BlockHash> {
+	/// Returns the details, validity window, digest statuses and presentations
+	/// of a statement.
+	#[method(name = "statement_query")]
+	fn query(
+		&self,
+		identifier: String,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<StatementDetails>>;
+
+	/// Resolves a statement or presentation digest anchored in a space and
+	/// returns the details of the statement it belongs to.
+	#[method(name = "statement_queryByDigest")]
+	fn query_by_digest(
+		&self,
+		digest: Hash,
+		space: String,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<StatementDetails>>;
+
+	/// Returns the status of a single digest anchored against a statement.
+	#[method(name = "statement_digestStatus")]
+	fn digest_status(
+		&self,
+		identifier: String,
+		digest: Hash,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<StatementStatus>>;
+
+	/// Returns the presentations attached to a statement with their MIME
+	/// type, language and location, optionally restricted to a single
+	/// presentation type.
+	#[method(name = "statement_presentations")]
+	fn presentations(
+		&self,
+		identifier: String,
+		presentation_type: Option<PresentationTypeOf>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<StatementPresentation>>;
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+/// Provides RPC methods to query statements.
+pub struct Statement<C> {
+	client: Arc<C>,
+}
+
+impl<C> Statement<C> {
+	/// Creates a new instance of the Statement RPC handler.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client }
+	}
+}
+
+fn parse_identifier(identifier: String) -> Result<Ss58Identifier, ErrorObjectOwned> {
+	Ss58Identifier::try_from(identifier.into_bytes()).map_err(|e| {
+		ErrorObject::owned(
+			ErrorCode::InvalidParams.code(),
+			"Invalid identifier.",
+			Some(format!("{:?}", e)),
+		)
+	})
+}
+
+fn runtime_error(message: &'static str, e: sp_api::ApiError) -> ErrorObjectOwned {
+	ErrorObject::owned(Error::RuntimeError.into(), message, Some(e.to_string()))
+}
+
+impl<C> StatementApiServer<Hash> for Statement<C>
+where
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: StatementRuntimeApi<Block, Ss58Identifier, Hash, DidIdentifier, BlockNumber>,
+{
+	fn query(&self, identifier: String, at: Option<Hash>) -> RpcResult<Option<StatementDetails>> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		let identifier = parse_identifier(identifier)?;
+
+		api.query(at_hash, identifier)
+			.map(|info| info.map(Into::into))
+			.map_err(|e| runtime_error("Unable to query statement.", e))
+	}
+
+	fn query_by_digest(
+		&self,
+		digest: Hash,
+		space: String,
+		at: Option<Hash>,
+	) -> RpcResult<Option<StatementDetails>> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		let space = parse_identifier(space)?;
+
+		api.query_by_digest(at_hash, digest, space)
+			.map(|info| info.map(Into::into))
+			.map_err(|e| runtime_error("Unable to query statement by digest.", e))
+	}
+
+	fn digest_status(
+		&self,
+		identifier: String,
+		digest: Hash,
+		at: Option<Hash>,
+	) -> RpcResult<Option<StatementStatus>> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		let identifier = parse_identifier(identifier)?;
+
+		api.digest_status(at_hash, identifier, digest)
+			.map_err(|e| runtime_error("Unable to query statement digest status.", e))
+	}
+
+	fn presentations(
+		&self,
+		identifier: String,
+		presentation_type: Option<PresentationTypeOf>,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<StatementPresentation>> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		let identifier = parse_identifier(identifier)?;
+
+		api.presentations(at_hash, identifier, presentation_type)
+			.map(|presentations| presentations.into_iter().map(Into::into).collect())
+			.map_err(|e| runtime_error("Unable to query statement presentations.", e))
+	}
+}
@@ -251,7 +251,7 @@ pub mod pallet {
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		#[pallet::call_index(0)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::create())]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::create().saturating_add(pallet_chain_space::Pallet::<T>::authorization_weight()))]
 		/// Creates a new asset entry within a specified space.
 		///
 		/// This function is responsible for creating a new asset entry in the blockchain.
@@ -360,7 +360,7 @@ pub mod pallet {
 		/// # Events
 		/// - `Event::Issue`: Emitted when asset instances are successfully issued.
 		#[pallet::call_index(1)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::issue())]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::issue().saturating_add(pallet_chain_space::Pallet::<T>::authorization_weight()))]
 		pub fn issue(
 			origin: OriginFor<T>,
 			entry: AssetIssuanceEntryOf<T>,
@@ -629,7 +629,7 @@ pub mod pallet {
 		/// - `Event::Create`: Emitted when a VC asset is successfully created.
 		// TODO: Set actual weights
 		#[pallet::call_index(4)]
-		#[pallet::weight({0})]
+		#[pallet::weight(pallet_chain_space::Pallet::<T>::authorization_weight())]
 		pub fn vc_create(
 			origin: OriginFor<T>,
 			asset_qty: AssetQtyOf,
@@ -709,7 +709,7 @@ pub mod pallet {
 		/// # Events
 		/// - `Event::Issue`: Emitted when a VC asset instance is successfully issued.
 		#[pallet::call_index(5)]
-		#[pallet::weight({0})]
+		#[pallet::weight(pallet_chain_space::Pallet::<T>::authorization_weight())]
 		pub fn vc_issue(
 			origin: OriginFor<T>,
 			entry: AssetIssuanceEntryOf<T>,
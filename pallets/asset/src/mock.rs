@@ -80,6 +80,8 @@ impl Config for Test {
 parameter_types! {
 	#[derive(Debug, Clone)]
 	pub const MaxSpaceDelegates: u32 = 5u32;
+	pub const MaxSubspaceDepth: u32 = 3u32;
+	pub const MaxDelegationExpirationsPerBlock: u32 = 5u32;
 }
 
 pub struct NetworkPermission;
@@ -95,18 +97,14 @@ impl pallet_chain_space::Config for Test {
 	type OriginSuccess = mock_origin::DoubleOrigin<AccountId, SubjectId>;
 	type SpaceCreatorId = SubjectId;
 	type MaxSpaceDelegates = MaxSpaceDelegates;
+	type MaxSubspaceDepth = MaxSubspaceDepth;
+	type MaxDelegationExpirationsPerBlock = MaxDelegationExpirationsPerBlock;
 	type ChainSpaceOrigin = EnsureRoot<AccountId>;
 	type NetworkPermission = NetworkPermission;
 	type WeightInfo = ();
 }
 
-parameter_types! {
-	pub const MaxEventsHistory: u32 = 6u32;
-}
-
-impl identifier::Config for Test {
-	type MaxEventsHistory = MaxEventsHistory;
-}
+impl identifier::Config for Test {}
 
 parameter_types! {
 	storage SpaceEvents: u32 = 0;
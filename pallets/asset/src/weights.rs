@@ -64,18 +64,19 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Asset::Assets` (r:1 w:1)
 	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3202), added: 5677, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `Asset::AssetLookup` (r:0 w:1)
 	/// Proof: `Asset::AssetLookup` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
 	fn create() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `719`
-		//  Estimated: `6667`
-		// Minimum execution time: 37_750_000 picoseconds.
-		Weight::from_parts(38_870_000, 6667)
+		//  Estimated: `13575`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(38_870_000, 13575)
 			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -85,50 +86,53 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3202), added: 5677, mode: `MaxEncodedLen`)
 	/// Storage: `Asset::Distribution` (r:1 w:1)
 	/// Proof: `Asset::Distribution` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:2 w:2)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:2 w:2)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:2)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `Asset::Issuance` (r:0 w:1)
 	/// Proof: `Asset::Issuance` (`max_values`: None, `max_size`: Some(3334), added: 5809, mode: `MaxEncodedLen`)
 	/// Storage: `Asset::AssetLookup` (r:0 w:1)
 	/// Proof: `Asset::AssetLookup` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
 	fn issue() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `968`
-		//  Estimated: `6667`
-		// Minimum execution time: 53_110_000 picoseconds.
-		Weight::from_parts(54_730_000, 6667)
+		//  Estimated: `18607`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(54_730_000, 18607)
 			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
 	/// Storage: `Asset::Assets` (r:1 w:0)
 	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3202), added: 5677, mode: `MaxEncodedLen`)
 	/// Storage: `Asset::Issuance` (r:1 w:1)
 	/// Proof: `Asset::Issuance` (`max_values`: None, `max_size`: Some(3334), added: 5809, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	fn transfer() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `864`
-		//  Estimated: `6799`
-		// Minimum execution time: 30_110_000 picoseconds.
-		Weight::from_parts(30_910_000, 6799)
+		//  Estimated: `14044`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(30_910_000, 14044)
 			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Asset::Assets` (r:1 w:0)
 	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3202), added: 5677, mode: `MaxEncodedLen`)
 	/// Storage: `Asset::Issuance` (r:1 w:1)
 	/// Proof: `Asset::Issuance` (`max_values`: None, `max_size`: Some(3334), added: 5809, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	fn status_change() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `864`
-		//  Estimated: `6799`
-		// Minimum execution time: 29_790_000 picoseconds.
-		Weight::from_parts(30_700_000, 6799)
+		//  Estimated: `14044`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(30_700_000, 14044)
 			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 }
 
@@ -140,18 +144,19 @@ impl WeightInfo for () {
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Asset::Assets` (r:1 w:1)
 	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3202), added: 5677, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `Asset::AssetLookup` (r:0 w:1)
 	/// Proof: `Asset::AssetLookup` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
 	fn create() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `719`
-		//  Estimated: `6667`
-		// Minimum execution time: 37_750_000 picoseconds.
-		Weight::from_parts(38_870_000, 6667)
+		//  Estimated: `13575`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(38_870_000, 13575)
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -161,49 +166,52 @@ impl WeightInfo for () {
 	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3202), added: 5677, mode: `MaxEncodedLen`)
 	/// Storage: `Asset::Distribution` (r:1 w:1)
 	/// Proof: `Asset::Distribution` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:2 w:2)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:2 w:2)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:2)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `Asset::Issuance` (r:0 w:1)
 	/// Proof: `Asset::Issuance` (`max_values`: None, `max_size`: Some(3334), added: 5809, mode: `MaxEncodedLen`)
 	/// Storage: `Asset::AssetLookup` (r:0 w:1)
 	/// Proof: `Asset::AssetLookup` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
 	fn issue() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `968`
-		//  Estimated: `6667`
-		// Minimum execution time: 53_110_000 picoseconds.
-		Weight::from_parts(54_730_000, 6667)
+		//  Estimated: `18607`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(54_730_000, 18607)
 			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
 	/// Storage: `Asset::Assets` (r:1 w:0)
 	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3202), added: 5677, mode: `MaxEncodedLen`)
 	/// Storage: `Asset::Issuance` (r:1 w:1)
 	/// Proof: `Asset::Issuance` (`max_values`: None, `max_size`: Some(3334), added: 5809, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	fn transfer() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `864`
-		//  Estimated: `6799`
-		// Minimum execution time: 30_110_000 picoseconds.
-		Weight::from_parts(30_910_000, 6799)
+		//  Estimated: `14044`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(30_910_000, 14044)
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Asset::Assets` (r:1 w:0)
 	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3202), added: 5677, mode: `MaxEncodedLen`)
 	/// Storage: `Asset::Issuance` (r:1 w:1)
 	/// Proof: `Asset::Issuance` (`max_values`: None, `max_size`: Some(3334), added: 5809, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	fn status_change() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `864`
-		//  Estimated: `6799`
-		// Minimum execution time: 29_790_000 picoseconds.
-		Weight::from_parts(30_700_000, 6799)
+		//  Estimated: `14044`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(30_700_000, 14044)
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 }
@@ -23,6 +23,20 @@ pub fn generate_authorization_id<T: Config>(digest: &SpaceCodeOf<T>) -> Authoriz
 
 const SEED: u32 = 0;
 
+/// Schedules `len` delegations without limits to be pruned at block `at`.
+fn fill_expirations<T: Config>(at: BlockNumberFor<T>, len: u32) {
+	let ids: Vec<AuthorizationIdOf> = (0..len)
+		.map(|i| {
+			let digest = <T as frame_system::Config>::Hashing::hash(&i.encode()[..]);
+			generate_authorization_id::<T>(&digest)
+		})
+		.collect();
+	AuthorizationExpirations::<T>::insert(
+		at,
+		BoundedVec::try_from(ids).expect("Expirations should fit."),
+	);
+}
+
 benchmarks! {
 		where_clause {
 			where
@@ -59,7 +73,7 @@ benchmarks! {
 			Pallet::<T>::approve(chain_space_origin, space_id.clone(), capacity ).expect("Approval should not fail.");
 
 			let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did);
-		}: _<T::RuntimeOrigin>(origin, space_id.clone(), delegate_did.clone(), authorization_id  )
+		}: _<T::RuntimeOrigin>(origin, space_id.clone(), delegate_did.clone(), authorization_id, None, None )
 		verify {
 			assert_last_event::<T>(Event::Authorization { space: space_id, authorization: delegate_authorization_id, delegate: delegate_did,  }.into());
 		}
@@ -93,7 +107,7 @@ benchmarks! {
 			 Pallet::<T>::create(origin.clone(), space_digest )?;
 			 Pallet::<T>::approve(root_origin, space_id.clone(), capacity )?;
 
-		}: _<T::RuntimeOrigin>(origin, space_id.clone(), delegate_did.clone(), authorization_id  )
+		}: _<T::RuntimeOrigin>(origin, space_id.clone(), delegate_did.clone(), authorization_id, None, None )
 		verify {
 			 assert_last_event::<T>(Event::Authorization { space: space_id, authorization: delegate_authorization_id, delegate: delegate_did,  }.into());
 		}
@@ -127,7 +141,7 @@ benchmarks! {
 			 Pallet::<T>::create(origin.clone(), space_digest )?;
 			 Pallet::<T>::approve(root_origin, space_id.clone(), capacity )?;
 
-		 }: _<T::RuntimeOrigin>(origin, space_id.clone(), delegate_did.clone(), authorization_id  )
+		 }: _<T::RuntimeOrigin>(origin, space_id.clone(), delegate_did.clone(), authorization_id, None, None )
 		 verify {
 			 assert_last_event::<T>(Event::Authorization { space: space_id, authorization: delegate_authorization_id, delegate: delegate_did,  }.into());
 		 }
@@ -161,7 +175,7 @@ benchmarks! {
 
 			 Pallet::<T>::create(origin.clone(), space_digest )?;
 			 Pallet::<T>::approve(root_origin, space_id.clone(), capacity )?;
-			 Pallet::<T>::add_delegate(origin.clone(), space_id.clone(), delegate_did, authorization_id.clone() )?;
+			 Pallet::<T>::add_delegate(origin.clone(), space_id.clone(), delegate_did, authorization_id.clone(), None, None )?;
 
 		}: _<T::RuntimeOrigin>(origin, space_id.clone(), delegate_authorization_id.clone(), authorization_id)
 		verify {
@@ -419,6 +433,147 @@ benchmarks! {
 			 assert_last_event::<T>(Event::Create { space: subspace_id, creator: did, authorization: authorization_id }.into());
 		 }
 
+		propagate_usage {
+			let d in 1 .. T::MaxSubspaceDepth::get();
+
+			let caller: T::AccountId = account("caller", 0, SEED);
+			let did: T::SpaceCreatorId = account("did", 0, SEED);
+			let space = [2u8; 256].to_vec();
+			let capacity = 1_000_000u64;
+
+			let space_digest = <T as frame_system::Config>::Hashing::hash(&space.encode()[..]);
+			let id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+			);
+			let top_space_id: SpaceIdOf = generate_space_id::<T>(&id_digest);
+
+			let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did.clone());
+
+			Pallet::<T>::create(origin.clone(), space_digest )?;
+			Pallet::<T>::approve(RawOrigin::Root.into(), top_space_id.clone(), capacity )?;
+
+			/* Nest `d` bounded sub-spaces, so that the usage of the last one is rolled up through `d` ancestors */
+			let mut space_id = top_space_id.clone();
+			for level in 0..d {
+				let subspace = [level as u8 + 3; 256].to_vec();
+				let subspace_digest = <T as frame_system::Config>::Hashing::hash(&subspace.encode()[..]);
+				let sub_id_digest = <T as frame_system::Config>::Hashing::hash(
+					&[&subspace_digest.encode()[..], &did.encode()[..]].concat()[..],
+				);
+				Pallet::<T>::subspace_create(origin.clone(), subspace_digest, Some(capacity >> (level + 1)), space_id.clone())?;
+				space_id = generate_space_id::<T>(&sub_id_digest);
+			}
+			let details = Spaces::<T>::get(&space_id).expect("Sub-space should exist.");
+
+		}: { Pallet::<T>::propagate_usage(&space_id, &details, 1, true).expect("Usage should roll up.") }
+		verify {
+			assert_eq!(Spaces::<T>::get(&top_space_id).map(|s| s.txn_count), Some(d as u64 + 1));
+		}
+
+		consume_delegation {
+			let caller: T::AccountId = account("caller", 0, SEED);
+			let did: T::SpaceCreatorId = account("did", 0, SEED);
+			let delegate_did: T::SpaceCreatorId = account("did", 1, SEED);
+			let space = [2u8; 256].to_vec();
+			let capacity = 5u64;
+
+			let space_digest = <T as frame_system::Config>::Hashing::hash(&space.encode()[..]);
+			let id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+			);
+			let space_id: SpaceIdOf = generate_space_id::<T>(&id_digest);
+
+			let auth_id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+			);
+			let authorization_id: AuthorizationIdOf = generate_authorization_id::<T>(&auth_id_digest);
+
+			let delegate_id_digest = T::Hashing::hash(
+				&[&space_id.encode()[..], &delegate_did.encode()[..], &did.encode()[..]].concat()[..],
+			);
+			let delegate_authorization_id = generate_authorization_id::<T>(&delegate_id_digest);
+
+			let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did);
+
+			Pallet::<T>::create(origin.clone(), space_digest )?;
+			Pallet::<T>::approve(RawOrigin::Root.into(), space_id.clone(), capacity )?;
+			Pallet::<T>::add_delegate(origin, space_id, delegate_did, authorization_id, None, Some(1))?;
+
+			/* The use exhausts the quota, which schedules the delegation in an almost full bucket */
+			let prune_at = frame_system::Pallet::<T>::block_number() + One::one();
+			fill_expirations::<T>(prune_at, T::MaxDelegationExpirationsPerBlock::get() - 1);
+
+		}: { Pallet::<T>::consume_delegation(&delegate_authorization_id).expect("Delegation should be usable.") }
+		verify {
+			assert_eq!(
+				AuthorizationExpirations::<T>::get(prune_at).len() as u32,
+				T::MaxDelegationExpirationsPerBlock::get()
+			);
+		}
+
+		visit_expiration_block {
+			let block = frame_system::Pallet::<T>::block_number();
+			fill_expirations::<T>(block, T::MaxDelegationExpirationsPerBlock::get());
+			ExpirationCursor::<T>::put(block);
+
+			/* Only leave room for visiting the block, so the full bucket is read and written back */
+			let limit = <T as Config>::WeightInfo::visit_expiration_block();
+
+		}: { Pallet::<T>::prune_lapsed_delegations(block, limit) }
+		verify {
+			assert_eq!(
+				AuthorizationExpirations::<T>::get(block).len() as u32,
+				T::MaxDelegationExpirationsPerBlock::get()
+			);
+		}
+
+		prune_lapsed_delegation {
+			let caller: T::AccountId = account("caller", 0, SEED);
+			let did: T::SpaceCreatorId = account("did", 0, SEED);
+			let delegate_did: T::SpaceCreatorId = account("did", 1, SEED);
+			let space = [2u8; 256].to_vec();
+			let capacity = 5u64;
+
+			let space_digest = <T as frame_system::Config>::Hashing::hash(&space.encode()[..]);
+			let id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+			);
+			let space_id: SpaceIdOf = generate_space_id::<T>(&id_digest);
+
+			let auth_id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+			);
+			let authorization_id: AuthorizationIdOf = generate_authorization_id::<T>(&auth_id_digest);
+
+			let delegate_id_digest = T::Hashing::hash(
+				&[&space_id.encode()[..], &delegate_did.encode()[..], &did.encode()[..]].concat()[..],
+			);
+			let delegate_authorization_id = generate_authorization_id::<T>(&delegate_id_digest);
+
+			let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did);
+			let expiry = frame_system::Pallet::<T>::block_number() + One::one();
+
+			Pallet::<T>::create(origin.clone(), space_digest )?;
+			Pallet::<T>::approve(RawOrigin::Root.into(), space_id.clone(), capacity )?;
+			Pallet::<T>::add_delegate(origin, space_id.clone(), delegate_did.clone(), authorization_id, Some(expiry), None)?;
+
+			/* The lapsed delegate is found at the end of a full list of delegates */
+			let mut delegates: Vec<T::SpaceCreatorId> = (2..T::MaxSpaceDelegates::get())
+				.map(|i| account("did", i, SEED))
+				.collect();
+			delegates.push(delegate_did.clone());
+			Delegates::<T>::insert(
+				&space_id,
+				BoundedVec::try_from(delegates).expect("Delegates should fit."),
+			);
+			frame_system::Pallet::<T>::set_block_number(expiry);
+
+		}: { Pallet::<T>::prune_delegation(delegate_authorization_id.clone(), &expiry) }
+		verify {
+			assert!(!Pallet::<T>::is_a_delegate(&space_id, delegate_did));
+			assert_last_event::<T>(Event::AuthorizationLapsed { space: space_id, authorization: delegate_authorization_id }.into());
+		}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 
 }
@@ -42,10 +42,29 @@
 //! - `archive`: Marks a space as archived, effectively freezing its state.
 //! - `restore`: Unarchives a space, returning it to active status.
 //! - `add_delegate`: Adds a delegate to a space, granting them specific permissions.
+//! - `add_delegate_with_permissions`: Adds a delegate to a space with an explicit set of
+//!   permissions.
 //! - `add_admin_delegate`: Adds an admin delegate to a space, granting them administrative
 //!   permissions.
 //! - `add_audit_delegate`: Adds an audit delegate to a space, granting them audit permissions.
 //! - `remove_delegate`: Removes a delegate from a space, revoking their permissions.
+//! - `subspace_create`: Creates a sub-space that draws its capacity from a parent space.
+//! - `update_transaction_capacity_sub`: Updates the capacity a sub-space reserves from its parent.
+//!
+//! ## Sub-spaces
+//!
+//! Spaces can be arranged in a hierarchy. A sub-space reserves its capacity
+//! from its parent, and usage recorded in any sub-space is rolled up to all of
+//! its ancestors, converting the reserved capacity of each ancestor into
+//! usage. The `txn_count` of a space therefore includes the usage of all its
+//! descendants. Archiving a sub-space hands its unused capacity back to its
+//! parent.
+//!
+//! ## Delegation Limits
+//!
+//! Delegates can be granted an optional expiry block and an optional quota of
+//! authorized operations. Expired or exhausted delegations are rejected when
+//! used and are pruned automatically at the start of a block.
 //!
 //! ## Permissions
 //!
@@ -53,6 +72,11 @@
 //! can perform within a space. Permissions are granular and can be assigned to
 //! different roles, such as an admin or a regular delegate.
 //!
+//! Besides `ASSERT`, `DELEGATE` and `ADMIN`, separate bits govern revoking
+//! (`REVOKE`), updating (`UPDATE`) and removing (`REMOVE`) statements, creating
+//! schemas (`SCHEMA_CREATE`) and managing presentations (`PRESENTATION`), so a
+//! delegate that may anchor statements cannot necessarily alter them.
+//!
 //! ## Data Privacy
 //!
 //! The ChainSpace pallet is designed with data privacy as a core consideration.
@@ -86,6 +110,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 
+pub mod migrations;
 pub mod weights;
 
 #[cfg(any(feature = "mock", test))]
@@ -97,15 +122,21 @@ pub mod benchmarking;
 #[cfg(test)]
 mod tests;
 
-use frame_support::{ensure, storage::types::StorageMap, BoundedVec};
+use frame_support::{
+	ensure, pallet_prelude::Weight, storage::types::StorageMap, traits::Get, weights::WeightMeter,
+	BoundedVec,
+};
 pub mod types;
 pub use crate::{pallet::*, types::*, weights::WeightInfo};
 use codec::Encode;
+use cord_utilities::delegation::{remove_delegate, DelegationLimits, DelegationLimitsError};
+use frame_system::pallet_prelude::BlockNumberFor;
 use identifier::{
 	types::{CallTypeOf, IdentifierTypeOf, Timepoint},
 	EventEntryOf,
 };
-use sp_runtime::traits::{Hash, UniqueSaturatedInto};
+use sp_runtime::traits::{Hash, One, Saturating, UniqueSaturatedInto};
+use sp_std::{vec, vec::Vec};
 
 /// Type of a CORD account.
 pub(crate) type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
@@ -121,6 +152,10 @@ pub type SpaceCodeOf<T> = <T as frame_system::Config>::Hash;
 pub type SpaceDetailsOf<T> = SpaceDetails<SpaceCodeOf<T>, SpaceCreatorOf<T>, StatusOf, SpaceIdOf>;
 
 pub type SpaceAuthorizationOf<T> = SpaceAuthorization<SpaceIdOf, SpaceCreatorOf<T>, Permissions>;
+/// Type of the limits attached to a space delegation.
+pub type DelegationLimitsOf<T> = DelegationLimits<BlockNumberFor<T>>;
+/// Type of a node in a space tree.
+pub type SpaceTreeNodeOf<T> = SpaceTreeNode<SpaceIdOf, SpaceCreatorOf<T>, StatusOf>;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -132,7 +167,7 @@ pub mod pallet {
 	pub use identifier::{IdentifierCreator, IdentifierTimeline, IdentifierType, Ss58Identifier};
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config + identifier::Config {
@@ -149,6 +184,14 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxSpaceDelegates: Get<u32>;
 
+		/// The maximum nesting depth of sub-spaces below a top level space.
+		#[pallet::constant]
+		type MaxSubspaceDepth: Get<u32>;
+
+		/// The maximum number of delegations that can lapse in a single block.
+		#[pallet::constant]
+		type MaxDelegationExpirationsPerBlock: Get<u32>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -158,7 +201,11 @@ pub mod pallet {
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::prune_lapsed_delegations(n, remaining_weight)
+		}
+	}
 
 	/// Space information stored on chain.
 	/// It maps from an identifier to its details.
@@ -183,6 +230,33 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Sub-spaces stored on chain.
+	/// It maps from a parent space identifier to its direct sub-spaces.
+	#[pallet::storage]
+	pub type SubSpaces<T> =
+		StorageDoubleMap<_, Blake2_128Concat, SpaceIdOf, Blake2_128Concat, SpaceIdOf, ()>;
+
+	/// Limits of time-limited or quota-limited space delegations.
+	/// It maps from an authorization identifier to its expiry and quota.
+	#[pallet::storage]
+	pub type AuthorizationLimits<T> =
+		StorageMap<_, Blake2_128Concat, AuthorizationIdOf, DelegationLimitsOf<T>, OptionQuery>;
+
+	/// maps block number to the list of delegations to be pruned at this block
+	#[pallet::storage]
+	pub type AuthorizationExpirations<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<AuthorizationIdOf, T::MaxDelegationExpirationsPerBlock>,
+		ValueQuery,
+	>;
+
+	/// The earliest block whose scheduled delegations have not all been
+	/// pruned yet.
+	#[pallet::storage]
+	pub type ExpirationCursor<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -223,6 +297,13 @@ pub mod pallet {
 		/// A chain space usage has been reset.
 		/// \[space identifier \]
 		ResetUsage { space: SpaceIdOf },
+		/// Unused capacity of an archived sub-space has been returned to its
+		/// parent.
+		/// \[space identifier, parent identifier, amount\]
+		CapacityReclaimed { space: SpaceIdOf, parent: SpaceIdOf, amount: u64 },
+		/// An expired or exhausted space authorization has been pruned.
+		/// \[space identifier, authorization\]
+		AuthorizationLapsed { space: SpaceIdOf, authorization: AuthorizationIdOf },
 	}
 
 	#[pallet::error]
@@ -266,6 +347,22 @@ pub mod pallet {
 		CapacityValueMissing,
 		/// Type capacity overflow
 		TypeCapacityOverflow,
+		/// The maximum sub-space nesting depth has been reached
+		MaxSubspaceDepthExceeded,
+		/// The delegation has expired
+		AuthorizationExpired,
+		/// The delegate has used up its transaction quota
+		AuthorizationQuotaExhausted,
+		/// The delegation expiry must be a future block
+		InvalidDelegationExpiry,
+		/// The delegation quota must be greater than zero
+		InvalidDelegationQuota,
+		/// Too many delegations are set to lapse at the given block
+		MaxDelegationExpirationsExceeded,
+		/// The permissions are empty or contain unknown bits
+		InvalidPermissions,
+		/// The capacity reserved for sub-spaces would drop below zero
+		ReserveUnderflow,
 	}
 
 	#[pallet::call]
@@ -285,6 +382,8 @@ pub mod pallet {
 		/// - `space_id`: The identifier of the space to which the delegate is being added.
 		/// - `delegate`: The identifier of the delegate being added to the space.
 		/// - `authorization`: The authorization ID used to validate the addition.
+		/// - `expires_at`: (Optional) The block at which the delegation lapses.
+		/// - `quota`: (Optional) The number of authorized operations the delegate may perform.
 		///
 		/// # Returns
 		/// Returns `Ok(())` if the delegate was successfully added with
@@ -295,12 +394,14 @@ pub mod pallet {
 		/// - `UnauthorizedOperation`: If the caller is not an admin of the space.
 		/// - Propagates errors from `space_delegate_addition` if it fails.
 		#[pallet::call_index(0)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_delegate())]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_delegate().saturating_add(Pallet::<T>::authorization_weight()))]
 		pub fn add_delegate(
 			origin: OriginFor<T>,
 			space_id: SpaceIdOf,
 			delegate: SpaceCreatorOf<T>,
 			authorization: AuthorizationIdOf,
+			expires_at: Option<BlockNumberFor<T>>,
+			quota: Option<u32>,
 		) -> DispatchResult {
 			let creator = T::EnsureOrigin::ensure_origin(origin)?.subject();
 			let auth_space_id =
@@ -308,7 +409,14 @@ pub mod pallet {
 			ensure!(auth_space_id == space_id, Error::<T>::UnauthorizedOperation);
 
 			let permissions = Permissions::ASSERT;
-			Self::space_delegate_addition(auth_space_id, delegate, creator, permissions)?;
+			Self::space_delegate_addition(
+				auth_space_id,
+				delegate,
+				creator,
+				permissions,
+				expires_at,
+				quota,
+			)?;
 
 			Ok(())
 		}
@@ -330,6 +438,8 @@ pub mod pallet {
 		/// - `space_id`: The identifier of the space to which the admin delegate is being added.
 		/// - `delegate`: The identifier of the delegate being granted admin permissions.
 		/// - `authorization`: The authorization ID used to validate the addition.
+		/// - `expires_at`: (Optional) The block at which the delegation lapses.
+		/// - `quota`: (Optional) The number of authorized operations the delegate may perform.
 		///
 		/// # Returns
 		/// Returns `Ok(())` if the admin delegate was successfully added, or an
@@ -339,12 +449,14 @@ pub mod pallet {
 		/// - `UnauthorizedOperation`: If the caller is not an admin of the space.
 		/// - Propagates errors from `space_delegate_addition` if it fails.
 		#[pallet::call_index(1)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_admin_delegate())]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_admin_delegate().saturating_add(Pallet::<T>::authorization_weight()))]
 		pub fn add_admin_delegate(
 			origin: OriginFor<T>,
 			space_id: SpaceIdOf,
 			delegate: SpaceCreatorOf<T>,
 			authorization: AuthorizationIdOf,
+			expires_at: Option<BlockNumberFor<T>>,
+			quota: Option<u32>,
 		) -> DispatchResult {
 			let creator = T::EnsureOrigin::ensure_origin(origin)?.subject();
 			let auth_space_id = Self::ensure_authorization_admin_origin(&authorization, &creator)?;
@@ -352,7 +464,14 @@ pub mod pallet {
 			ensure!(auth_space_id == space_id, Error::<T>::UnauthorizedOperation);
 
 			let permissions = Permissions::ADMIN;
-			Self::space_delegate_addition(auth_space_id, delegate, creator, permissions)?;
+			Self::space_delegate_addition(
+				auth_space_id,
+				delegate,
+				creator,
+				permissions,
+				expires_at,
+				quota,
+			)?;
 
 			Ok(())
 		}
@@ -373,17 +492,21 @@ pub mod pallet {
 		/// - `space_id`: The identifier of the space to which the audit delegate is being added.
 		/// - `delegate`: The identifier of the delegate being granted audit permissions.
 		/// - `authorization`: The authorization ID used to validate the addition.
+		/// - `expires_at`: (Optional) The block at which the delegation lapses.
+		/// - `quota`: (Optional) The number of authorized operations the delegate may perform.
 		///
 		/// # Returns
 		/// Returns `Ok(())` if the audit delegate was successfully added, or an
 		/// `Err` with an appropriate error if the operation fails.
 		#[pallet::call_index(2)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_delegator())]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_delegator().saturating_add(Pallet::<T>::authorization_weight()))]
 		pub fn add_delegator(
 			origin: OriginFor<T>,
 			space_id: SpaceIdOf,
 			delegate: SpaceCreatorOf<T>,
 			authorization: AuthorizationIdOf,
+			expires_at: Option<BlockNumberFor<T>>,
+			quota: Option<u32>,
 		) -> DispatchResult {
 			let creator = T::EnsureOrigin::ensure_origin(origin)?.subject();
 			let auth_space_id = Self::ensure_authorization_admin_origin(&authorization, &creator)?;
@@ -391,7 +514,14 @@ pub mod pallet {
 			ensure!(auth_space_id == space_id, Error::<T>::UnauthorizedOperation);
 
 			let permissions = Permissions::DELEGATE;
-			Self::space_delegate_addition(auth_space_id, delegate, creator, permissions)?;
+			Self::space_delegate_addition(
+				auth_space_id,
+				delegate,
+				creator,
+				permissions,
+				expires_at,
+				quota,
+			)?;
 
 			Ok(())
 		}
@@ -431,7 +561,7 @@ pub mod pallet {
 		/// - `Deauthorization`: Emitted when a delegate is successfully removed from a space. The
 		///   event includes the space ID and the authorization ID of the removed delegate.
 		#[pallet::call_index(3)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_delegate())]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_delegate().saturating_add(Pallet::<T>::authorization_weight()))]
 		pub fn remove_delegate(
 			origin: OriginFor<T>,
 			space_id: SpaceIdOf,
@@ -455,6 +585,7 @@ pub mod pallet {
 				Delegates::<T>::insert(&space_id, delegates);
 
 				Authorizations::<T>::remove(&remove_authorization);
+				AuthorizationLimits::<T>::remove(&remove_authorization);
 
 				Self::decrement_usage(&space_id).map_err(Error::<T>::from)?;
 
@@ -674,11 +805,17 @@ pub mod pallet {
 		/// - `SpaceNotApproved`: If the space has not been approved for use.
 		/// - `UnauthorizedOperation`: If the caller is not authorized to archive the space.
 		///
+		/// If the space is a sub-space, its unused capacity is returned to the
+		/// parent. At least one unit of capacity stays with the sub-space so
+		/// that it remains bounded if it is restored.
+		///
 		/// # Events
+		/// - `CapacityReclaimed`: Emitted when unused capacity of a sub-space is returned to its
+		///   parent.
 		/// - `Archive`: Emitted when a space is successfully archived. It includes the space ID and
 		///   the authority who performed the archival.
 		#[pallet::call_index(6)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::archive())]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::archive().saturating_add(Pallet::<T>::authorization_weight()))]
 		pub fn archive(
 			origin: OriginFor<T>,
 			space_id: SpaceIdOf,
@@ -693,11 +830,25 @@ pub mod pallet {
 			ensure!(!space_details.archive, Error::<T>::ArchivedSpace);
 			ensure!(space_details.approved, Error::<T>::SpaceNotApproved);
 
-			<Spaces<T>>::insert(&space_id, SpaceDetailsOf::<T> { archive: true, ..space_details });
+			let reclaimed = Self::reclaim_subspace_capacity(&space_id, &space_details)?;
+			let txn_capacity = space_details.txn_capacity.saturating_sub(reclaimed);
+
+			<Spaces<T>>::insert(
+				&space_id,
+				SpaceDetailsOf::<T> { archive: true, txn_capacity, ..space_details.clone() },
+			);
 
 			Self::update_activity(&space_id, IdentifierTypeOf::ChainSpace, CallTypeOf::Archive)
 				.map_err(Error::<T>::from)?;
 
+			if reclaimed > 0 {
+				Self::deposit_event(Event::CapacityReclaimed {
+					space: space_id.clone(),
+					parent: space_details.parent,
+					amount: reclaimed,
+				});
+			}
+
 			Self::deposit_event(Event::Archive { space: space_id, authority: creator });
 
 			Ok(())
@@ -735,7 +886,7 @@ pub mod pallet {
 		/// - `Restore`: Emitted when a space is successfully restored. It includes the space ID and
 		///   the authority who performed the restoration.
 		#[pallet::call_index(7)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::restore())]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::restore().saturating_add(Pallet::<T>::authorization_weight()))]
 		pub fn restore(
 			origin: OriginFor<T>,
 			space_id: SpaceIdOf,
@@ -779,6 +930,8 @@ pub mod pallet {
 		/// * `SpaceNotApproved` - If the space has not been approved for use yet.
 		/// * `CapacityLessThanUsage` - If the new capacity is less than the current usage of the
 		///   space.
+		/// * `ReserveUnderflow` - If the parent space has reserved less capacity than the sub-space
+		///   releases.
 		///
 		/// # Events
 		/// * `UpdateCapacity` - Emits the space ID when the capacity is successfully updated.
@@ -804,22 +957,29 @@ pub mod pallet {
 				let parent_details = Spaces::<T>::get(&space_details.parent.clone())
 					.ok_or(Error::<T>::SpaceNotFound)?;
 
+				// A sub-space of a bounded space must reserve its capacity
+				ensure!(
+					parent_details.txn_capacity == 0 || new_txn_capacity > 0,
+					Error::<T>::CapacityValueMissing
+				);
+
+				let txn_reserve = parent_details
+					.txn_reserve
+					.checked_add(new_txn_capacity)
+					.ok_or(Error::<T>::TypeCapacityOverflow)?
+					.checked_sub(space_details.txn_capacity)
+					.ok_or(Error::<T>::ReserveUnderflow)?;
+
 				// Ensure the new capacity is greater than the current usage
 				ensure!(
-					(parent_details.txn_capacity >=
-						(parent_details.txn_count +
-							parent_details.txn_reserve +
-							new_txn_capacity - space_details.txn_capacity)),
+					parent_details.txn_capacity >=
+						parent_details.txn_count.saturating_add(txn_reserve),
 					Error::<T>::CapacityLessThanUsage
 				);
 
 				<Spaces<T>>::insert(
 					&space_details.parent.clone(),
-					SpaceDetailsOf::<T> {
-						txn_reserve: parent_details.txn_reserve - space_details.txn_capacity +
-							new_txn_capacity,
-						..parent_details.clone()
-					},
+					SpaceDetailsOf::<T> { txn_reserve, ..parent_details.clone() },
 				);
 			}
 
@@ -842,7 +1002,8 @@ pub mod pallet {
 		/// `ChainSpaceOrigin`, and is used to reset the usage metrics for a
 		/// given space on the chain, identified by `space_id`. The reset action
 		/// is only permissible if the space exists, is not archived, and is
-		/// approved for operations.
+		/// approved for operations. The usage removed from the space is also
+		/// removed from its ancestors.
 		///
 		/// # Parameters
 		/// - `origin`: The transaction's origin, which must pass the `ChainSpaceOrigin` check.
@@ -857,7 +1018,7 @@ pub mod pallet {
 		/// # Events
 		/// - Emits `UpdateCapacity` upon successfully resetting the space's usage counter.
 		#[pallet::call_index(9)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::reset_transaction_count())]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::reset_transaction_count().saturating_add(Pallet::<T>::authorization_weight()))]
 		pub fn reset_transaction_count(
 			origin: OriginFor<T>,
 			space_id: SpaceIdOf,
@@ -879,7 +1040,12 @@ pub mod pallet {
 				T::ChainSpaceOrigin::ensure_origin(origin)?;
 			}
 
-			<Spaces<T>>::insert(&space_id, SpaceDetailsOf::<T> { txn_count: 0, ..space_details });
+			let txn_count = space_details.txn_count;
+			<Spaces<T>>::insert(
+				&space_id,
+				SpaceDetailsOf::<T> { txn_count: 0, ..space_details.clone() },
+			);
+			Self::propagate_usage(&space_id, &space_details, txn_count, false)?;
 
 			Self::update_activity(&space_id, IdentifierTypeOf::ChainSpace, CallTypeOf::Usage)
 				.map_err(Error::<T>::from)?;
@@ -991,12 +1157,15 @@ pub mod pallet {
 		///   are of invalid length.
 		/// - `SpaceAlreadyAnchored`: If the space identifier is already in use.
 		/// - `SpaceDelegatesLimitExceeded`: If the space exceeds the limit of allowed delegates.
+		/// - `CapacityValueMissing`: If no capacity is reserved from a bounded parent space.
+		/// - `CapacityLimitExceeded`: If the parent space cannot reserve the requested capacity.
+		/// - `MaxSubspaceDepthExceeded`: If the sub-space would be nested too deeply.
 		///
 		/// # Events
 		/// - `Create`: Emitted when a new space is successfully created. It includes the space
 		///   identifier, the creator's identifier, and the authorization ID.
 		#[pallet::call_index(12)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::subspace_create())]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::subspace_create().saturating_add(Pallet::<T>::authorization_weight()))]
 		pub fn subspace_create(
 			origin: OriginFor<T>,
 			space_code: SpaceCodeOf<T>,
@@ -1021,14 +1190,23 @@ pub mod pallet {
 				None => 0,
 			};
 
-			// Ensure the new capacity is greater than the current usage
 			ensure!(
-				count <=
-					(space_details.txn_capacity -
-						(space_details.txn_count + space_details.txn_reserve)),
-				Error::<T>::CapacityLimitExceeded
+				Self::space_depth(&space_id)? < T::MaxSubspaceDepth::get(),
+				Error::<T>::MaxSubspaceDepthExceeded
 			);
 
+			// A sub-space of a bounded space must reserve its capacity from the
+			// parent, which needs to have enough unreserved capacity left.
+			if space_details.txn_capacity != 0 {
+				ensure!(count > 0, Error::<T>::CapacityValueMissing);
+				let committed = space_details
+					.txn_count
+					.checked_add(space_details.txn_reserve)
+					.and_then(|c| c.checked_add(count))
+					.ok_or(Error::<T>::TypeCapacityOverflow)?;
+				ensure!(committed <= space_details.txn_capacity, Error::<T>::CapacityLimitExceeded);
+			}
+
 			// Id Digest = concat (H(<scale_encoded_registry_input>,
 			// <scale_encoded_creator_identifier>))
 			let id_digest = <T as frame_system::Config>::Hashing::hash(
@@ -1073,15 +1251,16 @@ pub mod pallet {
 				},
 			);
 
-			/* Update the parent space with added count */
+			/* Reserve the capacity of the sub-space from the parent space */
 			<Spaces<T>>::insert(
 				&space_id.clone(),
 				SpaceDetailsOf::<T> {
-					txn_count: space_details.txn_count + 1,
-					txn_reserve: space_details.txn_reserve + count,
+					txn_reserve: space_details.txn_reserve.saturating_add(count),
 					..space_details
 				},
 			);
+			Self::increment_usage(&space_id)?;
+			SubSpaces::<T>::insert(&space_id, &identifier, ());
 			<Spaces<T>>::insert(
 				&identifier,
 				SpaceDetailsOf::<T> {
@@ -1126,6 +1305,8 @@ pub mod pallet {
 		/// * `SpaceNotApproved` - If the space has not been approved for use yet.
 		/// * `CapacityLessThanUsage` - If the new capacity is less than the current usage of the
 		///   space.
+		/// * `ReserveUnderflow` - If the parent space has reserved less capacity than the sub-space
+		///   releases.
 		///
 		/// # Events
 		/// * `UpdateCapacity` - Emits the space ID when the capacity is successfully updated.
@@ -1157,21 +1338,28 @@ pub mod pallet {
 				Spaces::<T>::get(&space_details.parent.clone()).ok_or(Error::<T>::SpaceNotFound)?;
 			ensure!(parent_details.creator.clone() == creator, Error::<T>::UnauthorizedOperation);
 
+			// A sub-space of a bounded space must reserve its capacity
+			ensure!(
+				parent_details.txn_capacity == 0 || new_txn_capacity > 0,
+				Error::<T>::CapacityValueMissing
+			);
+
+			let txn_reserve = parent_details
+				.txn_reserve
+				.checked_add(new_txn_capacity)
+				.ok_or(Error::<T>::TypeCapacityOverflow)?
+				.checked_sub(space_details.txn_capacity)
+				.ok_or(Error::<T>::ReserveUnderflow)?;
+
 			// Ensure the new capacity is greater than the current usage
 			ensure!(
-				(parent_details.txn_capacity >=
-					(parent_details.txn_count + parent_details.txn_reserve + new_txn_capacity -
-						space_details.txn_capacity)),
+				parent_details.txn_capacity >= parent_details.txn_count.saturating_add(txn_reserve),
 				Error::<T>::CapacityLessThanUsage
 			);
 
 			<Spaces<T>>::insert(
 				&space_details.parent.clone(),
-				SpaceDetailsOf::<T> {
-					txn_reserve: parent_details.txn_reserve - space_details.txn_capacity +
-						new_txn_capacity,
-					..parent_details.clone()
-				},
+				SpaceDetailsOf::<T> { txn_reserve, ..parent_details.clone() },
 			);
 
 			<Spaces<T>>::insert(
@@ -1186,6 +1374,70 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Adds a delegate to a space with an explicit set of permissions.
+		///
+		/// Unlike `add_delegate`, which only grants `ASSERT`, this call lets an
+		/// admin pick the exact actions a delegate may perform, for example
+		/// `ASSERT | UPDATE` for a delegate that may anchor and update
+		/// statements but not revoke or remove them.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call, which must be signed by an admin of the space.
+		/// - `space_id`: The identifier of the space to which the delegate is being added.
+		/// - `delegate`: The identifier of the delegate being added to the space.
+		/// - `permissions`: The permissions granted to the delegate.
+		/// - `authorization`: The authorization ID used to validate the addition.
+		/// - `expires_at`: (Optional) The block at which the delegation lapses.
+		/// - `quota`: (Optional) The number of authorized operations the delegate may perform.
+		///
+		/// # Errors
+		/// - `InvalidPermissions`: If `permissions` is empty or contains unknown bits.
+		/// - `UnauthorizedOperation`: If the caller is not an admin of the space.
+		/// - Propagates errors from `space_delegate_addition` if it fails.
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_delegate().saturating_add(Pallet::<T>::authorization_weight()))]
+		pub fn add_delegate_with_permissions(
+			origin: OriginFor<T>,
+			space_id: SpaceIdOf,
+			delegate: SpaceCreatorOf<T>,
+			permissions: Permissions,
+			authorization: AuthorizationIdOf,
+			expires_at: Option<BlockNumberFor<T>>,
+			quota: Option<u32>,
+		) -> DispatchResult {
+			let creator = T::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			ensure!(
+				!permissions.is_empty() && Permissions::from_bits(permissions.bits()).is_some(),
+				Error::<T>::InvalidPermissions
+			);
+
+			let auth_space_id = Self::ensure_authorization_admin_origin(&authorization, &creator)?;
+			ensure!(auth_space_id == space_id, Error::<T>::UnauthorizedOperation);
+
+			Self::space_delegate_addition(
+				auth_space_id,
+				delegate,
+				creator,
+				permissions,
+				expires_at,
+				quota,
+			)?;
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> From<DelegationLimitsError> for Error<T> {
+	fn from(error: DelegationLimitsError) -> Self {
+		match error {
+			DelegationLimitsError::InvalidExpiry => Error::<T>::InvalidDelegationExpiry,
+			DelegationLimitsError::InvalidQuota => Error::<T>::InvalidDelegationQuota,
+			DelegationLimitsError::Expired => Error::<T>::AuthorizationExpired,
+			DelegationLimitsError::QuotaExhausted => Error::<T>::AuthorizationQuotaExhausted,
+		}
 	}
 }
 
@@ -1197,11 +1449,14 @@ impl<T: Config> Pallet<T> {
 	/// an authorization ID based on the space ID, delegate, and creator,
 	/// ensuring that the delegate is not already added. It also checks that the
 	/// space is not archived, is approved, and has not exceeded its capacity.
+	/// An optional expiry block and transaction quota limit the delegation.
 	fn space_delegate_addition(
 		space_id: SpaceIdOf,
 		delegate: SpaceCreatorOf<T>,
 		creator: SpaceCreatorOf<T>,
 		permissions: Permissions,
+		expires_at: Option<BlockNumberFor<T>>,
+		quota: Option<u32>,
 	) -> Result<(), Error<T>> {
 		// Id Digest = concat (H(<scale_encoded_space_identifier>,
 		// <scale_encoded_creator_identifier>, <scale_encoded_delegate_identifier>))
@@ -1218,6 +1473,8 @@ impl<T: Config> Pallet<T> {
 			Error::<T>::DelegateAlreadyAdded
 		);
 
+		Self::set_delegation_limits(&delegate_authorization_id, expires_at, quota)?;
+
 		let mut delegates = Delegates::<T>::get(&space_id);
 		delegates
 			.try_push(delegate.clone())
@@ -1262,17 +1519,36 @@ impl<T: Config> Pallet<T> {
 	pub fn ensure_authorization_origin(
 		authorization_id: &AuthorizationIdOf,
 		delegate: &SpaceCreatorOf<T>,
+	) -> Result<SpaceIdOf, Error<T>> {
+		Self::ensure_authorization_permission_origin(
+			authorization_id,
+			delegate,
+			Permissions::ASSERT,
+		)
+	}
+
+	/// Verifies if a given delegate has been granted `permission` through the
+	/// given authorization.
+	///
+	/// Other pallets use this to check the permission bit matching the action
+	/// being performed, such as `REVOKE` for revoking a statement.
+	pub fn ensure_authorization_permission_origin(
+		authorization_id: &AuthorizationIdOf,
+		delegate: &SpaceCreatorOf<T>,
+		permission: Permissions,
 	) -> Result<SpaceIdOf, Error<T>> {
 		let d =
 			<Authorizations<T>>::get(authorization_id).ok_or(Error::<T>::AuthorizationNotFound)?;
 
 		ensure!(d.delegate == *delegate, Error::<T>::UnauthorizedOperation);
 
+		Self::consume_delegation(authorization_id)?;
+
 		Self::increment_usage(&d.space_id)?;
 
 		Self::validate_space_for_transaction(&d.space_id)?;
 
-		ensure!(d.permissions.contains(Permissions::ASSERT), Error::<T>::UnauthorizedOperation);
+		ensure!(d.permissions.contains(permission), Error::<T>::UnauthorizedOperation);
 
 		Ok(d.space_id)
 	}
@@ -1286,6 +1562,8 @@ impl<T: Config> Pallet<T> {
 
 		ensure!(d.delegate == *delegate, Error::<T>::UnauthorizedOperation);
 
+		Self::consume_delegation(authorization_id)?;
+
 		Self::increment_usage(&d.space_id)?;
 
 		Self::validate_space_for_restore_transaction(&d.space_id)?;
@@ -1310,6 +1588,8 @@ impl<T: Config> Pallet<T> {
 
 		ensure!(d.delegate == *delegate, Error::<T>::UnauthorizedOperation);
 
+		Self::consume_delegation(authorization_id)?;
+
 		Self::increment_usage(&d.space_id)?;
 
 		Self::validate_space_for_transaction(&d.space_id)?;
@@ -1335,6 +1615,8 @@ impl<T: Config> Pallet<T> {
 
 		ensure!(d.delegate == *delegate, Error::<T>::UnauthorizedOperation);
 
+		Self::consume_delegation(authorization_id)?;
+
 		Self::increment_usage(&d.space_id)?;
 
 		Self::validate_space_for_transaction(&d.space_id)?;
@@ -1362,6 +1644,8 @@ impl<T: Config> Pallet<T> {
 
 		ensure!(d.delegate == *delegate, Error::<T>::UnauthorizedOperation);
 
+		Self::consume_delegation(authorization_id)?;
+
 		Self::validate_space_for_transaction(&d.space_id)?;
 
 		ensure!(d.permissions.contains(Permissions::ADMIN), Error::<T>::UnauthorizedOperation);
@@ -1369,6 +1653,132 @@ impl<T: Config> Pallet<T> {
 		Ok(d.space_id)
 	}
 
+	/// Records the expiry and quota of a delegation and schedules it to be
+	/// pruned once the expiry block is reached.
+	///
+	/// Nothing is stored for a delegation without limits.
+	///
+	/// # Errors
+	/// - `InvalidDelegationExpiry`: If the expiry is not a future block.
+	/// - `InvalidDelegationQuota`: If the quota is zero.
+	/// - `MaxDelegationExpirationsExceeded`: If the expiry bucket of the block is full.
+	pub fn set_delegation_limits(
+		authorization_id: &AuthorizationIdOf,
+		expires_at: Option<BlockNumberFor<T>>,
+		quota: Option<u32>,
+	) -> Result<(), Error<T>> {
+		let now = frame_system::Pallet::<T>::block_number();
+		let Some(limits) = DelegationLimitsOf::<T>::new(expires_at, quota, &now)? else {
+			AuthorizationLimits::<T>::remove(authorization_id);
+			return Ok(());
+		};
+
+		if let Some(expiry) = limits.expires_at {
+			AuthorizationExpirations::<T>::try_mutate(expiry, |ids| {
+				ids.try_push(authorization_id.clone())
+					.map_err(|_| Error::<T>::MaxDelegationExpirationsExceeded)
+			})?;
+		}
+
+		AuthorizationLimits::<T>::insert(authorization_id, limits);
+
+		Ok(())
+	}
+
+	/// Rejects expired or exhausted delegations and records one use of the
+	/// quota of a limited delegation.
+	///
+	/// A delegation that uses up its quota is scheduled to be pruned in the
+	/// next block.
+	///
+	/// # Errors
+	/// - `AuthorizationExpired`: If the expiry block of the delegation has been reached.
+	/// - `AuthorizationQuotaExhausted`: If the delegate has used up its quota.
+	pub fn consume_delegation(authorization_id: &AuthorizationIdOf) -> Result<(), Error<T>> {
+		let Some(mut limits) = AuthorizationLimits::<T>::get(authorization_id) else {
+			return Ok(());
+		};
+
+		let now = frame_system::Pallet::<T>::block_number();
+		if limits.consume(&now)? {
+			if limits.is_exhausted() {
+				// A full bucket only delays pruning, the delegation is already
+				// rejected by the quota check.
+				let _ = AuthorizationExpirations::<T>::try_mutate(
+					now.saturating_add(One::one()),
+					|ids| ids.try_push(authorization_id.clone()),
+				);
+			}
+			AuthorizationLimits::<T>::insert(authorization_id, limits);
+		}
+
+		Ok(())
+	}
+
+	/// Prunes the delegations scheduled up to block `n` that have expired or
+	/// used up their quota, and emits an `AuthorizationLapsed` event for each.
+	///
+	/// Pruning stops once `limit` is used up and resumes from
+	/// `ExpirationCursor` in a later block. Lapsed delegations are rejected
+	/// by `consume_delegation` until they are pruned. Scheduled delegations
+	/// that were removed or re-granted in the meantime are left untouched.
+	fn prune_lapsed_delegations(n: BlockNumberFor<T>, limit: Weight) -> Weight {
+		let mut meter = WeightMeter::with_limit(limit);
+		let visit_weight = <T as Config>::WeightInfo::visit_expiration_block();
+		let prune_weight = <T as Config>::WeightInfo::prune_lapsed_delegation()
+			.saturating_add(<T as Config>::WeightInfo::propagate_usage(T::MaxSubspaceDepth::get()));
+
+		let mut block = ExpirationCursor::<T>::get().unwrap_or(n);
+		while block <= n && meter.try_consume(visit_weight).is_ok() {
+			let mut scheduled = AuthorizationExpirations::<T>::take(block);
+			while let Some(authorization_id) = scheduled.last().cloned() {
+				if meter.try_consume(prune_weight).is_err() {
+					AuthorizationExpirations::<T>::insert(block, scheduled);
+					ExpirationCursor::<T>::put(block);
+					return meter.consumed();
+				}
+				scheduled.pop();
+				Self::prune_delegation(authorization_id, &n);
+			}
+			block.saturating_inc();
+		}
+
+		ExpirationCursor::<T>::put(block);
+		meter.consumed()
+	}
+
+	/// Removes a scheduled delegation if it has lapsed at block `now`.
+	fn prune_delegation(authorization_id: AuthorizationIdOf, now: &BlockNumberFor<T>) {
+		let Some(limits) = AuthorizationLimits::<T>::get(&authorization_id) else {
+			return;
+		};
+		if !limits.has_lapsed(now) {
+			return;
+		}
+		let Some(details) = Authorizations::<T>::get(&authorization_id) else {
+			AuthorizationLimits::<T>::remove(&authorization_id);
+			return;
+		};
+
+		Delegates::<T>::mutate(&details.space_id, |delegates| {
+			remove_delegate(delegates, &details.delegate)
+		});
+		Authorizations::<T>::remove(&authorization_id);
+		AuthorizationLimits::<T>::remove(&authorization_id);
+
+		let _ = Self::decrement_usage(&details.space_id);
+		let _ = Self::update_activity(
+			&details.space_id,
+			IdentifierTypeOf::Auth,
+			CallTypeOf::Deauthorization,
+		);
+
+		Self::deposit_event(Event::AuthorizationLapsed {
+			space: details.space_id,
+			authorization: authorization_id,
+		});
+	}
+
 	/// Validates that a space is eligible for a new transaction.
 	///
 	/// This function ensures that a space is not archived, is approved, and has
@@ -1384,8 +1794,10 @@ impl<T: Config> Pallet<T> {
 		// Ensure the space is approved for transactions.
 		ensure!(space_details.approved, Error::<T>::SpaceNotApproved);
 
-		// Ensure the space has not exceeded its capacity limit.
-		if space_details.txn_capacity == 0 || space_details.txn_count < space_details.txn_capacity {
+		// Ensure the space has not exceeded its capacity limit, including the
+		// capacity reserved for its sub-spaces.
+		let committed = space_details.txn_count.saturating_add(space_details.txn_reserve);
+		if space_details.txn_capacity == 0 || committed < space_details.txn_capacity {
 			Ok(())
 		} else {
 			Err(Error::<T>::CapacityLimitExceeded)
@@ -1407,8 +1819,10 @@ impl<T: Config> Pallet<T> {
 		// Ensure the space is approved for transactions.
 		ensure!(space_details.approved, Error::<T>::SpaceNotApproved);
 
-		// Ensure the space has not exceeded its capacity limit.
-		if space_details.txn_capacity == 0 || space_details.txn_count < space_details.txn_capacity {
+		// Ensure the space has not exceeded its capacity limit, including the
+		// capacity reserved for its sub-spaces.
+		let committed = space_details.txn_count.saturating_add(space_details.txn_reserve);
+		if space_details.txn_capacity == 0 || committed < space_details.txn_capacity {
 			Ok(())
 		} else {
 			Err(Error::<T>::CapacityLimitExceeded)
@@ -1435,10 +1849,12 @@ impl<T: Config> Pallet<T> {
 		// Ensure the space is approved for adding new entries.
 		ensure!(space_details.approved, Error::<T>::SpaceNotApproved);
 
-		// Calculate the new usage to check against the capacity.
+		// Calculate the new usage, including the capacity reserved for
+		// sub-spaces, to check against the capacity.
 		let new_usage = space_details
 			.txn_count
-			.checked_add(entries as u64)
+			.checked_add(space_details.txn_reserve)
+			.and_then(|usage| usage.checked_add(entries as u64))
 			.ok_or(Error::<T>::TypeCapacityOverflow)?;
 
 		// Ensure the space has enough capacity to accommodate the new entries.
@@ -1454,64 +1870,206 @@ impl<T: Config> Pallet<T> {
 	///
 	/// This function is used to increase the usage counter of a space,
 	/// typically when a new delegate or entry is added. It ensures that the
-	/// usage count does not overflow.
+	/// usage count does not overflow. The usage is rolled up to all ancestors
+	/// of the space.
 	pub fn increment_usage(tx_id: &SpaceIdOf) -> Result<(), Error<T>> {
-		Spaces::<T>::try_mutate(tx_id, |space_opt| {
-			if let Some(space_details) = space_opt {
-				space_details.txn_count = space_details.txn_count.saturating_add(1);
-				Ok(())
-			} else {
-				Err(Error::<T>::SpaceNotFound)
-			}
-		})
+		Self::adjust_usage(tx_id, 1, true)
 	}
 
 	/// Decrements the usage count of a space by one unit.
 	///
 	/// This function is used to decrease the usage counter of a space,
 	/// typically when a delegate or entry is removed. It ensures that the usage
-	/// count does not underflow.
+	/// count does not underflow. The usage is also released from all ancestors
+	/// of the space.
 	pub fn decrement_usage(tx_id: &SpaceIdOf) -> Result<(), Error<T>> {
-		Spaces::<T>::try_mutate(tx_id, |space_opt| {
-			if let Some(space_details) = space_opt {
-				space_details.txn_count = space_details.txn_count.saturating_sub(1);
-				Ok(())
-			} else {
-				Err(Error::<T>::SpaceNotFound)
-			}
-		})
+		Self::adjust_usage(tx_id, 1, false)
 	}
 
 	/// Increments the usage count of a space by a specified unit.
 	///
 	/// This function increases the usage counter of a space by the amount
 	/// specified in `increment`, which is useful for batch operations.
-	/// It ensures that the usage count does not overflow.
+	/// It ensures that the usage count does not overflow. The usage is rolled
+	/// up to all ancestors of the space.
 	pub fn increment_usage_entries(tx_id: &SpaceIdOf, increment: u16) -> Result<(), Error<T>> {
-		Spaces::<T>::try_mutate(tx_id, |space_opt| {
-			if let Some(space_details) = space_opt {
-				space_details.txn_count = space_details.txn_count.saturating_add(increment.into());
-				Ok(())
-			} else {
-				Err(Error::<T>::SpaceNotFound)
-			}
-		})
+		Self::adjust_usage(tx_id, increment.into(), true)
 	}
 
 	/// Decrements the usage count of a space by a specified amount.
 	///
 	/// This function decreases the usage counter of a space by the amount
 	/// specified in `decrement`, which is useful for batch removals. It ensures
-	/// that the usage count does not underflow.
+	/// that the usage count does not underflow. The usage is also released
+	/// from all ancestors of the space.
 	pub fn decrement_usage_entries(tx_id: &SpaceIdOf, decrement: u16) -> Result<(), Error<T>> {
-		Spaces::<T>::try_mutate(tx_id, |space_opt| {
-			if let Some(space_details) = space_opt {
-				space_details.txn_count = space_details.txn_count.saturating_sub(decrement.into());
-				Ok(())
+		Self::adjust_usage(tx_id, decrement.into(), false)
+	}
+
+	/// Adjusts the usage count of a space and rolls the change up to all of
+	/// its ancestors.
+	fn adjust_usage(tx_id: &SpaceIdOf, amount: u64, increase: bool) -> Result<(), Error<T>> {
+		let space_details = Spaces::<T>::try_mutate(tx_id, |space_opt| {
+			let space_details = space_opt.as_mut().ok_or(Error::<T>::SpaceNotFound)?;
+			space_details.txn_count = if increase {
+				space_details.txn_count.saturating_add(amount)
 			} else {
-				Err(Error::<T>::SpaceNotFound)
+				space_details.txn_count.saturating_sub(amount)
+			};
+			Ok::<_, Error<T>>(space_details.clone())
+		})?;
+
+		Self::propagate_usage(tx_id, &space_details, amount, increase)
+	}
+
+	/// Rolls a usage change of a space up to all of its ancestors.
+	///
+	/// Usage of a sub-space with its own capacity was reserved by its parent,
+	/// so the parent converts the matching part of its reserve into usage (or
+	/// back, when usage is released). Usage of an unbounded sub-space is
+	/// added to the usage of its parent directly.
+	pub(crate) fn propagate_usage(
+		tx_id: &SpaceIdOf,
+		space_details: &SpaceDetailsOf<T>,
+		amount: u64,
+		increase: bool,
+	) -> Result<(), Error<T>> {
+		let mut child_id = tx_id.clone();
+		let mut child = space_details.clone();
+
+		while child.parent != child_id {
+			let reserved = child.txn_capacity != 0;
+			let parent_id = child.parent.clone();
+			let parent = Spaces::<T>::try_mutate(&parent_id, |space_opt| {
+				let parent = space_opt.as_mut().ok_or(Error::<T>::SpaceNotFound)?;
+				if increase {
+					parent.txn_count = parent.txn_count.saturating_add(amount);
+					if reserved {
+						parent.txn_reserve = parent
+							.txn_reserve
+							.checked_sub(amount)
+							.ok_or(Error::<T>::ReserveUnderflow)?;
+					}
+				} else {
+					// The usage of an ancestor may have been reset below the usage
+					// of its sub-spaces, so releasing usage saturates at zero.
+					parent.txn_count = parent.txn_count.saturating_sub(amount);
+					if reserved {
+						parent.txn_reserve = parent.txn_reserve.saturating_add(amount);
+					}
+				}
+				Ok::<_, Error<T>>(parent.clone())
+			})?;
+
+			child_id = parent_id;
+			child = parent;
+		}
+
+		Ok(())
+	}
+
+	/// The weight of checking an authorization that the benchmarks of the
+	/// calling extrinsics do not cover: recording a use of a quota-limited
+	/// delegation, and rolling the usage of the space up to its ancestors for
+	/// a space nested as deeply as `MaxSubspaceDepth` allows.
+	///
+	/// Calls add this to their weight once for every authorization they check
+	/// and every other adjustment of the usage of a space they make.
+	pub fn authorization_weight() -> Weight {
+		<T as Config>::WeightInfo::consume_delegation()
+			.saturating_add(<T as Config>::WeightInfo::propagate_usage(T::MaxSubspaceDepth::get()))
+	}
+
+	/// Returns the unused capacity of a sub-space to its parent.
+	///
+	/// The capacity that is neither used nor reserved for further sub-spaces
+	/// is released from the reserve of the parent. At least one unit of
+	/// capacity is retained by the sub-space. Top level and unbounded spaces
+	/// have nothing to reclaim.
+	///
+	/// # Returns
+	/// The amount of capacity returned to the parent.
+	fn reclaim_subspace_capacity(
+		space_id: &SpaceIdOf,
+		space_details: &SpaceDetailsOf<T>,
+	) -> Result<u64, Error<T>> {
+		if space_details.parent == *space_id || space_details.txn_capacity == 0 {
+			return Ok(0);
+		}
+
+		let retained = space_details.txn_count.saturating_add(space_details.txn_reserve).max(1);
+		let reclaimed = space_details.txn_capacity.saturating_sub(retained);
+
+		if reclaimed > 0 {
+			Spaces::<T>::try_mutate(&space_details.parent, |space_opt| {
+				let parent = space_opt.as_mut().ok_or(Error::<T>::SpaceNotFound)?;
+				parent.txn_reserve = parent
+					.txn_reserve
+					.checked_sub(reclaimed)
+					.ok_or(Error::<T>::ReserveUnderflow)?;
+				Ok::<_, Error<T>>(())
+			})?;
+		}
+
+		Ok(reclaimed)
+	}
+
+	/// Returns the nesting depth of a space. Top level spaces have a depth
+	/// of zero.
+	pub fn space_depth(space_id: &SpaceIdOf) -> Result<u32, Error<T>> {
+		let mut depth = 0u32;
+		let mut current = space_id.clone();
+		loop {
+			let details = Spaces::<T>::get(&current).ok_or(Error::<T>::SpaceNotFound)?;
+			if details.parent == current {
+				return Ok(depth);
 			}
-		})
+			depth = depth.saturating_add(1);
+			current = details.parent;
+		}
+	}
+
+	/// Returns a space together with all of its descendants.
+	///
+	/// The nodes are returned in depth-first order, starting with the
+	/// requested space. The `txn_count` of each node includes the usage of
+	/// its descendants, while `subspace_usage` holds the combined usage of
+	/// its direct sub-spaces.
+	///
+	/// # Returns
+	/// `None` if the space does not exist.
+	pub fn space_tree(space_id: &SpaceIdOf) -> Option<Vec<SpaceTreeNodeOf<T>>> {
+		let root = Spaces::<T>::get(space_id)?;
+		let mut tree = Vec::new();
+		let mut stack = vec![(space_id.clone(), root, 0u32)];
+
+		while let Some((space, details, depth)) = stack.pop() {
+			let children: Vec<(SpaceIdOf, SpaceDetailsOf<T>)> =
+				SubSpaces::<T>::iter_key_prefix(&space)
+					.filter_map(|child| Spaces::<T>::get(&child).map(|d| (child, d)))
+					.collect();
+			let subspace_usage =
+				children.iter().fold(0u64, |acc, (_, d)| acc.saturating_add(d.txn_count));
+
+			tree.push(SpaceTreeNode {
+				parent: if details.parent == space { None } else { Some(details.parent.clone()) },
+				space: space.clone(),
+				depth,
+				creator: details.creator,
+				txn_capacity: details.txn_capacity,
+				txn_reserve: details.txn_reserve,
+				txn_count: details.txn_count,
+				subspace_usage,
+				approved: details.approved,
+				archive: details.archive,
+			});
+
+			for (child, child_details) in children.into_iter().rev() {
+				stack.push((child, child_details, depth.saturating_add(1)));
+			}
+		}
+
+		Some(tree)
 	}
 
 	/// Updates the global timeline with a new activity event for a space.
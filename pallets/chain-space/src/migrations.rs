@@ -0,0 +1,257 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Storage migrations for the chain space pallet.
+
+use crate::*;
+
+pub mod v2 {
+	use super::*;
+	use codec::{Decode, Encode, MaxEncodedLen};
+	use frame_support::{
+		migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+		pallet_prelude::*,
+		storage::with_storage_layer,
+		storage_alias,
+		weights::WeightMeter,
+		Blake2_128Concat,
+	};
+	use sp_std::marker::PhantomData;
+
+	/// The identifier of the chain space migrations in the multi-block
+	/// migrator.
+	pub(crate) const MIGRATION_ID: &[u8; 16] = b"cord-chain-space";
+
+	/// The usage each sub-space recorded for itself before the upgrade, kept
+	/// while the migration runs so that usage which has already been rolled
+	/// up is not counted twice.
+	#[storage_alias]
+	pub type PendingUsage<T: Config> =
+		StorageMap<Pallet<T>, Blake2_128Concat, SpaceIdOf, u64, OptionQuery>;
+
+	/// The progress of `MigrateToHierarchicalUsage`.
+	#[derive(Clone, Encode, Decode, MaxEncodedLen, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub enum HierarchicalUsageCursor {
+		/// Sub-spaces are being indexed; the last space visited.
+		Indexing(SpaceIdOf),
+		/// The recorded usage is being rolled up to the ancestors.
+		RollingUp,
+	}
+
+	/// Indexes existing sub-spaces under their parents and rolls the usage
+	/// recorded by each sub-space up to its ancestors, matching the
+	/// hierarchical accounting introduced with version 2 of the pallet.
+	///
+	/// The migration runs over several blocks in two phases. The first phase
+	/// visits one space per iteration, indexes it under its parent and
+	/// records its own usage in `PendingUsage`. The second phase rolls one
+	/// recorded usage up to the ancestors per iteration.
+	pub struct MigrateToHierarchicalUsage<T>(PhantomData<T>);
+
+	impl<T: Config> MigrateToHierarchicalUsage<T> {
+		/// The weight of a single iteration, which is bounded by rolling a
+		/// usage up through the deepest allowed chain of ancestors.
+		pub(crate) fn step_weight() -> Weight {
+			T::DbWeight::get().reads_writes(2, 2).saturating_add(
+				<T as Config>::WeightInfo::propagate_usage(T::MaxSubspaceDepth::get()),
+			)
+		}
+
+		/// Indexes the space after `last` under its parent and records its
+		/// usage. Returns `None` once every space has been visited.
+		fn index_next(last: Option<SpaceIdOf>) -> Option<SpaceIdOf> {
+			let mut iter = match last {
+				Some(last) => Spaces::<T>::iter_from(Spaces::<T>::hashed_key_for(last)),
+				None => Spaces::<T>::iter(),
+			};
+			let (space_id, details) = iter.next()?;
+
+			// Usage recorded before the upgrade only covers each space itself.
+			if details.parent != space_id {
+				SubSpaces::<T>::insert(&details.parent, &space_id, ());
+				if details.txn_count > 0 {
+					PendingUsage::<T>::insert(&space_id, details.txn_count);
+				}
+			}
+
+			Some(space_id)
+		}
+
+		/// Rolls one recorded usage up to the ancestors of its sub-space.
+		/// Returns `false` once no recorded usage is left.
+		fn roll_up_next() -> bool {
+			let Some((space_id, amount)) = PendingUsage::<T>::drain().next() else {
+				return false;
+			};
+
+			// A failed roll-up must not leave some ancestors updated.
+			let roll_up = || -> DispatchResult {
+				let details = Spaces::<T>::get(&space_id).ok_or(Error::<T>::SpaceNotFound)?;
+				Pallet::<T>::propagate_usage(&space_id, &details, amount, true)?;
+				Ok(())
+			};
+			if with_storage_layer(roll_up).is_err() {
+				log::warn!(
+					target: "runtime::chain-space",
+					"failed to roll up usage of sub-space {:?}",
+					space_id
+				);
+			}
+
+			true
+		}
+	}
+
+	impl<T: Config> SteppedMigration for MigrateToHierarchicalUsage<T> {
+		type Cursor = HierarchicalUsageCursor;
+		type Identifier = MigrationId<16>;
+
+		fn id() -> Self::Identifier {
+			MigrationId { pallet_id: *MIGRATION_ID, version_from: 1, version_to: 2 }
+		}
+
+		fn step(
+			mut cursor: Option<Self::Cursor>,
+			meter: &mut WeightMeter,
+		) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+			let required = Self::step_weight();
+			if meter.remaining().any_lt(required) {
+				return Err(SteppedMigrationError::InsufficientWeight { required });
+			}
+
+			if Pallet::<T>::on_chain_storage_version() != 1 {
+				log::info!(
+					target: "runtime::chain-space",
+					"skipping hierarchical usage migration, on-chain storage version is {:?}",
+					Pallet::<T>::on_chain_storage_version()
+				);
+				return Ok(None);
+			}
+
+			while meter.try_consume(required).is_ok() {
+				cursor = match cursor {
+					None => Some(match Self::index_next(None) {
+						Some(space_id) => HierarchicalUsageCursor::Indexing(space_id),
+						None => HierarchicalUsageCursor::RollingUp,
+					}),
+					Some(HierarchicalUsageCursor::Indexing(last)) =>
+						Some(match Self::index_next(Some(last)) {
+							Some(space_id) => HierarchicalUsageCursor::Indexing(space_id),
+							None => HierarchicalUsageCursor::RollingUp,
+						}),
+					Some(HierarchicalUsageCursor::RollingUp) => {
+						if !Self::roll_up_next() {
+							StorageVersion::new(2).put::<Pallet<T>>();
+							log::info!(
+								target: "runtime::chain-space",
+								"migrated space usage to version 2"
+							);
+							return Ok(None);
+						}
+						Some(HierarchicalUsageCursor::RollingUp)
+					},
+				};
+			}
+
+			Ok(cursor)
+		}
+	}
+}
+
+pub mod v3 {
+	use super::*;
+	use frame_support::{
+		migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+		pallet_prelude::*,
+		weights::WeightMeter,
+	};
+	use sp_std::marker::PhantomData;
+
+	/// The actions an `ASSERT` delegate could perform before they were split
+	/// into separate permission bits.
+	pub fn legacy_assert_permissions() -> Permissions {
+		Permissions::REVOKE |
+			Permissions::UPDATE |
+			Permissions::REMOVE |
+			Permissions::SCHEMA_CREATE |
+			Permissions::PRESENTATION
+	}
+
+	/// Grants every authorization holding `ASSERT` the permission bits that
+	/// were implied by `ASSERT` before version 3 of the pallet, so existing
+	/// delegates keep the rights they had.
+	///
+	/// The migration runs over several blocks, visiting one authorization per
+	/// iteration. The cursor is the last authorization that was visited. It
+	/// must run after `v2::MigrateToHierarchicalUsage`.
+	pub struct MigrateToFineGrainedPermissions<T>(PhantomData<T>);
+
+	impl<T: Config> SteppedMigration for MigrateToFineGrainedPermissions<T> {
+		type Cursor = AuthorizationIdOf;
+		type Identifier = MigrationId<16>;
+
+		fn id() -> Self::Identifier {
+			MigrationId { pallet_id: *v2::MIGRATION_ID, version_from: 2, version_to: 3 }
+		}
+
+		fn step(
+			mut cursor: Option<Self::Cursor>,
+			meter: &mut WeightMeter,
+		) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+			let required = T::DbWeight::get().reads_writes(1, 1);
+			if meter.remaining().any_lt(required) {
+				return Err(SteppedMigrationError::InsufficientWeight { required });
+			}
+
+			if Pallet::<T>::on_chain_storage_version() != 2 {
+				log::info!(
+					target: "runtime::chain-space",
+					"skipping permissions migration, on-chain storage version is {:?}",
+					Pallet::<T>::on_chain_storage_version()
+				);
+				return Ok(None);
+			}
+
+			while meter.try_consume(required).is_ok() {
+				let mut iter = match cursor {
+					Some(ref last) =>
+						Authorizations::<T>::iter_from(Authorizations::<T>::hashed_key_for(last)),
+					None => Authorizations::<T>::iter(),
+				};
+
+				let Some((authorization_id, mut auth)) = iter.next() else {
+					StorageVersion::new(3).put::<Pallet<T>>();
+					log::info!(
+						target: "runtime::chain-space",
+						"migrated space permissions to version 3"
+					);
+					return Ok(None);
+				};
+
+				if auth.permissions.contains(Permissions::ASSERT) {
+					auth.permissions.insert(legacy_assert_permissions());
+					Authorizations::<T>::insert(&authorization_id, auth);
+				}
+
+				cursor = Some(authorization_id);
+			}
+
+			Ok(cursor)
+		}
+	}
+}
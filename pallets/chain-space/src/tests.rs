@@ -2,7 +2,7 @@ use super::*;
 use crate::mock::*;
 use codec::Encode;
 use cord_utilities::mock::{mock_origin::DoubleOrigin, SubjectId};
-use frame_support::{assert_err, assert_ok, error::BadOrigin};
+use frame_support::{assert_err, assert_ok, error::BadOrigin, traits::Hooks};
 use frame_system::RawOrigin;
 use sp_runtime::{traits::Hash, AccountId32};
 use sp_std::prelude::*;
@@ -55,6 +55,8 @@ fn add_delegate_should_succeed() {
 			space_id,
 			DID_01,
 			authorization_id,
+			None,
+			None,
 		));
 	});
 }
@@ -92,6 +94,8 @@ fn add_admin_delegate_should_succeed() {
 			space_id,
 			DID_01,
 			authorization_id,
+			None,
+			None,
 		));
 	});
 }
@@ -129,6 +133,8 @@ fn add_admin_delegate_should_fail_if_admin_delegate_already_exists() {
 			space_id.clone(),
 			DID_01.clone(),
 			authorization_id.clone(),
+			None,
+			None,
 		));
 
 		assert_err!(
@@ -137,6 +143,8 @@ fn add_admin_delegate_should_fail_if_admin_delegate_already_exists() {
 				space_id,
 				DID_01,
 				authorization_id,
+				None,
+				None,
 			),
 			Error::<Test>::DelegateAlreadyAdded
 		);
@@ -176,6 +184,8 @@ fn add_delegator_should_succeed() {
 			space_id,
 			DID_01,
 			authorization_id,
+			None,
+			None,
 		));
 	});
 }
@@ -213,6 +223,8 @@ fn add_delegator_should_fail_if_delegator_already_exists() {
 			space_id.clone(),
 			DID_01.clone(),
 			authorization_id.clone(),
+			None,
+			None,
 		));
 
 		assert_err!(
@@ -221,6 +233,8 @@ fn add_delegator_should_fail_if_delegator_already_exists() {
 				space_id,
 				DID_01,
 				authorization_id,
+				None,
+				None,
 			),
 			Error::<Test>::DelegateAlreadyAdded
 		)
@@ -254,6 +268,8 @@ fn add_delegate_should_fail_if_space_is_not_created() {
 				space_id,
 				SubjectId(AccountId32::new([1u8; 32])),
 				authorization_id,
+				None,
+				None,
 			),
 			Error::<Test>::AuthorizationNotFound
 		);
@@ -287,6 +303,8 @@ fn add_admin_delegate_should_fail_if_space_is_not_created() {
 				space_id,
 				SubjectId(AccountId32::new([1u8; 32])),
 				authorization_id,
+				None,
+				None,
 			),
 			Error::<Test>::AuthorizationNotFound
 		);
@@ -320,6 +338,8 @@ fn add_delegator_should_fail_if_space_is_not_created() {
 				space_id,
 				SubjectId(AccountId32::new([1u8; 32])),
 				authorization_id,
+				None,
+				None,
 			),
 			Error::<Test>::AuthorizationNotFound
 		);
@@ -365,6 +385,8 @@ fn add_delegate_should_fail_if_the_regisrty_is_archived() {
 				space_id,
 				SubjectId(AccountId32::new([1u8; 32])),
 				authorization_id,
+				None,
+				None,
 			),
 			Error::<Test>::ArchivedSpace
 		);
@@ -401,6 +423,8 @@ fn add_delegate_should_fail_if_the_space_is_not_approved() {
 				space_id,
 				SubjectId(AccountId32::new([1u8; 32])),
 				authorization_id,
+				None,
+				None,
 			),
 			Error::<Test>::SpaceNotApproved
 		);
@@ -441,6 +465,8 @@ fn add_delegate_should_fail_if_a_non_delegate_tries_to_add() {
 				space_id,
 				SubjectId(AccountId32::new([1u8; 32])),
 				authorization_id,
+				None,
+				None,
 			),
 			Error::<Test>::UnauthorizedOperation
 		);
@@ -485,6 +511,8 @@ fn add_delegate_should_fail_if_the_space_capacity_is_full() {
 				space_id,
 				SubjectId(AccountId32::new([1u8; 32])),
 				authorization_id,
+				None,
+				None,
 			),
 			Error::<Test>::CapacityLimitExceeded
 		);
@@ -525,6 +553,8 @@ fn add_delegate_should_fail_if_delegate_already_exists() {
 			space_id.clone(),
 			DID_01.clone(),
 			authorization_id.clone(),
+			None,
+			None,
 		));
 
 		assert_err!(
@@ -533,6 +563,8 @@ fn add_delegate_should_fail_if_delegate_already_exists() {
 				space_id,
 				DID_01,
 				authorization_id,
+				None,
+				None,
 			),
 			Error::<Test>::DelegateAlreadyAdded
 		);
@@ -652,6 +684,8 @@ fn archiving_a_space_should_succeed() {
 				space_id,
 				delegate,
 				authorization_id,
+				None,
+				None,
 			),
 			Error::<Test>::ArchivedSpace
 		);
@@ -725,6 +759,8 @@ fn restoring_an_archived_a_space_should_succeed() {
 			space_id,
 			delegate,
 			authorization_id,
+			None,
+			None,
 		));
 	});
 }
@@ -1163,6 +1199,8 @@ fn add_delegate_should_fail_for_space_creator_delegating_themselves() {
 				space_id,
 				creator.clone(),
 				authorization_id,
+				None,
+				None,
 			),
 			Error::<Test>::DelegateAlreadyAdded
 		);
@@ -1203,6 +1241,8 @@ fn add_admin_delegate_should_fail_for_space_creator_delegating_themselves() {
 				space_id,
 				creator.clone(),
 				authorization_id,
+				None,
+				None,
 			),
 			Error::<Test>::DelegateAlreadyAdded
 		);
@@ -1243,8 +1283,604 @@ fn add_delegator_should_fail_for_space_creator_delegating_themselves() {
 				space_id,
 				creator.clone(),
 				authorization_id,
+				None,
+				None,
 			),
 			Error::<Test>::DelegateAlreadyAdded
 		);
 	});
 }
+
+#[test]
+fn subspace_usage_should_roll_up_to_the_parent() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let space = [2u8; 256].to_vec();
+	let subspace = [5u8; 256].to_vec();
+	let other_subspace = [6u8; 256].to_vec();
+	let capacity = 20u64;
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&space.encode()[..]);
+	let subspace_digest = <Test as frame_system::Config>::Hashing::hash(&subspace.encode()[..]);
+	let other_subspace_digest =
+		<Test as frame_system::Config>::Hashing::hash(&other_subspace.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&id_digest);
+
+	let sub_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&subspace_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let subspace_id: SpaceIdOf = generate_space_id::<Test>(&sub_id_digest);
+
+	let sub_auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&subspace_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let sub_authorization_id: AuthorizationIdOf =
+		generate_authorization_id::<Test>(&sub_auth_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		assert_err!(
+			Space::subspace_create(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				subspace_digest,
+				Some(0),
+				space_id.clone(),
+			),
+			Error::<Test>::CapacityValueMissing
+		);
+
+		assert_ok!(Space::subspace_create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			subspace_digest,
+			Some(10),
+			space_id.clone(),
+		));
+
+		assert_ok!(Space::add_delegate(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			subspace_id.clone(),
+			DID_01,
+			sub_authorization_id,
+			None,
+			None,
+		));
+
+		let sub_details = Spaces::<Test>::get(&subspace_id).unwrap();
+		assert_eq!(sub_details.txn_count, 1);
+
+		// The creation of the sub-space and its usage are both billed to the
+		// parent, and the used part of the reservation is released.
+		let parent_details = Spaces::<Test>::get(&space_id).unwrap();
+		assert_eq!(parent_details.txn_count, 2);
+		assert_eq!(parent_details.txn_reserve, 9);
+
+		// The parent cannot hand out capacity it has already reserved.
+		assert_err!(
+			Space::subspace_create(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				other_subspace_digest,
+				Some(10),
+				space_id.clone(),
+			),
+			Error::<Test>::CapacityLimitExceeded
+		);
+
+		let tree = Space::space_tree(&space_id).expect("space tree should exist");
+		assert_eq!(tree.len(), 2);
+		assert_eq!(tree[0].space, space_id);
+		assert_eq!(tree[0].parent, None);
+		assert_eq!(tree[0].subspace_usage, 1);
+		assert_eq!(tree[1].space, subspace_id);
+		assert_eq!(tree[1].parent, Some(space_id.clone()));
+		assert_eq!(tree[1].depth, 1);
+	});
+}
+
+#[test]
+fn archiving_a_subspace_should_reclaim_unused_capacity() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let space = [2u8; 256].to_vec();
+	let subspace = [5u8; 256].to_vec();
+	let capacity = 20u64;
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&space.encode()[..]);
+	let subspace_digest = <Test as frame_system::Config>::Hashing::hash(&subspace.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&id_digest);
+
+	let sub_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&subspace_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let subspace_id: SpaceIdOf = generate_space_id::<Test>(&sub_id_digest);
+
+	let sub_auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&subspace_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let sub_authorization_id: AuthorizationIdOf =
+		generate_authorization_id::<Test>(&sub_auth_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+		assert_ok!(Space::subspace_create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			subspace_digest,
+			Some(10),
+			space_id.clone(),
+		));
+
+		// Archiving is itself recorded as usage of the sub-space.
+		assert_ok!(Space::archive(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			subspace_id.clone(),
+			sub_authorization_id,
+		));
+
+		let sub_details = Spaces::<Test>::get(&subspace_id).unwrap();
+		assert_eq!(sub_details.txn_count, 1);
+		assert_eq!(sub_details.txn_capacity, 1);
+
+		let parent_details = Spaces::<Test>::get(&space_id).unwrap();
+		assert_eq!(parent_details.txn_count, 2);
+		assert_eq!(parent_details.txn_reserve, 0);
+
+		System::assert_has_event(
+			Event::<Test>::CapacityReclaimed { space: subspace_id, parent: space_id, amount: 9 }
+				.into(),
+		);
+	});
+}
+
+#[test]
+fn updating_subspace_capacity_should_fail_if_the_parent_reserve_would_underflow() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let space = [2u8; 256].to_vec();
+	let subspace = [5u8; 256].to_vec();
+	let capacity = 20u64;
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&space.encode()[..]);
+	let subspace_digest = <Test as frame_system::Config>::Hashing::hash(&subspace.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&id_digest);
+
+	let sub_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&subspace_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let subspace_id: SpaceIdOf = generate_space_id::<Test>(&sub_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+		assert_ok!(Space::subspace_create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			subspace_digest,
+			Some(10),
+			space_id.clone(),
+		));
+
+		// A parent that reserves less than its sub-space holds must not have
+		// the difference wrapped around.
+		Spaces::<Test>::mutate(&space_id, |details| {
+			if let Some(details) = details {
+				details.txn_reserve = 4;
+			}
+		});
+
+		assert_err!(
+			Space::update_transaction_capacity_sub(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				subspace_id.clone(),
+				5,
+			),
+			Error::<Test>::ReserveUnderflow
+		);
+		assert_err!(
+			Space::update_transaction_capacity(RawOrigin::Root.into(), subspace_id, 5),
+			Error::<Test>::ReserveUnderflow
+		);
+	});
+}
+
+#[test]
+fn hierarchical_usage_migration_should_roll_up_usage_over_several_steps() {
+	use frame_support::{
+		migrations::SteppedMigration,
+		traits::{GetStorageVersion, StorageVersion},
+		weights::WeightMeter,
+	};
+	use migrations::v2::{MigrateToHierarchicalUsage, PendingUsage};
+
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let space = [2u8; 256].to_vec();
+	let subspace = [5u8; 256].to_vec();
+	let capacity = 20u64;
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&space.encode()[..]);
+	let subspace_digest = <Test as frame_system::Config>::Hashing::hash(&subspace.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&id_digest);
+
+	let sub_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&subspace_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let subspace_id: SpaceIdOf = generate_space_id::<Test>(&sub_id_digest);
+
+	let sub_auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&subspace_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let sub_authorization_id: AuthorizationIdOf =
+		generate_authorization_id::<Test>(&sub_auth_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+		assert_ok!(Space::subspace_create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			subspace_digest,
+			Some(10),
+			space_id.clone(),
+		));
+		assert_ok!(Space::add_delegate(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			subspace_id.clone(),
+			DID_01,
+			sub_authorization_id,
+			None,
+			None,
+		));
+
+		// Rewind to the version 1 layout, where the parent only records its
+		// own usage and sub-spaces are not indexed.
+		Spaces::<Test>::mutate(&space_id, |details| {
+			if let Some(details) = details {
+				details.txn_count = 1;
+				details.txn_reserve = 10;
+			}
+		});
+		let _ = SubSpaces::<Test>::clear(u32::MAX, None);
+		StorageVersion::new(1).put::<Space>();
+
+		let mut cursor = None;
+		let mut steps = 0;
+		loop {
+			let mut meter =
+				WeightMeter::with_limit(MigrateToHierarchicalUsage::<Test>::step_weight());
+			cursor = MigrateToHierarchicalUsage::<Test>::step(cursor, &mut meter)
+				.expect("a step should have enough weight");
+			steps += 1;
+			if cursor.is_none() {
+				break;
+			}
+		}
+
+		// Each step visits a single space or rolls up a single usage.
+		assert!(steps > 2);
+		assert_eq!(Space::on_chain_storage_version(), 2);
+		assert!(SubSpaces::<Test>::contains_key(&space_id, &subspace_id));
+		assert_eq!(PendingUsage::<Test>::iter().count(), 0);
+
+		let parent_details = Spaces::<Test>::get(&space_id).unwrap();
+		assert_eq!(parent_details.txn_count, 2);
+		assert_eq!(parent_details.txn_reserve, 9);
+	});
+}
+
+#[test]
+fn expired_delegation_should_be_rejected_and_pruned() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let delegate = DID_01;
+	let space = [2u8; 256].to_vec();
+	let capacity = 10u64;
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&space.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&id_digest);
+
+	let auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: AuthorizationIdOf = generate_authorization_id::<Test>(&auth_id_digest);
+
+	let delegate_auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &delegate.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let delegate_authorization_id: AuthorizationIdOf =
+		generate_authorization_id::<Test>(&delegate_auth_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		// The expiry must lie in the future.
+		assert_err!(
+			Space::add_admin_delegate(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				space_id.clone(),
+				delegate.clone(),
+				authorization_id.clone(),
+				Some(1),
+				None,
+			),
+			Error::<Test>::InvalidDelegationExpiry
+		);
+
+		assert_ok!(Space::add_admin_delegate(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_id.clone(),
+			delegate.clone(),
+			authorization_id.clone(),
+			Some(3),
+			None,
+		));
+
+		assert_ok!(Space::add_admin_delegate(
+			DoubleOrigin(author.clone(), delegate.clone()).into(),
+			space_id.clone(),
+			SubjectId(AccountId32::new([3u8; 32])),
+			delegate_authorization_id.clone(),
+			None,
+			None,
+		));
+
+		System::set_block_number(3);
+		assert_err!(
+			Space::add_admin_delegate(
+				DoubleOrigin(author.clone(), delegate.clone()).into(),
+				space_id.clone(),
+				SubjectId(AccountId32::new([4u8; 32])),
+				delegate_authorization_id.clone(),
+				None,
+				None,
+			),
+			Error::<Test>::AuthorizationExpired
+		);
+
+		Space::on_idle(3, Weight::MAX);
+
+		assert!(Authorizations::<Test>::get(&delegate_authorization_id).is_none());
+		assert!(AuthorizationLimits::<Test>::get(&delegate_authorization_id).is_none());
+		assert!(!Space::is_a_delegate(&space_id, delegate));
+		System::assert_last_event(
+			Event::<Test>::AuthorizationLapsed {
+				space: space_id,
+				authorization: delegate_authorization_id,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn pruning_lapsed_delegations_should_resume_when_out_of_weight() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let delegates = [DID_01, SubjectId(AccountId32::new([3u8; 32]))];
+	let space = [2u8; 256].to_vec();
+	let capacity = 10u64;
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&space.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&id_digest);
+
+	let auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: AuthorizationIdOf = generate_authorization_id::<Test>(&auth_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		for delegate in delegates.iter() {
+			assert_ok!(Space::add_delegate(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				space_id.clone(),
+				delegate.clone(),
+				authorization_id.clone(),
+				Some(3),
+				None,
+			));
+		}
+
+		// Only leave room for pruning a single delegation.
+		let limit = <() as WeightInfo>::visit_expiration_block() +
+			<() as WeightInfo>::prune_lapsed_delegation() +
+			<() as WeightInfo>::propagate_usage(MaxSubspaceDepth::get());
+
+		System::set_block_number(3);
+		assert_eq!(Space::on_idle(3, limit), limit);
+		assert_eq!(AuthorizationExpirations::<Test>::get(3).len(), 1);
+		assert_eq!(ExpirationCursor::<Test>::get(), Some(3));
+		assert_eq!(Delegates::<Test>::get(&space_id).len(), 2);
+
+		System::set_block_number(4);
+		Space::on_idle(4, Weight::MAX);
+		assert!(AuthorizationExpirations::<Test>::get(3).is_empty());
+		assert_eq!(ExpirationCursor::<Test>::get(), Some(5));
+		for delegate in delegates {
+			assert!(!Space::is_a_delegate(&space_id, delegate));
+		}
+	});
+}
+
+#[test]
+fn exhausted_delegation_should_be_rejected_and_pruned() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let delegate = DID_01;
+	let space = [2u8; 256].to_vec();
+	let capacity = 10u64;
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&space.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&id_digest);
+
+	let auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: AuthorizationIdOf = generate_authorization_id::<Test>(&auth_id_digest);
+
+	let delegate_auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &delegate.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let delegate_authorization_id: AuthorizationIdOf =
+		generate_authorization_id::<Test>(&delegate_auth_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		assert_ok!(Space::add_admin_delegate(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_id.clone(),
+			delegate.clone(),
+			authorization_id.clone(),
+			None,
+			Some(1),
+		));
+
+		assert_ok!(Space::add_admin_delegate(
+			DoubleOrigin(author.clone(), delegate.clone()).into(),
+			space_id.clone(),
+			SubjectId(AccountId32::new([3u8; 32])),
+			delegate_authorization_id.clone(),
+			None,
+			None,
+		));
+		assert_eq!(
+			AuthorizationLimits::<Test>::get(&delegate_authorization_id).map(|l| l.used),
+			Some(1)
+		);
+
+		assert_err!(
+			Space::add_admin_delegate(
+				DoubleOrigin(author.clone(), delegate.clone()).into(),
+				space_id.clone(),
+				SubjectId(AccountId32::new([4u8; 32])),
+				delegate_authorization_id.clone(),
+				None,
+				None,
+			),
+			Error::<Test>::AuthorizationQuotaExhausted
+		);
+
+		// The delegation is pruned in the block after its quota ran out.
+		Space::on_idle(2, Weight::MAX);
+
+		assert!(Authorizations::<Test>::get(&delegate_authorization_id).is_none());
+		assert!(!Space::is_a_delegate(&space_id, delegate));
+	});
+}
+
+#[test]
+fn add_delegate_with_permissions_should_grant_only_the_given_permissions() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let delegate = DID_01;
+	let space = [2u8; 256].to_vec();
+	let capacity = 5u64;
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&space.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&id_digest);
+
+	let auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: AuthorizationIdOf = generate_authorization_id::<Test>(&auth_id_digest);
+
+	let delegate_auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &delegate.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let delegate_authorization_id: AuthorizationIdOf =
+		generate_authorization_id::<Test>(&delegate_auth_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		assert_err!(
+			Space::add_delegate_with_permissions(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				space_id.clone(),
+				delegate.clone(),
+				Permissions::empty(),
+				authorization_id.clone(),
+				None,
+				None,
+			),
+			Error::<Test>::InvalidPermissions
+		);
+
+		assert_ok!(Space::add_delegate_with_permissions(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_id.clone(),
+			delegate.clone(),
+			Permissions::ASSERT | Permissions::REVOKE,
+			authorization_id.clone(),
+			None,
+			None,
+		));
+
+		assert_ok!(Space::ensure_authorization_permission_origin(
+			&delegate_authorization_id,
+			&delegate,
+			Permissions::REVOKE,
+		));
+		assert_err!(
+			Space::ensure_authorization_permission_origin(
+				&delegate_authorization_id,
+				&delegate,
+				Permissions::UPDATE,
+			),
+			Error::<Test>::UnauthorizedOperation
+		);
+	});
+}
@@ -32,6 +32,11 @@ bitflags! {
 		const ASSERT = 0b0000_0001;
 		const DELEGATE = 0b0000_0010;
 		const ADMIN = 0b0000_0100;
+		const REVOKE = 0b0000_1000;
+		const UPDATE = 0b0001_0000;
+		const REMOVE = 0b0010_0000;
+		const SCHEMA_CREATE = 0b0100_0000;
+		const PRESENTATION = 0b1000_0000;
 	}
 }
 
@@ -108,3 +113,31 @@ pub struct SpaceAuthorization<SpaceIdOf, SpaceCreatorOf, Permissions> {
 	pub permissions: Permissions,
 	pub delegator: SpaceCreatorOf,
 }
+
+/// A space within a space tree, as returned by the runtime API.
+///
+/// ## Fields
+///
+/// - `space`: The identifier of the space.
+/// - `parent`: The identifier of the parent space, `None` for a top level space.
+/// - `depth`: The depth of the space below the space the tree was requested for.
+/// - `creator`: The entity that created the space.
+/// - `txn_capacity`: The capacity of the space. A value of zero denotes unlimited capacity.
+/// - `txn_reserve`: The capacity reserved for, but not yet used by, sub-spaces.
+/// - `txn_count`: The usage of the space, including the usage of all its descendants.
+/// - `subspace_usage`: The combined usage of the direct sub-spaces of the space.
+/// - `approved`: Indicates whether the space has been approved.
+/// - `archive`: Indicates whether the space is archived.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct SpaceTreeNode<SpaceIdOf, SpaceCreatorOf, StatusOf> {
+	pub space: SpaceIdOf,
+	pub parent: Option<SpaceIdOf>,
+	pub depth: u32,
+	pub creator: SpaceCreatorOf,
+	pub txn_capacity: u64,
+	pub txn_reserve: u64,
+	pub txn_count: u64,
+	pub subspace_usage: u64,
+	pub approved: StatusOf,
+	pub archive: StatusOf,
+}
@@ -62,6 +62,10 @@ pub trait WeightInfo {
 	fn approval_revoke() -> Weight;
 	fn approval_restore() -> Weight;
 	fn subspace_create() -> Weight;
+	fn propagate_usage(d: u32, ) -> Weight;
+	fn consume_delegation() -> Weight;
+	fn visit_expiration_block() -> Weight;
+	fn prune_lapsed_delegation() -> Weight;
 }
 
 /// Weights for `pallet_chain_space` using the CORD node and recommended hardware.
@@ -73,16 +77,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `ChainSpace::Delegates` (r:1 w:1)
 	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	fn add_delegate() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `804`
-		//  Estimated: `323533`
-		// Minimum execution time: 40_470_000 picoseconds.
-		Weight::from_parts(41_550_000, 323533)
+		//  Estimated: `333100`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(41_550_000, 333100)
 			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:2 w:1)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -90,16 +95,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `ChainSpace::Delegates` (r:1 w:1)
 	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	fn add_admin_delegate() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `804`
-		//  Estimated: `323533`
-		// Minimum execution time: 40_851_000 picoseconds.
-		Weight::from_parts(41_909_000, 323533)
+		//  Estimated: `333100`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(41_909_000, 333100)
 			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:2 w:1)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -107,16 +113,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `ChainSpace::Delegates` (r:1 w:1)
 	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	fn add_delegator() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `804`
-		//  Estimated: `323533`
-		// Minimum execution time: 40_730_000 picoseconds.
-		Weight::from_parts(41_720_000, 323533)
+		//  Estimated: `333100`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(41_720_000, 333100)
 			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:2 w:1)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -124,145 +131,212 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `ChainSpace::Delegates` (r:1 w:1)
 	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	fn remove_delegate() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1061`
-		//  Estimated: `323533`
-		// Minimum execution time: 40_860_000 picoseconds.
-		Weight::from_parts(41_970_000, 323533)
+		//  Estimated: `333100`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(41_970_000, 333100)
 			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `ChainSpace::Delegates` (r:0 w:1)
 	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
 	/// Storage: `ChainSpace::Authorizations` (r:0 w:1)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
 	fn create() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `115`
-		//  Estimated: `3671`
-		// Minimum execution time: 27_280_000 picoseconds.
-		Weight::from_parts(27_960_000, 3671)
+		//  Estimated: `5239`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(27_960_000, 5239)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	fn approve() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `494`
-		//  Estimated: `3671`
-		// Minimum execution time: 22_331_000 picoseconds.
-		Weight::from_parts(23_009_000, 3671)
+		//  Estimated: `5239`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(23_009_000, 5239)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
 	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	fn archive() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `715`
-		//  Estimated: `3671`
-		// Minimum execution time: 31_040_000 picoseconds.
-		Weight::from_parts(31_750_000, 3671)
+		//  Estimated: `7898`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(31_750_000, 7898)
 			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
 	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	fn restore() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `724`
-		//  Estimated: `3671`
-		// Minimum execution time: 30_580_000 picoseconds.
-		Weight::from_parts(31_880_000, 3671)
+		//  Estimated: `7898`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(31_880_000, 7898)
 			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	fn update_transaction_capacity() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `503`
-		//  Estimated: `3671`
-		// Minimum execution time: 22_570_000 picoseconds.
-		Weight::from_parts(23_300_000, 3671)
+		//  Estimated: `5239`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(23_300_000, 5239)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	fn reset_transaction_count() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `503`
-		//  Estimated: `3671`
-		// Minimum execution time: 22_330_000 picoseconds.
-		Weight::from_parts(23_040_000, 3671)
+		//  Estimated: `5239`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(23_040_000, 5239)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	fn approval_revoke() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `503`
-		//  Estimated: `3671`
-		// Minimum execution time: 22_000_000 picoseconds.
-		Weight::from_parts(22_650_000, 3671)
+		//  Estimated: `5239`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(22_650_000, 5239)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	fn approval_restore() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `512`
-		//  Estimated: `3671`
-		// Minimum execution time: 22_150_000 picoseconds.
-		Weight::from_parts(22_570_000, 3671)
+		//  Estimated: `5239`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(22_570_000, 5239)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:2 w:2)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `ChainSpace::Delegates` (r:0 w:1)
 	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
 	/// Storage: `ChainSpace::Authorizations` (r:0 w:1)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
 	fn subspace_create() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `503`
-		//  Estimated: `6352`
-		// Minimum execution time: 36_090_000 picoseconds.
-		Weight::from_parts(36_930_000, 6352)
+		//  Estimated: `7920`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(36_930_000, 7920)
 			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `ChainSpace::Spaces` (r:8 w:8)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// The range of component `d` is `[1, 8]`.
+	fn propagate_usage(d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `0 + d * (2681 ±0)`
+		// Estimated from the storage accesses of each ancestor level, pending benchmarking.
+		Weight::from_parts(2_000_000, 0)
+			.saturating_add(Weight::from_parts(6_000_000, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2681).saturating_mul(d.into()))
+	}
+	/// Storage: `ChainSpace::AuthorizationLimits` (r:1 w:1)
+	/// Proof: `ChainSpace::AuthorizationLimits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::AuthorizationExpirations` (r:1 w:1)
+	/// Proof: `ChainSpace::AuthorizationExpirations` (`max_values`: None, `max_size`: Some(50014), added: 52489, mode: `MaxEncodedLen`)
+	fn consume_delegation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `55044`
+		// Estimated from the storage accesses of a use that exhausts the quota, pending benchmarking.
+		Weight::from_parts(14_000_000, 55044)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `ChainSpace::ExpirationCursor` (r:1 w:1)
+	/// Proof: `ChainSpace::ExpirationCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::AuthorizationExpirations` (r:1 w:1)
+	/// Proof: `ChainSpace::AuthorizationExpirations` (`max_values`: None, `max_size`: Some(50014), added: 52489, mode: `MaxEncodedLen`)
+	fn visit_expiration_block() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `52988`
+		// Estimated from the storage accesses of a full expiry bucket, pending benchmarking.
+		Weight::from_parts(9_000_000, 52988)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `ChainSpace::AuthorizationLimits` (r:1 w:1)
+	/// Proof: `ChainSpace::AuthorizationLimits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Authorizations` (r:1 w:1)
+	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Delegates` (r:1 w:1)
+	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn prune_lapsed_delegation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `332996`
+		// Estimated from the storage accesses with a full `Delegates` list, pending benchmarking.
+		Weight::from_parts(310_000_000, 332996)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 }
 
@@ -274,16 +348,17 @@ impl WeightInfo for () {
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `ChainSpace::Delegates` (r:1 w:1)
 	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	fn add_delegate() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `804`
-		//  Estimated: `323533`
-		// Minimum execution time: 40_470_000 picoseconds.
-		Weight::from_parts(41_550_000, 323533)
+		//  Estimated: `333100`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(41_550_000, 333100)
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:2 w:1)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -291,16 +366,17 @@ impl WeightInfo for () {
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `ChainSpace::Delegates` (r:1 w:1)
 	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	fn add_admin_delegate() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `804`
-		//  Estimated: `323533`
-		// Minimum execution time: 40_851_000 picoseconds.
-		Weight::from_parts(41_909_000, 323533)
+		//  Estimated: `333100`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(41_909_000, 333100)
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:2 w:1)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -308,16 +384,17 @@ impl WeightInfo for () {
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `ChainSpace::Delegates` (r:1 w:1)
 	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	fn add_delegator() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `804`
-		//  Estimated: `323533`
-		// Minimum execution time: 40_730_000 picoseconds.
-		Weight::from_parts(41_720_000, 323533)
+		//  Estimated: `333100`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(41_720_000, 333100)
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:2 w:1)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -325,144 +402,211 @@ impl WeightInfo for () {
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `ChainSpace::Delegates` (r:1 w:1)
 	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	fn remove_delegate() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1061`
-		//  Estimated: `323533`
-		// Minimum execution time: 40_860_000 picoseconds.
-		Weight::from_parts(41_970_000, 323533)
+		//  Estimated: `333100`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(41_970_000, 333100)
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `ChainSpace::Delegates` (r:0 w:1)
 	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
 	/// Storage: `ChainSpace::Authorizations` (r:0 w:1)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
 	fn create() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `115`
-		//  Estimated: `3671`
-		// Minimum execution time: 27_280_000 picoseconds.
-		Weight::from_parts(27_960_000, 3671)
+		//  Estimated: `5239`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(27_960_000, 5239)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	fn approve() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `494`
-		//  Estimated: `3671`
-		// Minimum execution time: 22_331_000 picoseconds.
-		Weight::from_parts(23_009_000, 3671)
+		//  Estimated: `5239`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(23_009_000, 5239)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
 	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	fn archive() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `715`
-		//  Estimated: `3671`
-		// Minimum execution time: 31_040_000 picoseconds.
-		Weight::from_parts(31_750_000, 3671)
+		//  Estimated: `7898`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(31_750_000, 7898)
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
 	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	fn restore() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `724`
-		//  Estimated: `3671`
-		// Minimum execution time: 30_580_000 picoseconds.
-		Weight::from_parts(31_880_000, 3671)
+		//  Estimated: `7898`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(31_880_000, 7898)
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	fn update_transaction_capacity() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `503`
-		//  Estimated: `3671`
-		// Minimum execution time: 22_570_000 picoseconds.
-		Weight::from_parts(23_300_000, 3671)
+		//  Estimated: `5239`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(23_300_000, 5239)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	fn reset_transaction_count() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `503`
-		//  Estimated: `3671`
-		// Minimum execution time: 22_330_000 picoseconds.
-		Weight::from_parts(23_040_000, 3671)
+		//  Estimated: `5239`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(23_040_000, 5239)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	fn approval_revoke() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `503`
-		//  Estimated: `3671`
-		// Minimum execution time: 22_000_000 picoseconds.
-		Weight::from_parts(22_650_000, 3671)
+		//  Estimated: `5239`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(22_650_000, 5239)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	fn approval_restore() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `512`
-		//  Estimated: `3671`
-		// Minimum execution time: 22_150_000 picoseconds.
-		Weight::from_parts(22_570_000, 3671)
+		//  Estimated: `5239`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(22_570_000, 5239)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:2 w:2)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `ChainSpace::Delegates` (r:0 w:1)
 	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
 	/// Storage: `ChainSpace::Authorizations` (r:0 w:1)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
 	fn subspace_create() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `503`
-		//  Estimated: `6352`
-		// Minimum execution time: 36_090_000 picoseconds.
-		Weight::from_parts(36_930_000, 6352)
+		//  Estimated: `7920`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(36_930_000, 7920)
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `ChainSpace::Spaces` (r:8 w:8)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// The range of component `d` is `[1, 8]`.
+	fn propagate_usage(d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `0 + d * (2681 ±0)`
+		// Estimated from the storage accesses of each ancestor level, pending benchmarking.
+		Weight::from_parts(2_000_000, 0)
+			.saturating_add(Weight::from_parts(6_000_000, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2681).saturating_mul(d.into()))
+	}
+	/// Storage: `ChainSpace::AuthorizationLimits` (r:1 w:1)
+	/// Proof: `ChainSpace::AuthorizationLimits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::AuthorizationExpirations` (r:1 w:1)
+	/// Proof: `ChainSpace::AuthorizationExpirations` (`max_values`: None, `max_size`: Some(50014), added: 52489, mode: `MaxEncodedLen`)
+	fn consume_delegation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `55044`
+		// Estimated from the storage accesses of a use that exhausts the quota, pending benchmarking.
+		Weight::from_parts(14_000_000, 55044)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `ChainSpace::ExpirationCursor` (r:1 w:1)
+	/// Proof: `ChainSpace::ExpirationCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::AuthorizationExpirations` (r:1 w:1)
+	/// Proof: `ChainSpace::AuthorizationExpirations` (`max_values`: None, `max_size`: Some(50014), added: 52489, mode: `MaxEncodedLen`)
+	fn visit_expiration_block() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `52988`
+		// Estimated from the storage accesses of a full expiry bucket, pending benchmarking.
+		Weight::from_parts(9_000_000, 52988)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `ChainSpace::AuthorizationLimits` (r:1 w:1)
+	/// Proof: `ChainSpace::AuthorizationLimits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Authorizations` (r:1 w:1)
+	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Delegates` (r:1 w:1)
+	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn prune_lapsed_delegation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `332996`
+		// Estimated from the storage accesses with a full `Delegates` list, pending benchmarking.
+		Weight::from_parts(310_000_000, 332996)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 }
@@ -35,12 +35,14 @@ use cord_utilities::signature::VerifySignature;
 use crate::{
 	did_details::{
 		DeriveDidCallAuthorizationVerificationKeyRelationship, DidAuthorizedCallOperation,
-		DidPublicKey, DidSignature, DidVerificationKey,
+		DidDetails, DidKeyLifecycle, DidPublicKey, DidSignature, DidVerificationKey,
 	},
+	migrations::v2::MigrateToKeyHistory,
 	mock_utils::{
 		generate_base_did_creation_details, generate_base_did_details, get_key_agreement_keys,
 		get_service_endpoints,
 	},
+	recovery::{DidRecoveryPolicy, DidRecoveryRequest, RecoveryDidsOf},
 	service_endpoints::DidEndpoint,
 	signature::DidSignatureVerify,
 	AccountIdOf, DidAuthorizedCallOperationOf, DidIdentifierOf,
@@ -48,6 +50,8 @@ use crate::{
 
 const DEFAULT_ACCOUNT_ID: &str = "tx_submitter";
 const DEFAULT_ACCOUNT_SEED: u32 = 0;
+const CONTROLLER_DID_ID: &str = "controller";
+const RECOVERY_DID_ID: &str = "recovery";
 const AUTHENTICATION_KEY_ID: KeyTypeId = KeyTypeId(*b"0000");
 const ASSERTION_KEY_ID: KeyTypeId = KeyTypeId(*b"0001");
 const DELEGATION_KEY_ID: KeyTypeId = KeyTypeId(*b"0002");
@@ -116,6 +120,42 @@ fn save_service_endpoints<T: Config>(
 	DidEndpointsCount::<T>::insert(did_subject, endpoints.len().saturated_into::<u32>());
 }
 
+fn get_recovery_dids<T: Config>(n: u32) -> RecoveryDidsOf<T> {
+	(0..n)
+		.map(|i| account(RECOVERY_DID_ID, i, DEFAULT_ACCOUNT_SEED))
+		.collect::<Vec<_>>()
+		.try_into()
+		.expect("Should not exceed the max number of recovery DIDs.")
+}
+
+/// Fills the key history of a DID up to `MaxKeyHistoryEntries`, with its
+/// current keys followed by revoked ones, so that a key change scans the
+/// whole history and evicts the oldest revoked entry.
+fn fill_key_history<T: Config>(did_subject: &DidIdentifierOf<T>, did_details: &DidDetails<T>) {
+	let mut history = MigrateToKeyHistory::<T>::seed_history(did_details);
+	let revoked = history
+		.first()
+		.cloned()
+		.map(|entry| DidKeyLifecycle { revoked_at: Some(BlockNumberFor::<T>::zero()), ..entry });
+	if let Some(revoked) = revoked {
+		while history.try_push(revoked.clone()).is_ok() {}
+	}
+	KeyHistory::<T>::insert(did_subject, history);
+}
+
+fn save_recovery_policy<T: Config>(did_subject: &DidIdentifierOf<T>) -> RecoveryDidsOf<T> {
+	let recovery_dids = get_recovery_dids::<T>(T::MaxRecoveryDids::get());
+	RecoveryPolicies::<T>::insert(
+		did_subject,
+		DidRecoveryPolicy {
+			recovery_dids: recovery_dids.clone(),
+			threshold: T::MaxRecoveryDids::get(),
+			delay: T::MinRecoveryDelay::get(),
+		},
+	);
+	recovery_dids
+}
+
 benchmarks! {
 	where_clause {
 		where
@@ -420,6 +460,7 @@ benchmarks! {
 		assert_ok!(did_details.update_assertion_key(DidVerificationKey::from(get_ed25519_public_assertion_key()), block_number));
 		assert_ok!(did_details.update_delegation_key(DidVerificationKey::from(get_ed25519_public_delegation_key()), block_number));
 
+		fill_key_history::<T>(&did_subject, &did_details);
 		Did::<T>::insert(&did_subject, did_details);
 
 		let new_did_public_auth_key = DidVerificationKey::from(ed25519_generate(UNUSED_KEY_ID, None));
@@ -442,6 +483,7 @@ benchmarks! {
 		assert_ok!(did_details.update_assertion_key(DidVerificationKey::from(get_sr25519_public_assertion_key()), block_number));
 		assert_ok!(did_details.update_delegation_key(DidVerificationKey::from(get_sr25519_public_delegation_key()), block_number));
 
+		fill_key_history::<T>(&did_subject, &did_details);
 		Did::<T>::insert(&did_subject, did_details);
 
 		let new_did_public_auth_key = DidVerificationKey::from(sr25519_generate(UNUSED_KEY_ID, None));
@@ -464,6 +506,7 @@ benchmarks! {
 		assert_ok!(did_details.update_assertion_key(DidVerificationKey::from(get_ecdsa_public_assertion_key()), block_number));
 		assert_ok!(did_details.update_delegation_key(DidVerificationKey::from(get_ecdsa_public_delegation_key()), block_number));
 
+		fill_key_history::<T>(&did_subject, &did_details);
 		Did::<T>::insert(&did_subject, did_details);
 
 		let new_did_public_auth_key = DidVerificationKey::from(ecdsa_generate(UNUSED_KEY_ID, None));
@@ -489,6 +532,7 @@ benchmarks! {
 		assert_ok!(did_details.update_assertion_key(DidVerificationKey::from(get_ed25519_public_assertion_key()), block_number));
 		assert_ok!(did_details.update_delegation_key(DidVerificationKey::from(old_delegation_key), block_number));
 
+		fill_key_history::<T>(&did_subject, &did_details);
 		Did::<T>::insert(&did_subject, did_details);
 		let origin = RawOrigin::Signed(did_subject.clone());
 		let cloned_new_delegation_key = new_delegation_key.clone();
@@ -511,6 +555,7 @@ benchmarks! {
 		assert_ok!(did_details.update_assertion_key(DidVerificationKey::from(get_sr25519_public_assertion_key()), block_number));
 		assert_ok!(did_details.update_delegation_key(DidVerificationKey::from(old_delegation_key), block_number));
 
+		fill_key_history::<T>(&did_subject, &did_details);
 		Did::<T>::insert(&did_subject, did_details);
 		let origin = RawOrigin::Signed(did_subject.clone());
 		let cloned_new_delegation_key = new_delegation_key.clone();
@@ -533,6 +578,7 @@ benchmarks! {
 		assert_ok!(did_details.update_assertion_key(DidVerificationKey::from(get_ecdsa_public_assertion_key()), block_number));
 		assert_ok!(did_details.update_delegation_key(DidVerificationKey::from(old_delegation_key), block_number));
 
+		fill_key_history::<T>(&did_subject, &did_details);
 		Did::<T>::insert(&did_subject, did_details);
 		let origin = RawOrigin::Signed(did_subject.clone());
 		let cloned_new_delegation_key = new_delegation_key.clone();
@@ -555,6 +601,7 @@ benchmarks! {
 		assert_ok!(did_details.update_assertion_key(DidVerificationKey::from(get_ed25519_public_assertion_key()), block_number));
 		assert_ok!(did_details.update_delegation_key(DidVerificationKey::from(old_delegation_key), block_number));
 
+		fill_key_history::<T>(&did_subject, &did_details);
 		Did::<T>::insert(&did_subject, did_details);
 		let origin = RawOrigin::Signed(did_subject.clone());
 	}: remove_delegation_key(origin)
@@ -577,6 +624,7 @@ benchmarks! {
 		assert_ok!(did_details.update_assertion_key(DidVerificationKey::from(get_sr25519_public_assertion_key()), block_number));
 		assert_ok!(did_details.update_delegation_key(DidVerificationKey::from(old_delegation_key), block_number));
 
+		fill_key_history::<T>(&did_subject, &did_details);
 		Did::<T>::insert(&did_subject, did_details);
 		let origin = RawOrigin::Signed(did_subject.clone());
 	}: remove_delegation_key(origin)
@@ -599,6 +647,7 @@ benchmarks! {
 		assert_ok!(did_details.update_assertion_key(DidVerificationKey::from(get_ecdsa_public_assertion_key()), block_number));
 		assert_ok!(did_details.update_delegation_key(DidVerificationKey::from(old_delegation_key), block_number));
 
+		fill_key_history::<T>(&did_subject, &did_details);
 		Did::<T>::insert(&did_subject, did_details);
 		let origin = RawOrigin::Signed(did_subject.clone());
 	}: remove_delegation_key(origin)
@@ -623,6 +672,7 @@ benchmarks! {
 		assert_ok!(did_details.update_delegation_key(DidVerificationKey::from(get_ed25519_public_delegation_key()), block_number));
 		assert_ok!(did_details.update_assertion_key(DidVerificationKey::from(old_assertion_key), block_number));
 
+		fill_key_history::<T>(&did_subject, &did_details);
 		Did::<T>::insert(&did_subject, did_details);
 		let origin = RawOrigin::Signed(did_subject.clone());
 		let cloned_new_assertion_key = new_assertion_key.clone();
@@ -645,6 +695,7 @@ benchmarks! {
 		assert_ok!(did_details.update_delegation_key(DidVerificationKey::from(get_sr25519_public_delegation_key()), block_number));
 		assert_ok!(did_details.update_assertion_key(DidVerificationKey::from(old_assertion_key), block_number));
 
+		fill_key_history::<T>(&did_subject, &did_details);
 		Did::<T>::insert(&did_subject, did_details);
 		let origin = RawOrigin::Signed(did_subject.clone());
 		let cloned_new_assertion_key = new_assertion_key.clone();
@@ -667,6 +718,7 @@ benchmarks! {
 		assert_ok!(did_details.update_delegation_key(DidVerificationKey::from(get_ecdsa_public_delegation_key()), block_number));
 		assert_ok!(did_details.update_assertion_key(DidVerificationKey::from(old_assertion_key), block_number));
 
+		fill_key_history::<T>(&did_subject, &did_details);
 		Did::<T>::insert(&did_subject, did_details);
 		let origin = RawOrigin::Signed(did_subject.clone());
 		let cloned_new_assertion_key = new_assertion_key.clone();
@@ -689,6 +741,7 @@ benchmarks! {
 		assert_ok!(did_details.update_delegation_key(DidVerificationKey::from(get_ed25519_public_delegation_key()), block_number));
 		assert_ok!(did_details.update_assertion_key(DidVerificationKey::from(old_assertion_key), block_number));
 
+		fill_key_history::<T>(&did_subject, &did_details);
 		Did::<T>::insert(&did_subject, did_details);
 		let origin = RawOrigin::Signed(did_subject.clone());
 	}: remove_assertion_key(origin)
@@ -711,6 +764,7 @@ benchmarks! {
 		assert_ok!(did_details.update_delegation_key(DidVerificationKey::from(get_sr25519_public_delegation_key()), block_number));
 		assert_ok!(did_details.update_assertion_key(DidVerificationKey::from(old_assertion_key), block_number));
 
+		fill_key_history::<T>(&did_subject, &did_details);
 		Did::<T>::insert(&did_subject, did_details);
 		let origin = RawOrigin::Signed(did_subject.clone());
 	}: remove_assertion_key(origin)
@@ -733,6 +787,7 @@ benchmarks! {
 		assert_ok!(did_details.update_delegation_key(DidVerificationKey::from(get_ecdsa_public_delegation_key()), block_number));
 		assert_ok!(did_details.update_assertion_key(DidVerificationKey::from(old_assertion_key), block_number));
 
+		fill_key_history::<T>(&did_subject, &did_details);
 		Did::<T>::insert(&did_subject, did_details);
 		let origin = RawOrigin::Signed(did_subject.clone());
 	}: remove_assertion_key(origin)
@@ -760,6 +815,7 @@ benchmarks! {
 		assert_ok!(did_details.update_delegation_key(DidVerificationKey::from(get_ed25519_public_delegation_key()), block_number));
 		assert_ok!(did_details.update_assertion_key(DidVerificationKey::from(get_ed25519_public_assertion_key()), block_number));
 
+		fill_key_history::<T>(&did_subject, &did_details);
 		Did::<T>::insert(&did_subject, did_details);
 		let origin = RawOrigin::Signed(did_subject.clone());
 	}: add_key_agreement_key(origin, new_key_agreement_key)
@@ -784,6 +840,7 @@ benchmarks! {
 		assert_ok!(did_details.update_delegation_key(DidVerificationKey::from(get_sr25519_public_delegation_key()), block_number));
 		assert_ok!(did_details.update_assertion_key(DidVerificationKey::from(get_sr25519_public_assertion_key()), block_number));
 
+		fill_key_history::<T>(&did_subject, &did_details);
 		Did::<T>::insert(&did_subject, did_details);
 		let origin = RawOrigin::Signed(did_subject.clone());
 	}: add_key_agreement_key(origin, new_key_agreement_key)
@@ -808,6 +865,7 @@ benchmarks! {
 		assert_ok!(did_details.update_delegation_key(DidVerificationKey::from(get_ecdsa_public_delegation_key()), block_number));
 		assert_ok!(did_details.update_assertion_key(DidVerificationKey::from(get_ecdsa_public_assertion_key()), block_number));
 
+		fill_key_history::<T>(&did_subject, &did_details);
 		Did::<T>::insert(&did_subject, did_details);
 		let origin = RawOrigin::Signed(did_subject.clone());
 	}: add_key_agreement_key(origin, new_key_agreement_key)
@@ -833,6 +891,7 @@ benchmarks! {
 		assert_ok!(did_details.update_delegation_key(DidVerificationKey::from(get_ed25519_public_delegation_key()), block_number));
 		assert_ok!(did_details.update_assertion_key(DidVerificationKey::from(get_ed25519_public_assertion_key()), block_number));
 
+		fill_key_history::<T>(&did_subject, &did_details);
 		Did::<T>::insert(&did_subject, did_details);
 		let origin = RawOrigin::Signed(did_subject.clone());
 	}: remove_key_agreement_key(origin, key_agreement_key_id)
@@ -856,6 +915,7 @@ benchmarks! {
 		assert_ok!(did_details.update_delegation_key(DidVerificationKey::from(get_sr25519_public_delegation_key()), block_number));
 		assert_ok!(did_details.update_assertion_key(DidVerificationKey::from(get_sr25519_public_assertion_key()), block_number));
 
+		fill_key_history::<T>(&did_subject, &did_details);
 		Did::<T>::insert(&did_subject, did_details);
 		let origin = RawOrigin::Signed(did_subject.clone());
 	}: remove_key_agreement_key(origin, key_agreement_key_id)
@@ -879,6 +939,7 @@ benchmarks! {
 		assert_ok!(did_details.update_delegation_key(DidVerificationKey::from(get_ecdsa_public_delegation_key()), block_number));
 		assert_ok!(did_details.update_assertion_key(DidVerificationKey::from(get_ecdsa_public_assertion_key()), block_number));
 
+		fill_key_history::<T>(&did_subject, &did_details);
 		Did::<T>::insert(&did_subject, did_details);
 		let origin = RawOrigin::Signed(did_subject.clone());
 	}: remove_key_agreement_key(origin, key_agreement_key_id)
@@ -1069,6 +1130,181 @@ benchmarks! {
 	verify {
 			Did::<T>::get(&did_subject).expect("DID entry should be created");
 	}
+	add_controller {
+		let public_auth_key = get_ecdsa_public_authentication_key();
+		let did_subject: DidIdentifierOf<T> = MultiSigner::from(public_auth_key).into_account().into();
+		// Max allowed - 1.
+		let old_controllers: Vec<DidIdentifierOf<T>> = (1..T::MaxControllersPerDid::get())
+			.map(|i| account(CONTROLLER_DID_ID, i, DEFAULT_ACCOUNT_SEED))
+			.collect();
+		let controller: DidIdentifierOf<T> = account(CONTROLLER_DID_ID, 0, DEFAULT_ACCOUNT_SEED);
+
+		let did_details = generate_base_did_details::<T>(DidVerificationKey::from(public_auth_key));
+		Did::<T>::insert(&did_subject, did_details.clone());
+		Did::<T>::insert(&controller, did_details);
+		Controllers::<T>::insert(&did_subject, DidControllersOf::<T>::try_from(old_controllers).expect("Should not exceed the max number of controllers."));
+		let origin = RawOrigin::Signed(did_subject.clone());
+	}: _(origin, controller.clone())
+	verify {
+		assert!(Controllers::<T>::get(&did_subject).contains(&controller));
+	}
+
+	remove_controller {
+		let public_auth_key = get_ecdsa_public_authentication_key();
+		let did_subject: DidIdentifierOf<T> = MultiSigner::from(public_auth_key).into_account().into();
+		let controllers: Vec<DidIdentifierOf<T>> = (0..T::MaxControllersPerDid::get())
+			.map(|i| account(CONTROLLER_DID_ID, i, DEFAULT_ACCOUNT_SEED))
+			.collect();
+		let controller = controllers.last().cloned().expect("There should be at least one controller.");
+
+		let did_details = generate_base_did_details::<T>(DidVerificationKey::from(public_auth_key));
+		Did::<T>::insert(&did_subject, did_details);
+		Controllers::<T>::insert(&did_subject, DidControllersOf::<T>::try_from(controllers).expect("Should not exceed the max number of controllers."));
+		let origin = RawOrigin::Signed(did_subject.clone());
+	}: _(origin, controller.clone())
+	verify {
+		assert!(!Controllers::<T>::get(&did_subject).contains(&controller));
+	}
+
+	controller_set_authentication_key {
+		let old_public_auth_key = get_ecdsa_public_authentication_key();
+		let did_subject: DidIdentifierOf<T> = MultiSigner::from(old_public_auth_key).into_account().into();
+		let new_auth_key = ecdsa_generate(UNUSED_KEY_ID, None);
+		let controllers: Vec<DidIdentifierOf<T>> = (0..T::MaxControllersPerDid::get())
+			.map(|i| account(CONTROLLER_DID_ID, i, DEFAULT_ACCOUNT_SEED))
+			.collect();
+		let controller = controllers.last().cloned().expect("There should be at least one controller.");
+
+		let did_details = generate_base_did_details::<T>(DidVerificationKey::from(old_public_auth_key));
+		fill_key_history::<T>(&did_subject, &did_details);
+		Did::<T>::insert(&did_subject, did_details);
+		Controllers::<T>::insert(&did_subject, DidControllersOf::<T>::try_from(controllers).expect("Should not exceed the max number of controllers."));
+		let origin = RawOrigin::Signed(controller);
+		let did_key = DidVerificationKey::from(new_auth_key);
+	}: _(origin, did_subject.clone(), did_key.clone())
+	verify {
+		let new_did_details = Did::<T>::get(&did_subject).expect("DID entry should be present.");
+		assert_eq!(
+			new_did_details.authentication_key,
+			utils::calculate_key_id::<T>(&did_key.into())
+		);
+	}
+
+	set_recovery_policy {
+		let r in 1 .. T::MaxRecoveryDids::get();
+
+		let public_auth_key = get_ecdsa_public_authentication_key();
+		let did_subject: DidIdentifierOf<T> = MultiSigner::from(public_auth_key).into_account().into();
+		let recovery_dids = get_recovery_dids::<T>(r);
+
+		let did_details = generate_base_did_details::<T>(DidVerificationKey::from(public_auth_key));
+		Did::<T>::insert(&did_subject, did_details);
+		let origin = RawOrigin::Signed(did_subject.clone());
+	}: _(origin, recovery_dids, r, T::MinRecoveryDelay::get())
+	verify {
+		assert!(RecoveryPolicies::<T>::contains_key(&did_subject));
+	}
+
+	remove_recovery_policy {
+		let public_auth_key = get_ecdsa_public_authentication_key();
+		let did_subject: DidIdentifierOf<T> = MultiSigner::from(public_auth_key).into_account().into();
+		let recovery_dids = save_recovery_policy::<T>(&did_subject);
+		RecoveryRequests::<T>::insert(&did_subject, DidRecoveryRequest {
+			new_authentication_key: DidVerificationKey::from(ecdsa_generate(UNUSED_KEY_ID, None)),
+			initiated_at: BlockNumberFor::<T>::zero(),
+			approvals: recovery_dids,
+		});
+
+		let did_details = generate_base_did_details::<T>(DidVerificationKey::from(public_auth_key));
+		Did::<T>::insert(&did_subject, did_details);
+		let origin = RawOrigin::Signed(did_subject.clone());
+	}: _(origin)
+	verify {
+		assert!(!RecoveryPolicies::<T>::contains_key(&did_subject));
+		assert!(!RecoveryRequests::<T>::contains_key(&did_subject));
+	}
+
+	initiate_recovery {
+		let public_auth_key = get_ecdsa_public_authentication_key();
+		let did_subject: DidIdentifierOf<T> = MultiSigner::from(public_auth_key).into_account().into();
+		let recovery_dids = save_recovery_policy::<T>(&did_subject);
+		let initiator = recovery_dids.last().cloned().expect("There should be at least one recovery DID.");
+
+		let did_details = generate_base_did_details::<T>(DidVerificationKey::from(public_auth_key));
+		Did::<T>::insert(&did_subject, did_details);
+		let origin = RawOrigin::Signed(initiator);
+		let new_auth_key = DidVerificationKey::from(ecdsa_generate(UNUSED_KEY_ID, None));
+	}: _(origin, did_subject.clone(), new_auth_key)
+	verify {
+		assert!(RecoveryRequests::<T>::contains_key(&did_subject));
+	}
+
+	approve_recovery {
+		let public_auth_key = get_ecdsa_public_authentication_key();
+		let did_subject: DidIdentifierOf<T> = MultiSigner::from(public_auth_key).into_account().into();
+		let recovery_dids = save_recovery_policy::<T>(&did_subject);
+		let approver = recovery_dids.last().cloned().expect("There should be at least one recovery DID.");
+		// All but the approver.
+		let approvals = get_recovery_dids::<T>(T::MaxRecoveryDids::get() - 1);
+		RecoveryRequests::<T>::insert(&did_subject, DidRecoveryRequest {
+			new_authentication_key: DidVerificationKey::from(ecdsa_generate(UNUSED_KEY_ID, None)),
+			initiated_at: BlockNumberFor::<T>::zero(),
+			approvals,
+		});
+
+		let did_details = generate_base_did_details::<T>(DidVerificationKey::from(public_auth_key));
+		Did::<T>::insert(&did_subject, did_details);
+		let origin = RawOrigin::Signed(approver.clone());
+	}: _(origin, did_subject.clone())
+	verify {
+		let request = RecoveryRequests::<T>::get(&did_subject).expect("Recovery should be ongoing.");
+		assert!(request.approvals.contains(&approver));
+	}
+
+	cancel_recovery {
+		let public_auth_key = get_ecdsa_public_authentication_key();
+		let did_subject: DidIdentifierOf<T> = MultiSigner::from(public_auth_key).into_account().into();
+		let recovery_dids = save_recovery_policy::<T>(&did_subject);
+		RecoveryRequests::<T>::insert(&did_subject, DidRecoveryRequest {
+			new_authentication_key: DidVerificationKey::from(ecdsa_generate(UNUSED_KEY_ID, None)),
+			initiated_at: BlockNumberFor::<T>::zero(),
+			approvals: recovery_dids,
+		});
+
+		let did_details = generate_base_did_details::<T>(DidVerificationKey::from(public_auth_key));
+		Did::<T>::insert(&did_subject, did_details);
+		let origin = RawOrigin::Signed(did_subject.clone());
+	}: _(origin)
+	verify {
+		assert!(!RecoveryRequests::<T>::contains_key(&did_subject));
+	}
+
+	recover {
+		let old_public_auth_key = get_ecdsa_public_authentication_key();
+		let did_subject: DidIdentifierOf<T> = MultiSigner::from(old_public_auth_key).into_account().into();
+		let new_auth_key = DidVerificationKey::from(ecdsa_generate(UNUSED_KEY_ID, None));
+		let recovery_dids = save_recovery_policy::<T>(&did_subject);
+		let caller = recovery_dids.last().cloned().expect("There should be at least one recovery DID.");
+		RecoveryRequests::<T>::insert(&did_subject, DidRecoveryRequest {
+			new_authentication_key: new_auth_key.clone(),
+			initiated_at: BlockNumberFor::<T>::zero(),
+			approvals: recovery_dids,
+		});
+
+		let did_details = generate_base_did_details::<T>(DidVerificationKey::from(old_public_auth_key));
+		fill_key_history::<T>(&did_subject, &did_details);
+		Did::<T>::insert(&did_subject, did_details);
+		frame_system::Pallet::<T>::set_block_number(T::MinRecoveryDelay::get());
+		let origin = RawOrigin::Signed(caller);
+	}: _(origin, did_subject.clone())
+	verify {
+		let new_did_details = Did::<T>::get(&did_subject).expect("DID entry should be present.");
+		assert_eq!(
+			new_did_details.authentication_key,
+			utils::calculate_key_id::<T>(&new_auth_key.into())
+		);
+	}
+
 	impl_benchmark_test_suite! (
 		Pallet,
 		crate::mock::new_test_ext(),
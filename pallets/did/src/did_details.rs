@@ -176,6 +176,55 @@ pub enum DidVerificationKeyRelationship {
 	AssertionMethod,
 }
 
+/// Relationships a key of a DID can be bound to, as tracked by the DID key
+/// history.
+#[derive(Clone, Copy, RuntimeDebug, Decode, Encode, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub enum DidKeyRelationship {
+	/// See [DidVerificationKeyRelationship::Authentication].
+	Authentication,
+	/// See [DidVerificationKeyRelationship::CapabilityDelegation].
+	CapabilityDelegation,
+	/// See [DidVerificationKeyRelationship::AssertionMethod].
+	AssertionMethod,
+	/// Key used to encrypt data addressed to the DID subject.
+	KeyAgreement,
+}
+
+impl TryFrom<DidVerificationKeyRelationship> for DidKeyRelationship {
+	type Error = ();
+
+	fn try_from(relationship: DidVerificationKeyRelationship) -> Result<Self, Self::Error> {
+		match relationship {
+			DidVerificationKeyRelationship::Authentication => Ok(Self::Authentication),
+			DidVerificationKeyRelationship::CapabilityDelegation => Ok(Self::CapabilityDelegation),
+			DidVerificationKeyRelationship::AssertionMethod => Ok(Self::AssertionMethod),
+			// No key is ever bound to this relationship.
+			DidVerificationKeyRelationship::CapabilityInvocation => Err(()),
+		}
+	}
+}
+
+/// A lifecycle record of a key bound to a DID under a given relationship.
+///
+/// A key is considered valid for the relationship from `added_at`
+/// (inclusive) until `revoked_at` (exclusive), or indefinitely if it has not
+/// been revoked yet.
+#[derive(Clone, RuntimeDebug, Decode, Encode, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct DidKeyLifecycle<KeyId, BlockNumber, AccountId> {
+	/// The ID of the key.
+	pub key_id: KeyId,
+	/// The public key itself, kept so that it can still be used after it has
+	/// been removed from the DID.
+	pub key: DidPublicKey<AccountId>,
+	/// The relationship the key was bound to.
+	pub relationship: DidKeyRelationship,
+	/// The block number in which the key was bound to the relationship.
+	pub added_at: BlockNumber,
+	/// The block number in which the key was unbound from the relationship,
+	/// if any.
+	pub revoked_at: Option<BlockNumber>,
+}
+
 /// Types of signatures supported by this pallet.
 #[derive(Clone, Decode, RuntimeDebug, Encode, Eq, PartialEq, TypeInfo)]
 pub enum DidSignature {
@@ -562,6 +611,25 @@ impl<T: Config> DidDetails<T> {
 		}
 	}
 
+	/// Returns the IDs of all the keys currently bound to the DID, along with
+	/// the relationship each of them is bound to.
+	pub fn key_relationships(&self) -> Vec<(KeyIdOf<T>, DidKeyRelationship)> {
+		let mut relationships = Vec::with_capacity(self.key_agreement_keys.len() + 3);
+		relationships.push((self.authentication_key, DidKeyRelationship::Authentication));
+		if let Some(delegation_key) = self.delegation_key {
+			relationships.push((delegation_key, DidKeyRelationship::CapabilityDelegation));
+		}
+		if let Some(assertion_key) = self.assertion_key {
+			relationships.push((assertion_key, DidKeyRelationship::AssertionMethod));
+		}
+		relationships.extend(
+			self.key_agreement_keys
+				.iter()
+				.map(|key_id| (*key_id, DidKeyRelationship::KeyAgreement)),
+		);
+		relationships
+	}
+
 	/// Increase the tx counter of the DID.
 	pub fn increase_tx_counter(&mut self) -> u64 {
 		// Since we have transaction mortality now, we can safely wrap nonces around.
@@ -616,6 +684,24 @@ pub enum RelationshipDeriveError {
 pub type DeriveDidCallKeyRelationshipResult =
 	Result<DidVerificationKeyRelationship, RelationshipDeriveError>;
 
+/// Derives the verification key relationship of calls which anchor
+/// assertions, such as statements and schemas, on behalf of a DID.
+///
+/// The assertion key is required from the block returned by `RequiredFrom`
+/// on. Until then, or if no block is set, the authentication key is used, so
+/// that DIDs without an assertion key can add one before it is required.
+pub fn assertion_key_relationship<T, RequiredFrom>() -> DeriveDidCallKeyRelationshipResult
+where
+	T: frame_system::Config,
+	RequiredFrom: Get<Option<BlockNumberFor<T>>>,
+{
+	match RequiredFrom::get() {
+		Some(block) if frame_system::Pallet::<T>::block_number() >= block =>
+			Ok(DidVerificationKeyRelationship::AssertionMethod),
+		_ => Ok(DidVerificationKeyRelationship::Authentication),
+	}
+}
+
 /// Trait for extrinsic DID-based authorization.
 ///
 /// The trait allows
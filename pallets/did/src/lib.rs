@@ -52,14 +52,31 @@
 //! - Zero or one **assertion key**: used to sign and authorise the creation of new entries [stream,
 //!   score..] on the CORD blockchain. In case no assertion key is present, the DID subject cannot
 //!   write new entries on the CORD blockchain. For more info, check the [streams
-//!   pallet](../../streams/).
+//!   pallet](../../streams/). Runtimes may keep accepting the authentication key for such calls
+//!   until an activation block through `assertion_key_relationship`, so that DIDs can add an
+//!   assertion key first.
 //!
 //! - A set of **public keys**: includes at least the previous keys in addition to any past
 //!   assertion key that has been rotated but not entirely revoked.
 //!
+//! - Zero or more **controllers**: other DIDs allowed to rotate the authentication key of the DID,
+//!   e.g., an organisation DID controlling the DIDs issued to its staff members. Controllers are
+//!   appointed with the authentication key and share its authority, so their rotations take effect
+//!   immediately and are not subject to the time-lock of a recovery policy.
+//!
+//! - An optional **recovery policy**: a set of recovery DIDs, a threshold of them, and a time-lock.
+//!   Once enough recovery DIDs approve a recovery and the time-lock elapses, the authentication key
+//!   is rotated to the one proposed in the recovery. Until then, the current authentication key
+//!   can cancel the recovery.
+//!
 //! - A set of **service endpoints**: pointing to the description of the services the DID subject
 //!   exposes. For more information, check the W3C DID Core specification.
 //!
+//! - A **key history**: the keys that have been bound to the DID, along with the blocks in which
+//!   they were added and revoked, so that signatures can be verified against the keys that were
+//!   valid at a past block. DIDs created before the history was introduced are seeded with their
+//!   current keys by `migrations::v2::MigrateToKeyHistory`.
+//!
 //! - A **transaction counter**: acts as a nonce to avoid replay or signature forgery attacks. Each
 //!   time a DID-signed transaction is executed, the counter is incremented.
 //!
@@ -75,7 +92,9 @@
 
 pub mod did_details;
 pub mod errors;
+pub mod migrations;
 pub mod origin;
+pub mod recovery;
 pub mod service_endpoints;
 pub mod weights;
 
@@ -97,9 +116,9 @@ mod utils;
 
 pub use crate::{
 	did_details::{
-		DeriveDidCallAuthorizationVerificationKeyRelationship, DeriveDidCallKeyRelationshipResult,
-		DidAuthorizedCallOperationWithVerificationRelationship, DidSignature,
-		DidVerificationKeyRelationship, RelationshipDeriveError,
+		assertion_key_relationship, DeriveDidCallAuthorizationVerificationKeyRelationship,
+		DeriveDidCallKeyRelationshipResult, DidAuthorizedCallOperationWithVerificationRelationship,
+		DidKeyRelationship, DidSignature, DidVerificationKeyRelationship, RelationshipDeriveError,
 	},
 	origin::{DidRawOrigin, EnsureDidOrigin},
 	pallet::*,
@@ -140,14 +159,15 @@ pub mod pallet {
 	use crate::{
 		did_details::{
 			DeriveDidCallAuthorizationVerificationKeyRelationship, DidAuthorizedCallOperation,
-			DidCreationDetails, DidDetails, DidEncryptionKey, DidSignature,
-			DidVerifiableIdentifier, DidVerificationKey, RelationshipDeriveError,
+			DidCreationDetails, DidDetails, DidEncryptionKey, DidKeyLifecycle, DidPublicKey,
+			DidSignature, DidVerifiableIdentifier, DidVerificationKey, RelationshipDeriveError,
 		},
+		recovery::{DidRecoveryPolicy, DidRecoveryRequest, RecoveryDidsOf},
 		service_endpoints::{utils as service_endpoints_utils, DidEndpoint, ServiceEndpointId},
 	};
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	/// Reference to a payload of data of variable size.
 	pub type Payload = [u8];
@@ -183,6 +203,17 @@ pub mod pallet {
 		u64,
 	>;
 
+	/// Type for a single entry of the key history of a DID.
+	pub type DidKeyLifecycleOf<T> = DidKeyLifecycle<KeyIdOf<T>, BlockNumberFor<T>, AccountIdOf<T>>;
+
+	/// Type for the bounded list of controllers of a DID.
+	pub type DidControllersOf<T> =
+		BoundedVec<DidIdentifierOf<T>, <T as Config>::MaxControllersPerDid>;
+
+	/// Type for the bounded key history of a DID.
+	pub type DidKeyHistoryOf<T> =
+		BoundedVec<DidKeyLifecycleOf<T>, <T as Config>::MaxKeyHistoryEntries>;
+
 	#[pallet::config]
 	pub trait Config: frame_system::Config + Debug {
 		/// Type for a dispatchable call that can be proxied through the DID
@@ -265,6 +296,26 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxNumberOfUrlsPerService: Get<u32>;
 
+		/// The maximum number of key lifecycle entries kept for a DID.
+		///
+		/// Should be greater than `MaxPublicKeysPerDid`, as the oldest revoked
+		/// entries are the only ones that can be evicted.
+		#[pallet::constant]
+		type MaxKeyHistoryEntries: Get<u32>;
+
+		/// The maximum number of controllers a DID can have.
+		#[pallet::constant]
+		type MaxControllersPerDid: Get<u32>;
+
+		/// The maximum number of recovery DIDs in a recovery policy.
+		#[pallet::constant]
+		type MaxRecoveryDids: Get<u32>;
+
+		/// The minimum number of blocks a recovery policy can require to
+		/// elapse between the initiation of a recovery and its completion.
+		#[pallet::constant]
+		type MinRecoveryDelay: Get<BlockNumberFor<Self>>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -305,7 +356,38 @@ pub mod pallet {
 	/// It maps from a DID identifier to a unit tuple, for the sake of tracking
 	/// DID identifiers.
 	#[pallet::storage]
-	pub(crate) type DidBlacklist<T> = StorageMap<_, Blake2_128Concat, DidIdentifierOf<T>, ()>;
+	pub type DidBlacklist<T> = StorageMap<_, Blake2_128Concat, DidIdentifierOf<T>, ()>;
+
+	/// The key lifecycle history of DIDs.
+	///
+	/// It maps from a DID identifier to the list of keys that have been
+	/// bound to it, along with the blocks in which they were added and
+	/// revoked. The history is removed along with the DID.
+	#[pallet::storage]
+	pub type KeyHistory<T> =
+		StorageMap<_, Blake2_128Concat, DidIdentifierOf<T>, DidKeyHistoryOf<T>, ValueQuery>;
+
+	/// Controllers of DIDs.
+	///
+	/// It maps from a DID identifier to the DIDs allowed to rotate its
+	/// authentication key.
+	#[pallet::storage]
+	pub type Controllers<T> =
+		StorageMap<_, Blake2_128Concat, DidIdentifierOf<T>, DidControllersOf<T>, ValueQuery>;
+
+	/// Social-recovery policies of DIDs.
+	///
+	/// It maps from a DID identifier to its recovery policy.
+	#[pallet::storage]
+	pub type RecoveryPolicies<T> =
+		StorageMap<_, Blake2_128Concat, DidIdentifierOf<T>, DidRecoveryPolicy<T>>;
+
+	/// Recoveries of DIDs that have been initiated but not completed yet.
+	///
+	/// It maps from a DID identifier to the details of its ongoing recovery.
+	#[pallet::storage]
+	pub type RecoveryRequests<T> =
+		StorageMap<_, Blake2_128Concat, DidIdentifierOf<T>, DidRecoveryRequest<T>>;
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -320,8 +402,36 @@ pub mod pallet {
 		/// \[DID identifier\]
 		Deleted { identifier: DidIdentifierOf<T> },
 		/// A DID-authorised call has been executed.
-		/// \[DID caller, dispatch result\]
-		CallDispatched { identifier: DidIdentifierOf<T>, result: DispatchResult },
+		/// \[DID caller, verification key relationship, dispatch result\]
+		CallDispatched {
+			identifier: DidIdentifierOf<T>,
+			key_relationship: DidVerificationKeyRelationship,
+			result: DispatchResult,
+		},
+		/// A controller has been added to a DID.
+		/// \[DID identifier, controller DID identifier\]
+		ControllerAdded { identifier: DidIdentifierOf<T>, controller: DidIdentifierOf<T> },
+		/// A controller has been removed from a DID.
+		/// \[DID identifier, controller DID identifier\]
+		ControllerRemoved { identifier: DidIdentifierOf<T>, controller: DidIdentifierOf<T> },
+		/// The recovery policy of a DID has been set.
+		/// \[DID identifier\]
+		RecoveryPolicySet { identifier: DidIdentifierOf<T> },
+		/// The recovery policy of a DID has been removed.
+		/// \[DID identifier\]
+		RecoveryPolicyRemoved { identifier: DidIdentifierOf<T> },
+		/// The recovery of a DID has been initiated.
+		/// \[DID identifier, recovery DID identifier\]
+		RecoveryInitiated { identifier: DidIdentifierOf<T>, initiator: DidIdentifierOf<T> },
+		/// The recovery of a DID has been approved by a recovery DID.
+		/// \[DID identifier, recovery DID identifier\]
+		RecoveryApproved { identifier: DidIdentifierOf<T>, approver: DidIdentifierOf<T> },
+		/// The recovery of a DID has been cancelled by the DID subject.
+		/// \[DID identifier\]
+		RecoveryCancelled { identifier: DidIdentifierOf<T> },
+		/// The authentication key of a DID has been rotated by a recovery.
+		/// \[DID identifier\]
+		Recovered { identifier: DidIdentifierOf<T> },
 	}
 
 	#[pallet::error]
@@ -385,6 +495,37 @@ pub mod pallet {
 		/// The number of service endpoints stored under the DID is larger than
 		/// the number of endpoints to delete.
 		MaxStoredEndpointsCountExceeded,
+		/// The key history of the DID is full and holds no revoked entry that
+		/// could be evicted.
+		MaxKeyHistoryEntriesExceeded,
+		/// A DID cannot be its own controller.
+		InvalidController,
+		/// The DID is already a controller of the given DID.
+		ControllerAlreadyExists,
+		/// The DID is not a controller of the given DID.
+		ControllerNotFound,
+		/// The maximum number of controllers for a DID has been reached.
+		MaxControllersExceeded,
+		/// The caller is not a controller of the given DID.
+		NotController,
+		/// The recovery policy has a threshold of zero or greater than the
+		/// number of recovery DIDs, duplicate recovery DIDs, includes the DID
+		/// itself, or has a delay shorter than the minimum allowed.
+		InvalidRecoveryPolicy,
+		/// The DID has no recovery policy.
+		RecoveryPolicyNotFound,
+		/// The caller is not a recovery DID of the given DID.
+		NotRecoveryDid,
+		/// A recovery of the DID has already been initiated.
+		RecoveryAlreadyInitiated,
+		/// No recovery of the DID has been initiated.
+		RecoveryNotInitiated,
+		/// The recovery DID has already approved the recovery.
+		RecoveryAlreadyApproved,
+		/// The recovery has not been approved by enough recovery DIDs yet.
+		RecoveryThresholdNotMet,
+		/// The time-lock of the recovery has not elapsed yet.
+		RecoveryDelayNotElapsed,
 		/// An error that is not supposed to take place, yet it happened.
 		Internal,
 	}
@@ -951,7 +1092,8 @@ pub mod pallet {
 		/// execute the extrinsic and it does not have to be tied in any way to
 		/// the CORD account identifying the DID subject.
 		///
-		/// Emits `DidCallDispatched`.
+		/// Emits `DidCallDispatched`, recording the verification key
+		/// relationship the call was authorized with.
 		///
 		/// # <weight>
 		/// Weight: O(1) + weight of the dispatched call
@@ -1006,6 +1148,7 @@ pub mod pallet {
 
 			Self::deposit_event(Event::CallDispatched {
 				identifier: did_identifier,
+				key_relationship: verification_key_relationship,
 				result: dispatch_event_payload,
 			});
 
@@ -1028,7 +1171,8 @@ pub mod pallet {
 		/// execute the extrinsic and must correspond to the required DID
 		/// Verification Key.
 		///
-		/// Emits `DidCallDispatched`.
+		/// Emits `DidCallDispatched`, recording the verification key
+		/// relationship the call was authorized with.
 		#[allow(clippy::boxed_local)]
 		#[pallet::call_index(15)]
 		#[pallet::weight({
@@ -1064,6 +1208,7 @@ pub mod pallet {
 
 			Self::deposit_event(Event::CallDispatched {
 				identifier: did_identifier,
+				key_relationship: verification_key_relationship,
 				result: dispatch_event_payload,
 			});
 
@@ -1114,6 +1259,365 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Add a controller to the DID.
+		///
+		/// A controller is another DID that is allowed to rotate the
+		/// authentication key of the DID, so that the DID survives the loss
+		/// of its authentication key. Its rotations take effect immediately,
+		/// even if the DID has a recovery policy.
+		///
+		/// The dispatch origin must be a DID origin proxied via the
+		/// `submit_did_call` extrinsic.
+		///
+		/// Emits `ControllerAdded`.
+		///
+		/// # <weight>
+		/// Weight: O(1)
+		/// - Reads: [Origin Account], Did, Controllers
+		/// - Writes: Controllers
+		/// # </weight>
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_controller())]
+		pub fn add_controller(
+			origin: OriginFor<T>,
+			controller: DidIdentifierOf<T>,
+		) -> DispatchResult {
+			let did_subject = T::EnsureOrigin::ensure_origin(origin)?.subject();
+			ensure!(Did::<T>::contains_key(&did_subject), Error::<T>::NotFound);
+			ensure!(controller != did_subject, Error::<T>::InvalidController);
+			ensure!(Did::<T>::contains_key(&controller), Error::<T>::NotFound);
+
+			Controllers::<T>::try_mutate(&did_subject, |controllers| {
+				ensure!(!controllers.contains(&controller), Error::<T>::ControllerAlreadyExists);
+				controllers
+					.try_push(controller.clone())
+					.map_err(|_| Error::<T>::MaxControllersExceeded)
+			})?;
+
+			Self::deposit_event(Event::ControllerAdded { identifier: did_subject, controller });
+			Ok(())
+		}
+
+		/// Remove a controller from the DID.
+		///
+		/// The dispatch origin must be a DID origin proxied via the
+		/// `submit_did_call` extrinsic.
+		///
+		/// Emits `ControllerRemoved`.
+		///
+		/// # <weight>
+		/// Weight: O(1)
+		/// - Reads: [Origin Account], Controllers
+		/// - Writes: Controllers
+		/// # </weight>
+		#[pallet::call_index(18)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_controller())]
+		pub fn remove_controller(
+			origin: OriginFor<T>,
+			controller: DidIdentifierOf<T>,
+		) -> DispatchResult {
+			let did_subject = T::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			Controllers::<T>::try_mutate_exists(&did_subject, |maybe_controllers| {
+				let controllers =
+					maybe_controllers.as_mut().ok_or(Error::<T>::ControllerNotFound)?;
+				let position = controllers
+					.iter()
+					.position(|c| c == &controller)
+					.ok_or(Error::<T>::ControllerNotFound)?;
+				controllers.remove(position);
+				if controllers.is_empty() {
+					*maybe_controllers = None;
+				}
+				Ok::<_, Error<T>>(())
+			})?;
+
+			Self::deposit_event(Event::ControllerRemoved { identifier: did_subject, controller });
+			Ok(())
+		}
+
+		/// Update the authentication key of a DID on behalf of one of its
+		/// controllers.
+		///
+		/// The old key is deleted from the set of public keys if it is
+		/// not used in any other part of the DID. The new key is added to the
+		/// set of public keys.
+		///
+		/// Unlike `recover`, the rotation is not time-locked and cannot be
+		/// cancelled by the current key. Controllers are appointed by the DID
+		/// itself through its authentication key and hold the same authority
+		/// over it, whereas recovery DIDs act once that key is lost. A DID
+		/// whose key should only be rotated after a delay must not appoint
+		/// controllers.
+		///
+		/// The dispatch origin must be a DID origin of a controller of the
+		/// DID, proxied via the `submit_did_call` extrinsic.
+		///
+		/// Emits `DidUpdated`.
+		///
+		/// # <weight>
+		/// Weight: O(1)
+		/// - Reads: [Origin Account], Controllers, Did, KeyHistory
+		/// - Writes: Did, KeyHistory
+		/// # </weight>
+		#[pallet::call_index(19)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::controller_set_authentication_key())]
+		pub fn controller_set_authentication_key(
+			origin: OriginFor<T>,
+			did: DidIdentifierOf<T>,
+			new_key: DidVerificationKey<AccountIdOf<T>>,
+		) -> DispatchResult {
+			let controller = T::EnsureOrigin::ensure_origin(origin)?.subject();
+			ensure!(Controllers::<T>::get(&did).contains(&controller), Error::<T>::NotController);
+			let mut did_details = Did::<T>::get(&did).ok_or(Error::<T>::NotFound)?;
+
+			log::debug!(
+				"Controller {:?} setting new authentication key {:?} for DID {:?}",
+				&controller,
+				&new_key,
+				&did
+			);
+
+			did_details
+				.update_authentication_key(new_key, frame_system::Pallet::<T>::block_number())
+				.map_err(Error::<T>::from)?;
+
+			Self::try_update_did(&did, did_details)?;
+
+			Self::deposit_event(Event::Updated { identifier: did });
+			Ok(())
+		}
+
+		/// Set the social-recovery policy of the DID, replacing any existing
+		/// one.
+		///
+		/// The policy cannot be changed while a recovery is ongoing; it must
+		/// be cancelled first.
+		///
+		/// The dispatch origin must be a DID origin proxied via the
+		/// `submit_did_call` extrinsic.
+		///
+		/// Emits `RecoveryPolicySet`.
+		///
+		/// # <weight>
+		/// Weight: O(R) where R is the number of recovery DIDs bounded by
+		/// `MaxRecoveryDids`.
+		/// - Reads: [Origin Account], Did, RecoveryRequests
+		/// - Writes: RecoveryPolicies
+		/// # </weight>
+		#[pallet::call_index(20)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_recovery_policy(
+			recovery_dids.len().saturated_into::<u32>()
+		))]
+		pub fn set_recovery_policy(
+			origin: OriginFor<T>,
+			recovery_dids: RecoveryDidsOf<T>,
+			threshold: u32,
+			delay: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let did_subject = T::EnsureOrigin::ensure_origin(origin)?.subject();
+			ensure!(Did::<T>::contains_key(&did_subject), Error::<T>::NotFound);
+			ensure!(
+				!RecoveryRequests::<T>::contains_key(&did_subject),
+				Error::<T>::RecoveryAlreadyInitiated
+			);
+
+			Self::ensure_valid_recovery_policy(&did_subject, &recovery_dids, threshold, delay)?;
+
+			RecoveryPolicies::<T>::insert(
+				&did_subject,
+				DidRecoveryPolicy { recovery_dids, threshold, delay },
+			);
+
+			Self::deposit_event(Event::RecoveryPolicySet { identifier: did_subject });
+			Ok(())
+		}
+
+		/// Remove the social-recovery policy of the DID, cancelling any
+		/// ongoing recovery.
+		///
+		/// The dispatch origin must be a DID origin proxied via the
+		/// `submit_did_call` extrinsic.
+		///
+		/// Emits `RecoveryPolicyRemoved`.
+		///
+		/// # <weight>
+		/// Weight: O(1)
+		/// - Reads: [Origin Account], RecoveryPolicies
+		/// - Writes: RecoveryPolicies, RecoveryRequests
+		/// # </weight>
+		#[pallet::call_index(21)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_recovery_policy())]
+		pub fn remove_recovery_policy(origin: OriginFor<T>) -> DispatchResult {
+			let did_subject = T::EnsureOrigin::ensure_origin(origin)?.subject();
+			RecoveryPolicies::<T>::take(&did_subject).ok_or(Error::<T>::RecoveryPolicyNotFound)?;
+			RecoveryRequests::<T>::remove(&did_subject);
+
+			Self::deposit_event(Event::RecoveryPolicyRemoved { identifier: did_subject });
+			Ok(())
+		}
+
+		/// Initiate the recovery of a DID, proposing a new authentication key
+		/// for it.
+		///
+		/// The initiation counts as an approval by the initiator.
+		///
+		/// The dispatch origin must be a DID origin of a recovery DID of the
+		/// DID being recovered, proxied via the `submit_did_call` extrinsic.
+		///
+		/// Emits `RecoveryInitiated`.
+		///
+		/// # <weight>
+		/// Weight: O(1)
+		/// - Reads: [Origin Account], RecoveryPolicies, RecoveryRequests
+		/// - Writes: RecoveryRequests
+		/// # </weight>
+		#[pallet::call_index(22)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::initiate_recovery())]
+		pub fn initiate_recovery(
+			origin: OriginFor<T>,
+			did: DidIdentifierOf<T>,
+			new_authentication_key: DidVerificationKey<AccountIdOf<T>>,
+		) -> DispatchResult {
+			let initiator = T::EnsureOrigin::ensure_origin(origin)?.subject();
+			let policy =
+				RecoveryPolicies::<T>::get(&did).ok_or(Error::<T>::RecoveryPolicyNotFound)?;
+			ensure!(policy.recovery_dids.contains(&initiator), Error::<T>::NotRecoveryDid);
+			ensure!(
+				!RecoveryRequests::<T>::contains_key(&did),
+				Error::<T>::RecoveryAlreadyInitiated
+			);
+
+			let mut approvals = RecoveryDidsOf::<T>::default();
+			approvals.try_push(initiator.clone()).map_err(|_| Error::<T>::Internal)?;
+
+			RecoveryRequests::<T>::insert(
+				&did,
+				DidRecoveryRequest {
+					new_authentication_key,
+					initiated_at: frame_system::Pallet::<T>::block_number(),
+					approvals,
+				},
+			);
+
+			Self::deposit_event(Event::RecoveryInitiated { identifier: did, initiator });
+			Ok(())
+		}
+
+		/// Approve an ongoing recovery of a DID.
+		///
+		/// The dispatch origin must be a DID origin of a recovery DID of the
+		/// DID being recovered, proxied via the `submit_did_call` extrinsic.
+		///
+		/// Emits `RecoveryApproved`.
+		///
+		/// # <weight>
+		/// Weight: O(1)
+		/// - Reads: [Origin Account], RecoveryPolicies, RecoveryRequests
+		/// - Writes: RecoveryRequests
+		/// # </weight>
+		#[pallet::call_index(23)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::approve_recovery())]
+		pub fn approve_recovery(origin: OriginFor<T>, did: DidIdentifierOf<T>) -> DispatchResult {
+			let approver = T::EnsureOrigin::ensure_origin(origin)?.subject();
+			let policy =
+				RecoveryPolicies::<T>::get(&did).ok_or(Error::<T>::RecoveryPolicyNotFound)?;
+			ensure!(policy.recovery_dids.contains(&approver), Error::<T>::NotRecoveryDid);
+
+			RecoveryRequests::<T>::try_mutate(&did, |maybe_request| {
+				let request = maybe_request.as_mut().ok_or(Error::<T>::RecoveryNotInitiated)?;
+				ensure!(
+					!request.approvals.contains(&approver),
+					Error::<T>::RecoveryAlreadyApproved
+				);
+				request.approvals.try_push(approver.clone()).map_err(|_| Error::<T>::Internal)
+			})?;
+
+			Self::deposit_event(Event::RecoveryApproved { identifier: did, approver });
+			Ok(())
+		}
+
+		/// Cancel an ongoing recovery of the DID.
+		///
+		/// As the DID origin requires the current authentication key, this
+		/// lets the DID subject stop a recovery it has not asked for.
+		///
+		/// The dispatch origin must be a DID origin proxied via the
+		/// `submit_did_call` extrinsic.
+		///
+		/// Emits `RecoveryCancelled`.
+		///
+		/// # <weight>
+		/// Weight: O(1)
+		/// - Reads: [Origin Account], RecoveryRequests
+		/// - Writes: RecoveryRequests
+		/// # </weight>
+		#[pallet::call_index(24)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_recovery())]
+		pub fn cancel_recovery(origin: OriginFor<T>) -> DispatchResult {
+			let did_subject = T::EnsureOrigin::ensure_origin(origin)?.subject();
+			RecoveryRequests::<T>::take(&did_subject).ok_or(Error::<T>::RecoveryNotInitiated)?;
+
+			Self::deposit_event(Event::RecoveryCancelled { identifier: did_subject });
+			Ok(())
+		}
+
+		/// Complete the recovery of a DID, rotating its authentication key to
+		/// the one proposed when the recovery was initiated.
+		///
+		/// The recovery must have been approved by at least the threshold of
+		/// recovery DIDs required by the policy, and the time-lock of the
+		/// policy must have elapsed since its initiation.
+		///
+		/// The dispatch origin must be a DID origin of a recovery DID of the
+		/// DID being recovered, proxied via the `submit_did_call` extrinsic.
+		///
+		/// Emits `Recovered`.
+		///
+		/// # <weight>
+		/// Weight: O(1)
+		/// - Reads: [Origin Account], RecoveryPolicies, RecoveryRequests, Did, KeyHistory
+		/// - Writes: RecoveryRequests, Did, KeyHistory
+		/// # </weight>
+		#[pallet::call_index(25)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::recover())]
+		pub fn recover(origin: OriginFor<T>, did: DidIdentifierOf<T>) -> DispatchResult {
+			let caller = T::EnsureOrigin::ensure_origin(origin)?.subject();
+			let policy =
+				RecoveryPolicies::<T>::get(&did).ok_or(Error::<T>::RecoveryPolicyNotFound)?;
+			ensure!(policy.recovery_dids.contains(&caller), Error::<T>::NotRecoveryDid);
+
+			let request =
+				RecoveryRequests::<T>::get(&did).ok_or(Error::<T>::RecoveryNotInitiated)?;
+			ensure!(
+				request.approvals.len().saturated_into::<u32>() >= policy.threshold,
+				Error::<T>::RecoveryThresholdNotMet
+			);
+			let current_block_number = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				current_block_number >= request.initiated_at.saturating_add(policy.delay),
+				Error::<T>::RecoveryDelayNotElapsed
+			);
+
+			let mut did_details = Did::<T>::get(&did).ok_or(Error::<T>::NotFound)?;
+
+			log::debug!(
+				"Recovering DID {:?} with new authentication key {:?}",
+				&did,
+				&request.new_authentication_key
+			);
+
+			did_details
+				.update_authentication_key(request.new_authentication_key, current_block_number)
+				.map_err(Error::<T>::from)?;
+
+			Self::try_update_did(&did, did_details)?;
+			RecoveryRequests::<T>::remove(&did);
+
+			Self::deposit_event(Event::Recovered { identifier: did });
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T>
@@ -1140,6 +1644,7 @@ pub mod pallet {
 			// otherwise generate a AlreadyExists error.
 			ensure!(!Did::<T>::contains_key(&did_identifier), Error::<T>::AlreadyExists);
 
+			Self::record_key_changes(&did_identifier, None, &did_entry)?;
 			Did::<T>::insert(&did_identifier, did_entry);
 
 			Pallet::<T>::deposit_event(Event::Created {
@@ -1160,11 +1665,136 @@ pub mod pallet {
 			did_identifier: &DidIdentifierOf<T>,
 			did_details: DidDetails<T>,
 		) -> DispatchResult {
+			let old_details = Did::<T>::get(did_identifier);
+			Self::record_key_changes(did_identifier, old_details.as_ref(), &did_details)?;
 			Did::<T>::insert(did_identifier, did_details);
 
 			Ok(())
 		}
 
+		/// Update the key history of a DID with the difference between its
+		/// old and new details, marking keys that are no longer bound to a
+		/// relationship as revoked and recording newly bound ones as added in
+		/// the current block.
+		///
+		/// When the history is full, the oldest revoked entries are evicted to
+		/// make room for the new ones.
+		fn record_key_changes(
+			did_identifier: &DidIdentifierOf<T>,
+			old_details: Option<&DidDetails<T>>,
+			new_details: &DidDetails<T>,
+		) -> Result<(), Error<T>> {
+			let old_relationships =
+				old_details.map(|details| details.key_relationships()).unwrap_or_default();
+			let new_relationships = new_details.key_relationships();
+
+			let removed = old_relationships.iter().filter(|r| !new_relationships.contains(r));
+			let mut added = new_relationships.iter().filter(|r| !old_relationships.contains(r));
+
+			if removed.clone().next().is_none() && added.clone().next().is_none() {
+				return Ok(());
+			}
+
+			let block_number = frame_system::Pallet::<T>::block_number();
+			let mut history = KeyHistory::<T>::get(did_identifier);
+
+			for (key_id, relationship) in removed {
+				history
+					.iter_mut()
+					.filter(|entry| {
+						entry.key_id == *key_id &&
+							entry.relationship == *relationship &&
+							entry.revoked_at.is_none()
+					})
+					.for_each(|entry| entry.revoked_at = Some(block_number));
+			}
+
+			added.try_for_each(|(key_id, relationship)| {
+				let key = new_details
+					.public_keys
+					.get(key_id)
+					.map(|key_details| key_details.key.clone())
+					.ok_or(Error::<T>::Internal)?;
+				if history.is_full() {
+					let evicted = history
+						.iter()
+						.position(|entry| entry.revoked_at.is_some())
+						.ok_or(Error::<T>::MaxKeyHistoryEntriesExceeded)?;
+					history.remove(evicted);
+				}
+				history
+					.try_push(DidKeyLifecycle {
+						key_id: *key_id,
+						key,
+						relationship: *relationship,
+						added_at: block_number,
+						revoked_at: None,
+					})
+					.map_err(|_| Error::<T>::MaxKeyHistoryEntriesExceeded)
+			})?;
+
+			KeyHistory::<T>::insert(did_identifier, history);
+
+			Ok(())
+		}
+
+		/// Check that a recovery policy for the given DID is well-formed.
+		fn ensure_valid_recovery_policy(
+			did_identifier: &DidIdentifierOf<T>,
+			recovery_dids: &RecoveryDidsOf<T>,
+			threshold: u32,
+			delay: BlockNumberFor<T>,
+		) -> Result<(), Error<T>> {
+			ensure!(
+				threshold > 0 && threshold <= recovery_dids.len().saturated_into::<u32>(),
+				Error::<T>::InvalidRecoveryPolicy
+			);
+			ensure!(delay >= T::MinRecoveryDelay::get(), Error::<T>::InvalidRecoveryPolicy);
+			ensure!(!recovery_dids.contains(did_identifier), Error::<T>::InvalidRecoveryPolicy);
+			ensure!(
+				recovery_dids
+					.iter()
+					.enumerate()
+					.all(|(index, recovery_did)| !recovery_dids[..index].contains(recovery_did)),
+				Error::<T>::InvalidRecoveryPolicy
+			);
+
+			Ok(())
+		}
+
+		/// Check whether `signature` is a valid signature over `payload`
+		/// created with a key that was bound to the DID under the given
+		/// relationship at `block_number`.
+		///
+		/// Keys that have since been rotated or removed are still taken into
+		/// account for blocks in which they were valid. Signatures of deleted
+		/// DIDs are not verified.
+		pub fn verify_signature_at(
+			did_identifier: &DidIdentifierOf<T>,
+			key_relationship: DidVerificationKeyRelationship,
+			payload: &Payload,
+			signature: &DidSignature,
+			block_number: BlockNumberFor<T>,
+		) -> bool {
+			let relationship = match DidKeyRelationship::try_from(key_relationship) {
+				Ok(relationship) => relationship,
+				Err(_) => return false,
+			};
+
+			KeyHistory::<T>::get(did_identifier)
+				.iter()
+				.filter(|entry| {
+					entry.relationship == relationship &&
+						entry.added_at <= block_number &&
+						entry.revoked_at.map_or(true, |revoked_at| block_number < revoked_at)
+				})
+				.any(|entry| match &entry.key {
+					DidPublicKey::PublicVerificationKey(key) =>
+						key.verify_signature(payload, signature).is_ok(),
+					DidPublicKey::PublicEncryptionKey(_) => false,
+				})
+		}
+
 		/// Verify the validity (i.e., nonce, signature and mortality) of a
 		/// DID-authorized operation and, if valid, update the DID state with
 		/// the latest nonce.
@@ -1312,9 +1942,13 @@ pub mod pallet {
 			};
 
 			// `take` calls `kill` internally
-			let _did_entry = Did::<T>::take(&did_subject).ok_or(Error::<T>::NotFound)?;
+			ensure!(Did::<T>::take(&did_subject).is_some(), Error::<T>::NotFound);
+			KeyHistory::<T>::remove(&did_subject);
 
 			DidEndpointsCount::<T>::remove(&did_subject);
+			Controllers::<T>::remove(&did_subject);
+			RecoveryPolicies::<T>::remove(&did_subject);
+			RecoveryRequests::<T>::remove(&did_subject);
 			// Mark as deleted to prevent potential replay-attacks of re-adding a previously
 			// deleted DID.
 			DidBlacklist::<T>::insert(&did_subject, ());
@@ -0,0 +1,125 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Storage migrations for the DID pallet.
+
+use crate::*;
+
+pub mod v2 {
+	use super::*;
+	use crate::did_details::{DidDetails, DidKeyLifecycle};
+	use frame_support::{
+		migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+		pallet_prelude::*,
+		weights::WeightMeter,
+	};
+	use sp_std::marker::PhantomData;
+
+	/// The identifier of the DID migrations in the multi-block migrator.
+	pub(crate) const MIGRATION_ID: &[u8; 16] = b"cord-did-history";
+
+	/// Seeds the key history of every DID created before version 2 of the
+	/// pallet with the keys currently bound to it, so that
+	/// `verify_signature_at` accepts signatures of DIDs which have not changed
+	/// their keys since.
+	///
+	/// Each key is recorded as bound since the block it was added to the DID
+	/// in. Keys which were rotated out before the upgrade are not known and
+	/// are not recorded.
+	///
+	/// The migration runs over several blocks, visiting one DID per iteration.
+	/// The cursor is the last DID that was visited.
+	pub struct MigrateToKeyHistory<T>(PhantomData<T>);
+
+	impl<T: Config> MigrateToKeyHistory<T> {
+		/// The weight of a single iteration, which reads the DID and its key
+		/// history, and seeds the history.
+		pub(crate) fn step_weight() -> Weight {
+			T::DbWeight::get().reads_writes(2, 1)
+		}
+
+		/// Returns the key history of a DID holding the keys currently bound
+		/// to it.
+		pub(crate) fn seed_history(details: &DidDetails<T>) -> DidKeyHistoryOf<T> {
+			let mut history = DidKeyHistoryOf::<T>::default();
+			for (key_id, relationship) in details.key_relationships() {
+				let Some(key_details) = details.public_keys.get(&key_id) else {
+					continue;
+				};
+				let _ = history.try_push(DidKeyLifecycle {
+					key_id,
+					key: key_details.key.clone(),
+					relationship,
+					added_at: key_details.block_number,
+					revoked_at: None,
+				});
+			}
+			history
+		}
+	}
+
+	impl<T: Config> SteppedMigration for MigrateToKeyHistory<T> {
+		type Cursor = DidIdentifierOf<T>;
+		type Identifier = MigrationId<16>;
+
+		fn id() -> Self::Identifier {
+			MigrationId { pallet_id: *MIGRATION_ID, version_from: 1, version_to: 2 }
+		}
+
+		fn step(
+			mut cursor: Option<Self::Cursor>,
+			meter: &mut WeightMeter,
+		) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+			let required = Self::step_weight();
+			if meter.remaining().any_lt(required) {
+				return Err(SteppedMigrationError::InsufficientWeight { required });
+			}
+
+			if Pallet::<T>::on_chain_storage_version() != 1 {
+				log::info!(
+					target: "runtime::did",
+					"skipping key history migration, on-chain storage version is {:?}",
+					Pallet::<T>::on_chain_storage_version()
+				);
+				return Ok(None);
+			}
+
+			while meter.try_consume(required).is_ok() {
+				let mut iter = match cursor {
+					Some(ref last) => Did::<T>::iter_from(Did::<T>::hashed_key_for(last)),
+					None => Did::<T>::iter(),
+				};
+
+				let Some((did_identifier, details)) = iter.next() else {
+					StorageVersion::new(2).put::<Pallet<T>>();
+					log::info!(target: "runtime::did", "migrated DIDs to version 2");
+					return Ok(None);
+				};
+
+				// DIDs which already record a history are left untouched.
+				if !KeyHistory::<T>::contains_key(&did_identifier) {
+					KeyHistory::<T>::insert(&did_identifier, Self::seed_history(&details));
+				}
+
+				cursor = Some(did_identifier);
+			}
+
+			Ok(cursor)
+		}
+	}
+}
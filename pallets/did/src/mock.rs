@@ -94,6 +94,11 @@ parameter_types! {
 	pub const MaxServiceUrlLength: u32 = 100u32;
 	pub const MaxNumberOfTypesPerService: u32 = 1u32;
 	pub const MaxNumberOfUrlsPerService: u32 = 1u32;
+	pub const MaxKeyHistoryEntries: u32 = 20u32;
+	pub const MaxControllersPerDid: u32 = 5u32;
+	pub const MaxRecoveryDids: u32 = 5u32;
+	pub const MinRecoveryDelay: u64 = 10u64;
+	pub storage AssertionKeyRequiredFrom: Option<u64> = None;
 }
 
 impl Config for Test {
@@ -122,6 +127,10 @@ impl Config for Test {
 	type MaxServiceUrlLength = MaxServiceUrlLength;
 	type MaxNumberOfTypesPerService = MaxNumberOfTypesPerService;
 	type MaxNumberOfUrlsPerService = MaxNumberOfUrlsPerService;
+	type MaxKeyHistoryEntries = MaxKeyHistoryEntries;
+	type MaxControllersPerDid = MaxControllersPerDid;
+	type MaxRecoveryDids = MaxRecoveryDids;
+	type MinRecoveryDelay = MinRecoveryDelay;
 }
 
 impl mock_origin::Config for Test {
@@ -133,6 +142,8 @@ impl mock_origin::Config for Test {
 parameter_types! {
 	#[derive(Debug, Clone)]
 	pub const MaxSpaceDelegates: u32 = 5u32;
+	pub const MaxSubspaceDepth: u32 = 3u32;
+	pub const MaxDelegationExpirationsPerBlock: u32 = 5u32;
 }
 
 pub struct NetworkPermission;
@@ -148,18 +159,14 @@ impl pallet_chain_space::Config for Test {
 	type OriginSuccess = mock_origin::DoubleOrigin<AccountId, SubjectId>;
 	type SpaceCreatorId = SubjectId;
 	type MaxSpaceDelegates = MaxSpaceDelegates;
+	type MaxSubspaceDepth = MaxSubspaceDepth;
+	type MaxDelegationExpirationsPerBlock = MaxDelegationExpirationsPerBlock;
 	type ChainSpaceOrigin = EnsureRoot<AccountId>;
 	type NetworkPermission = NetworkPermission;
 	type WeightInfo = ();
 }
 
-parameter_types! {
-	pub const MaxEventsHistory: u32 = 6u32;
-}
-
-impl identifier::Config for Test {
-	type MaxEventsHistory = MaxEventsHistory;
-}
+impl identifier::Config for Test {}
 
 pub(crate) const ACCOUNT_00: AccountId = AccountId::new([1u8; 32]);
 pub(crate) const ACCOUNT_01: AccountId = AccountId::new([2u8; 32]);
@@ -298,6 +305,9 @@ impl DeriveDidCallAuthorizationVerificationKeyRelationship for RuntimeCall {
 			Ok(DidVerificationKeyRelationship::Authentication)
 		} else if *self == get_delegation_key_call() {
 			Ok(DidVerificationKeyRelationship::CapabilityDelegation)
+		} else if let RuntimeCall::Did(pallet_did::Call::add_service_endpoint { .. }) = self {
+			// Stands in for the calls anchoring assertions in the runtimes.
+			crate::assertion_key_relationship::<Test, AssertionKeyRequiredFrom>()
 		} else {
 			#[cfg(feature = "runtime-benchmarks")]
 			if *self == Self::get_call_for_did_call_benchmark() {
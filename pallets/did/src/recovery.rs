@@ -0,0 +1,60 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::BoundedVec;
+use frame_system::pallet_prelude::BlockNumberFor;
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+use crate::{did_details::DidVerificationKey, AccountIdOf, Config, DidIdentifierOf};
+
+/// A bounded vector of DIDs that can take part in the recovery of a DID.
+pub type RecoveryDidsOf<T> = BoundedVec<DidIdentifierOf<T>, <T as Config>::MaxRecoveryDids>;
+
+/// The social-recovery policy of a DID.
+///
+/// A recovery must be approved by at least `threshold` of the
+/// `recovery_dids`, and can only be completed `delay` blocks after it has
+/// been initiated. Until then, the DID subject can cancel it with its
+/// current authentication key.
+#[derive(Clone, Decode, RuntimeDebug, Encode, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct DidRecoveryPolicy<T: Config> {
+	/// The DIDs allowed to initiate and approve a recovery.
+	pub recovery_dids: RecoveryDidsOf<T>,
+	/// The number of recovery DIDs that must approve a recovery.
+	pub threshold: u32,
+	/// The number of blocks that must elapse between the initiation of a
+	/// recovery and its completion.
+	pub delay: BlockNumberFor<T>,
+}
+
+/// A recovery of a DID that has been initiated but not completed yet.
+#[derive(Clone, Decode, RuntimeDebug, Encode, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct DidRecoveryRequest<T: Config> {
+	/// The authentication key the DID is going to be rotated to.
+	pub new_authentication_key: DidVerificationKey<AccountIdOf<T>>,
+	/// The block number in which the recovery was initiated.
+	pub initiated_at: BlockNumberFor<T>,
+	/// The recovery DIDs that have approved the recovery so far.
+	pub approvals: RecoveryDidsOf<T>,
+}
@@ -31,7 +31,10 @@ use sp_std::{
 
 use crate::{
 	self as did,
-	did_details::{DidEncryptionKey, DidVerificationKey, DidVerificationKeyRelationship},
+	did_details::{
+		DidAuthorizedCallOperation, DidEncryptionKey, DidVerificationKey,
+		DidVerificationKeyRelationship,
+	},
 	mock::*,
 	mock_utils::*,
 	service_endpoints::DidEndpoint,
@@ -2565,6 +2568,106 @@ fn check_null_key_error() {
 	});
 }
 
+#[test]
+fn check_call_dispatched_with_authentication_key_before_assertion_keys_are_required() {
+	let auth_key = get_sr25519_authentication_key(&AUTH_SEED_0);
+	let did = get_did_identifier_from_sr25519_key(auth_key.public());
+	let caller = ACCOUNT_00;
+	let mock_did = generate_base_did_details::<Test>(DidVerificationKey::from(auth_key.public()));
+	let service_endpoint = get_service_endpoints::<Test>(1, 10, 1, 10, 1, 10)[0].clone();
+
+	// The mock derives the relationship of this call as for anchoring calls.
+	let operation = DidAuthorizedCallOperation {
+		did: did.clone(),
+		call: RuntimeCall::Did(did::Call::add_service_endpoint { service_endpoint }),
+		tx_counter: 1u64,
+		block_number: 0u64,
+		submitter: caller.clone(),
+	};
+	let signature = auth_key.sign(operation.encode().as_ref());
+
+	new_test_ext().execute_with(|| {
+		did::Did::<Test>::insert(did.clone(), mock_did);
+
+		assert_eq!(AssertionKeyRequiredFrom::get(), None);
+		assert_ok!(Did::submit_did_call(
+			RuntimeOrigin::signed(caller),
+			Box::new(operation),
+			did::DidSignature::from(signature)
+		));
+		System::assert_last_event(
+			did::Event::CallDispatched {
+				identifier: did,
+				key_relationship: DidVerificationKeyRelationship::Authentication,
+				result: Ok(()),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn check_call_dispatched_with_assertion_key_once_assertion_keys_are_required() {
+	let auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let did = get_did_identifier_from_ed25519_key(auth_key.public());
+	let caller = ACCOUNT_00;
+	let assertion_key = get_ed25519_assertion_key(&ATT_SEED_0);
+	let service_endpoint = get_service_endpoints::<Test>(1, 10, 1, 10, 1, 10)[0].clone();
+
+	let mut mock_did =
+		generate_base_did_details::<Test>(DidVerificationKey::from(auth_key.public()));
+	assert_ok!(mock_did.update_assertion_key(DidVerificationKey::from(assertion_key.public()), 0));
+
+	let operation = DidAuthorizedCallOperation {
+		did: did.clone(),
+		call: RuntimeCall::Did(did::Call::add_service_endpoint { service_endpoint }),
+		tx_counter: 1u64,
+		block_number: 0u64,
+		submitter: caller.clone(),
+	};
+	let auth_signature = auth_key.sign(operation.encode().as_ref());
+	let assertion_signature = assertion_key.sign(operation.encode().as_ref());
+
+	new_test_ext().execute_with(|| {
+		did::Did::<Test>::insert(did.clone(), mock_did);
+		AssertionKeyRequiredFrom::set(&Some(2));
+
+		// The authentication key is used until the activation block.
+		assert_eq!(
+			did::assertion_key_relationship::<Test, AssertionKeyRequiredFrom>(),
+			Ok(DidVerificationKeyRelationship::Authentication)
+		);
+
+		System::set_block_number(2);
+		assert_eq!(
+			did::assertion_key_relationship::<Test, AssertionKeyRequiredFrom>(),
+			Ok(DidVerificationKeyRelationship::AssertionMethod)
+		);
+
+		assert_noop!(
+			Did::submit_did_call(
+				RuntimeOrigin::signed(caller.clone()),
+				Box::new(operation.clone()),
+				did::DidSignature::from(auth_signature)
+			),
+			did::Error::<Test>::InvalidSignature
+		);
+		assert_ok!(Did::submit_did_call(
+			RuntimeOrigin::signed(caller),
+			Box::new(operation),
+			did::DidSignature::from(assertion_signature)
+		));
+		System::assert_last_event(
+			did::Event::CallDispatched {
+				identifier: did,
+				key_relationship: DidVerificationKeyRelationship::AssertionMethod,
+				result: Ok(()),
+			}
+			.into(),
+		);
+	});
+}
+
 // Internal function: verify_did_operation_signature_and_increase_nonce
 
 #[test]
@@ -2874,3 +2977,381 @@ fn check_invalid_signature_operation_verification() {
 		);
 	});
 }
+
+#[test]
+fn check_signature_verification_at_past_blocks() {
+	let old_auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(old_auth_key.public());
+	let new_auth_key = get_ed25519_authentication_key(&AUTH_SEED_1);
+	let details = generate_base_did_creation_details::<Test>(alice_did.clone(), ACCOUNT_00);
+	let creation_signature = old_auth_key.sign(details.encode().as_ref());
+
+	let payload = b"signed payload".to_vec();
+	let old_signature = did::DidSignature::from(old_auth_key.sign(&payload));
+	let new_signature = did::DidSignature::from(new_auth_key.sign(&payload));
+	let origin = build_test_origin(alice_did.clone(), alice_did.clone());
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Did::create(
+			RuntimeOrigin::signed(ACCOUNT_00),
+			Box::new(details),
+			did::DidSignature::from(creation_signature),
+		));
+
+		System::set_block_number(5);
+		assert_ok!(Did::set_authentication_key(
+			origin.clone(),
+			DidVerificationKey::from(new_auth_key.public())
+		));
+
+		System::set_block_number(10);
+		assert_eq!(did::KeyHistory::<Test>::get(&alice_did).len(), 2);
+
+		let verify_at = |signature: &did::DidSignature, block_number: BlockNumberFor<Test>| {
+			Did::verify_signature_at(
+				&alice_did,
+				DidVerificationKeyRelationship::Authentication,
+				&payload,
+				signature,
+				block_number,
+			)
+		};
+		// The old key is valid from its creation until its rotation.
+		assert!(!verify_at(&old_signature, 0));
+		assert!(verify_at(&old_signature, 1));
+		assert!(verify_at(&old_signature, 4));
+		assert!(!verify_at(&old_signature, 5));
+		// The new key is valid from its rotation on.
+		assert!(!verify_at(&new_signature, 4));
+		assert!(verify_at(&new_signature, 5));
+		assert!(verify_at(&new_signature, 10));
+		// Keys are only valid for the relationship they were bound to.
+		assert!(!Did::verify_signature_at(
+			&alice_did,
+			DidVerificationKeyRelationship::AssertionMethod,
+			&payload,
+			&old_signature,
+			1,
+		));
+
+		// The history is removed along with the DID.
+		assert_ok!(Did::delete(origin, 0));
+		assert!(!did::KeyHistory::<Test>::contains_key(&alice_did));
+		assert!(!verify_at(&new_signature, 5));
+	});
+}
+
+#[test]
+fn key_history_migration_should_seed_dids_without_history() {
+	use did::migrations::v2::MigrateToKeyHistory;
+	use frame_support::{
+		migrations::SteppedMigration,
+		traits::{GetStorageVersion, StorageVersion},
+		weights::WeightMeter,
+	};
+
+	let auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(auth_key.public());
+	let att_key = get_ed25519_authentication_key(&ATT_SEED_0);
+	let details = generate_base_did_creation_details::<Test>(alice_did.clone(), ACCOUNT_00);
+	let creation_signature = auth_key.sign(details.encode().as_ref());
+
+	let payload = b"signed payload".to_vec();
+	let auth_signature = did::DidSignature::from(auth_key.sign(&payload));
+	let att_signature = did::DidSignature::from(att_key.sign(&payload));
+	let origin = build_test_origin(alice_did.clone(), alice_did.clone());
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Did::create(
+			RuntimeOrigin::signed(ACCOUNT_00),
+			Box::new(details),
+			did::DidSignature::from(creation_signature),
+		));
+		System::set_block_number(5);
+		assert_ok!(Did::set_assertion_key(origin, DidVerificationKey::from(att_key.public())));
+
+		// Rewind to a DID created before the key history was recorded.
+		did::KeyHistory::<Test>::remove(&alice_did);
+		StorageVersion::new(1).put::<Did>();
+
+		let mut cursor = None;
+		loop {
+			cursor = MigrateToKeyHistory::<Test>::step(cursor, &mut WeightMeter::new())
+				.expect("Migration step should not fail.");
+			if cursor.is_none() {
+				break;
+			}
+		}
+		assert_eq!(Did::on_chain_storage_version(), 2);
+
+		let verify_at = |relationship, signature: &did::DidSignature, block_number| {
+			Did::verify_signature_at(&alice_did, relationship, &payload, signature, block_number)
+		};
+		// Each key is seeded as bound since the block it was added in.
+		assert_eq!(did::KeyHistory::<Test>::get(&alice_did).len(), 2);
+		assert!(!verify_at(DidVerificationKeyRelationship::Authentication, &auth_signature, 0));
+		assert!(verify_at(DidVerificationKeyRelationship::Authentication, &auth_signature, 1));
+		assert!(!verify_at(DidVerificationKeyRelationship::AssertionMethod, &att_signature, 4));
+		assert!(verify_at(DidVerificationKeyRelationship::AssertionMethod, &att_signature, 5));
+	});
+}
+
+#[test]
+fn check_successful_controller_authentication_key_update() {
+	let auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(auth_key.public());
+	let controller_key = get_ed25519_authentication_key(&AUTH_SEED_1);
+	let bob_did = get_did_identifier_from_ed25519_key(controller_key.public());
+	let new_auth_key =
+		DidVerificationKey::from(get_ed25519_authentication_key(&ATT_SEED_0).public());
+
+	new_test_ext().execute_with(|| {
+		did::Did::<Test>::insert(
+			alice_did.clone(),
+			generate_base_did_details::<Test>(DidVerificationKey::from(auth_key.public())),
+		);
+		did::Did::<Test>::insert(
+			bob_did.clone(),
+			generate_base_did_details::<Test>(DidVerificationKey::from(controller_key.public())),
+		);
+
+		// Bob is not a controller of Alice yet.
+		assert_noop!(
+			Did::controller_set_authentication_key(
+				build_test_origin(bob_did.clone(), bob_did.clone()),
+				alice_did.clone(),
+				new_auth_key.clone()
+			),
+			did::Error::<Test>::NotController
+		);
+
+		assert_noop!(
+			Did::add_controller(
+				build_test_origin(alice_did.clone(), alice_did.clone()),
+				alice_did.clone()
+			),
+			did::Error::<Test>::InvalidController
+		);
+		assert_ok!(Did::add_controller(
+			build_test_origin(alice_did.clone(), alice_did.clone()),
+			bob_did.clone()
+		));
+		assert_noop!(
+			Did::add_controller(
+				build_test_origin(alice_did.clone(), alice_did.clone()),
+				bob_did.clone()
+			),
+			did::Error::<Test>::ControllerAlreadyExists
+		);
+
+		assert_ok!(Did::controller_set_authentication_key(
+			build_test_origin(bob_did.clone(), bob_did.clone()),
+			alice_did.clone(),
+			new_auth_key.clone()
+		));
+		let did_details =
+			did::Did::<Test>::get(&alice_did).expect("ALICE_DID should be present on chain.");
+		assert_eq!(did_details.authentication_key, generate_key_id(&new_auth_key.clone().into()));
+
+		assert_ok!(Did::remove_controller(
+			build_test_origin(alice_did.clone(), alice_did.clone()),
+			bob_did.clone()
+		));
+		assert!(!did::Controllers::<Test>::contains_key(&alice_did));
+		assert_noop!(
+			Did::controller_set_authentication_key(
+				build_test_origin(bob_did.clone(), bob_did),
+				alice_did,
+				new_auth_key
+			),
+			did::Error::<Test>::NotController
+		);
+	});
+}
+
+#[test]
+fn check_successful_did_recovery() {
+	let auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(auth_key.public());
+	let bob_did =
+		get_did_identifier_from_ed25519_key(get_ed25519_authentication_key(&AUTH_SEED_1).public());
+	let charlie_did =
+		get_did_identifier_from_ed25519_key(get_ed25519_authentication_key(&DEL_SEED_0).public());
+	let new_auth_key =
+		DidVerificationKey::from(get_ed25519_authentication_key(&ATT_SEED_0).public());
+	let recovery_dids: did::recovery::RecoveryDidsOf<Test> =
+		vec![bob_did.clone(), charlie_did.clone()].try_into().unwrap();
+	let delay = MinRecoveryDelay::get();
+
+	new_test_ext().execute_with(|| {
+		did::Did::<Test>::insert(
+			alice_did.clone(),
+			generate_base_did_details::<Test>(DidVerificationKey::from(auth_key.public())),
+		);
+
+		assert_noop!(
+			Did::set_recovery_policy(
+				build_test_origin(alice_did.clone(), alice_did.clone()),
+				recovery_dids.clone(),
+				3,
+				delay
+			),
+			did::Error::<Test>::InvalidRecoveryPolicy
+		);
+		assert_noop!(
+			Did::set_recovery_policy(
+				build_test_origin(alice_did.clone(), alice_did.clone()),
+				recovery_dids.clone(),
+				2,
+				delay - 1
+			),
+			did::Error::<Test>::InvalidRecoveryPolicy
+		);
+		assert_ok!(Did::set_recovery_policy(
+			build_test_origin(alice_did.clone(), alice_did.clone()),
+			recovery_dids,
+			2,
+			delay
+		));
+
+		System::set_block_number(1);
+		assert_ok!(Did::initiate_recovery(
+			build_test_origin(bob_did.clone(), bob_did.clone()),
+			alice_did.clone(),
+			new_auth_key.clone()
+		));
+		assert_noop!(
+			Did::approve_recovery(
+				build_test_origin(bob_did.clone(), bob_did.clone()),
+				alice_did.clone()
+			),
+			did::Error::<Test>::RecoveryAlreadyApproved
+		);
+		assert_noop!(
+			Did::recover(build_test_origin(bob_did.clone(), bob_did.clone()), alice_did.clone()),
+			did::Error::<Test>::RecoveryThresholdNotMet
+		);
+
+		assert_ok!(Did::approve_recovery(
+			build_test_origin(charlie_did.clone(), charlie_did.clone()),
+			alice_did.clone()
+		));
+		assert_noop!(
+			Did::recover(build_test_origin(bob_did.clone(), bob_did.clone()), alice_did.clone()),
+			did::Error::<Test>::RecoveryDelayNotElapsed
+		);
+
+		System::set_block_number(1 + delay);
+		assert_ok!(Did::recover(build_test_origin(bob_did.clone(), bob_did), alice_did.clone()));
+
+		let did_details =
+			did::Did::<Test>::get(&alice_did).expect("ALICE_DID should be present on chain.");
+		assert_eq!(did_details.authentication_key, generate_key_id(&new_auth_key.into()));
+		assert!(!did::RecoveryRequests::<Test>::contains_key(&alice_did));
+	});
+}
+
+#[test]
+fn check_did_recovery_cancelled_by_current_key() {
+	let auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(auth_key.public());
+	let bob_did =
+		get_did_identifier_from_ed25519_key(get_ed25519_authentication_key(&AUTH_SEED_1).public());
+	let new_auth_key =
+		DidVerificationKey::from(get_ed25519_authentication_key(&ATT_SEED_0).public());
+	let recovery_dids: did::recovery::RecoveryDidsOf<Test> =
+		vec![bob_did.clone()].try_into().unwrap();
+	let delay = MinRecoveryDelay::get();
+
+	new_test_ext().execute_with(|| {
+		did::Did::<Test>::insert(
+			alice_did.clone(),
+			generate_base_did_details::<Test>(DidVerificationKey::from(auth_key.public())),
+		);
+		assert_ok!(Did::set_recovery_policy(
+			build_test_origin(alice_did.clone(), alice_did.clone()),
+			recovery_dids,
+			1,
+			delay
+		));
+
+		System::set_block_number(1);
+		assert_ok!(Did::initiate_recovery(
+			build_test_origin(bob_did.clone(), bob_did.clone()),
+			alice_did.clone(),
+			new_auth_key
+		));
+		assert_ok!(Did::cancel_recovery(build_test_origin(alice_did.clone(), alice_did.clone())));
+
+		System::set_block_number(1 + delay);
+		assert_noop!(
+			Did::recover(build_test_origin(bob_did.clone(), bob_did), alice_did.clone()),
+			did::Error::<Test>::RecoveryNotInitiated
+		);
+		let did_details =
+			did::Did::<Test>::get(&alice_did).expect("ALICE_DID should be present on chain.");
+		assert_eq!(
+			did_details.authentication_key,
+			generate_key_id(&DidVerificationKey::from(auth_key.public()).into())
+		);
+	});
+}
+
+#[test]
+fn check_controller_authentication_key_update_is_not_time_locked() {
+	let auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(auth_key.public());
+	let bob_did =
+		get_did_identifier_from_ed25519_key(get_ed25519_authentication_key(&AUTH_SEED_1).public());
+	let charlie_did =
+		get_did_identifier_from_ed25519_key(get_ed25519_authentication_key(&DEL_SEED_0).public());
+	let new_auth_key =
+		DidVerificationKey::from(get_ed25519_authentication_key(&ATT_SEED_0).public());
+	let recovery_dids: did::recovery::RecoveryDidsOf<Test> =
+		vec![bob_did.clone()].try_into().unwrap();
+	let delay = MinRecoveryDelay::get();
+
+	new_test_ext().execute_with(|| {
+		did::Did::<Test>::insert(
+			alice_did.clone(),
+			generate_base_did_details::<Test>(DidVerificationKey::from(auth_key.public())),
+		);
+		did::Did::<Test>::insert(
+			charlie_did.clone(),
+			generate_base_did_details::<Test>(DidVerificationKey::from(
+				get_ed25519_authentication_key(&DEL_SEED_0).public(),
+			)),
+		);
+		assert_ok!(Did::set_recovery_policy(
+			build_test_origin(alice_did.clone(), alice_did.clone()),
+			recovery_dids,
+			1,
+			delay
+		));
+		assert_ok!(Did::add_controller(
+			build_test_origin(alice_did.clone(), alice_did.clone()),
+			charlie_did.clone()
+		));
+
+		// The rotation of a controller applies in the same block, regardless
+		// of the time-lock of the recovery policy.
+		System::set_block_number(1);
+		assert_ok!(Did::controller_set_authentication_key(
+			build_test_origin(charlie_did.clone(), charlie_did),
+			alice_did.clone(),
+			new_auth_key.clone()
+		));
+		let did_details =
+			did::Did::<Test>::get(&alice_did).expect("ALICE_DID should be present on chain.");
+		assert_eq!(did_details.authentication_key, generate_key_id(&new_auth_key.into()));
+
+		// No recovery request is opened, so there is nothing left to cancel.
+		assert!(!did::RecoveryRequests::<Test>::contains_key(&alice_did));
+		assert_noop!(
+			Did::cancel_recovery(build_test_origin(alice_did.clone(), alice_did)),
+			did::Error::<Test>::RecoveryNotInitiated
+		);
+	});
+}
@@ -84,6 +84,15 @@ pub trait WeightInfo {
 	fn signature_verification_ecdsa(l: u32, ) -> Weight;
 	fn dispatch_as() -> Weight;
 	fn create_from_account() -> Weight;
+	fn add_controller() -> Weight;
+	fn remove_controller() -> Weight;
+	fn controller_set_authentication_key() -> Weight;
+	fn set_recovery_policy(r: u32, ) -> Weight;
+	fn remove_recovery_policy() -> Weight;
+	fn initiate_recovery() -> Weight;
+	fn approve_recovery() -> Weight;
+	fn cancel_recovery() -> Weight;
+	fn recover() -> Weight;
 }
 
 /// Weights for `pallet_did` using the CORD node and recommended hardware.
@@ -97,20 +106,19 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Did::DidEndpointsCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `Did::ServiceEndpoints` (r:0 w:25)
 	/// Proof: `Did::ServiceEndpoints` (`max_values`: None, `max_size`: Some(413), added: 2888, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 10]`.
 	/// The range of component `c` is `[1, 25]`.
 	fn create_ed25519_keys(n: u32, c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `42`
-		//  Estimated: `5649`
-		// Minimum execution time: 103_390_000 picoseconds.
-		Weight::from_parts(93_029_009, 5649)
-			// Standard Error: 47_693
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(93_029_009, 15874)
 			.saturating_add(Weight::from_parts(1_100_329, 0).saturating_mul(n.into()))
-			// Standard Error: 18_439
 			.saturating_add(Weight::from_parts(7_004_725, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
 	}
 	/// Storage: `Did::DidBlacklist` (r:1 w:0)
@@ -121,20 +129,19 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Did::DidEndpointsCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `Did::ServiceEndpoints` (r:0 w:25)
 	/// Proof: `Did::ServiceEndpoints` (`max_values`: None, `max_size`: Some(413), added: 2888, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 10]`.
 	/// The range of component `c` is `[1, 25]`.
 	fn create_sr25519_keys(n: u32, c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `42`
-		//  Estimated: `5649`
-		// Minimum execution time: 88_100_000 picoseconds.
-		Weight::from_parts(69_436_166, 5649)
-			// Standard Error: 49_041
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(69_436_166, 15874)
 			.saturating_add(Weight::from_parts(1_326_418, 0).saturating_mul(n.into()))
-			// Standard Error: 18_961
 			.saturating_add(Weight::from_parts(8_086_917, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
 	}
 	/// Storage: `Did::DidBlacklist` (r:1 w:0)
@@ -145,20 +152,19 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Did::DidEndpointsCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `Did::ServiceEndpoints` (r:0 w:25)
 	/// Proof: `Did::ServiceEndpoints` (`max_values`: None, `max_size`: Some(413), added: 2888, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 10]`.
 	/// The range of component `c` is `[1, 25]`.
 	fn create_ecdsa_keys(n: u32, c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `42`
-		//  Estimated: `5649`
-		// Minimum execution time: 89_560_000 picoseconds.
-		Weight::from_parts(76_768_742, 5649)
-			// Standard Error: 39_426
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(76_768_742, 15874)
 			.saturating_add(Weight::from_parts(951_571, 0).saturating_mul(n.into()))
-			// Standard Error: 15_243
 			.saturating_add(Weight::from_parts(6_828_914, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
 	}
 	/// Storage: `Did::DidEndpointsCount` (r:1 w:1)
@@ -169,18 +175,24 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
 	/// Storage: `Did::DidBlacklist` (r:0 w:1)
 	/// Proof: `Did::DidBlacklist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:0 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
+	/// Storage: `Did::Controllers` (r:0 w:1)
+	/// Proof: `Did::Controllers` (`max_values`: None, `max_size`: Some(369), added: 2844, mode: `MaxEncodedLen`)
+	/// Storage: `Did::RecoveryPolicies` (r:0 w:1)
+	/// Proof: `Did::RecoveryPolicies` (`max_values`: None, `max_size`: Some(377), added: 2852, mode: `MaxEncodedLen`)
+	/// Storage: `Did::RecoveryRequests` (r:0 w:1)
+	/// Proof: `Did::RecoveryRequests` (`max_values`: None, `max_size`: Some(407), added: 2882, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 25]`.
 	fn delete(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `364 + c * (105 ±0)`
 		//  Estimated: `5649 + c * (2888 ±0)`
-		// Minimum execution time: 25_940_000 picoseconds.
+		// Estimated from the storage accesses, pending benchmarking.
 		Weight::from_parts(26_520_178, 5649)
-			// Standard Error: 4_861
 			.saturating_add(Weight::from_parts(1_245_907, 0).saturating_mul(c.into()))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
 			.saturating_add(Weight::from_parts(0, 2888).saturating_mul(c.into()))
 	}
@@ -219,234 +231,255 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn set_ed25519_authentication_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1457`
-		//  Estimated: `5649`
-		// Minimum execution time: 25_330_000 picoseconds.
-		Weight::from_parts(26_690_000, 5649)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(26_690_000, 15874)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn set_sr25519_authentication_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1457`
-		//  Estimated: `5649`
-		// Minimum execution time: 25_811_000 picoseconds.
-		Weight::from_parts(26_830_000, 5649)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(26_830_000, 15874)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn set_ecdsa_authentication_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1460`
-		//  Estimated: `5649`
-		// Minimum execution time: 26_100_000 picoseconds.
-		Weight::from_parts(27_670_000, 5649)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(27_670_000, 15874)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn set_ed25519_delegation_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1457`
-		//  Estimated: `5649`
-		// Minimum execution time: 25_590_000 picoseconds.
-		Weight::from_parts(27_269_000, 5649)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(27_269_000, 15874)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn set_sr25519_delegation_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1457`
-		//  Estimated: `5649`
-		// Minimum execution time: 26_071_000 picoseconds.
-		Weight::from_parts(26_940_000, 5649)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(26_940_000, 15874)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn set_ecdsa_delegation_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1460`
-		//  Estimated: `5649`
-		// Minimum execution time: 25_690_000 picoseconds.
-		Weight::from_parts(27_340_000, 5649)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(27_340_000, 15874)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn remove_ed25519_delegation_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1457`
-		//  Estimated: `5649`
-		// Minimum execution time: 23_100_000 picoseconds.
-		Weight::from_parts(24_850_000, 5649)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(24_850_000, 15874)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn remove_sr25519_delegation_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1457`
-		//  Estimated: `5649`
-		// Minimum execution time: 23_310_000 picoseconds.
-		Weight::from_parts(24_550_000, 5649)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(24_550_000, 15874)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn remove_ecdsa_delegation_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1460`
-		//  Estimated: `5649`
-		// Minimum execution time: 23_700_000 picoseconds.
-		Weight::from_parts(24_800_000, 5649)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(24_800_000, 15874)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn set_ed25519_assertion_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1457`
-		//  Estimated: `5649`
-		// Minimum execution time: 24_690_000 picoseconds.
-		Weight::from_parts(25_600_000, 5649)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(25_600_000, 15874)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn set_sr25519_assertion_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1457`
-		//  Estimated: `5649`
-		// Minimum execution time: 23_940_000 picoseconds.
-		Weight::from_parts(26_100_000, 5649)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(26_100_000, 15874)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn set_ecdsa_assertion_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1460`
-		//  Estimated: `5649`
-		// Minimum execution time: 22_870_000 picoseconds.
-		Weight::from_parts(26_400_000, 5649)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(26_400_000, 15874)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn remove_ed25519_assertion_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1457`
-		//  Estimated: `5649`
-		// Minimum execution time: 23_060_000 picoseconds.
-		Weight::from_parts(24_630_000, 5649)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(24_630_000, 15874)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn remove_sr25519_assertion_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1457`
-		//  Estimated: `5649`
-		// Minimum execution time: 23_949_000 picoseconds.
-		Weight::from_parts(26_110_000, 5649)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(26_110_000, 15874)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn remove_ecdsa_assertion_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1460`
-		//  Estimated: `5649`
-		// Minimum execution time: 22_349_000 picoseconds.
-		Weight::from_parts(25_229_000, 5649)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(25_229_000, 15874)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn add_ed25519_key_agreement_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1355`
-		//  Estimated: `5649`
-		// Minimum execution time: 24_129_000 picoseconds.
-		Weight::from_parts(25_940_000, 5649)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(25_940_000, 15874)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn add_sr25519_key_agreement_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1355`
-		//  Estimated: `5649`
-		// Minimum execution time: 24_900_000 picoseconds.
-		Weight::from_parts(27_130_000, 5649)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(27_130_000, 15874)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn add_ecdsa_key_agreement_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1358`
-		//  Estimated: `5649`
-		// Minimum execution time: 24_020_000 picoseconds.
-		Weight::from_parts(25_380_000, 5649)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(25_380_000, 15874)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn remove_ed25519_key_agreement_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1457`
-		//  Estimated: `5649`
-		// Minimum execution time: 23_131_000 picoseconds.
-		Weight::from_parts(24_291_000, 5649)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(24_291_000, 15874)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn remove_sr25519_key_agreement_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1457`
-		//  Estimated: `5649`
-		// Minimum execution time: 23_360_000 picoseconds.
-		Weight::from_parts(24_440_000, 5649)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(24_440_000, 15874)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn remove_ecdsa_key_agreement_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1460`
-		//  Estimated: `5649`
-		// Minimum execution time: 23_490_000 picoseconds.
-		Weight::from_parts(24_791_000, 5649)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(24_791_000, 15874)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
@@ -531,15 +564,130 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Did::DidBlacklist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn create_from_account() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `42`
-		//  Estimated: `5649`
-		// Minimum execution time: 13_889_000 picoseconds.
-		Weight::from_parts(14_910_000, 5649)
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(14_910_000, 15874)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Did::Did` (r:2 w:0)
+	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::Controllers` (r:1 w:1)
+	/// Proof: `Did::Controllers` (`max_values`: None, `max_size`: Some(369), added: 2844, mode: `MaxEncodedLen`)
+	fn add_controller() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `12162`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(19_310_000, 12162)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Did::Controllers` (r:1 w:1)
+	/// Proof: `Did::Controllers` (`max_values`: None, `max_size`: Some(369), added: 2844, mode: `MaxEncodedLen`)
+	fn remove_controller() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `2844`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(13_540_000, 2844)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Did::Controllers` (r:1 w:0)
+	/// Proof: `Did::Controllers` (`max_values`: None, `max_size`: Some(369), added: 2844, mode: `MaxEncodedLen`)
+	/// Storage: `Did::Did` (r:1 w:1)
+	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
+	fn controller_set_authentication_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `17728`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(32_980_000, 17728)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Did::Did` (r:1 w:0)
+	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::RecoveryRequests` (r:1 w:0)
+	/// Proof: `Did::RecoveryRequests` (`max_values`: None, `max_size`: Some(407), added: 2882, mode: `MaxEncodedLen`)
+	/// Storage: `Did::RecoveryPolicies` (r:0 w:1)
+	/// Proof: `Did::RecoveryPolicies` (`max_values`: None, `max_size`: Some(377), added: 2852, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 10]`.
+	fn set_recovery_policy(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `7541`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(17_102_441, 7541)
+			.saturating_add(Weight::from_parts(201_530, 0).saturating_mul(r.into()))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Did::RecoveryPolicies` (r:1 w:1)
+	/// Proof: `Did::RecoveryPolicies` (`max_values`: None, `max_size`: Some(377), added: 2852, mode: `MaxEncodedLen`)
+	/// Storage: `Did::RecoveryRequests` (r:0 w:1)
+	/// Proof: `Did::RecoveryRequests` (`max_values`: None, `max_size`: Some(407), added: 2882, mode: `MaxEncodedLen`)
+	fn remove_recovery_policy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `2852`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(14_890_000, 2852)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Did::RecoveryPolicies` (r:1 w:0)
+	/// Proof: `Did::RecoveryPolicies` (`max_values`: None, `max_size`: Some(377), added: 2852, mode: `MaxEncodedLen`)
+	/// Storage: `Did::RecoveryRequests` (r:1 w:1)
+	/// Proof: `Did::RecoveryRequests` (`max_values`: None, `max_size`: Some(407), added: 2882, mode: `MaxEncodedLen`)
+	fn initiate_recovery() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `5734`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(17_760_000, 5734)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Did::RecoveryPolicies` (r:1 w:0)
+	/// Proof: `Did::RecoveryPolicies` (`max_values`: None, `max_size`: Some(377), added: 2852, mode: `MaxEncodedLen`)
+	/// Storage: `Did::RecoveryRequests` (r:1 w:1)
+	/// Proof: `Did::RecoveryRequests` (`max_values`: None, `max_size`: Some(407), added: 2882, mode: `MaxEncodedLen`)
+	fn approve_recovery() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `5734`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(18_420_000, 5734)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Did::RecoveryRequests` (r:1 w:1)
+	/// Proof: `Did::RecoveryRequests` (`max_values`: None, `max_size`: Some(407), added: 2882, mode: `MaxEncodedLen`)
+	fn cancel_recovery() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `2882`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(12_950_000, 2882)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Did::RecoveryPolicies` (r:1 w:0)
+	/// Proof: `Did::RecoveryPolicies` (`max_values`: None, `max_size`: Some(377), added: 2852, mode: `MaxEncodedLen`)
+	/// Storage: `Did::RecoveryRequests` (r:1 w:1)
+	/// Proof: `Did::RecoveryRequests` (`max_values`: None, `max_size`: Some(407), added: 2882, mode: `MaxEncodedLen`)
+	/// Storage: `Did::Did` (r:1 w:1)
+	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
+	fn recover() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `20618`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(35_260_000, 20618)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -552,20 +700,19 @@ impl WeightInfo for () {
 	/// Proof: `Did::DidEndpointsCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `Did::ServiceEndpoints` (r:0 w:25)
 	/// Proof: `Did::ServiceEndpoints` (`max_values`: None, `max_size`: Some(413), added: 2888, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 10]`.
 	/// The range of component `c` is `[1, 25]`.
 	fn create_ed25519_keys(n: u32, c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `42`
-		//  Estimated: `5649`
-		// Minimum execution time: 103_390_000 picoseconds.
-		Weight::from_parts(93_029_009, 5649)
-			// Standard Error: 47_693
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(93_029_009, 15874)
 			.saturating_add(Weight::from_parts(1_100_329, 0).saturating_mul(n.into()))
-			// Standard Error: 18_439
 			.saturating_add(Weight::from_parts(7_004_725, 0).saturating_mul(c.into()))
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c.into())))
 	}
 	/// Storage: `Did::DidBlacklist` (r:1 w:0)
@@ -576,20 +723,19 @@ impl WeightInfo for () {
 	/// Proof: `Did::DidEndpointsCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `Did::ServiceEndpoints` (r:0 w:25)
 	/// Proof: `Did::ServiceEndpoints` (`max_values`: None, `max_size`: Some(413), added: 2888, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 10]`.
 	/// The range of component `c` is `[1, 25]`.
 	fn create_sr25519_keys(n: u32, c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `42`
-		//  Estimated: `5649`
-		// Minimum execution time: 88_100_000 picoseconds.
-		Weight::from_parts(69_436_166, 5649)
-			// Standard Error: 49_041
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(69_436_166, 15874)
 			.saturating_add(Weight::from_parts(1_326_418, 0).saturating_mul(n.into()))
-			// Standard Error: 18_961
 			.saturating_add(Weight::from_parts(8_086_917, 0).saturating_mul(c.into()))
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c.into())))
 	}
 	/// Storage: `Did::DidBlacklist` (r:1 w:0)
@@ -600,20 +746,19 @@ impl WeightInfo for () {
 	/// Proof: `Did::DidEndpointsCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `Did::ServiceEndpoints` (r:0 w:25)
 	/// Proof: `Did::ServiceEndpoints` (`max_values`: None, `max_size`: Some(413), added: 2888, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 10]`.
 	/// The range of component `c` is `[1, 25]`.
 	fn create_ecdsa_keys(n: u32, c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `42`
-		//  Estimated: `5649`
-		// Minimum execution time: 89_560_000 picoseconds.
-		Weight::from_parts(76_768_742, 5649)
-			// Standard Error: 39_426
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(76_768_742, 15874)
 			.saturating_add(Weight::from_parts(951_571, 0).saturating_mul(n.into()))
-			// Standard Error: 15_243
 			.saturating_add(Weight::from_parts(6_828_914, 0).saturating_mul(c.into()))
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c.into())))
 	}
 	/// Storage: `Did::DidEndpointsCount` (r:1 w:1)
@@ -624,18 +769,24 @@ impl WeightInfo for () {
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
 	/// Storage: `Did::DidBlacklist` (r:0 w:1)
 	/// Proof: `Did::DidBlacklist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:0 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
+	/// Storage: `Did::Controllers` (r:0 w:1)
+	/// Proof: `Did::Controllers` (`max_values`: None, `max_size`: Some(369), added: 2844, mode: `MaxEncodedLen`)
+	/// Storage: `Did::RecoveryPolicies` (r:0 w:1)
+	/// Proof: `Did::RecoveryPolicies` (`max_values`: None, `max_size`: Some(377), added: 2852, mode: `MaxEncodedLen`)
+	/// Storage: `Did::RecoveryRequests` (r:0 w:1)
+	/// Proof: `Did::RecoveryRequests` (`max_values`: None, `max_size`: Some(407), added: 2882, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 25]`.
 	fn delete(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `364 + c * (105 ±0)`
 		//  Estimated: `5649 + c * (2888 ±0)`
-		// Minimum execution time: 25_940_000 picoseconds.
+		// Estimated from the storage accesses, pending benchmarking.
 		Weight::from_parts(26_520_178, 5649)
-			// Standard Error: 4_861
 			.saturating_add(Weight::from_parts(1_245_907, 0).saturating_mul(c.into()))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c.into())))
 			.saturating_add(Weight::from_parts(0, 2888).saturating_mul(c.into()))
 	}
@@ -674,234 +825,255 @@ impl WeightInfo for () {
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn set_ed25519_authentication_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1457`
-		//  Estimated: `5649`
-		// Minimum execution time: 25_330_000 picoseconds.
-		Weight::from_parts(26_690_000, 5649)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(26_690_000, 15874)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn set_sr25519_authentication_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1457`
-		//  Estimated: `5649`
-		// Minimum execution time: 25_811_000 picoseconds.
-		Weight::from_parts(26_830_000, 5649)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(26_830_000, 15874)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn set_ecdsa_authentication_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1460`
-		//  Estimated: `5649`
-		// Minimum execution time: 26_100_000 picoseconds.
-		Weight::from_parts(27_670_000, 5649)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(27_670_000, 15874)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn set_ed25519_delegation_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1457`
-		//  Estimated: `5649`
-		// Minimum execution time: 25_590_000 picoseconds.
-		Weight::from_parts(27_269_000, 5649)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(27_269_000, 15874)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn set_sr25519_delegation_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1457`
-		//  Estimated: `5649`
-		// Minimum execution time: 26_071_000 picoseconds.
-		Weight::from_parts(26_940_000, 5649)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(26_940_000, 15874)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn set_ecdsa_delegation_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1460`
-		//  Estimated: `5649`
-		// Minimum execution time: 25_690_000 picoseconds.
-		Weight::from_parts(27_340_000, 5649)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(27_340_000, 15874)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn remove_ed25519_delegation_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1457`
-		//  Estimated: `5649`
-		// Minimum execution time: 23_100_000 picoseconds.
-		Weight::from_parts(24_850_000, 5649)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(24_850_000, 15874)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn remove_sr25519_delegation_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1457`
-		//  Estimated: `5649`
-		// Minimum execution time: 23_310_000 picoseconds.
-		Weight::from_parts(24_550_000, 5649)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(24_550_000, 15874)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn remove_ecdsa_delegation_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1460`
-		//  Estimated: `5649`
-		// Minimum execution time: 23_700_000 picoseconds.
-		Weight::from_parts(24_800_000, 5649)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(24_800_000, 15874)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn set_ed25519_assertion_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1457`
-		//  Estimated: `5649`
-		// Minimum execution time: 24_690_000 picoseconds.
-		Weight::from_parts(25_600_000, 5649)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(25_600_000, 15874)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn set_sr25519_assertion_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1457`
-		//  Estimated: `5649`
-		// Minimum execution time: 23_940_000 picoseconds.
-		Weight::from_parts(26_100_000, 5649)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(26_100_000, 15874)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn set_ecdsa_assertion_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1460`
-		//  Estimated: `5649`
-		// Minimum execution time: 22_870_000 picoseconds.
-		Weight::from_parts(26_400_000, 5649)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(26_400_000, 15874)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn remove_ed25519_assertion_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1457`
-		//  Estimated: `5649`
-		// Minimum execution time: 23_060_000 picoseconds.
-		Weight::from_parts(24_630_000, 5649)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(24_630_000, 15874)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn remove_sr25519_assertion_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1457`
-		//  Estimated: `5649`
-		// Minimum execution time: 23_949_000 picoseconds.
-		Weight::from_parts(26_110_000, 5649)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(26_110_000, 15874)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn remove_ecdsa_assertion_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1460`
-		//  Estimated: `5649`
-		// Minimum execution time: 22_349_000 picoseconds.
-		Weight::from_parts(25_229_000, 5649)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(25_229_000, 15874)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn add_ed25519_key_agreement_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1355`
-		//  Estimated: `5649`
-		// Minimum execution time: 24_129_000 picoseconds.
-		Weight::from_parts(25_940_000, 5649)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(25_940_000, 15874)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn add_sr25519_key_agreement_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1355`
-		//  Estimated: `5649`
-		// Minimum execution time: 24_900_000 picoseconds.
-		Weight::from_parts(27_130_000, 5649)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(27_130_000, 15874)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn add_ecdsa_key_agreement_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1358`
-		//  Estimated: `5649`
-		// Minimum execution time: 24_020_000 picoseconds.
-		Weight::from_parts(25_380_000, 5649)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(25_380_000, 15874)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn remove_ed25519_key_agreement_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1457`
-		//  Estimated: `5649`
-		// Minimum execution time: 23_131_000 picoseconds.
-		Weight::from_parts(24_291_000, 5649)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(24_291_000, 15874)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn remove_sr25519_key_agreement_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1457`
-		//  Estimated: `5649`
-		// Minimum execution time: 23_360_000 picoseconds.
-		Weight::from_parts(24_440_000, 5649)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(24_440_000, 15874)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn remove_ecdsa_key_agreement_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1460`
-		//  Estimated: `5649`
-		// Minimum execution time: 23_490_000 picoseconds.
-		Weight::from_parts(24_791_000, 5649)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(24_791_000, 15874)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
@@ -986,13 +1158,128 @@ impl WeightInfo for () {
 	/// Proof: `Did::DidBlacklist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn create_from_account() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `42`
-		//  Estimated: `5649`
-		// Minimum execution time: 13_889_000 picoseconds.
-		Weight::from_parts(14_910_000, 5649)
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(14_910_000, 15874)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Did::Did` (r:2 w:0)
+	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::Controllers` (r:1 w:1)
+	/// Proof: `Did::Controllers` (`max_values`: None, `max_size`: Some(369), added: 2844, mode: `MaxEncodedLen`)
+	fn add_controller() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `12162`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(19_310_000, 12162)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Did::Controllers` (r:1 w:1)
+	/// Proof: `Did::Controllers` (`max_values`: None, `max_size`: Some(369), added: 2844, mode: `MaxEncodedLen`)
+	fn remove_controller() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `2844`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(13_540_000, 2844)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Did::Controllers` (r:1 w:0)
+	/// Proof: `Did::Controllers` (`max_values`: None, `max_size`: Some(369), added: 2844, mode: `MaxEncodedLen`)
+	/// Storage: `Did::Did` (r:1 w:1)
+	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
+	fn controller_set_authentication_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `17728`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(32_980_000, 17728)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Did::Did` (r:1 w:0)
+	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::RecoveryRequests` (r:1 w:0)
+	/// Proof: `Did::RecoveryRequests` (`max_values`: None, `max_size`: Some(407), added: 2882, mode: `MaxEncodedLen`)
+	/// Storage: `Did::RecoveryPolicies` (r:0 w:1)
+	/// Proof: `Did::RecoveryPolicies` (`max_values`: None, `max_size`: Some(377), added: 2852, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 10]`.
+	fn set_recovery_policy(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `7541`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(17_102_441, 7541)
+			.saturating_add(Weight::from_parts(201_530, 0).saturating_mul(r.into()))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Did::RecoveryPolicies` (r:1 w:1)
+	/// Proof: `Did::RecoveryPolicies` (`max_values`: None, `max_size`: Some(377), added: 2852, mode: `MaxEncodedLen`)
+	/// Storage: `Did::RecoveryRequests` (r:0 w:1)
+	/// Proof: `Did::RecoveryRequests` (`max_values`: None, `max_size`: Some(407), added: 2882, mode: `MaxEncodedLen`)
+	fn remove_recovery_policy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `2852`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(14_890_000, 2852)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Did::RecoveryPolicies` (r:1 w:0)
+	/// Proof: `Did::RecoveryPolicies` (`max_values`: None, `max_size`: Some(377), added: 2852, mode: `MaxEncodedLen`)
+	/// Storage: `Did::RecoveryRequests` (r:1 w:1)
+	/// Proof: `Did::RecoveryRequests` (`max_values`: None, `max_size`: Some(407), added: 2882, mode: `MaxEncodedLen`)
+	fn initiate_recovery() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `5734`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(17_760_000, 5734)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Did::RecoveryPolicies` (r:1 w:0)
+	/// Proof: `Did::RecoveryPolicies` (`max_values`: None, `max_size`: Some(377), added: 2852, mode: `MaxEncodedLen`)
+	/// Storage: `Did::RecoveryRequests` (r:1 w:1)
+	/// Proof: `Did::RecoveryRequests` (`max_values`: None, `max_size`: Some(407), added: 2882, mode: `MaxEncodedLen`)
+	fn approve_recovery() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `5734`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(18_420_000, 5734)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Did::RecoveryRequests` (r:1 w:1)
+	/// Proof: `Did::RecoveryRequests` (`max_values`: None, `max_size`: Some(407), added: 2882, mode: `MaxEncodedLen`)
+	fn cancel_recovery() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `2882`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(12_950_000, 2882)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Did::RecoveryPolicies` (r:1 w:0)
+	/// Proof: `Did::RecoveryPolicies` (`max_values`: None, `max_size`: Some(377), added: 2852, mode: `MaxEncodedLen`)
+	/// Storage: `Did::RecoveryRequests` (r:1 w:1)
+	/// Proof: `Did::RecoveryRequests` (`max_values`: None, `max_size`: Some(407), added: 2882, mode: `MaxEncodedLen`)
+	/// Storage: `Did::Did` (r:1 w:1)
+	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
+	fn recover() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `20618`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(35_260_000, 20618)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
 }
@@ -0,0 +1,332 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use codec::Encode;
+use cord_utilities::traits::GenerateBenchmarkOrigin;
+use frame_benchmarking::{account, benchmarks};
+use frame_support::{
+	sp_runtime::{
+		traits::{Hash, One},
+		DispatchError,
+	},
+	traits::Get,
+	BoundedVec,
+};
+use sp_std::{convert::TryFrom, vec, vec::Vec};
+
+const SEED: u32 = 0;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+pub fn generate_registry_id<T: Config>(digest: &RegistryEntryHashOf<T>) -> RegistryIdOf {
+	Ss58Identifier::create_identifier(&(digest).encode()[..], IdentifierType::Registries).unwrap()
+}
+
+pub fn generate_registry_entry_id<T: Config>(digest: &RegistryEntryHashOf<T>) -> RegistryEntryIdOf {
+	Ss58Identifier::create_identifier(&(digest).encode()[..], IdentifierType::Entries).unwrap()
+}
+
+pub fn generate_authorization_id<T: Config>(digest: &RegistryEntryHashOf<T>) -> AuthorizationIdOf {
+	Ss58Identifier::create_identifier(&(digest).encode()[..], IdentifierType::RegistryAuthorization)
+		.unwrap()
+}
+
+/// Generates a registry entry blob of `length` bytes.
+pub fn generate_blob<T: Config>(length: u32, fill: u8) -> RegistryEntryBlobOf<T> {
+	BoundedVec::try_from(vec![fill; length as usize])
+		.expect("Test blob should fit into the expected input length of the test runtime.")
+}
+
+/// Returns the origin of `creator`, with the transaction submitted by `caller`.
+fn generate_origin<T: Config>(caller: &T::AccountId, creator: &CreatorOf<T>) -> T::RuntimeOrigin
+where
+	<T as pallet_registries::Config>::EnsureOrigin:
+		GenerateBenchmarkOrigin<T::RuntimeOrigin, T::AccountId, T::RegistryCreatorId>,
+{
+	<T as pallet_registries::Config>::EnsureOrigin::generate_origin(caller.clone(), creator.clone())
+}
+
+/// Creates a registry of `creator`, returning its identifier and the
+/// authorization of its creator.
+fn create_registry<T: Config>(
+	caller: &T::AccountId,
+	creator: &CreatorOf<T>,
+) -> Result<(RegistryIdOf, AuthorizationIdOf), DispatchError>
+where
+	<T as pallet_registries::Config>::EnsureOrigin:
+		GenerateBenchmarkOrigin<T::RuntimeOrigin, T::AccountId, T::RegistryCreatorId>,
+{
+	let digest = <T as frame_system::Config>::Hashing::hash(&[2u8; 256].encode()[..]);
+	let id_digest = <T as frame_system::Config>::Hashing::hash(
+		&[&digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let registry_id: RegistryIdOf = generate_registry_id::<T>(&id_digest);
+	let auth_digest = <T as frame_system::Config>::Hashing::hash(
+		&[&registry_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: AuthorizationIdOf = generate_authorization_id::<T>(&auth_digest);
+
+	pallet_registries::Pallet::<T>::create(
+		generate_origin::<T>(caller, creator),
+		registry_id.clone(),
+		digest,
+		None,
+		None,
+	)?;
+
+	Ok((registry_id, authorization_id))
+}
+
+/// Links the registry `registry_id` to a new schema of `creator`, which its
+/// entries must declare conformance to, returning the schema identifier.
+fn enforce_schema<T: Config>(
+	caller: &T::AccountId,
+	creator: &CreatorOf<T>,
+	registry_id: &RegistryIdOf,
+	authorization_id: &AuthorizationIdOf,
+) -> Result<SchemaIdOf, DispatchError>
+where
+	<T as pallet_registries::Config>::EnsureOrigin:
+		GenerateBenchmarkOrigin<T::RuntimeOrigin, T::AccountId, T::RegistryCreatorId>,
+{
+	let schema_id = pallet_registries::benchmarking::create_schema::<T>(caller)?;
+
+	pallet_registries::Pallet::<T>::update_schema(
+		generate_origin::<T>(caller, creator),
+		registry_id.clone(),
+		schema_id.clone(),
+		true,
+		authorization_id.clone(),
+	)?;
+
+	Ok(schema_id)
+}
+
+/// Returns the identifier of the registry entry of `creator` with `digest`.
+fn registry_entry_id<T: Config>(
+	digest: &RegistryEntryHashOf<T>,
+	registry_id: &RegistryIdOf,
+	creator: &CreatorOf<T>,
+) -> RegistryEntryIdOf {
+	let id_digest = <T as frame_system::Config>::Hashing::hash(
+		&[&digest.encode()[..], &registry_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	generate_registry_entry_id::<T>(&id_digest)
+}
+
+/// Creates a registry entry of `creator` with a blob of one byte, returning
+/// the identifier of the registry entry and the authorization of its creator.
+fn create_registry_entry<T: Config>(
+	caller: &T::AccountId,
+	creator: &CreatorOf<T>,
+) -> Result<(RegistryEntryIdOf, AuthorizationIdOf), DispatchError>
+where
+	<T as pallet_registries::Config>::EnsureOrigin:
+		GenerateBenchmarkOrigin<T::RuntimeOrigin, T::AccountId, T::RegistryCreatorId>,
+{
+	let (registry_id, authorization_id) = create_registry::<T>(caller, creator)?;
+	let blob = generate_blob::<T>(1, 1);
+	let digest = <T as frame_system::Config>::Hashing::hash(&blob[..]);
+	let registry_entry_id = registry_entry_id::<T>(&digest, &registry_id, creator);
+
+	<T as pallet_registries::Config>::Consideration::ensure_successful(
+		caller,
+		Footprint::from_parts(1, 1),
+	);
+	Pallet::<T>::create(
+		generate_origin::<T>(caller, creator),
+		registry_entry_id.clone(),
+		authorization_id.clone(),
+		digest,
+		Some(blob),
+		None,
+	)?;
+
+	Ok((registry_entry_id, authorization_id))
+}
+
+/// Delegates the registry `registry_id` of `creator` to `delegate` with a
+/// quota of `quota` operations, returning the authorization of `delegate`.
+///
+/// The use that exhausts the quota queues the delegation in an almost full
+/// bucket of expirations, as the worst case of counting a use.
+fn add_limited_delegate<T: Config>(
+	caller: &T::AccountId,
+	creator: &CreatorOf<T>,
+	delegate: &CreatorOf<T>,
+	registry_id: &RegistryIdOf,
+	authorization_id: &AuthorizationIdOf,
+	quota: u32,
+) -> Result<AuthorizationIdOf, DispatchError>
+where
+	<T as pallet_registries::Config>::EnsureOrigin:
+		GenerateBenchmarkOrigin<T::RuntimeOrigin, T::AccountId, T::RegistryCreatorId>,
+{
+	pallet_registries::Pallet::<T>::add_delegate(
+		generate_origin::<T>(caller, creator),
+		registry_id.clone(),
+		delegate.clone(),
+		authorization_id.clone(),
+		None,
+		Some(quota),
+	)?;
+
+	let prune_at = frame_system::Pallet::<T>::block_number() + One::one();
+	pallet_registries::benchmarking::fill_expirations::<T>(
+		prune_at,
+		<T as pallet_registries::Config>::MaxDelegationExpirationsPerBlock::get() - 1,
+	);
+
+	Ok(pallet_registries::benchmarking::delegate_authorization_id::<T>(
+		registry_id,
+		delegate,
+		creator,
+	))
+}
+
+benchmarks! {
+	where_clause {
+		where
+		<T as pallet_registries::Config>::EnsureOrigin: GenerateBenchmarkOrigin<T::RuntimeOrigin, T::AccountId, T::RegistryCreatorId>,
+	}
+
+	create {
+		let l in 1 .. <T as Config>::MaxRegistryEntryBlobSize::get();
+
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let creator: T::RegistryCreatorId = account("did", 0, SEED);
+		let (registry_id, authorization_id) = create_registry::<T>(&caller, &creator)?;
+		let schema_id = enforce_schema::<T>(&caller, &creator, &registry_id, &authorization_id)?;
+		let blob = generate_blob::<T>(l, 1);
+		let digest = <T as frame_system::Config>::Hashing::hash(&blob[..]);
+		let registry_entry_id = registry_entry_id::<T>(&digest, &registry_id, &creator);
+
+		<T as pallet_registries::Config>::Consideration::ensure_successful(
+			&caller,
+			Footprint::from_parts(1, l as usize),
+		);
+		let origin = generate_origin::<T>(&caller, &creator);
+	}: _<T::RuntimeOrigin>(origin, registry_entry_id.clone(), authorization_id, digest, Some(blob), Some(schema_id))
+	verify {
+		assert_last_event::<T>(Event::<T>::RegistryEntryCreated { creator, registry_id, registry_entry_id }.into());
+	}
+
+	update {
+		let l in 1 .. <T as Config>::MaxRegistryEntryBlobSize::get();
+
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let creator: T::RegistryCreatorId = account("did", 0, SEED);
+		let (registry_entry_id, authorization_id) = create_registry_entry::<T>(&caller, &creator)?;
+
+		/* The previous blob of the entry is released and a new one stored */
+		let blob = generate_blob::<T>(l, 2);
+		let digest = <T as frame_system::Config>::Hashing::hash(&blob[..]);
+		<T as pallet_registries::Config>::Consideration::ensure_successful(
+			&caller,
+			Footprint::from_parts(1, l as usize),
+		);
+		let origin = generate_origin::<T>(&caller, &creator);
+	}: _<T::RuntimeOrigin>(origin, registry_entry_id.clone(), authorization_id, digest, Some(blob))
+	verify {
+		assert_last_event::<T>(Event::<T>::RegistryEntryUpdated { updater: creator, registry_entry_id }.into());
+	}
+
+	revoke {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let creator: T::RegistryCreatorId = account("did", 0, SEED);
+		let (registry_entry_id, authorization_id) = create_registry_entry::<T>(&caller, &creator)?;
+		let origin = generate_origin::<T>(&caller, &creator);
+	}: _<T::RuntimeOrigin>(origin, registry_entry_id.clone(), authorization_id)
+	verify {
+		assert_last_event::<T>(Event::<T>::RegistryEntryRevoked { updater: creator, registry_entry_id }.into());
+	}
+
+	reinstate {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let creator: T::RegistryCreatorId = account("did", 0, SEED);
+		let (registry_entry_id, authorization_id) = create_registry_entry::<T>(&caller, &creator)?;
+		Pallet::<T>::revoke(
+			generate_origin::<T>(&caller, &creator),
+			registry_entry_id.clone(),
+			authorization_id.clone(),
+		)?;
+		let origin = generate_origin::<T>(&caller, &creator);
+	}: _<T::RuntimeOrigin>(origin, registry_entry_id.clone(), authorization_id)
+	verify {
+		assert_last_event::<T>(Event::<T>::RegistryEntryReinstated { updater: creator, registry_entry_id }.into());
+	}
+
+	create_batch {
+		let n in 1 .. T::MaxRegistryEntriesPerBatch::get() as u32;
+
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let creator: T::RegistryCreatorId = account("did", 0, SEED);
+		let delegate: T::RegistryCreatorId = account("did", 1, SEED);
+		let (registry_id, authorization_id) = create_registry::<T>(&caller, &creator)?;
+		let schema_id = enforce_schema::<T>(&caller, &creator, &registry_id, &authorization_id)?;
+
+		/* Every entry counts against the quota, the last one exhausting it */
+		let delegate_authorization_id = add_limited_delegate::<T>(&caller, &creator, &delegate, &registry_id, &authorization_id, n)?;
+		let entries: Vec<_> = (0..n)
+			.map(|i| {
+				let digest = <T as frame_system::Config>::Hashing::hash(&i.encode()[..]);
+				(registry_entry_id::<T>(&digest, &registry_id, &delegate), digest)
+			})
+			.collect();
+		let origin = generate_origin::<T>(&caller, &delegate);
+	}: _<T::RuntimeOrigin>(origin, entries, delegate_authorization_id, Some(schema_id))
+	verify {
+		assert_last_event::<T>(Event::<T>::RegistryEntryBatchCreated { creator: delegate, registry_id, successful: n, failed: 0, indices: Vec::new() }.into());
+	}
+
+	revoke_batch {
+		let n in 1 .. T::MaxRegistryEntriesPerBatch::get() as u32;
+
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let creator: T::RegistryCreatorId = account("did", 0, SEED);
+		let (registry_id, authorization_id) = create_registry::<T>(&caller, &creator)?;
+		let entries: Vec<_> = (0..n)
+			.map(|i| {
+				let digest = <T as frame_system::Config>::Hashing::hash(&i.encode()[..]);
+				(registry_entry_id::<T>(&digest, &registry_id, &creator), digest)
+			})
+			.collect();
+		let registry_entry_ids = entries.iter().map(|(id, _)| id.clone()).collect::<Vec<_>>();
+		Pallet::<T>::create_batch(
+			generate_origin::<T>(&caller, &creator),
+			entries,
+			authorization_id.clone(),
+			None,
+		)?;
+
+		/* Every entry counts against the quota, the last one exhausting it */
+		let delegate: T::RegistryCreatorId = account("did", 1, SEED);
+		let delegate_authorization_id = add_limited_delegate::<T>(&caller, &creator, &delegate, &registry_id, &authorization_id, n)?;
+		let origin = generate_origin::<T>(&caller, &delegate);
+	}: _<T::RuntimeOrigin>(origin, registry_entry_ids, delegate_authorization_id)
+	verify {
+		assert_last_event::<T>(Event::<T>::RegistryEntryBatchRevoked { updater: delegate, registry_id, successful: n, failed: 0, indices: Vec::new() }.into());
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
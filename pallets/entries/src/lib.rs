@@ -27,6 +27,11 @@
 //! decentralized manner. Thereby enabling trust and transperency of Registries utilizing CORD
 //! blockchain. Registry & Delegation management is handled by the Registries Pallet.
 //!
+//! Entries are created and changed by the DIDs authorized on their Registry.
+//! Entries created by an account before Registries were owned by DIDs decode
+//! as created by the DID sharing the identifier of that account, which keeps
+//! managing them once created with `pallet_did::create_from_account`.
+//!
 //! ## Interface
 //!
 //! ### Dispatchable Functions
@@ -35,6 +40,35 @@
 //! * `update` - Updates a existing Registry Entry.
 //! * `revoke` - Revokes a existing Registry Entry.
 //! * `reinstate` - Reinstates a existing Registry Entry.
+//! * `create_batch` - Creates Registry Entries in bulk, reporting the entries that failed.
+//! * `revoke_batch` - Revokes Registry Entries in bulk, reporting the entries that failed.
+//!
+//! ### Version History
+//!
+//! Every change to a Registry Entry records a version holding its digest,
+//! status, the `Timepoint` of the change and the account which made it. Up to
+//! `MaxRegistryEntryVersions` versions are retained per entry, the oldest
+//! being dropped first. `entry_state_at` answers the state of an entry at a
+//! given block from this history. Entries created before the history was
+//! introduced are seeded with their state at the upgrade by
+//! `migrations::v2::MigrateToVersionHistory`.
+//!
+//! ### Schema Conformance
+//!
+//! An entry may declare conformance to the schema of its Registry, and must do
+//! so when the Registry enforces its schema. Every entry is pinned to the
+//! schema of its Registry at creation, so that once the Registry migrates to a
+//! newer schema, existing entries remain pinned to the schema they were created
+//! under. Entries created before pinning was introduced are pinned to the
+//! schema of their Registry at the upgrade by
+//! `migrations::v3::MigrateToPinnedSchemas`.
+//!
+//! ### Blob Storage
+//!
+//! The blob of a Registry Entry is stored on chain under its digest, which it
+//! must hash to, bounded by `MaxRegistryEntryBlobSize`. Identical blobs are
+//! stored once. The deposit for a blob is held through the `Consideration` of
+//! the Registries Pallet and released once no entry references the blob.
 #![cfg_attr(not(feature = "std"), no_std)]
 
 mod types;
@@ -45,13 +79,26 @@ pub mod mock;
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+pub mod migrations;
+pub mod weights;
+pub use weights::WeightInfo;
+
+use codec::Encode;
 use frame_support::{
 	ensure,
 	pallet_prelude::DispatchResult,
-	traits::{Get, StorageVersion},
+	storage::with_storage_layer,
+	traits::{Consideration, Footprint, Get, StorageVersion},
 	BoundedVec,
 };
-use sp_runtime::traits::{Hash, UniqueSaturatedInto};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::{
+	traits::{Hash, UniqueSaturatedInto},
+	DispatchError,
+};
 
 use identifier::{
 	types::{CallTypeOf, IdentifierTypeOf, Timepoint},
@@ -61,14 +108,15 @@ use identifier::{
 pub use pallet::*;
 use sp_std::{prelude::*, str};
 
-pub use frame_system::WeightInfo;
-pub use types::RegistryEntryDetails;
+pub use pallet_registries::BlobDetails;
+pub use types::{RegistryEntryDetails, RegistryEntryVersion};
 
 pub use cord_primitives::StatusOf;
 
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
+	use cord_utilities::traits::CallSources;
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
 	pub use identifier::{
@@ -85,16 +133,31 @@ pub mod pallet {
 	pub type RegistryEntryIdOf = Ss58Identifier;
 	/// Type of the Maximum size of Registry Entry Blob
 	pub type MaxRegistryEntryBlobSizeOf<T> = <T as crate::Config>::MaxRegistryEntryBlobSize;
+	/// Type of a CORD account.
+	pub(crate) type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 	/// Type of the Registry Entry Creator
-	pub type CreatorOf<T> = <T as frame_system::Config>::AccountId;
+	pub type CreatorOf<T> = pallet_registries::RegistryCreatorOf<T>;
 	/// Type of the Registry Entry Blob
 	pub type RegistryEntryBlobOf<T> = BoundedVec<u8, MaxRegistryEntryBlobSizeOf<T>>;
+	/// Type of the Schema Identifier
+	pub type SchemaIdOf = Ss58Identifier;
+
+	/// Type of a Registry Entry Blob stored on chain, with its deposit.
+	pub type RegistryEntryBlobDetailsOf<T> = BlobDetails<
+		RegistryEntryBlobOf<T>,
+		AccountIdOf<T>,
+		<T as pallet_registries::Config>::Consideration,
+	>;
 
 	/// Type of the Registry Entry Details.
 	/// Consists of Entry status, creator, registry id.
 	pub type RegistryEntryDetailsOf<T> =
 		RegistryEntryDetails<RegistryEntryHashOf<T>, StatusOf, CreatorOf<T>, RegistryIdOf>;
 
+	/// Type of a recorded version of a Registry Entry.
+	pub type RegistryEntryVersionOf<T> =
+		RegistryEntryVersion<RegistryEntryHashOf<T>, StatusOf, CreatorOf<T>>;
+
 	#[pallet::config]
 	pub trait Config:
 		frame_system::Config + pallet_registries::Config + identifier::Config
@@ -107,6 +170,14 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxRegistryEntryBlobSize: Get<u32>;
 
+		/// The maximum number of Registry Entries created or revoked in a batch.
+		#[pallet::constant]
+		type MaxRegistryEntriesPerBatch: Get<u16>;
+
+		/// The maximum number of versions retained in the history of a Registry Entry.
+		#[pallet::constant]
+		type MaxRegistryEntryVersions: Get<u32>;
+
 		/// The maximum encoded length available for naming.
 		#[pallet::constant]
 		type MaxEncodedInputLength: Get<u32>;
@@ -115,7 +186,7 @@ pub mod pallet {
 		type WeightInfo: WeightInfo;
 	}
 
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -130,12 +201,44 @@ pub mod pallet {
 	pub type RegistryEntries<T: Config> =
 		StorageMap<_, Blake2_128Concat, RegistryEntryIdOf, RegistryEntryDetailsOf<T>, OptionQuery>;
 
+	/// Storage for Registry Entry Blobs.
+	/// It maps a blob digest to the blob and its deposit.
+	#[pallet::storage]
+	pub type RegistryEntryBlobs<T: Config> =
+		StorageMap<_, Identity, RegistryEntryHashOf<T>, RegistryEntryBlobDetailsOf<T>, OptionQuery>;
+
+	/// Digests of the blobs referenced by Registry Entries.
+	/// It maps a Registry Entry Identifier to the digest of its blob.
+	#[pallet::storage]
+	pub type RegistryEntryBlobDigests<T: Config> =
+		StorageMap<_, Blake2_128Concat, RegistryEntryIdOf, RegistryEntryHashOf<T>, OptionQuery>;
+
+	/// Version history of Registry Entries.
+	/// It maps a Registry Entry Identifier to its recorded versions, oldest first.
+	#[pallet::storage]
+	pub type RegistryEntryVersions<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		RegistryEntryIdOf,
+		BoundedVec<RegistryEntryVersionOf<T>, T::MaxRegistryEntryVersions>,
+		ValueQuery,
+	>;
+
+	/// Schemas declared by Registry Entries.
+	/// It maps a Registry Entry Identifier to the schema it conforms to.
+	#[pallet::storage]
+	pub type RegistryEntrySchemas<T: Config> =
+		StorageMap<_, Blake2_128Concat, RegistryEntryIdOf, SchemaIdOf, OptionQuery>;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Invalid Identifer Length
 		InvalidIdentifierLength,
 		/// Identifier Invalid or Not of DeDir Type
 		InvalidRegistryEntryIdentifier,
+		/// Registry Entry Identifier does not match the one derived from its digest, registry
+		/// and creator
+		RegistryEntryIdentifierMismatch,
 		/// Account has no valid authorization
 		UnauthorizedOperation,
 		/// Registry Entry Identifier Already Exists
@@ -144,6 +247,16 @@ pub mod pallet {
 		RegistryEntryIdentifierDoesNotExist,
 		/// Registry Entry has not been revoked.
 		RegistryEntryNotRevoked,
+		/// The blob does not hash to the supplied digest
+		BlobDigestMismatch,
+		/// The batch holds more entries than permitted
+		MaxRegistryEntriesPerBatchExceeded,
+		/// None of the entries of the batch could be processed
+		BulkTransactionFailed,
+		/// The Registry requires entries to declare conformance to its schema
+		SchemaDeclarationRequired,
+		/// The declared schema is not the schema of the Registry
+		SchemaMismatch,
 	}
 
 	#[pallet::event]
@@ -152,22 +265,42 @@ pub mod pallet {
 		/// A new registry entry has been created.
 		/// \[creator, registry_identifier, registry_entry_identifier\]
 		RegistryEntryCreated {
-			creator: T::AccountId,
+			creator: CreatorOf<T>,
 			registry_id: RegistryIdOf,
 			registry_entry_id: RegistryEntryIdOf,
 		},
 
 		/// A existing registry entry has been updated.
 		/// \[updater, registry_entry_identifier\]
-		RegistryEntryUpdated { updater: T::AccountId, registry_entry_id: RegistryEntryIdOf },
+		RegistryEntryUpdated { updater: CreatorOf<T>, registry_entry_id: RegistryEntryIdOf },
 
 		/// A existing registry entry has been revoked.
 		/// \[updater, registry_entry_identifier\]
-		RegistryEntryRevoked { updater: T::AccountId, registry_entry_id: RegistryEntryIdOf },
+		RegistryEntryRevoked { updater: CreatorOf<T>, registry_entry_id: RegistryEntryIdOf },
 
 		/// A existing registry entry has been reinstated.
 		/// \[updater, registry_enrtry_identifier\]
-		RegistryEntryReinstated { updater: T::AccountId, registry_entry_id: RegistryEntryIdOf },
+		RegistryEntryReinstated { updater: CreatorOf<T>, registry_entry_id: RegistryEntryIdOf },
+
+		/// A batch of registry entries has been created.
+		/// \[creator, registry_identifier, successful count, failed count, failed indices\]
+		RegistryEntryBatchCreated {
+			creator: CreatorOf<T>,
+			registry_id: RegistryIdOf,
+			successful: u32,
+			failed: u32,
+			indices: Vec<u16>,
+		},
+
+		/// A batch of registry entries has been revoked.
+		/// \[updater, registry_identifier, successful count, failed count, failed indices\]
+		RegistryEntryBatchRevoked {
+			updater: CreatorOf<T>,
+			registry_id: RegistryIdOf,
+			successful: u32,
+			failed: u32,
+			indices: Vec<u16>,
+		},
 	}
 
 	#[pallet::call]
@@ -183,11 +316,14 @@ pub mod pallet {
 		/// # Arguments
 		/// * `origin` - The origin of the call, which must be a signed account (creator of the
 		///   entry).
-		/// * `registry_entry_id` - A unique id as registry entry identifier.
+		/// * `registry_entry_id` - A unique id as registry entry identifier. It must be an SS58
+		///   identifier of type `Entries` derived from the digest, registry and creator.
 		/// * `authorization` - The authorization identifier that links the creator to the Registry.
 		/// * `digest` - The hash value or digest of the content associated with the Registry entry.
-		/// * `blob` - (Optional) Additional data associated with the Registry entry, provided as an
-		///   optional field.
+		/// * `blob` - (Optional) Additional data associated with the Registry entry. It is stored on
+		///   chain under the digest, which it must hash to, against a deposit held from the account.
+		/// * `schema_id` - (Optional) The schema of the Registry the entry declares conformance to.
+		///   Required if the Registry enforces its schema.
 		///
 		/// # Errors
 		/// This function returns an error in the following cases:
@@ -197,6 +333,14 @@ pub mod pallet {
 		///   the storage.
 		/// * `InvalidIdentifierLength` - If the `registry_entry_id` generated from the hash exceeds
 		///   the expected length for identifiers.
+		/// * `InvalidRegistryEntryIdentifier` - If the `registry_entry_id` is not a valid SS58
+		///   identifier of type `Entries`.
+		/// * `RegistryEntryIdentifierMismatch` - If the `registry_entry_id` is not the one derived
+		///   from the digest, registry and creator.
+		/// * `BlobDigestMismatch` - If the blob does not hash to the digest.
+		/// * `SchemaDeclarationRequired` - If the Registry enforces its schema and no schema is
+		///   declared.
+		/// * `SchemaMismatch` - If the declared schema is not the schema of the Registry.
 		///
 		/// # Events
 		/// Emits the `Event::RegistryEntryCreated` event upon successful creation of a new Registry
@@ -205,18 +349,21 @@ pub mod pallet {
 		///
 		/// # Example
 		/// ```rust
-		/// create(origin, registry_entry_id, authorization, digest, Some(blob))?;
+		/// create(origin, registry_entry_id, authorization, digest, Some(blob), Some(schema_id))?;
 		/// ```
 		#[pallet::call_index(0)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::create(blob.as_ref().map_or(0, |blob| blob.len() as u32)).saturating_add(pallet_registries::Pallet::<T>::authorization_weight()))]
 		pub fn create(
 			origin: OriginFor<T>,
-			_registry_entry_id: RegistryEntryIdOf,
+			registry_entry_id: RegistryEntryIdOf,
 			authorization: AuthorizationIdOf,
 			digest: RegistryEntryHashOf<T>,
-			_blob: Option<RegistryEntryBlobOf<T>>,
+			blob: Option<RegistryEntryBlobOf<T>>,
+			schema_id: Option<SchemaIdOf>,
 		) -> DispatchResult {
-			let creator = ensure_signed(origin)?;
+			let tx_authors = <T as pallet_registries::Config>::EnsureOrigin::ensure_origin(origin)?;
+			let creator = tx_authors.subject();
+			let depositor = tx_authors.sender();
 
 			let registry_id = pallet_registries::Pallet::<T>::ensure_authorization_origin(
 				&authorization,
@@ -224,48 +371,20 @@ pub mod pallet {
 			)
 			.map_err(<pallet_registries::Error<T>>::from)?;
 
-			// TODO:
-			/* Identifier Management will happen at SDK.
-			 * It is to be constructed as below.
-			 */
-			// Id Digest = concat (H(<scale_encoded_statement_digest>,
-			// <scale_encoded_space_identifier>, <scale_encoded_creator_identifier>))
-			let id_digest = <T as frame_system::Config>::Hashing::hash(
-				&[&digest.encode()[..], &registry_id.encode()[..], &creator.encode()[..]].concat()
-					[..],
-			);
-
-			let registry_entry_id = Ss58Identifier::create_identifier(
-				&(id_digest).encode()[..],
-				IdentifierType::Entries,
-			)
-			.map_err(|_| Error::<T>::InvalidIdentifierLength)?;
-
-			/* Ensure that the registry_entry_id does not already exist */
-			ensure!(
-				!RegistryEntries::<T>::contains_key(&registry_entry_id),
-				Error::<T>::RegistryEntryIdentifierAlreadyExists
-			);
+			let pinned_schema_id =
+				Self::ensure_schema_conformance(&registry_id, schema_id.as_ref())?;
 
-			// TODO: Validate incoming `registry_entry_id` from SDK.
-			// /* Ensure that registry_id is of valid ss58 format,
-			//  * and also the type matches to be of `Registry`
-			//  */
-			// ensure!(
-			// 	Self::is_valid_ss58_format(&registry_entry_id),
-			// 	Error::<T>::InvalidRegistryEntryIdentifier
-			// );
-			let registry_entry = RegistryEntryDetails {
+			Self::insert_registry_entry(
+				&registry_entry_id,
+				&registry_id,
+				&creator,
 				digest,
-				revoked: false,
-				creator: creator.clone(),
-				registry_id: registry_id.clone(),
-			};
+				pinned_schema_id.as_ref(),
+			)?;
 
-			RegistryEntries::<T>::insert(&registry_entry_id, registry_entry);
-
-			Self::update_activity(&registry_entry_id, CallTypeOf::Genesis)
-				.map_err(<Error<T>>::from)?;
+			if let Some(blob) = blob {
+				Self::attach_blob(&registry_entry_id, &depositor, &digest, blob)?;
+			}
 
 			Self::deposit_event(Event::RegistryEntryCreated {
 				creator,
@@ -288,7 +407,9 @@ pub mod pallet {
 		/// * `registry_entry_id` - The unique identifier of the Registry Entry to be updated.
 		/// * `authorization` - The authorization identifier that links the updater to the Registry.
 		/// * `digest` - The new hash value or digest to be associated with the Registry Entry.
-		/// * `blob` - (Optional) New additional data to be associated with the Registry Entry.
+		/// * `blob` - (Optional) New additional data to be associated with the Registry Entry. It
+		///   must hash to the new digest and replaces the stored blob of the entry. If `None` and
+		///   the digest changes, the stored blob no longer matches the entry and is dropped.
 		///
 		/// # Errors
 		/// This function returns an error in the following cases:
@@ -297,6 +418,7 @@ pub mod pallet {
 		/// * `RegistryEntryIdentifierDoesNotExist` - If the specified `registry_entry_id` does not
 		///   exist.
 		/// * `StateNotSupported` - If an unsupported state is provided.
+		/// * `BlobDigestMismatch` - If the blob does not hash to the new digest.
 		///
 		/// # Events
 		/// Emits the `Event::RegistryEntryUpdated` event upon successful update of the Registry
@@ -307,15 +429,17 @@ pub mod pallet {
 		/// update(origin, registry_entry_id, authorization, digest, Some(blob))?;
 		/// ```
 		#[pallet::call_index(1)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::update(blob.as_ref().map_or(0, |blob| blob.len() as u32)).saturating_add(pallet_registries::Pallet::<T>::authorization_weight()))]
 		pub fn update(
 			origin: OriginFor<T>,
 			registry_entry_id: RegistryEntryIdOf,
 			authorization: AuthorizationIdOf,
 			digest: RegistryEntryHashOf<T>,
-			_blob: Option<RegistryEntryBlobOf<T>>,
+			blob: Option<RegistryEntryBlobOf<T>>,
 		) -> DispatchResult {
-			let updater = ensure_signed(origin)?;
+			let tx_authors = <T as pallet_registries::Config>::EnsureOrigin::ensure_origin(origin)?;
+			let updater = tx_authors.subject();
+			let depositor = tx_authors.sender();
 			let registry_id = pallet_registries::Pallet::<T>::ensure_authorization_origin(
 				&authorization,
 				&updater,
@@ -327,8 +451,16 @@ pub mod pallet {
 
 			ensure!(entry.registry_id == registry_id, Error::<T>::UnauthorizedOperation);
 
+			match blob {
+				Some(blob) => Self::attach_blob(&registry_entry_id, &depositor, &digest, blob)?,
+				None if entry.digest != digest => Self::detach_blob(&registry_entry_id)?,
+				None => {},
+			}
+
 			entry.digest = digest;
 
+			Self::record_version(&registry_entry_id, &entry, &updater);
+
 			RegistryEntries::<T>::insert(&registry_entry_id, entry);
 
 			Self::update_activity(&registry_entry_id, CallTypeOf::Update)
@@ -366,30 +498,21 @@ pub mod pallet {
 		/// revoke(origin, registry_entry_id, authorization)?;
 		/// ```
 		#[pallet::call_index(2)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::revoke().saturating_add(pallet_registries::Pallet::<T>::authorization_weight()))]
 		pub fn revoke(
 			origin: OriginFor<T>,
 			registry_entry_id: RegistryEntryIdOf,
 			authorization: AuthorizationIdOf,
 		) -> DispatchResult {
-			let updater = ensure_signed(origin)?;
+			let updater =
+				<T as pallet_registries::Config>::EnsureOrigin::ensure_origin(origin)?.subject();
 			let registry_id = pallet_registries::Pallet::<T>::ensure_authorization_origin(
 				&authorization,
 				&updater,
 			)
 			.map_err(<pallet_registries::Error<T>>::from)?;
 
-			let mut entry = RegistryEntries::<T>::get(&registry_entry_id)
-				.ok_or(Error::<T>::RegistryEntryIdentifierDoesNotExist)?;
-
-			ensure!(entry.registry_id == registry_id, Error::<T>::UnauthorizedOperation);
-
-			entry.revoked = true;
-
-			RegistryEntries::<T>::insert(&registry_entry_id, entry);
-
-			Self::update_activity(&registry_entry_id, CallTypeOf::Revoke)
-				.map_err(<Error<T>>::from)?;
+			Self::revoke_registry_entry(&registry_entry_id, &registry_id, &updater)?;
 
 			Self::deposit_event(Event::RegistryEntryRevoked { updater, registry_entry_id });
 
@@ -423,13 +546,14 @@ pub mod pallet {
 		/// reinstate(origin, registry_entry_id, authorization)?;
 		/// ```
 		#[pallet::call_index(3)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::reinstate().saturating_add(pallet_registries::Pallet::<T>::authorization_weight()))]
 		pub fn reinstate(
 			origin: OriginFor<T>,
 			registry_entry_id: RegistryEntryIdOf,
 			authorization: AuthorizationIdOf,
 		) -> DispatchResult {
-			let updater = ensure_signed(origin)?;
+			let updater =
+				<T as pallet_registries::Config>::EnsureOrigin::ensure_origin(origin)?.subject();
 			let registry_id = pallet_registries::Pallet::<T>::ensure_authorization_origin(
 				&authorization,
 				&updater,
@@ -445,6 +569,8 @@ pub mod pallet {
 
 			entry.revoked = false;
 
+			Self::record_version(&registry_entry_id, &entry, &updater);
+
 			RegistryEntries::<T>::insert(&registry_entry_id, entry);
 
 			Self::update_activity(&registry_entry_id, CallTypeOf::Reinstate)
@@ -454,10 +580,354 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Creates Registry Entries in bulk within a specified Registry.
+		///
+		/// Each entry is validated and created as by `create`, without a blob.
+		/// An entry that cannot be created is recorded as failed and leaves no
+		/// trace in storage, without failing the remaining entries of the batch.
+		/// The authorization is checked once for the batch, and every entry
+		/// created counts as one operation against the quota of the delegation.
+		/// Entries beyond the quota are recorded as failed. The weight covers
+		/// checking the authorization once and consuming it for every entry.
+		///
+		/// # Arguments
+		/// * `origin` - The origin of the call, which must be a signed account (creator of the
+		///   entries).
+		/// * `entries` - The identifiers and digests of the Registry Entries to be created.
+		/// * `authorization` - The authorization identifier that links the creator to the Registry.
+		/// * `schema_id` - (Optional) The schema of the Registry the entries declare conformance
+		///   to. Required if the Registry enforces its schema.
+		///
+		/// # Errors
+		/// This function returns an error in the following cases:
+		/// * `MaxRegistryEntriesPerBatchExceeded` - If the batch holds more entries than
+		///   `MaxRegistryEntriesPerBatch`.
+		/// * `UnauthorizedOperation` - If the caller does not have permission to create entries
+		///   within the Registry.
+		/// * `SchemaDeclarationRequired` - If the Registry enforces its schema and no schema is
+		///   declared.
+		/// * `SchemaMismatch` - If the declared schema is not the schema of the Registry.
+		/// * `BulkTransactionFailed` - If none of the entries could be created.
+		///
+		/// # Events
+		/// Emits the `Event::RegistryEntryBatchCreated` event with the number of created and failed
+		/// entries, and the indices of the failed entries in the batch.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::create_batch(entries.len() as u32).saturating_add(pallet_registries::Pallet::<T>::authorization_weight().saturating_mul(entries.len().saturating_add(1) as u64)))]
+		pub fn create_batch(
+			origin: OriginFor<T>,
+			entries: Vec<(RegistryEntryIdOf, RegistryEntryHashOf<T>)>,
+			authorization: AuthorizationIdOf,
+			schema_id: Option<SchemaIdOf>,
+		) -> DispatchResult {
+			let creator =
+				<T as pallet_registries::Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			ensure!(
+				entries.len() <= T::MaxRegistryEntriesPerBatch::get() as usize,
+				Error::<T>::MaxRegistryEntriesPerBatchExceeded
+			);
+
+			let registry_id = pallet_registries::Pallet::<T>::ensure_authorization_batch_origin(
+				&authorization,
+				&creator,
+			)
+			.map_err(<pallet_registries::Error<T>>::from)?;
+
+			let pinned_schema_id =
+				Self::ensure_schema_conformance(&registry_id, schema_id.as_ref())?;
+
+			let mut successful = 0u32;
+			let mut failed = 0u32;
+			let mut indices: Vec<u16> = Vec::new();
+
+			for (index, (registry_entry_id, digest)) in entries.iter().enumerate() {
+				let result = with_storage_layer(|| {
+					pallet_registries::Pallet::<T>::consume_delegation(&authorization)?;
+					Self::insert_registry_entry(
+						registry_entry_id,
+						&registry_id,
+						&creator,
+						*digest,
+						pinned_schema_id.as_ref(),
+					)
+				});
+				match result {
+					Ok(()) => successful += 1,
+					Err(_) => {
+						failed += 1;
+						indices.push(index as u16);
+					},
+				}
+			}
+
+			ensure!(successful > 0, Error::<T>::BulkTransactionFailed);
+
+			Self::deposit_event(Event::RegistryEntryBatchCreated {
+				creator,
+				registry_id,
+				successful,
+				failed,
+				indices,
+			});
+
+			Ok(())
+		}
+
+		/// Revokes Registry Entries in bulk within a specified Registry.
+		///
+		/// Each entry is revoked as by `revoke`. An entry that cannot be
+		/// revoked is recorded as failed, without failing the remaining entries
+		/// of the batch. The authorization is checked once for the batch, and
+		/// every entry revoked counts as one operation against the quota of the
+		/// delegation. Entries beyond the quota are recorded as failed. The
+		/// weight covers checking the authorization once and consuming it for
+		/// every entry.
+		///
+		/// # Arguments
+		/// * `origin` - The origin of the call, which must be a signed account (updater).
+		/// * `registry_entry_ids` - The identifiers of the Registry Entries to be revoked.
+		/// * `authorization` - The authorization identifier that links the updater to the Registry.
+		///
+		/// # Errors
+		/// This function returns an error in the following cases:
+		/// * `MaxRegistryEntriesPerBatchExceeded` - If the batch holds more entries than
+		///   `MaxRegistryEntriesPerBatch`.
+		/// * `UnauthorizedOperation` - If the caller does not have permission to revoke entries
+		///   within the Registry.
+		/// * `BulkTransactionFailed` - If none of the entries could be revoked.
+		///
+		/// # Events
+		/// Emits the `Event::RegistryEntryBatchRevoked` event with the number of revoked and failed
+		/// entries, and the indices of the failed entries in the batch.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::revoke_batch(registry_entry_ids.len() as u32).saturating_add(pallet_registries::Pallet::<T>::authorization_weight().saturating_mul(registry_entry_ids.len().saturating_add(1) as u64)))]
+		pub fn revoke_batch(
+			origin: OriginFor<T>,
+			registry_entry_ids: Vec<RegistryEntryIdOf>,
+			authorization: AuthorizationIdOf,
+		) -> DispatchResult {
+			let updater =
+				<T as pallet_registries::Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			ensure!(
+				registry_entry_ids.len() <= T::MaxRegistryEntriesPerBatch::get() as usize,
+				Error::<T>::MaxRegistryEntriesPerBatchExceeded
+			);
+
+			let registry_id = pallet_registries::Pallet::<T>::ensure_authorization_batch_origin(
+				&authorization,
+				&updater,
+			)
+			.map_err(<pallet_registries::Error<T>>::from)?;
+
+			let mut successful = 0u32;
+			let mut failed = 0u32;
+			let mut indices: Vec<u16> = Vec::new();
+
+			for (index, registry_entry_id) in registry_entry_ids.iter().enumerate() {
+				let result = with_storage_layer(|| {
+					pallet_registries::Pallet::<T>::consume_delegation(&authorization)?;
+					Self::revoke_registry_entry(registry_entry_id, &registry_id, &updater)
+				});
+				match result {
+					Ok(()) => successful += 1,
+					Err(_) => {
+						failed += 1;
+						indices.push(index as u16);
+					},
+				}
+			}
+
+			ensure!(successful > 0, Error::<T>::BulkTransactionFailed);
+
+			Self::deposit_event(Event::RegistryEntryBatchRevoked {
+				updater,
+				registry_id,
+				successful,
+				failed,
+				indices,
+			});
+
+			Ok(())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	/// Validates the identifier of a new Registry Entry of `creator` in the
+	/// Registry `registry_id`, and records the entry pinned to the schema
+	/// `schema_id` of the Registry, if any.
+	///
+	/// # Errors
+	/// - `InvalidRegistryEntryIdentifier`: If the identifier is not a valid SS58 identifier of
+	///   type `Entries`.
+	/// - `RegistryEntryIdentifierMismatch`: If the identifier is not the one derived from the
+	///   digest, registry and creator.
+	/// - `RegistryEntryIdentifierAlreadyExists`: If the identifier is already in use.
+	pub fn insert_registry_entry(
+		registry_entry_id: &RegistryEntryIdOf,
+		registry_id: &RegistryIdOf,
+		creator: &CreatorOf<T>,
+		digest: RegistryEntryHashOf<T>,
+		schema_id: Option<&SchemaIdOf>,
+	) -> DispatchResult {
+		/* Ensure that registry_entry_id is of valid ss58 format,
+		 * and also the type matches to be of `Entries`
+		 */
+		ensure!(
+			Self::is_valid_ss58_format(registry_entry_id),
+			Error::<T>::InvalidRegistryEntryIdentifier
+		);
+
+		// Id Digest = concat (H(<scale_encoded_statement_digest>,
+		// <scale_encoded_space_identifier>, <scale_encoded_creator_identifier>))
+		let id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&digest.encode()[..], &registry_id.encode()[..], &creator.encode()[..]].concat()[..],
+		);
+
+		let identifier =
+			Ss58Identifier::create_identifier(&(id_digest).encode()[..], IdentifierType::Entries)
+				.map_err(|_| Error::<T>::InvalidIdentifierLength)?;
+
+		ensure!(identifier == *registry_entry_id, Error::<T>::RegistryEntryIdentifierMismatch);
+
+		/* Ensure that the registry_entry_id does not already exist */
+		ensure!(
+			!RegistryEntries::<T>::contains_key(registry_entry_id),
+			Error::<T>::RegistryEntryIdentifierAlreadyExists
+		);
+
+		let registry_entry = RegistryEntryDetails {
+			digest,
+			revoked: false,
+			creator: creator.clone(),
+			registry_id: registry_id.clone(),
+		};
+
+		Self::record_version(registry_entry_id, &registry_entry, creator);
+
+		RegistryEntries::<T>::insert(registry_entry_id, registry_entry);
+
+		if let Some(schema_id) = schema_id {
+			RegistryEntrySchemas::<T>::insert(registry_entry_id, schema_id);
+		}
+
+		Self::update_activity(registry_entry_id, CallTypeOf::Genesis).map_err(<Error<T>>::from)?;
+
+		Ok(())
+	}
+
+	/// Marks the Registry Entry `registry_entry_id` of the Registry
+	/// `registry_id` as revoked by `updater`.
+	///
+	/// # Errors
+	/// - `RegistryEntryIdentifierDoesNotExist`: If the entry does not exist.
+	/// - `UnauthorizedOperation`: If the entry does not belong to the registry.
+	pub fn revoke_registry_entry(
+		registry_entry_id: &RegistryEntryIdOf,
+		registry_id: &RegistryIdOf,
+		updater: &CreatorOf<T>,
+	) -> DispatchResult {
+		let mut entry = RegistryEntries::<T>::get(registry_entry_id)
+			.ok_or(Error::<T>::RegistryEntryIdentifierDoesNotExist)?;
+
+		ensure!(entry.registry_id == *registry_id, Error::<T>::UnauthorizedOperation);
+
+		entry.revoked = true;
+
+		Self::record_version(registry_entry_id, &entry, updater);
+
+		RegistryEntries::<T>::insert(registry_entry_id, entry);
+
+		Self::update_activity(registry_entry_id, CallTypeOf::Revoke).map_err(<Error<T>>::from)?;
+
+		Ok(())
+	}
+
+	/// Ensures that a schema declared by an entry of the Registry
+	/// `registry_id` is the existing schema of the Registry, and that a schema
+	/// is declared if the Registry enforces it.
+	///
+	/// Returns the current schema of the Registry, if any, which new entries
+	/// are pinned to whether or not they declare it.
+	///
+	/// # Errors
+	/// - `SchemaMismatch`: If the declared schema is not the schema of the Registry.
+	/// - `SchemaNotFound`: If the declared schema does not exist.
+	/// - `SchemaDeclarationRequired`: If the Registry enforces its schema and none is declared.
+	pub fn ensure_schema_conformance(
+		registry_id: &RegistryIdOf,
+		schema_id: Option<&SchemaIdOf>,
+	) -> Result<Option<SchemaIdOf>, DispatchError> {
+		let registry_schema_id = pallet_registries::RegistryInfo::<T>::get(registry_id)
+			.and_then(|registry| registry.schema_id);
+
+		match schema_id {
+			Some(schema_id) => {
+				ensure!(registry_schema_id.as_ref() == Some(schema_id), Error::<T>::SchemaMismatch);
+
+				pallet_schema_accounts::Pallet::<T>::is_valid(schema_id)?;
+			},
+			None => ensure!(
+				!pallet_registries::SchemaEnforcement::<T>::get(registry_id),
+				Error::<T>::SchemaDeclarationRequired
+			),
+		}
+
+		Ok(registry_schema_id)
+	}
+
+	/// Returns the schema a Registry Entry is pinned to, being the schema of
+	/// its Registry when the entry was created. The entry remains pinned to
+	/// it once the Registry migrates to a newer schema.
+	pub fn pinned_schema(registry_entry_id: &RegistryEntryIdOf) -> Option<SchemaIdOf> {
+		RegistryEntrySchemas::<T>::get(registry_entry_id)
+	}
+
+	/// Records the state of `entry` as the latest version of the Registry
+	/// Entry `registry_entry_id`, changed by `actor` at the current
+	/// `Timepoint`. Once `MaxRegistryEntryVersions` versions are held, the
+	/// oldest version is dropped.
+	fn record_version(
+		registry_entry_id: &RegistryEntryIdOf,
+		entry: &RegistryEntryDetailsOf<T>,
+		actor: &CreatorOf<T>,
+	) {
+		let version = RegistryEntryVersion {
+			digest: entry.digest,
+			revoked: entry.revoked,
+			timepoint: Self::timepoint(),
+			actor: actor.clone(),
+		};
+
+		RegistryEntryVersions::<T>::mutate(registry_entry_id, |versions| {
+			if versions.is_full() {
+				versions.remove(0);
+			}
+			let _ = versions.try_push(version);
+		});
+	}
+
+	/// Returns the state of the Registry Entry `registry_entry_id` as of the
+	/// end of block `block`, being the latest version recorded at or before
+	/// it.
+	///
+	/// Returns `None` if the entry did not exist at `block`, or if the
+	/// versions covering `block` are no longer retained.
+	pub fn entry_state_at(
+		registry_entry_id: &RegistryEntryIdOf,
+		block: BlockNumberFor<T>,
+	) -> Option<RegistryEntryVersionOf<T>> {
+		let height: u32 = block.unique_saturated_into();
+
+		RegistryEntryVersions::<T>::get(registry_entry_id)
+			.into_iter()
+			.rev()
+			.find(|version| version.timepoint.height <= height)
+	}
+
 	/// Method to check if the input identifier calculated from sdk
 	/// is actually a valid SS58 Identifier Format and of valid type `Entries`.
 	pub fn is_valid_ss58_format(identifier: &Ss58Identifier) -> bool {
@@ -477,6 +947,82 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Stores `blob` as the blob of a registry entry, replacing the blob it
+	/// referenced before.
+	///
+	/// The blob must hash to `digest`. A blob already stored under the
+	/// digest is shared, otherwise a deposit for it is held from `who`.
+	pub fn attach_blob(
+		registry_entry_id: &RegistryEntryIdOf,
+		who: &AccountIdOf<T>,
+		digest: &RegistryEntryHashOf<T>,
+		blob: RegistryEntryBlobOf<T>,
+	) -> DispatchResult {
+		ensure!(
+			<T as frame_system::Config>::Hashing::hash(&blob[..]) == *digest,
+			Error::<T>::BlobDigestMismatch
+		);
+
+		if <RegistryEntryBlobDigests<T>>::get(registry_entry_id).as_ref() == Some(digest) {
+			return Ok(());
+		}
+		Self::detach_blob(registry_entry_id)?;
+
+		let details = match <RegistryEntryBlobs<T>>::get(digest) {
+			Some(details) => RegistryEntryBlobDetailsOf::<T> {
+				references: details.references.saturating_add(1),
+				..details
+			},
+			None => {
+				let ticket = <T as pallet_registries::Config>::Consideration::new(
+					who,
+					Footprint::from_parts(1, blob.len()),
+				)?;
+				RegistryEntryBlobDetailsOf::<T> {
+					blob,
+					depositor: who.clone(),
+					ticket,
+					references: 1,
+				}
+			},
+		};
+		<RegistryEntryBlobs<T>>::insert(digest, details);
+		<RegistryEntryBlobDigests<T>>::insert(registry_entry_id, digest);
+
+		Ok(())
+	}
+
+	/// Drops the reference of a registry entry to its blob. The blob is
+	/// removed and its deposit released once it is no longer referenced.
+	pub fn detach_blob(registry_entry_id: &RegistryEntryIdOf) -> DispatchResult {
+		let digest = match <RegistryEntryBlobDigests<T>>::take(registry_entry_id) {
+			Some(digest) => digest,
+			None => return Ok(()),
+		};
+
+		if let Some(details) = <RegistryEntryBlobs<T>>::get(&digest) {
+			if details.references > 1 {
+				<RegistryEntryBlobs<T>>::insert(
+					&digest,
+					RegistryEntryBlobDetailsOf::<T> {
+						references: details.references - 1,
+						..details
+					},
+				);
+			} else {
+				<RegistryEntryBlobs<T>>::remove(&digest);
+				details.ticket.drop(&details.depositor)?;
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Returns the registry entry blob stored under `digest`, if any.
+	pub fn blob(digest: &RegistryEntryHashOf<T>) -> Option<Vec<u8>> {
+		<RegistryEntryBlobs<T>>::get(digest).map(|details| details.blob.into_inner())
+	}
+
 	/// Updates the global timeline with a new activity event for a registry entry.
 	/// This function is called whenever a significant action is performed on a
 	/// registry entry, ensuring that all such activities are logged with a timestamp
@@ -0,0 +1,229 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Storage migrations for the entries pallet.
+
+use crate::*;
+
+pub mod v2 {
+	use super::*;
+	use frame_support::{
+		migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+		pallet_prelude::*,
+		weights::WeightMeter,
+	};
+	use sp_std::marker::PhantomData;
+
+	/// The identifier of the entries migrations in the multi-block migrator.
+	pub(crate) const MIGRATION_ID: &[u8; 16] = b"cord-reg-entries";
+
+	/// Seeds the version history of every Registry Entry created before
+	/// version 2 of the pallet with its current state, so that
+	/// `entry_state_at` answers for entries which have not changed since.
+	///
+	/// The seeded version carries the `Timepoint` of the last event on the
+	/// timeline of the entry, which is when it reached its current state, and
+	/// the creator of the entry as its actor. It must run after
+	/// `identifier::migrations::v2::MigrateToPagedTimeline`.
+	///
+	/// The migration runs over several blocks, visiting one entry per
+	/// iteration. The cursor is the last entry that was visited.
+	pub struct MigrateToVersionHistory<T>(PhantomData<T>);
+
+	impl<T: Config> MigrateToVersionHistory<T> {
+		/// The weight of a single iteration, which reads the entry, its
+		/// versions and the last event of its timeline, and seeds a version.
+		pub(crate) fn step_weight() -> Weight {
+			T::DbWeight::get().reads_writes(4, 1)
+		}
+
+		/// Returns the version an entry reached its current state in, as
+		/// recorded by the last event on its timeline.
+		fn seed_version(
+			registry_entry_id: &RegistryEntryIdOf,
+			entry: &RegistryEntryDetailsOf<T>,
+		) -> RegistryEntryVersionOf<T> {
+			let length =
+				identifier::TimelineLength::<T>::get(registry_entry_id, IdentifierTypeOf::Entries);
+			let timepoint = length
+				.checked_sub(1)
+				.and_then(|last| {
+					identifier::Timeline::<T>::get((
+						registry_entry_id,
+						IdentifierTypeOf::Entries,
+						last,
+					))
+				})
+				.map(|event| event.location)
+				.unwrap_or_default();
+
+			RegistryEntryVersion {
+				digest: entry.digest,
+				revoked: entry.revoked,
+				timepoint,
+				actor: entry.creator.clone(),
+			}
+		}
+	}
+
+	impl<T: Config> SteppedMigration for MigrateToVersionHistory<T> {
+		type Cursor = RegistryEntryIdOf;
+		type Identifier = MigrationId<16>;
+
+		fn id() -> Self::Identifier {
+			MigrationId { pallet_id: *MIGRATION_ID, version_from: 1, version_to: 2 }
+		}
+
+		fn step(
+			mut cursor: Option<Self::Cursor>,
+			meter: &mut WeightMeter,
+		) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+			let required = Self::step_weight();
+			if meter.remaining().any_lt(required) {
+				return Err(SteppedMigrationError::InsufficientWeight { required });
+			}
+
+			if Pallet::<T>::on_chain_storage_version() != 1 {
+				log::info!(
+					target: "runtime::entries",
+					"skipping version history migration, on-chain storage version is {:?}",
+					Pallet::<T>::on_chain_storage_version()
+				);
+				return Ok(None);
+			}
+
+			while meter.try_consume(required).is_ok() {
+				let mut iter = match cursor {
+					Some(ref last) =>
+						RegistryEntries::<T>::iter_from(RegistryEntries::<T>::hashed_key_for(last)),
+					None => RegistryEntries::<T>::iter(),
+				};
+
+				let Some((registry_entry_id, entry)) = iter.next() else {
+					StorageVersion::new(2).put::<Pallet<T>>();
+					log::info!(
+						target: "runtime::entries",
+						"migrated registry entries to version 2"
+					);
+					return Ok(None);
+				};
+
+				// Entries which already record versions are left untouched.
+				if !RegistryEntryVersions::<T>::contains_key(&registry_entry_id) {
+					let version = Self::seed_version(&registry_entry_id, &entry);
+					RegistryEntryVersions::<T>::mutate(&registry_entry_id, |versions| {
+						let _ = versions.try_push(version);
+					});
+				}
+
+				cursor = Some(registry_entry_id);
+			}
+
+			Ok(cursor)
+		}
+	}
+}
+
+pub mod v3 {
+	use super::*;
+	use frame_support::{
+		migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+		pallet_prelude::*,
+		weights::WeightMeter,
+	};
+	use sp_std::marker::PhantomData;
+
+	/// Pins every Registry Entry created before version 3 of the pallet
+	/// without a declared schema to the current schema of its Registry, as
+	/// entries created since are pinned at creation.
+	///
+	/// The schema of a Registry at the creation of such an entry is not
+	/// recorded, so its current schema is the closest available. Entries of
+	/// Registries without a schema are left unpinned.
+	///
+	/// The migration runs over several blocks, visiting one entry per
+	/// iteration. The cursor is the last entry that was visited. It must run
+	/// after `v2::MigrateToVersionHistory`.
+	pub struct MigrateToPinnedSchemas<T>(PhantomData<T>);
+
+	impl<T: Config> MigrateToPinnedSchemas<T> {
+		/// The weight of a single iteration, which reads the entry, its pinned
+		/// schema and its Registry, and pins a schema.
+		pub(crate) fn step_weight() -> Weight {
+			T::DbWeight::get().reads_writes(3, 1)
+		}
+	}
+
+	impl<T: Config> SteppedMigration for MigrateToPinnedSchemas<T> {
+		type Cursor = RegistryEntryIdOf;
+		type Identifier = MigrationId<16>;
+
+		fn id() -> Self::Identifier {
+			MigrationId { pallet_id: *v2::MIGRATION_ID, version_from: 2, version_to: 3 }
+		}
+
+		fn step(
+			mut cursor: Option<Self::Cursor>,
+			meter: &mut WeightMeter,
+		) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+			let required = Self::step_weight();
+			if meter.remaining().any_lt(required) {
+				return Err(SteppedMigrationError::InsufficientWeight { required });
+			}
+
+			if Pallet::<T>::on_chain_storage_version() != 2 {
+				log::info!(
+					target: "runtime::entries",
+					"skipping pinned schemas migration, on-chain storage version is {:?}",
+					Pallet::<T>::on_chain_storage_version()
+				);
+				return Ok(None);
+			}
+
+			while meter.try_consume(required).is_ok() {
+				let mut iter = match cursor {
+					Some(ref last) =>
+						RegistryEntries::<T>::iter_from(RegistryEntries::<T>::hashed_key_for(last)),
+					None => RegistryEntries::<T>::iter(),
+				};
+
+				let Some((registry_entry_id, entry)) = iter.next() else {
+					StorageVersion::new(3).put::<Pallet<T>>();
+					log::info!(
+						target: "runtime::entries",
+						"migrated registry entries to version 3"
+					);
+					return Ok(None);
+				};
+
+				if !RegistryEntrySchemas::<T>::contains_key(&registry_entry_id) {
+					if let Some(schema_id) =
+						pallet_registries::RegistryInfo::<T>::get(&entry.registry_id)
+							.and_then(|registry| registry.schema_id)
+					{
+						RegistryEntrySchemas::<T>::insert(&registry_entry_id, schema_id);
+					}
+				}
+
+				cursor = Some(registry_entry_id);
+			}
+
+			Ok(cursor)
+		}
+	}
+}
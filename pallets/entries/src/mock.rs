@@ -77,34 +77,38 @@ parameter_types! {
 	pub const MaxRegistryBlobSize: u32 = 4 * 1024;
 	pub const MaxRegistryDelegates: u32 = 5;
 	pub const MaxEncodedInputLength: u32 = 30;
+	pub const MaxDelegationExpirationsPerBlock: u32 = 5u32;
 }
 
 impl pallet_registries::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
+	type EnsureOrigin = mock_origin::EnsureDoubleOrigin<AccountId, SubjectId>;
+	type OriginSuccess = mock_origin::DoubleOrigin<AccountId, SubjectId>;
+	type RegistryCreatorId = SubjectId;
 	type MaxRegistryDelegates = MaxRegistryDelegates;
 	type MaxEncodedInputLength = MaxEncodedInputLength;
 	type MaxRegistryBlobSize = MaxRegistryBlobSize;
+	type MaxDelegationExpirationsPerBlock = MaxDelegationExpirationsPerBlock;
+	type Consideration = ();
 	type WeightInfo = ();
 }
 
 parameter_types! {
 	pub const MaxRegistryEntryBlobSize: u32 = 4 * 1024; // 4KB in bytes
+	pub const MaxRegistryEntriesPerBatch: u16 = 5;
+	pub const MaxRegistryEntryVersions: u32 = 3;
 }
 
 impl pallet_entries::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type MaxEncodedInputLength = MaxEncodedInputLength;
 	type MaxRegistryEntryBlobSize = MaxRegistryEntryBlobSize;
+	type MaxRegistryEntriesPerBatch = MaxRegistryEntriesPerBatch;
+	type MaxRegistryEntryVersions = MaxRegistryEntryVersions;
 	type WeightInfo = ();
 }
 
-parameter_types! {
-	pub const MaxEventsHistory: u32 = 6u32;
-}
-
-impl identifier::Config for Test {
-	type MaxEventsHistory = MaxEventsHistory;
-}
+impl identifier::Config for Test {}
 
 parameter_types! {
 	storage SpaceEvents: u32 = 0;
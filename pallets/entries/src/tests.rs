@@ -19,9 +19,10 @@
 use super::*;
 use crate::mock::*;
 use codec::Encode;
-use frame_support::{assert_ok, BoundedVec};
+use cord_utilities::mock::{mock_origin::DoubleOrigin, SubjectId};
+use frame_support::{assert_err, assert_ok, BoundedVec};
 use serde_json::json;
-use sp_runtime::traits::Hash;
+use sp_runtime::{traits::Hash, AccountId32};
 use sp_std::prelude::*;
 
 use pallet_registries::{RegistryBlobOf, RegistryHashOf};
@@ -57,11 +58,14 @@ pub fn generate_schema_id<T: Config>(digest: &SchemaHashOf<T>) -> SchemaIdOf {
 		.unwrap()
 }
 
+pub(crate) const DID_00: SubjectId = SubjectId(AccountId32::new([1u8; 32]));
+pub(crate) const DID_01: SubjectId = SubjectId(AccountId32::new([2u8; 32]));
 pub(crate) const ACCOUNT_00: AccountId = AccountId::new([1u8; 32]);
 
 #[test]
 fn create_registry_entry_should_work() {
-	let creator = ACCOUNT_00;
+	let creator = DID_00;
+	let author = ACCOUNT_00;
 	let registry = [2u8; 256].to_vec();
 
 	let raw_blob = [2u8; 256].to_vec();
@@ -92,7 +96,7 @@ fn create_registry_entry_should_work() {
 	new_test_ext().execute_with(|| {
 		/* Test creation of a Registry */
 		assert_ok!(Registries::create(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_id.clone(),
 			registry_digest,
 			Some(schema_id),
@@ -142,11 +146,12 @@ fn create_registry_entry_should_work() {
 			generate_registry_entry_id::<Test>(&registry_entry_id_digest);
 
 		assert_ok!(Entries::create(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_entry_id.clone(),
 			authorization_id.clone(),
 			registry_entry_digest,
 			Some(registry_entry_blob.clone()),
+			None,
 		));
 
 		/* Check if the Entry was created */
@@ -172,7 +177,8 @@ fn create_registry_entry_should_work() {
 
 #[test]
 fn update_registry_entry_should_work() {
-	let creator = ACCOUNT_00;
+	let creator = DID_00;
+	let author = ACCOUNT_00;
 	let registry = [2u8; 256].to_vec();
 
 	let raw_blob = [2u8; 256].to_vec();
@@ -203,7 +209,7 @@ fn update_registry_entry_should_work() {
 	new_test_ext().execute_with(|| {
 		/* Test creation of a Registry */
 		assert_ok!(Registries::create(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_id.clone(),
 			registry_digest,
 			Some(schema_id),
@@ -253,11 +259,12 @@ fn update_registry_entry_should_work() {
 			generate_registry_entry_id::<Test>(&registry_entry_id_digest);
 
 		assert_ok!(Entries::create(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_entry_id.clone(),
 			authorization_id.clone(),
 			registry_entry_digest,
 			Some(registry_entry_blob.clone()),
+			None,
 		));
 
 		/* Assumed JSON for Registry Entry */
@@ -295,7 +302,7 @@ fn update_registry_entry_should_work() {
 			);
 
 		assert_ok!(Entries::update(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_entry_id.clone(),
 			authorization_id.clone(),
 			updated_registry_entry_digest,
@@ -324,7 +331,8 @@ fn update_registry_entry_should_work() {
 
 #[test]
 fn revoke_registry_entry_should_work() {
-	let creator = ACCOUNT_00;
+	let creator = DID_00;
+	let author = ACCOUNT_00;
 	let registry = [2u8; 256].to_vec();
 
 	let raw_blob = [2u8; 256].to_vec();
@@ -355,7 +363,7 @@ fn revoke_registry_entry_should_work() {
 	new_test_ext().execute_with(|| {
 		/* Test creation of a Registry */
 		assert_ok!(Registries::create(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_id.clone(),
 			registry_digest,
 			Some(schema_id),
@@ -405,15 +413,16 @@ fn revoke_registry_entry_should_work() {
 			generate_registry_entry_id::<Test>(&registry_entry_id_digest);
 
 		assert_ok!(Entries::create(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_entry_id.clone(),
 			authorization_id.clone(),
 			registry_entry_digest,
 			Some(registry_entry_blob.clone()),
+			None,
 		));
 
 		assert_ok!(Entries::revoke(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_entry_id.clone(),
 			authorization_id.clone(),
 		));
@@ -437,7 +446,8 @@ fn revoke_registry_entry_should_work() {
 
 #[test]
 fn reinstating_revoked_registry_entry_should_work() {
-	let creator = ACCOUNT_00;
+	let creator = DID_00;
+	let author = ACCOUNT_00;
 	let registry = [2u8; 256].to_vec();
 
 	let raw_blob = [2u8; 256].to_vec();
@@ -468,7 +478,7 @@ fn reinstating_revoked_registry_entry_should_work() {
 	new_test_ext().execute_with(|| {
 		/* Test creation of a Registry */
 		assert_ok!(Registries::create(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_id.clone(),
 			registry_digest,
 			Some(schema_id),
@@ -518,15 +528,16 @@ fn reinstating_revoked_registry_entry_should_work() {
 			generate_registry_entry_id::<Test>(&registry_entry_id_digest);
 
 		assert_ok!(Entries::create(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_entry_id.clone(),
 			authorization_id.clone(),
 			registry_entry_digest,
 			Some(registry_entry_blob.clone()),
+			None,
 		));
 
 		assert_ok!(Entries::revoke(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_entry_id.clone(),
 			authorization_id.clone(),
 		));
@@ -539,7 +550,7 @@ fn reinstating_revoked_registry_entry_should_work() {
 		assert_eq!(entry.revoked, true);
 
 		assert_ok!(Entries::reinstate(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_entry_id.clone(),
 			authorization_id.clone(),
 		));
@@ -560,3 +571,804 @@ fn reinstating_revoked_registry_entry_should_work() {
 		);
 	});
 }
+
+#[test]
+fn registry_entry_blobs_should_be_stored_by_digest() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let registry = [2u8; 256].to_vec();
+	let registry_digest = <Test as frame_system::Config>::Hashing::hash(&registry.encode()[..]);
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let registry_id: RegistryIdOf = generate_registry_id::<Test>(&id_digest);
+	let auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: AuthorizationIdOf = generate_authorization_id::<Test>(&auth_id_digest);
+
+	let blob: RegistryEntryBlobOf<Test> = BoundedVec::try_from(
+		json!({ "lab": "Bengaluru" }).to_string().into_bytes(),
+	)
+	.expect("Test Blob should fit into the expected input length of BLOB for the test runtime.");
+	let new_blob: RegistryEntryBlobOf<Test> = BoundedVec::try_from(
+		json!({ "lab": "Mysuru" }).to_string().into_bytes(),
+	)
+	.expect("Test Blob should fit into the expected input length of BLOB for the test runtime.");
+	let digest = <Test as frame_system::Config>::Hashing::hash(&blob[..]);
+	let new_digest = <Test as frame_system::Config>::Hashing::hash(&new_blob[..]);
+	let registry_entry_id: RegistryEntryIdOf =
+		generate_registry_entry_id::<Test>(&<Test as frame_system::Config>::Hashing::hash(
+			&[&digest.encode()[..], &registry_id.encode()[..], &creator.encode()[..]].concat()[..],
+		));
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Registries::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			registry_id.clone(),
+			registry_digest,
+			None,
+			None,
+		));
+
+		/* The blob must hash to the digest of the entry */
+		assert_err!(
+			Entries::create(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				registry_entry_id.clone(),
+				authorization_id.clone(),
+				digest,
+				Some(new_blob.clone()),
+				None,
+			),
+			Error::<Test>::BlobDigestMismatch
+		);
+
+		assert_ok!(Entries::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			registry_entry_id.clone(),
+			authorization_id.clone(),
+			digest,
+			Some(blob.clone()),
+			None,
+		));
+		assert_eq!(Entries::blob(&digest), Some(blob.to_vec()));
+		assert_eq!(RegistryEntryBlobDigests::<Test>::get(&registry_entry_id), Some(digest));
+
+		/* Replacing the blob removes the one no longer referenced */
+		assert_ok!(Entries::update(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			registry_entry_id.clone(),
+			authorization_id.clone(),
+			new_digest,
+			Some(new_blob.clone()),
+		));
+		assert!(Entries::blob(&digest).is_none());
+		assert_eq!(Entries::blob(&new_digest), Some(new_blob.to_vec()));
+
+		/* Changing the digest without a new blob drops the blob that no longer matches */
+		let other_digest = <Test as frame_system::Config>::Hashing::hash(&[3u8; 256][..]);
+		assert_ok!(Entries::update(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			registry_entry_id.clone(),
+			authorization_id,
+			other_digest,
+			None,
+		));
+		assert!(RegistryEntryBlobDigests::<Test>::get(&registry_entry_id).is_none());
+		assert!(Entries::blob(&new_digest).is_none());
+	});
+}
+
+#[test]
+fn creating_a_registry_entry_with_an_invalid_identifier_should_fail() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let registry = [2u8; 256].to_vec();
+	let registry_digest = <Test as frame_system::Config>::Hashing::hash(&registry.encode()[..]);
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let registry_id: RegistryIdOf = generate_registry_id::<Test>(&id_digest);
+	let auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: AuthorizationIdOf = generate_authorization_id::<Test>(&auth_id_digest);
+
+	let digest = <Test as frame_system::Config>::Hashing::hash(&[3u8; 256].encode()[..]);
+	let registry_entry_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&digest.encode()[..], &registry_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let registry_entry_id: RegistryEntryIdOf =
+		generate_registry_entry_id::<Test>(&registry_entry_id_digest);
+
+	/* An identifier of another type, and one derived from another digest */
+	let other_digest = <Test as frame_system::Config>::Hashing::hash(&[4u8; 256].encode()[..]);
+	let other_registry_entry_id: RegistryEntryIdOf =
+		generate_registry_entry_id::<Test>(&<Test as frame_system::Config>::Hashing::hash(
+			&[&other_digest.encode()[..], &registry_id.encode()[..], &creator.encode()[..]]
+				.concat()[..],
+		));
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Registries::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			registry_id.clone(),
+			registry_digest,
+			None,
+			None,
+		));
+
+		assert_err!(
+			Entries::create(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				registry_id.clone(),
+				authorization_id.clone(),
+				digest,
+				None,
+				None,
+			),
+			Error::<Test>::InvalidRegistryEntryIdentifier
+		);
+
+		assert_err!(
+			Entries::create(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				other_registry_entry_id,
+				authorization_id.clone(),
+				digest,
+				None,
+				None,
+			),
+			Error::<Test>::RegistryEntryIdentifierMismatch
+		);
+
+		assert_ok!(Entries::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			registry_entry_id.clone(),
+			authorization_id,
+			digest,
+			None,
+			None,
+		));
+		assert!(RegistryEntries::<Test>::contains_key(&registry_entry_id));
+	});
+}
+
+#[test]
+fn batch_creation_and_revocation_should_report_failed_entries() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let registry = [2u8; 256].to_vec();
+	let registry_digest = <Test as frame_system::Config>::Hashing::hash(&registry.encode()[..]);
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let registry_id: RegistryIdOf = generate_registry_id::<Test>(&id_digest);
+	let auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: AuthorizationIdOf = generate_authorization_id::<Test>(&auth_id_digest);
+
+	let entries: Vec<(RegistryEntryIdOf, RegistryEntryHashOf<Test>)> = (0u8..3)
+		.map(|i| {
+			let digest = <Test as frame_system::Config>::Hashing::hash(&[i; 256].encode()[..]);
+			let registry_entry_id: RegistryEntryIdOf =
+				generate_registry_entry_id::<Test>(&<Test as frame_system::Config>::Hashing::hash(
+					&[&digest.encode()[..], &registry_id.encode()[..], &creator.encode()[..]]
+						.concat()[..],
+				));
+			(registry_entry_id, digest)
+		})
+		.collect();
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Registries::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			registry_id.clone(),
+			registry_digest,
+			None,
+			None,
+		));
+
+		/* A batch larger than `MaxRegistryEntriesPerBatch` is rejected */
+		assert_err!(
+			Entries::create_batch(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				vec![entries[0].clone(); 6],
+				authorization_id.clone(),
+				None,
+			),
+			Error::<Test>::MaxRegistryEntriesPerBatchExceeded
+		);
+
+		assert_ok!(Entries::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			entries[0].0.clone(),
+			authorization_id.clone(),
+			entries[0].1,
+			None,
+			None,
+		));
+
+		/* A batch of entries which all fail is rejected */
+		assert_err!(
+			Entries::create_batch(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				vec![entries[0].clone()],
+				authorization_id.clone(),
+				None,
+			),
+			Error::<Test>::BulkTransactionFailed
+		);
+
+		/* An existing entry and a mismatching identifier fail, the rest are created */
+		assert_ok!(Entries::create_batch(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			vec![
+				entries[0].clone(),
+				entries[1].clone(),
+				(entries[1].0.clone(), entries[2].1),
+				entries[2].clone(),
+			],
+			authorization_id.clone(),
+			None,
+		));
+		System::assert_last_event(
+			Event::RegistryEntryBatchCreated {
+				creator: creator.clone(),
+				registry_id: registry_id.clone(),
+				successful: 2,
+				failed: 2,
+				indices: vec![0, 2],
+			}
+			.into(),
+		);
+		assert!(RegistryEntries::<Test>::contains_key(&entries[1].0));
+		assert!(RegistryEntries::<Test>::contains_key(&entries[2].0));
+
+		/* An unknown entry fails, the rest are revoked */
+		assert_ok!(Entries::revoke_batch(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			vec![registry_id.clone(), entries[1].0.clone(), entries[2].0.clone()],
+			authorization_id.clone(),
+		));
+		System::assert_last_event(
+			Event::RegistryEntryBatchRevoked {
+				updater: creator.clone(),
+				registry_id: registry_id.clone(),
+				successful: 2,
+				failed: 1,
+				indices: vec![0],
+			}
+			.into(),
+		);
+		assert!(entries[1..].iter().all(|(registry_entry_id, _)| {
+			RegistryEntries::<Test>::get(registry_entry_id).map_or(false, |entry| entry.revoked)
+		}));
+	});
+}
+
+#[test]
+fn batches_should_count_every_entry_against_the_delegation_quota() {
+	let creator = DID_00;
+	let delegate = DID_01;
+	let author = ACCOUNT_00;
+	let registry = [2u8; 256].to_vec();
+	let registry_digest = <Test as frame_system::Config>::Hashing::hash(&registry.encode()[..]);
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let registry_id: RegistryIdOf = generate_registry_id::<Test>(&id_digest);
+	let auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: AuthorizationIdOf = generate_authorization_id::<Test>(&auth_id_digest);
+	let delegate_auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_id.encode()[..], &delegate.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let delegate_authorization_id: AuthorizationIdOf =
+		generate_authorization_id::<Test>(&delegate_auth_id_digest);
+
+	let entries: Vec<(RegistryEntryIdOf, RegistryEntryHashOf<Test>)> = (0u8..3)
+		.map(|i| {
+			let digest = <Test as frame_system::Config>::Hashing::hash(&[i; 256].encode()[..]);
+			let registry_entry_id: RegistryEntryIdOf =
+				generate_registry_entry_id::<Test>(&<Test as frame_system::Config>::Hashing::hash(
+					&[&digest.encode()[..], &registry_id.encode()[..], &delegate.encode()[..]]
+						.concat()[..],
+				));
+			(registry_entry_id, digest)
+		})
+		.collect();
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Registries::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			registry_id.clone(),
+			registry_digest,
+			None,
+			None,
+		));
+		assert_ok!(Registries::add_delegate(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			registry_id.clone(),
+			delegate.clone(),
+			authorization_id,
+			None,
+			Some(3),
+		));
+
+		/* A failed entry does not use up the quota, the entry beyond it fails */
+		assert_ok!(Entries::create_batch(
+			DoubleOrigin(author.clone(), delegate.clone()).into(),
+			vec![entries[0].clone(), entries[0].clone(), entries[1].clone()],
+			delegate_authorization_id.clone(),
+			None,
+		));
+		System::assert_last_event(
+			Event::RegistryEntryBatchCreated {
+				creator: delegate.clone(),
+				registry_id: registry_id.clone(),
+				successful: 2,
+				failed: 1,
+				indices: vec![1],
+			}
+			.into(),
+		);
+
+		assert_ok!(Entries::revoke_batch(
+			DoubleOrigin(author.clone(), delegate.clone()).into(),
+			vec![entries[0].0.clone(), entries[1].0.clone()],
+			delegate_authorization_id.clone(),
+		));
+		System::assert_last_event(
+			Event::RegistryEntryBatchRevoked {
+				updater: delegate.clone(),
+				registry_id: registry_id.clone(),
+				successful: 1,
+				failed: 1,
+				indices: vec![1],
+			}
+			.into(),
+		);
+		assert!(RegistryEntries::<Test>::get(&entries[0].0).map_or(false, |entry| entry.revoked));
+		assert!(RegistryEntries::<Test>::get(&entries[1].0).map_or(false, |entry| !entry.revoked));
+
+		/* The exhausted delegation is refused before the batch is processed */
+		assert_err!(
+			Entries::create_batch(
+				DoubleOrigin(author.clone(), delegate.clone()).into(),
+				vec![entries[2].clone()],
+				delegate_authorization_id,
+				None,
+			),
+			pallet_registries::Error::<Test>::AuthorizationQuotaExhausted
+		);
+	});
+}
+
+#[test]
+fn registry_entry_state_should_be_answered_at_past_blocks() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let registry = [2u8; 256].to_vec();
+	let registry_digest = <Test as frame_system::Config>::Hashing::hash(&registry.encode()[..]);
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let registry_id: RegistryIdOf = generate_registry_id::<Test>(&id_digest);
+	let auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: AuthorizationIdOf = generate_authorization_id::<Test>(&auth_id_digest);
+
+	let digest = <Test as frame_system::Config>::Hashing::hash(&[3u8; 256].encode()[..]);
+	let new_digest = <Test as frame_system::Config>::Hashing::hash(&[4u8; 256].encode()[..]);
+	let registry_entry_id: RegistryEntryIdOf =
+		generate_registry_entry_id::<Test>(&<Test as frame_system::Config>::Hashing::hash(
+			&[&digest.encode()[..], &registry_id.encode()[..], &creator.encode()[..]].concat()[..],
+		));
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Registries::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			registry_id.clone(),
+			registry_digest,
+			None,
+			None,
+		));
+
+		System::set_block_number(2);
+		assert_ok!(Entries::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			registry_entry_id.clone(),
+			authorization_id.clone(),
+			digest,
+			None,
+			None,
+		));
+
+		System::set_block_number(4);
+		assert_ok!(Entries::update(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			registry_entry_id.clone(),
+			authorization_id.clone(),
+			new_digest,
+			None,
+		));
+
+		System::set_block_number(6);
+		assert_ok!(Entries::revoke(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			registry_entry_id.clone(),
+			authorization_id.clone(),
+		));
+
+		/* The entry did not exist before its creation */
+		assert!(Entries::entry_state_at(&registry_entry_id, 1).is_none());
+
+		let state = Entries::entry_state_at(&registry_entry_id, 3).unwrap();
+		assert_eq!(state.digest, digest);
+		assert!(!state.revoked);
+		assert_eq!(state.timepoint.height, 2);
+		assert_eq!(state.actor, creator);
+
+		let state = Entries::entry_state_at(&registry_entry_id, 5).unwrap();
+		assert_eq!(state.digest, new_digest);
+		assert!(!state.revoked);
+
+		let state = Entries::entry_state_at(&registry_entry_id, 6).unwrap();
+		assert_eq!(state.digest, new_digest);
+		assert!(state.revoked);
+		assert_eq!(state.timepoint.height, 6);
+
+		/* Beyond `MaxRegistryEntryVersions` the oldest versions are dropped */
+		System::set_block_number(8);
+		assert_ok!(Entries::reinstate(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			registry_entry_id.clone(),
+			authorization_id,
+		));
+
+		assert_eq!(RegistryEntryVersions::<Test>::get(&registry_entry_id).len(), 3);
+		assert!(Entries::entry_state_at(&registry_entry_id, 3).is_none());
+		assert_eq!(Entries::entry_state_at(&registry_entry_id, 4).unwrap().digest, new_digest);
+		assert!(!Entries::entry_state_at(&registry_entry_id, 8).unwrap().revoked);
+	});
+}
+
+#[test]
+fn version_history_migration_should_seed_entries_without_versions() {
+	use frame_support::{
+		migrations::SteppedMigration,
+		traits::{GetStorageVersion, StorageVersion},
+		weights::WeightMeter,
+	};
+	use migrations::v2::MigrateToVersionHistory;
+
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let registry = [2u8; 256].to_vec();
+	let registry_digest = <Test as frame_system::Config>::Hashing::hash(&registry.encode()[..]);
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let registry_id: RegistryIdOf = generate_registry_id::<Test>(&id_digest);
+	let auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: AuthorizationIdOf = generate_authorization_id::<Test>(&auth_id_digest);
+
+	let entries: Vec<(RegistryEntryIdOf, RegistryEntryHashOf<Test>)> = (3u8..5)
+		.map(|i| {
+			let digest = <Test as frame_system::Config>::Hashing::hash(&[i; 256].encode()[..]);
+			let registry_entry_id: RegistryEntryIdOf =
+				generate_registry_entry_id::<Test>(&<Test as frame_system::Config>::Hashing::hash(
+					&[&digest.encode()[..], &registry_id.encode()[..], &creator.encode()[..]]
+						.concat()[..],
+				));
+			(registry_entry_id, digest)
+		})
+		.collect();
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Registries::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			registry_id.clone(),
+			registry_digest,
+			None,
+			None,
+		));
+
+		System::set_block_number(2);
+		for (registry_entry_id, digest) in &entries {
+			assert_ok!(Entries::create(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				registry_entry_id.clone(),
+				authorization_id.clone(),
+				*digest,
+				None,
+				None,
+			));
+		}
+
+		System::set_block_number(4);
+		assert_ok!(Entries::revoke(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			entries[1].0.clone(),
+			authorization_id,
+		));
+
+		// Rewind to the version 1 layout, where no versions are recorded.
+		let _ = RegistryEntryVersions::<Test>::clear(u32::MAX, None);
+		StorageVersion::new(1).put::<Entries>();
+		assert!(Entries::entry_state_at(&entries[0].0, 4).is_none());
+
+		System::set_block_number(10);
+		let mut cursor = None;
+		loop {
+			let mut meter = WeightMeter::new();
+			cursor = MigrateToVersionHistory::<Test>::step(cursor, &mut meter)
+				.expect("a step should have enough weight");
+			if cursor.is_none() {
+				break;
+			}
+		}
+		assert_eq!(Entries::on_chain_storage_version(), 2);
+
+		/* Each entry is seeded with its state since its last change */
+		let state = Entries::entry_state_at(&entries[0].0, 2).unwrap();
+		assert_eq!(state.digest, entries[0].1);
+		assert!(!state.revoked);
+		assert_eq!(state.timepoint.height, 2);
+		assert_eq!(state.actor, creator);
+
+		assert!(Entries::entry_state_at(&entries[1].0, 3).is_none());
+		let state = Entries::entry_state_at(&entries[1].0, 4).unwrap();
+		assert!(state.revoked);
+		assert_eq!(state.timepoint.height, 4);
+
+		/* Running the migration again leaves the seeded versions untouched */
+		assert!(matches!(
+			MigrateToVersionHistory::<Test>::step(None, &mut WeightMeter::new()),
+			Ok(None)
+		));
+		assert_eq!(RegistryEntryVersions::<Test>::get(&entries[1].0).len(), 1);
+	});
+}
+
+#[test]
+fn registry_entries_should_conform_to_the_schema_of_the_registry() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let registry = [2u8; 256].to_vec();
+	let registry_digest = <Test as frame_system::Config>::Hashing::hash(&registry.encode()[..]);
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let registry_id: RegistryIdOf = generate_registry_id::<Test>(&id_digest);
+	let auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: AuthorizationIdOf = generate_authorization_id::<Test>(&auth_id_digest);
+
+	let schema: InputSchemaOf<Test> = BoundedVec::try_from([2u8; 256].to_vec())
+		.expect("Test Schema should fit into the expected input length of for the test runtime.");
+	let schema_id: SchemaIdOf = generate_schema_id::<Test>(
+		&<Test as frame_system::Config>::Hashing::hash(&schema.encode()[..]),
+	);
+	let new_schema: InputSchemaOf<Test> = BoundedVec::try_from([3u8; 256].to_vec())
+		.expect("Test Schema should fit into the expected input length of for the test runtime.");
+	let new_schema_id: SchemaIdOf = generate_schema_id::<Test>(
+		&<Test as frame_system::Config>::Hashing::hash(&new_schema.encode()[..]),
+	);
+
+	let entry_id_of = |digest: &RegistryEntryHashOf<Test>| -> RegistryEntryIdOf {
+		generate_registry_entry_id::<Test>(&<Test as frame_system::Config>::Hashing::hash(
+			&[&digest.encode()[..], &registry_id.encode()[..], &creator.encode()[..]].concat()[..],
+		))
+	};
+	let digest = <Test as frame_system::Config>::Hashing::hash(&[3u8; 256].encode()[..]);
+	let new_digest = <Test as frame_system::Config>::Hashing::hash(&[4u8; 256].encode()[..]);
+	let registry_entry_id = entry_id_of(&digest);
+	let new_registry_entry_id = entry_id_of(&new_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Registries::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			registry_id.clone(),
+			registry_digest,
+			None,
+			None,
+		));
+		assert_ok!(SchemaAccounts::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			schema
+		));
+		assert_ok!(SchemaAccounts::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			new_schema
+		));
+		assert_ok!(Registries::update_schema(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			registry_id.clone(),
+			schema_id.clone(),
+			true,
+			authorization_id.clone(),
+		));
+
+		/* Entries of the registry must declare conformance to its schema */
+		assert_err!(
+			Entries::create(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				registry_entry_id.clone(),
+				authorization_id.clone(),
+				digest,
+				None,
+				None,
+			),
+			Error::<Test>::SchemaDeclarationRequired
+		);
+		assert_err!(
+			Entries::create(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				registry_entry_id.clone(),
+				authorization_id.clone(),
+				digest,
+				None,
+				Some(new_schema_id.clone()),
+			),
+			Error::<Test>::SchemaMismatch
+		);
+		assert_err!(
+			Entries::create_batch(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				vec![(registry_entry_id.clone(), digest)],
+				authorization_id.clone(),
+				None,
+			),
+			Error::<Test>::SchemaDeclarationRequired
+		);
+
+		assert_ok!(Entries::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			registry_entry_id.clone(),
+			authorization_id.clone(),
+			digest,
+			None,
+			Some(schema_id.clone()),
+		));
+		assert_eq!(Entries::pinned_schema(&registry_entry_id), Some(schema_id.clone()));
+
+		/* Migrating the registry pins existing entries to the previous schema */
+		assert_ok!(Registries::update_schema(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			registry_id.clone(),
+			new_schema_id.clone(),
+			true,
+			authorization_id.clone(),
+		));
+		assert_eq!(Entries::pinned_schema(&registry_entry_id), Some(schema_id.clone()));
+
+		assert_err!(
+			Entries::create(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				new_registry_entry_id.clone(),
+				authorization_id.clone(),
+				new_digest,
+				None,
+				Some(schema_id),
+			),
+			Error::<Test>::SchemaMismatch
+		);
+		assert_ok!(Entries::create_batch(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			vec![(new_registry_entry_id.clone(), new_digest)],
+			authorization_id,
+			Some(new_schema_id.clone()),
+		));
+		assert_eq!(Entries::pinned_schema(&new_registry_entry_id), Some(new_schema_id));
+	});
+}
+
+#[test]
+fn registry_entries_should_be_pinned_to_the_schema_of_the_registry_at_creation() {
+	use frame_support::{
+		migrations::SteppedMigration,
+		traits::{GetStorageVersion, StorageVersion},
+		weights::WeightMeter,
+	};
+	use migrations::v3::MigrateToPinnedSchemas;
+
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let registry = [2u8; 256].to_vec();
+	let registry_digest = <Test as frame_system::Config>::Hashing::hash(&registry.encode()[..]);
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let registry_id: RegistryIdOf = generate_registry_id::<Test>(&id_digest);
+	let auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: AuthorizationIdOf = generate_authorization_id::<Test>(&auth_id_digest);
+
+	let schema: InputSchemaOf<Test> = BoundedVec::try_from([2u8; 256].to_vec())
+		.expect("Test Schema should fit into the expected input length of for the test runtime.");
+	let schema_id: SchemaIdOf = generate_schema_id::<Test>(
+		&<Test as frame_system::Config>::Hashing::hash(&schema.encode()[..]),
+	);
+
+	let entry_id_of = |digest: &RegistryEntryHashOf<Test>| -> RegistryEntryIdOf {
+		generate_registry_entry_id::<Test>(&<Test as frame_system::Config>::Hashing::hash(
+			&[&digest.encode()[..], &registry_id.encode()[..], &creator.encode()[..]].concat()[..],
+		))
+	};
+	let digest = <Test as frame_system::Config>::Hashing::hash(&[3u8; 256].encode()[..]);
+	let new_digest = <Test as frame_system::Config>::Hashing::hash(&[4u8; 256].encode()[..]);
+	let registry_entry_id = entry_id_of(&digest);
+	let new_registry_entry_id = entry_id_of(&new_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Registries::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			registry_id.clone(),
+			registry_digest,
+			None,
+			None,
+		));
+
+		/* Entries of a registry without a schema are not pinned */
+		assert_ok!(Entries::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			registry_entry_id.clone(),
+			authorization_id.clone(),
+			digest,
+			None,
+			None,
+		));
+		assert!(Entries::pinned_schema(&registry_entry_id).is_none());
+
+		/* Entries which do not declare the schema are pinned to it as well */
+		assert_ok!(SchemaAccounts::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			schema
+		));
+		assert_ok!(Registries::update_schema(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			registry_id.clone(),
+			schema_id.clone(),
+			false,
+			authorization_id.clone(),
+		));
+		assert_ok!(Entries::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			new_registry_entry_id.clone(),
+			authorization_id,
+			new_digest,
+			None,
+			None,
+		));
+		assert_eq!(Entries::pinned_schema(&new_registry_entry_id), Some(schema_id.clone()));
+
+		// Rewind to the version 2 layout, where undeclared schemas were not recorded.
+		RegistryEntrySchemas::<Test>::remove(&new_registry_entry_id);
+		StorageVersion::new(2).put::<Entries>();
+
+		let mut cursor = None;
+		loop {
+			let mut meter = WeightMeter::new();
+			cursor = MigrateToPinnedSchemas::<Test>::step(cursor, &mut meter)
+				.expect("a step should have enough weight");
+			if cursor.is_none() {
+				break;
+			}
+		}
+		assert_eq!(Entries::on_chain_storage_version(), 3);
+
+		/* Existing entries are pinned to the current schema of their registry */
+		assert_eq!(Entries::pinned_schema(&registry_entry_id), Some(schema_id.clone()));
+		assert_eq!(Entries::pinned_schema(&new_registry_entry_id), Some(schema_id));
+	});
+}
@@ -21,6 +21,7 @@
 //! Entries pallet types.
 
 use codec::{Decode, Encode, MaxEncodedLen};
+use identifier::types::Timepoint;
 use scale_info::TypeInfo;
 use sp_runtime::RuntimeDebug;
 use sp_std::prelude::*;
@@ -36,3 +37,16 @@ pub struct RegistryEntryDetails<RegistryEntryHashOf, StatusOf, CreatorOf, Regist
 	/// Type of Reistry Entry Identifier.
 	pub registry_id: RegistryIdOf,
 }
+
+/// A version of a Registry Entry, recording its state after a change.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct RegistryEntryVersion<RegistryEntryHashOf, StatusOf, CreatorOf> {
+	/// Type of Registry Entry Digest.
+	pub digest: RegistryEntryHashOf,
+	/// Type of Registry Entry Revoked state.
+	pub revoked: StatusOf,
+	/// The point in time at which the version was recorded.
+	pub timepoint: Timepoint,
+	/// The creator (DID) which made the change.
+	pub actor: CreatorOf,
+}
@@ -21,14 +21,18 @@
 use super::*;
 use codec::Encode;
 use cord_utilities::traits::GenerateBenchmarkOrigin;
-use frame_benchmarking::{account, benchmarks};
-use frame_support::{sp_runtime::traits::Hash, BoundedVec};
+use frame_benchmarking::{account, benchmarks, BenchmarkError};
+use frame_support::{
+	sp_runtime::traits::Hash,
+	traits::{EnsureOrigin, Get},
+	BoundedVec,
+};
 use frame_system::RawOrigin;
 use identifier::{IdentifierType, Ss58Identifier};
 use pallet_chain_space::SpaceCodeOf;
+use sp_std::{vec, vec::Vec};
 
 const SEED: u32 = 0;
-const MAX_PAYLOAD_BYTE_LENGTH: u32 = 15 * 1024;
 
 pub fn generate_space_id<T: Config>(other_digest: &SpaceCodeOf<T>) -> SpaceIdOf {
 	Ss58Identifier::create_identifier(&(other_digest).encode()[..], IdentifierType::Space).unwrap()
@@ -47,26 +51,52 @@ fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
 	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 }
 
+/// Returns an identifier of `length` bytes, all set to `byte`.
+fn generate_value<T: Config>(byte: u8, length: u32) -> MessageIdentifierOf<T> {
+	BoundedVec::try_from(vec![byte; length as usize]).unwrap()
+}
+
+/// Moves the clock to the last epoch of the retention window and fills the
+/// window of `entity` with one bucket per retained epoch, so that a rating
+/// has to scan and rewrite the largest `EpochScores` value.
+fn fill_epoch_window<T: Config>(entity: &EntityIdentifierOf<T>) {
+	let retained = <T as Config>::MaxRetainedEpochs::get();
+	let now = <T as Config>::EpochDuration::get().saturating_mul(retained.saturating_sub(1).into());
+	pallet_timestamp::Now::<T>::put(now);
+
+	let buckets: Vec<EpochAggregate> = (0..retained as u64)
+		.map(|epoch| EpochAggregate {
+			epoch,
+			aggregate: AggregatedEntryOf { count_of_txn: 1, total_encoded_rating: 1 },
+		})
+		.collect();
+	<EpochScores<T>>::insert(
+		entity,
+		RatingTypeOf::OVERALL,
+		BoundedVec::<_, <T as Config>::MaxRetainedEpochs>::try_from(buckets).unwrap(),
+	);
+}
+
 benchmarks! {
 	where_clause {
 		where
 		<T as pallet::Config>::EnsureOrigin: GenerateBenchmarkOrigin<T::RuntimeOrigin, T::AccountId, T::SpaceCreatorId>,
 	}
 	register_rating {
-		let l in 1 .. MAX_PAYLOAD_BYTE_LENGTH;
+		let l in 1 .. <T as Config>::MaxEncodedValueLength::get();
 
 		let caller: T::AccountId = account("caller", 0, SEED);
 		let did1: T::SpaceCreatorId = account("did1", 0, SEED);
 
-		let message_id = BoundedVec::try_from([72u8; 10].to_vec()).unwrap();
-		let entity_id = BoundedVec::try_from([73u8; 10].to_vec()).unwrap();
+		let message_id = generate_value::<T>(72, l);
+		let entity_id = generate_value::<T>(73, <T as Config>::MaxEncodedValueLength::get());
 		let provider_id = BoundedVec::try_from([74u8; 10].to_vec()).unwrap();
 		let entry = RatingInputEntryOf::<T> {
 			entity_id: entity_id.clone(),
 			provider_id,
 			total_encoded_rating: 250u64,
 			count_of_txn: 7u64,
-			rating_type: RatingTypeOf::Overall,
+			rating_type: RatingTypeOf::OVERALL,
 			provider_did: did1.clone(),
 		};
 
@@ -94,6 +124,7 @@ benchmarks! {
 
 		pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
 		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, 3u64 ).expect("Approval should not fail.");
+		fill_epoch_window::<T>(&entity_id);
 
 	}: _<T::RuntimeOrigin>(origin, entry, entry_digest, message_id, authorization_id)
 	verify {
@@ -101,7 +132,7 @@ benchmarks! {
 	}
 
 	revoke_rating {
-		let l in 1 .. MAX_PAYLOAD_BYTE_LENGTH;
+		let l in 1 .. <T as Config>::MaxEncodedValueLength::get();
 
 		let caller: T::AccountId = account("caller", 0, SEED);
 		let did1: T::SpaceCreatorId = account("did1", 0, SEED);
@@ -112,16 +143,16 @@ benchmarks! {
 			&[&space_digest.encode()[..], &did1.encode()[..]].concat()[..],
 		);
 		let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
-		let message_id_add = BoundedVec::try_from([82u8; 10].to_vec()).unwrap();
-		let message_id_revoke = BoundedVec::try_from([85u8; 10].to_vec()).unwrap();
-		let entity_id = BoundedVec::try_from([83u8; 10].to_vec()).unwrap();
+		let message_id_add = generate_value::<T>(82, l);
+		let message_id_revoke = generate_value::<T>(85, l);
+		let entity_id = generate_value::<T>(83, <T as Config>::MaxEncodedValueLength::get());
 		let provider_id = BoundedVec::try_from([84u8; 10].to_vec()).unwrap();
 		let entry = RatingInputEntryOf::<T> {
 			entity_id: entity_id.clone(),
 			provider_id,
 			total_encoded_rating: 250u64,
 			count_of_txn: 7u64,
-			rating_type: RatingTypeOf::Overall,
+			rating_type: RatingTypeOf::OVERALL,
 			provider_did: did1.clone(),
 		};
 		let entry_digest = <T as frame_system::Config>::Hashing::hash(
@@ -149,6 +180,7 @@ benchmarks! {
 
 		pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
 		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, 3u64 ).expect("Approval should not fail.");
+		fill_epoch_window::<T>(&entity_id);
 
 		let _ = Pallet::<T>::register_rating(origin.clone(), entry, entry_digest, message_id_add, authorization_id.clone());
 	}: _<T::RuntimeOrigin>(origin, identifier_add, message_id_revoke, entry_digest, authorization_id)
@@ -157,7 +189,7 @@ benchmarks! {
 	}
 
 	revise_rating {
-		let l in 1 .. MAX_PAYLOAD_BYTE_LENGTH;
+		let l in 1 .. <T as Config>::MaxEncodedValueLength::get();
 
 		let caller: T::AccountId = account("caller", 0, SEED);
 		let did: T::SpaceCreatorId = account("did", 0, SEED);
@@ -169,17 +201,17 @@ benchmarks! {
 		);
 		let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
 
-		let message_id_add = BoundedVec::try_from([82u8; 10].to_vec()).unwrap();
-		let message_id_revoke = BoundedVec::try_from([85u8; 10].to_vec()).unwrap();
-		let message_id_revise = BoundedVec::try_from([86u8; 10].to_vec()).unwrap();
-		let entity_id = BoundedVec::try_from([83u8; 10].to_vec()).unwrap();
+		let message_id_add = generate_value::<T>(82, l);
+		let message_id_revoke = generate_value::<T>(85, l);
+		let message_id_revise = generate_value::<T>(86, l);
+		let entity_id = generate_value::<T>(83, <T as Config>::MaxEncodedValueLength::get());
 		let provider_id = BoundedVec::try_from([84u8; 10].to_vec()).unwrap();
 		let entry = RatingInputEntryOf::<T> {
 			entity_id: entity_id.clone(),
 			provider_id: provider_id.clone(),
 			total_encoded_rating: 250u64,
 			count_of_txn: 7u64,
-			rating_type: RatingTypeOf::Overall,
+			rating_type: RatingTypeOf::OVERALL,
 			provider_did: did.clone(),
 		};
 
@@ -192,7 +224,7 @@ benchmarks! {
 			provider_id,
 			total_encoded_rating: 250u64,
 			count_of_txn: 6u64,
-			rating_type: RatingTypeOf::Overall,
+			rating_type: RatingTypeOf::OVERALL,
 			provider_did: did.clone(),
 		};
 		let entry_revise_digest = <T as frame_system::Config>::Hashing::hash(
@@ -224,6 +256,7 @@ benchmarks! {
 
 		pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
 		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, 10u64 ).expect("Approval should not fail.");
+		fill_epoch_window::<T>(&entity_id);
 
 		let _ = Pallet::<T>::register_rating(origin.clone(), entry, entry_digest, message_id_add, authorization_id.clone());
 		let _ = Pallet::<T>::revoke_rating(origin.clone(), identifier_add, message_id_revoke, entry_digest, authorization_id.clone());
@@ -232,5 +265,43 @@ benchmarks! {
 		assert_last_event::<T>(Event::RatingEntryRevised { identifier: identifier_revise, entity: entity_id, provider: did, creator: caller}.into());
 	}
 
+	register_rating_category {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let did: T::SpaceCreatorId = account("did", 0, SEED);
+		let name = generate_value::<T>(67, <T as Config>::MaxEncodedValueLength::get());
+
+		let raw_space = [2u8; 256].to_vec();
+		let space_digest = <T as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+		let space_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
+		let auth_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let authorization_id: AuthorizationIdOf = generate_authorization_id::<T>(&auth_digest);
+		let category = RatingTypeOf(NextRatingCategory::<T>::get());
+
+		let origin =  <T as pallet::Config>::EnsureOrigin::generate_origin(caller, did);
+		let chain_space_origin = RawOrigin::Root.into();
+
+		pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
+		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id.clone(), 3u64 ).expect("Approval should not fail.");
+	}: _<T::RuntimeOrigin>(origin, name, <T as Config>::MaxRatingValue::get(), Permill::one(), authorization_id)
+	verify {
+		assert_last_event::<T>(Event::RatingCategoryRegistered { category, space: Some(space_id) }.into());
+	}
+
+	register_network_rating_category {
+		let name = generate_value::<T>(67, <T as Config>::MaxEncodedValueLength::get());
+		let category = RatingTypeOf(NextRatingCategory::<T>::get());
+
+		let origin = T::RatingCategoryOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, name, <T as Config>::MaxRatingValue::get(), Permill::one())
+	verify {
+		assert_last_event::<T>(Event::RatingCategoryRegistered { category, space: None }.into());
+	}
+
 	impl_benchmark_test_suite! (Pallet, crate::mock::new_test_ext(), crate::mock::Test)
 }
@@ -27,6 +27,7 @@
 //! ## Overview
 //!
 //! The Score Pallet provides functionality to:
+//! - Register rating categories for the network or for a chain space.
 //! - Register new rating entries.
 //! - Amend or revise existing ratings.
 //! - Aggregate scores based on credit/debit entries.
@@ -54,6 +55,30 @@
 //!
 //! - `RatingEntries`: Stores all the rating entries, indexed by a unique identifier.
 //! - `AggregateScores`: Keeps track of the aggregate scores for each entity and rating type.
+//! - `EpochScores`: Keeps the aggregate scores of the most recent epochs for each entity and
+//!   rating type.
+//! - `RatingCategories`: Stores the registered rating categories, indexed by their identifier.
+//!
+//! ### Rating Categories
+//!
+//! Every rating belongs to a rating category, such as the overall or the
+//! delivery experience. Network-wide categories are registered through
+//! `RatingCategoryOrigin` and can be used in any space, while a space admin
+//! can register categories that are only available within that space. A
+//! category has a name, a scale, which is the highest rating value a single
+//! transaction can carry, and a weight used when the scores of several
+//! categories are combined through `combined_score`. The `OVERALL` and
+//! `DELIVERY` categories are registered at genesis.
+//!
+//! ### Rolling Aggregates
+//!
+//! Besides the all-time aggregate, ratings are bucketed into epochs of
+//! `EpochDuration` based on the time they were recorded. The buckets of the
+//! last `MaxRetainedEpochs` epochs are kept, so that a score can be computed
+//! over a recent window such as the last 30 days or the last 12 months.
+//! Decay-weighted scores scale each epoch down by `EpochDecay` for every
+//! epoch of age, so older ratings count less than recent ones. Revoking a
+//! rating removes it from the epoch in which it was recorded.
 //!
 //! ### Events
 //!
@@ -86,6 +111,8 @@
 //!
 //! ### Public Functions
 //!
+//! - `register_rating_category`: Registers a rating category for a chain space.
+//! - `register_network_rating_category`: Registers a network-wide rating category.
 //! - `register_rating`: Registers a new rating entry.
 //! - `amend_rating`: Amends an existing rating entry.
 //! - `revise_rating`: Revises a rating entry, creating a new linked entry.
@@ -110,6 +137,7 @@ pub mod tests;
 
 pub mod weights;
 
+pub mod migrations;
 pub mod types;
 
 pub use crate::{pallet::*, types::*, weights::WeightInfo};
@@ -118,7 +146,10 @@ use identifier::{
 	types::{CallTypeOf, IdentifierTypeOf, Timepoint},
 	EventEntryOf,
 };
-use sp_runtime::traits::UniqueSaturatedInto;
+use sp_runtime::{
+	traits::{CheckedDiv, Saturating, UniqueSaturatedInto, Zero},
+	PerThing, Permill,
+};
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -131,6 +162,9 @@ pub mod pallet {
 	use sp_runtime::traits::Hash;
 	use sp_std::{prelude::Clone, str};
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	/// SS58 Chain Space Identifier
 	pub type SpaceIdOf = Ss58Identifier;
 
@@ -170,6 +204,11 @@ pub mod pallet {
 	pub type RatingInputEntryOf<T> =
 		RatingInputEntry<EntityIdentifierOf<T>, RatingProviderIdOf<T>, RatingTypeOf>;
 
+	/// Name of a rating category.
+	pub type RatingCategoryNameOf<T> = BoundedVec<u8, <T as Config>::MaxEncodedValueLength>;
+
+	pub type RatingCategoryOf<T> = RatingCategory<SpaceIdOf, RatingCategoryNameOf<T>>;
+
 	pub type RatingEntryOf<T> = RatingEntry<
 		EntityIdentifierOf<T>,
 		RatingProviderIdOf<T>,
@@ -196,6 +235,8 @@ pub mod pallet {
 			Success = <Self as Config>::OriginSuccess,
 		>;
 		type OriginSuccess: CallSources<AccountIdOf<Self>, RatingProviderIdOf<Self>>;
+		/// Origin allowed to register network-wide rating categories
+		type RatingCategoryOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
 
 		type RatingProviderIdOf: Parameter + MaxEncodedLen;
 
@@ -203,11 +244,22 @@ pub mod pallet {
 		type MaxEncodedValueLength: Get<u32>;
 		#[pallet::constant]
 		type MaxRatingValue: Get<u32>;
+		/// Length of an epoch over which ratings are bucketed
+		#[pallet::constant]
+		type EpochDuration: Get<Self::Moment>;
+		/// Number of most recent epochs for which bucketed ratings are kept
+		#[pallet::constant]
+		type MaxRetainedEpochs: Get<u32>;
+		/// Share of its weight an epoch keeps for each epoch of age in
+		/// decay-weighted scores
+		#[pallet::constant]
+		type EpochDecay: Get<Permill>;
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
 
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
@@ -231,6 +283,28 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// aggregated network scores of the most recent epochs, oldest epoch
+	/// first - mapped to an entity identifier and rating type.
+	#[pallet::storage]
+	pub type EpochScores<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		EntityIdentifierOf<T>,
+		Blake2_128Concat,
+		RatingTypeOf,
+		BoundedVec<EpochAggregate, <T as Config>::MaxRetainedEpochs>,
+		ValueQuery,
+	>;
+
+	/// rating categories - mapped to a rating category identifier.
+	#[pallet::storage]
+	pub type RatingCategories<T> =
+		StorageMap<_, Twox64Concat, RatingTypeOf, RatingCategoryOf<T>, OptionQuery>;
+
+	/// identifier of the next rating category to be registered.
+	#[pallet::storage]
+	pub type NextRatingCategory<T> = StorageValue<_, u32, ValueQuery>;
+
 	#[pallet::storage]
 	pub type MessageIdentifiers<T> = StorageDoubleMap<
 		_,
@@ -241,6 +315,19 @@ pub mod pallet {
 		RatingEntryIdOf,
 	>;
 
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		pub _marker: PhantomData<T>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			Pallet::<T>::initialize_rating_categories();
+		}
+	}
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -271,6 +358,9 @@ pub mod pallet {
 		/// Aggregate scores has been updated.
 		/// \[entity identifier\]
 		AggregateScoreUpdated { entity: EntityIdentifierOf<T> },
+		/// A rating category has been registered.
+		/// \[category identifier, space identifier\]
+		RatingCategoryRegistered { category: RatingTypeOf, space: Option<SpaceIdOf> },
 	}
 
 	#[pallet::error]
@@ -297,7 +387,7 @@ pub mod pallet {
 		DigestAlreadyAnchored,
 		/// Rating idenfier already exist
 		RatingIdentifierAlreadyAdded,
-		/// Invalid rating type
+		/// Invalid rating type - unknown or not available in the space
 		InvalidRatingType,
 		/// Rating identifier not found
 		RatingIdentifierNotFound,
@@ -309,6 +399,11 @@ pub mod pallet {
 		EntityMismatch,
 		/// Rating Space mismatch
 		SpaceMismatch,
+		/// Invalid rating scale - should be between 1 and the maximum rating
+		/// value
+		InvalidRatingScale,
+		/// No more rating categories can be registered
+		TooManyRatingCategories,
 	}
 
 	#[pallet::call]
@@ -349,7 +444,7 @@ pub mod pallet {
 		/// register_rating(origin, entry, digest, authorization)?;
 		/// ```
 		#[pallet::call_index(0)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::register_rating(message_id.len() as u32).saturating_add(pallet_chain_space::Pallet::<T>::authorization_weight()))]
 		pub fn register_rating(
 			origin: OriginFor<T>,
 			entry: RatingInputEntryOf<T>,
@@ -366,16 +461,15 @@ pub mod pallet {
 			)
 			.map_err(<pallet_chain_space::Error<T>>::from)?;
 
+			let category = Self::ensure_rating_category(&entry.rating_type, &space_id)?;
+
 			ensure!(
 				entry.total_encoded_rating > 0 &&
 					entry.count_of_txn > 0 &&
-					entry.total_encoded_rating <=
-						entry.count_of_txn * T::MaxRatingValue::get() as u64,
+					entry.total_encoded_rating <= entry.count_of_txn * category.scale as u64,
 				Error::<T>::InvalidRatingValue
 			);
 
-			ensure!(entry.rating_type.is_valid_rating_type(), Error::<T>::InvalidRatingType);
-
 			ensure!(
 				!<MessageIdentifiers<T>>::contains_key(&message_id, &provider),
 				Error::<T>::MessageIdAlreadyExists
@@ -410,6 +504,7 @@ pub mod pallet {
 			);
 
 			Self::aggregate_score(&entry, EntryTypeOf::Credit)?;
+			Self::aggregate_epoch_score(&entry, EntryTypeOf::Credit, Self::current_epoch());
 
 			let entity = entry.entity_id.clone();
 			let created_at = Self::get_current_time();
@@ -480,7 +575,7 @@ pub mod pallet {
 		/// amend_rating(origin, entry_identifier, message_id, digest, authorization)?;
 		/// ```
 		#[pallet::call_index(1)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::revoke_rating(message_id.len() as u32).saturating_add(pallet_chain_space::Pallet::<T>::authorization_weight()))]
 		pub fn revoke_rating(
 			origin: OriginFor<T>,
 			entry_identifier: RatingEntryIdOf,
@@ -537,6 +632,11 @@ pub mod pallet {
 			);
 
 			Self::aggregate_score(&rating_details.entry, EntryTypeOf::Debit)?;
+			Self::aggregate_epoch_score(
+				&rating_details.entry,
+				EntryTypeOf::Debit,
+				Self::epoch_of(rating_details.created_at.clone()),
+			);
 
 			let entity = rating_details.entry.entity_id.clone();
 			let created_at = Self::get_current_time();
@@ -614,7 +714,7 @@ pub mod pallet {
 		/// revise_rating(origin, entry, digest, message_id, amend_ref_id, authorization)?;
 		/// ```
 		#[pallet::call_index(2)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::revise_rating(message_id.len() as u32).saturating_add(pallet_chain_space::Pallet::<T>::authorization_weight()))]
 		pub fn revise_rating(
 			origin: OriginFor<T>,
 			entry: RatingInputEntryOf<T>,
@@ -633,16 +733,15 @@ pub mod pallet {
 			)
 			.map_err(<pallet_chain_space::Error<T>>::from)?;
 
+			let category = Self::ensure_rating_category(&entry.rating_type, &space_id)?;
+
 			ensure!(
 				entry.total_encoded_rating > 0 &&
 					entry.count_of_txn > 0 &&
-					entry.total_encoded_rating <=
-						entry.count_of_txn * T::MaxRatingValue::get() as u64,
+					entry.total_encoded_rating <= entry.count_of_txn * category.scale as u64,
 				Error::<T>::InvalidRatingValue
 			);
 
-			ensure!(entry.rating_type.is_valid_rating_type(), Error::<T>::InvalidRatingType);
-
 			let rating_details = <RatingEntries<T>>::get(&debit_ref_id)
 				.ok_or(Error::<T>::ReferenceIdentifierNotFound)?;
 
@@ -688,6 +787,7 @@ pub mod pallet {
 			);
 
 			Self::aggregate_score(&entry, EntryTypeOf::Credit)?;
+			Self::aggregate_epoch_score(&entry, EntryTypeOf::Credit, Self::current_epoch());
 			let entity = rating_details.entry.entity_id.clone();
 			let reference_id_option = rating_details.reference_id;
 			let created_at = Self::get_current_time();
@@ -722,6 +822,92 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Registers a rating category for a chain space.
+		///
+		/// The category can only be used by ratings registered within the
+		/// space of the authorization. It is assigned the next free rating
+		/// category identifier.
+		///
+		/// # Arguments
+		/// * `origin` - The origin of the call, which must be an admin of the space.
+		/// * `name` - The human readable name of the category.
+		/// * `scale` - The highest rating value a single transaction can carry.
+		/// * `weight` - The weight of the category when scores are combined.
+		/// * `authorization` - An identifier for authorization, validating the origin's admin
+		///   rights over the space.
+		///
+		/// # Errors
+		/// Returns `Error::<T>::InvalidRatingScale` if the scale is zero or
+		/// above `MaxRatingValue`.
+		/// Returns `Error::<T>::TooManyRatingCategories` if no identifier is
+		/// left for the category.
+		///
+		/// # Events
+		/// Emits `RatingCategoryRegistered` when the category is registered.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::register_rating_category().saturating_add(pallet_chain_space::Pallet::<T>::authorization_weight()))]
+		pub fn register_rating_category(
+			origin: OriginFor<T>,
+			name: RatingCategoryNameOf<T>,
+			scale: u32,
+			weight: Permill,
+			authorization: AuthorizationIdOf,
+		) -> DispatchResult {
+			let creator = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_admin_origin(
+				&authorization,
+				&creator,
+			)
+			.map_err(<pallet_chain_space::Error<T>>::from)?;
+
+			Self::insert_rating_category(RatingCategoryOf::<T> {
+				space: Some(space_id),
+				name,
+				scale,
+				weight,
+			})?;
+
+			Ok(())
+		}
+
+		/// Registers a network-wide rating category.
+		///
+		/// Network-wide categories can be used by ratings in every space.
+		///
+		/// # Arguments
+		/// * `origin` - The origin of the call, which must pass `RatingCategoryOrigin`.
+		/// * `name` - The human readable name of the category.
+		/// * `scale` - The highest rating value a single transaction can carry.
+		/// * `weight` - The weight of the category when scores are combined.
+		///
+		/// # Errors
+		/// Returns `Error::<T>::InvalidRatingScale` if the scale is zero or
+		/// above `MaxRatingValue`.
+		/// Returns `Error::<T>::TooManyRatingCategories` if no identifier is
+		/// left for the category.
+		///
+		/// # Events
+		/// Emits `RatingCategoryRegistered` when the category is registered.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::register_network_rating_category())]
+		pub fn register_network_rating_category(
+			origin: OriginFor<T>,
+			name: RatingCategoryNameOf<T>,
+			scale: u32,
+			weight: Permill,
+		) -> DispatchResult {
+			T::RatingCategoryOrigin::ensure_origin(origin)?;
+
+			Self::insert_rating_category(RatingCategoryOf::<T> {
+				space: None,
+				name,
+				scale,
+				weight,
+			})?;
+
+			Ok(())
+		}
 	}
 }
 
@@ -792,6 +978,225 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Updates the epoch bucket of an entity's ratings.
+	///
+	/// Credits are recorded in `epoch`, which is the current epoch for new
+	/// ratings. Debits are applied to the epoch the revoked rating was
+	/// recorded in, and are ignored once that epoch is no longer retained.
+	/// Epochs that fell out of the retention window are pruned on every
+	/// update.
+	///
+	/// # Arguments
+	/// * `entry` - The rating input entry which contains the rating details.
+	/// * `rtype` - Whether the entry is added to or removed from the epoch.
+	/// * `epoch` - The epoch the rating belongs to.
+	pub fn aggregate_epoch_score(entry: &RatingInputEntryOf<T>, rtype: EntryTypeOf, epoch: u64) {
+		let current = Self::current_epoch();
+		let retained = T::MaxRetainedEpochs::get() as u64;
+		let is_new_credit =
+			rtype == EntryTypeOf::Credit && current.saturating_sub(epoch) < retained;
+
+		<EpochScores<T>>::mutate(&entry.entity_id, &entry.rating_type, |epochs| {
+			epochs.retain(|e| current.saturating_sub(e.epoch) < retained);
+
+			match epochs.iter_mut().find(|e| e.epoch == epoch) {
+				Some(bucket) => match rtype {
+					EntryTypeOf::Credit => {
+						bucket.aggregate.count_of_txn =
+							bucket.aggregate.count_of_txn.saturating_add(entry.count_of_txn);
+						bucket.aggregate.total_encoded_rating = bucket
+							.aggregate
+							.total_encoded_rating
+							.saturating_add(entry.total_encoded_rating);
+					},
+					EntryTypeOf::Debit => {
+						bucket.aggregate.count_of_txn =
+							bucket.aggregate.count_of_txn.saturating_sub(entry.count_of_txn);
+						bucket.aggregate.total_encoded_rating = bucket
+							.aggregate
+							.total_encoded_rating
+							.saturating_sub(entry.total_encoded_rating);
+					},
+				},
+				None if is_new_credit => {
+					let position =
+						epochs.iter().position(|e| e.epoch > epoch).unwrap_or(epochs.len());
+					let bucket = EpochAggregate {
+						epoch,
+						aggregate: AggregatedEntryOf {
+							count_of_txn: entry.count_of_txn,
+							total_encoded_rating: entry.total_encoded_rating,
+						},
+					};
+					// Every retained epoch fits, as the window is pruned above.
+					let _ = epochs.try_insert(position, bucket);
+				},
+				None => {},
+			}
+		});
+	}
+
+	/// Returns the score of an entity for a rating type over `window`.
+	///
+	/// `AllTime` returns the running aggregate. `LastEpochs(n)` sums the
+	/// buckets of the last `n` epochs, including the current one, and
+	/// `Decayed(n)` does the same while scaling each bucket by `EpochDecay`
+	/// for every epoch of age. Windows longer than `MaxRetainedEpochs` are
+	/// capped to the retained epochs.
+	///
+	/// Returns `None` if the entity was never rated for the rating type.
+	pub fn score(
+		entity: &EntityIdentifierOf<T>,
+		rating_type: &RatingTypeOf,
+		window: ScoreWindow,
+	) -> Option<AggregatedEntryOf> {
+		let all_time = <AggregateScores<T>>::get(entity, rating_type)?;
+
+		let (epochs, decay) = match window {
+			ScoreWindow::AllTime => return Some(all_time),
+			ScoreWindow::LastEpochs(epochs) => (epochs, Permill::one()),
+			ScoreWindow::Decayed(epochs) => (epochs, T::EpochDecay::get()),
+		};
+		let epochs = epochs.min(T::MaxRetainedEpochs::get()) as u64;
+		let current = Self::current_epoch();
+
+		let mut score = AggregatedEntryOf { count_of_txn: 0, total_encoded_rating: 0 };
+		for bucket in <EpochScores<T>>::get(entity, rating_type) {
+			let age = current.saturating_sub(bucket.epoch);
+			if age >= epochs {
+				continue;
+			}
+			let weight = decay.saturating_pow(age as usize);
+			score.count_of_txn = score
+				.count_of_txn
+				.saturating_add(weight.mul_floor(bucket.aggregate.count_of_txn));
+			score.total_encoded_rating = score
+				.total_encoded_rating
+				.saturating_add(weight.mul_floor(bucket.aggregate.total_encoded_rating));
+		}
+		Some(score)
+	}
+
+	/// Returns the score of an entity over `window`, combined across every
+	/// rating category the entity was rated in.
+	///
+	/// The ratings of each category are rescaled from the scale of the
+	/// category to `MaxRatingValue`, so that categories with different scales
+	/// are comparable, and then scaled by the weight of the category. The
+	/// categories of the entity are iterated, so this is meant for the
+	/// runtime API rather than for dispatchables.
+	///
+	/// Returns `None` if the entity was never rated.
+	pub fn combined_score(
+		entity: &EntityIdentifierOf<T>,
+		window: ScoreWindow,
+	) -> Option<AggregatedEntryOf> {
+		let max_rating = T::MaxRatingValue::get() as u64;
+
+		let mut combined: Option<AggregatedEntryOf> = None;
+		for rating_type in <AggregateScores<T>>::iter_key_prefix(entity) {
+			let Some(category) = <RatingCategories<T>>::get(rating_type) else { continue };
+			let Some(score) = Self::score(entity, &rating_type, window.clone()) else { continue };
+
+			let total_encoded_rating = score
+				.total_encoded_rating
+				.saturating_mul(max_rating)
+				.checked_div(category.scale as u64)
+				.unwrap_or_default();
+			let combined = combined
+				.get_or_insert(AggregatedEntryOf { count_of_txn: 0, total_encoded_rating: 0 });
+			combined.count_of_txn = combined
+				.count_of_txn
+				.saturating_add(category.weight.mul_floor(score.count_of_txn));
+			combined.total_encoded_rating = combined
+				.total_encoded_rating
+				.saturating_add(category.weight.mul_floor(total_encoded_rating));
+		}
+		combined
+	}
+
+	/// Registers a rating category under the next free identifier.
+	///
+	/// # Errors
+	/// Returns `Error::<T>::InvalidRatingScale` if the scale is zero or above
+	/// `MaxRatingValue`, and `Error::<T>::TooManyRatingCategories` if the
+	/// identifiers are exhausted.
+	///
+	/// # Events
+	/// Emits `RatingCategoryRegistered` upon successful registration.
+	pub fn insert_rating_category(category: RatingCategoryOf<T>) -> Result<RatingTypeOf, Error<T>> {
+		ensure!(
+			category.scale > 0 && category.scale <= T::MaxRatingValue::get(),
+			Error::<T>::InvalidRatingScale
+		);
+
+		let id = <NextRatingCategory<T>>::get();
+		let next = id.checked_add(1).ok_or(Error::<T>::TooManyRatingCategories)?;
+		let rating_type = RatingTypeOf(id);
+		let space = category.space.clone();
+
+		<RatingCategories<T>>::insert(rating_type, category);
+		<NextRatingCategory<T>>::put(next);
+
+		Self::deposit_event(Event::RatingCategoryRegistered { category: rating_type, space });
+		Ok(rating_type)
+	}
+
+	/// Returns the rating category `rating_type` if it can be used by
+	/// ratings in `space`, which is the case for network-wide categories
+	/// and for categories of that space.
+	///
+	/// # Errors
+	/// Returns `Error::<T>::InvalidRatingType` if the category is unknown or
+	/// belongs to another space.
+	pub fn ensure_rating_category(
+		rating_type: &RatingTypeOf,
+		space: &SpaceIdOf,
+	) -> Result<RatingCategoryOf<T>, Error<T>> {
+		let category =
+			<RatingCategories<T>>::get(rating_type).ok_or(Error::<T>::InvalidRatingType)?;
+		ensure!(
+			category.space.as_ref().map_or(true, |s| s == space),
+			Error::<T>::InvalidRatingType
+		);
+		Ok(category)
+	}
+
+	/// Registers the network-wide `OVERALL` and `DELIVERY` rating
+	/// categories, which the rating types of earlier releases map to.
+	/// Categories registered afterwards are numbered from two onwards.
+	pub fn initialize_rating_categories() {
+		let defaults =
+			[(RatingTypeOf::OVERALL, &b"Overall"[..]), (RatingTypeOf::DELIVERY, &b"Delivery"[..])];
+		for (rating_type, name) in defaults {
+			<RatingCategories<T>>::insert(
+				rating_type,
+				RatingCategoryOf::<T> {
+					space: None,
+					name: name.to_vec().try_into().unwrap_or_default(),
+					scale: T::MaxRatingValue::get(),
+					weight: Permill::one(),
+				},
+			);
+		}
+		<NextRatingCategory<T>>::put(RatingTypeOf::DELIVERY.0 + 1);
+	}
+
+	/// Returns the epoch containing `moment`. All moments fall into epoch
+	/// zero if `EpochDuration` is zero.
+	pub fn epoch_of(moment: T::Moment) -> u64 {
+		let duration = T::EpochDuration::get();
+		if duration.is_zero() {
+			return 0;
+		}
+		moment.checked_div(&duration).unwrap_or_default().unique_saturated_into()
+	}
+
+	/// Returns the epoch containing the current block's timestamp.
+	pub fn current_epoch() -> u64 {
+		Self::epoch_of(Self::get_current_time())
+	}
+
 	/// Updates the global timeline with a new rating event for an entity.
 	///
 	/// An `EventEntryOf` struct is created, encapsulating the type of action
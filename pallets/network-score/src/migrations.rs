@@ -0,0 +1,327 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Storage migrations for the network score pallet.
+
+use crate::*;
+
+pub mod v1 {
+	use super::*;
+	use frame_support::{
+		migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+		pallet_prelude::*,
+		storage_alias,
+		weights::WeightMeter,
+	};
+	use sp_std::marker::PhantomData;
+	#[cfg(feature = "try-runtime")]
+	use sp_std::vec::Vec;
+
+	/// The identifier of the network score migrations in the multi-block
+	/// migrator.
+	pub(crate) const MIGRATION_ID: &[u8; 16] = b"cord-netw-scores";
+
+	/// Rating types before version 1 of the pallet, when they were a closed
+	/// set rather than registerable categories.
+	#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq)]
+	pub enum OldRatingTypeOf {
+		Overall,
+		Delivery,
+	}
+
+	impl From<OldRatingTypeOf> for RatingTypeOf {
+		fn from(old: OldRatingTypeOf) -> Self {
+			match old {
+				OldRatingTypeOf::Overall => RatingTypeOf::OVERALL,
+				OldRatingTypeOf::Delivery => RatingTypeOf::DELIVERY,
+			}
+		}
+	}
+
+	pub type OldRatingEntryOf<T> = RatingEntry<
+		EntityIdentifierOf<T>,
+		RatingProviderIdOf<T>,
+		OldRatingTypeOf,
+		RatingEntryIdOf,
+		RatingEntryHashOf<T>,
+		MessageIdentifierOf<T>,
+		SpaceIdOf,
+		AccountIdOf<T>,
+		EntryTypeOf,
+		<T as pallet_timestamp::Config>::Moment,
+	>;
+
+	pub mod old {
+		use super::*;
+
+		#[storage_alias]
+		pub type RatingEntries<T: Config> =
+			StorageMap<Pallet<T>, Blake2_128Concat, RatingEntryIdOf, OldRatingEntryOf<T>>;
+
+		#[storage_alias]
+		pub type AggregateScores<T: Config> = StorageDoubleMap<
+			Pallet<T>,
+			Twox64Concat,
+			EntityIdentifierOf<T>,
+			Blake2_128Concat,
+			OldRatingTypeOf,
+			AggregatedEntryOf,
+			OptionQuery,
+		>;
+
+		#[storage_alias]
+		pub type EpochScores<T: Config> = StorageDoubleMap<
+			Pallet<T>,
+			Twox64Concat,
+			EntityIdentifierOf<T>,
+			Blake2_128Concat,
+			OldRatingTypeOf,
+			BoundedVec<EpochAggregate, <T as Config>::MaxRetainedEpochs>,
+			OptionQuery,
+		>;
+	}
+
+	/// The aggregate scores removed from their old keys, kept while the
+	/// migration runs so that they are not mistaken for old entries once
+	/// stored under their new keys.
+	#[storage_alias]
+	pub type PendingAggregateScores<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Twox64Concat,
+		EntityIdentifierOf<T>,
+		Blake2_128Concat,
+		RatingTypeOf,
+		AggregatedEntryOf,
+		OptionQuery,
+	>;
+
+	/// The epoch scores removed from their old keys, kept for the same reason
+	/// as `PendingAggregateScores`.
+	#[storage_alias]
+	pub type PendingEpochScores<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Twox64Concat,
+		EntityIdentifierOf<T>,
+		Blake2_128Concat,
+		RatingTypeOf,
+		BoundedVec<EpochAggregate, <T as Config>::MaxRetainedEpochs>,
+		OptionQuery,
+	>;
+
+	/// The progress of `MigrateToRatingCategories`.
+	#[derive(Clone, Encode, Decode, MaxEncodedLen, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub enum RatingCategoriesCursor {
+		/// Rating entries are being translated; the last entry visited.
+		Entries(Option<RatingEntryIdOf>),
+		/// Aggregate scores are being removed from their old keys.
+		DrainingAggregates,
+		/// Epoch scores are being removed from their old keys.
+		DrainingEpochs,
+		/// The removed scores are being stored under their new keys.
+		Restoring,
+	}
+
+	/// Registers the `OVERALL` and `DELIVERY` rating categories and re-keys
+	/// the ratings and scores recorded under the former `Overall` and
+	/// `Delivery` rating types to them.
+	///
+	/// The migration runs over several blocks and moves one entry per
+	/// iteration. The encoding of the rating type changes, so scores cannot
+	/// be translated in place: they are first drained from their old keys
+	/// into `PendingAggregateScores` and `PendingEpochScores`, and only then
+	/// stored under their new keys.
+	pub struct MigrateToRatingCategories<T>(PhantomData<T>);
+
+	impl<T: Config> MigrateToRatingCategories<T> {
+		/// The weight of a single iteration, which is bounded by moving one
+		/// score, or by registering the default categories.
+		pub(crate) fn step_weight() -> Weight {
+			T::DbWeight::get().reads_writes(1, 3)
+		}
+
+		/// Translates the rating entry after `last`. Returns `None` once every
+		/// entry has been visited.
+		fn translate_next(last: Option<RatingEntryIdOf>) -> Option<RatingEntryIdOf> {
+			let mut iter = match last {
+				Some(last) => old::RatingEntries::<T>::iter_from(
+					old::RatingEntries::<T>::hashed_key_for(last),
+				),
+				None => old::RatingEntries::<T>::iter(),
+			};
+			let (identifier, old) = iter.next()?;
+
+			RatingEntries::<T>::insert(
+				&identifier,
+				RatingEntryOf::<T> {
+					entry: RatingInputEntry {
+						entity_id: old.entry.entity_id,
+						provider_id: old.entry.provider_id,
+						count_of_txn: old.entry.count_of_txn,
+						total_encoded_rating: old.entry.total_encoded_rating,
+						rating_type: old.entry.rating_type.into(),
+						provider_did: old.entry.provider_did,
+					},
+					digest: old.digest,
+					message_id: old.message_id,
+					space: old.space,
+					creator_id: old.creator_id,
+					entry_type: old.entry_type,
+					reference_id: old.reference_id,
+					created_at: old.created_at,
+				},
+			);
+
+			Some(identifier)
+		}
+
+		/// Moves one aggregate score from its old key to the pending scores.
+		/// Returns `false` once no aggregate score is left under an old key.
+		fn drain_aggregate_next() -> bool {
+			let Some((entity, rating_type, score)) = old::AggregateScores::<T>::drain().next()
+			else {
+				return false;
+			};
+			PendingAggregateScores::<T>::insert(entity, RatingTypeOf::from(rating_type), score);
+			true
+		}
+
+		/// Moves one epoch score from its old key to the pending scores.
+		/// Returns `false` once no epoch score is left under an old key.
+		fn drain_epoch_next() -> bool {
+			let Some((entity, rating_type, buckets)) = old::EpochScores::<T>::drain().next() else {
+				return false;
+			};
+			PendingEpochScores::<T>::insert(entity, RatingTypeOf::from(rating_type), buckets);
+			true
+		}
+
+		/// Stores one pending score under its new key. Returns `false` once no
+		/// pending score is left.
+		fn restore_next() -> bool {
+			if let Some((entity, rating_type, score)) = PendingAggregateScores::<T>::drain().next()
+			{
+				AggregateScores::<T>::insert(entity, rating_type, score);
+				return true;
+			}
+			if let Some((entity, rating_type, buckets)) = PendingEpochScores::<T>::drain().next() {
+				EpochScores::<T>::insert(entity, rating_type, buckets);
+				return true;
+			}
+			false
+		}
+	}
+
+	impl<T: Config> SteppedMigration for MigrateToRatingCategories<T> {
+		type Cursor = RatingCategoriesCursor;
+		type Identifier = MigrationId<16>;
+
+		fn id() -> Self::Identifier {
+			MigrationId { pallet_id: *MIGRATION_ID, version_from: 0, version_to: 1 }
+		}
+
+		fn step(
+			mut cursor: Option<Self::Cursor>,
+			meter: &mut WeightMeter,
+		) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+			let required = Self::step_weight();
+			if meter.remaining().any_lt(required) {
+				return Err(SteppedMigrationError::InsufficientWeight { required });
+			}
+
+			if Pallet::<T>::on_chain_storage_version() != 0 {
+				log::info!(
+					target: "runtime::network-score",
+					"skipping rating categories migration, on-chain storage version is {:?}",
+					Pallet::<T>::on_chain_storage_version()
+				);
+				return Ok(None);
+			}
+
+			while meter.try_consume(required).is_ok() {
+				cursor = Some(match cursor {
+					None => {
+						Pallet::<T>::initialize_rating_categories();
+						RatingCategoriesCursor::Entries(None)
+					},
+					Some(RatingCategoriesCursor::Entries(last)) =>
+						match Self::translate_next(last) {
+							Some(identifier) => RatingCategoriesCursor::Entries(Some(identifier)),
+							None => RatingCategoriesCursor::DrainingAggregates,
+						},
+					Some(RatingCategoriesCursor::DrainingAggregates) =>
+						if Self::drain_aggregate_next() {
+							RatingCategoriesCursor::DrainingAggregates
+						} else {
+							RatingCategoriesCursor::DrainingEpochs
+						},
+					Some(RatingCategoriesCursor::DrainingEpochs) =>
+						if Self::drain_epoch_next() {
+							RatingCategoriesCursor::DrainingEpochs
+						} else {
+							RatingCategoriesCursor::Restoring
+						},
+					Some(RatingCategoriesCursor::Restoring) => {
+						if !Self::restore_next() {
+							StorageVersion::new(1).put::<Pallet<T>>();
+							log::info!(
+								target: "runtime::network-score",
+								"migrated rating types to version 1"
+							);
+							return Ok(None);
+						}
+						RatingCategoriesCursor::Restoring
+					},
+				});
+			}
+
+			Ok(cursor)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			let entries = old::RatingEntries::<T>::iter_keys().count() as u64;
+			let scores = old::AggregateScores::<T>::iter_keys().count() as u64;
+			Ok((entries, scores).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let (entries, scores) = <(u64, u64)>::decode(&mut &state[..])
+				.map_err(|_| sp_runtime::TryRuntimeError::Other("invalid pre-upgrade state"))?;
+
+			ensure!(
+				RatingEntries::<T>::iter_values().count() as u64 == entries,
+				"every rating entry should be decodable"
+			);
+			ensure!(
+				AggregateScores::<T>::iter_values().count() as u64 == scores,
+				"every aggregate score should be re-keyed"
+			);
+			ensure!(
+				RatingCategories::<T>::contains_key(RatingTypeOf::OVERALL) &&
+					RatingCategories::<T>::contains_key(RatingTypeOf::DELIVERY),
+				"default rating categories should be registered"
+			);
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 1,
+				"storage version should be updated"
+			);
+			Ok(())
+		}
+	}
+}
@@ -19,12 +19,14 @@
 use super::*;
 use crate as pallet_score;
 use cord_utilities::mock::{mock_origin, SubjectId};
-use frame_support::{derive_impl, parameter_types, traits::ConstU64};
+use frame_support::{
+	derive_impl, parameter_types, traits::ConstU64, weights::constants::RocksDbWeight,
+};
 use frame_system::EnsureRoot;
 use pallet_chain_space::IsPermissioned;
 use sp_runtime::{
 	traits::{IdentifyAccount, IdentityLookup, Verify},
-	BuildStorage, MultiSignature,
+	BuildStorage, MultiSignature, Permill,
 };
 
 type Signature = MultiSignature;
@@ -56,6 +58,7 @@ impl frame_system::Config for Test {
 	type AccountId = AccountId;
 	type Lookup = IdentityLookup<Self::AccountId>;
 	type SS58Prefix = SS58Prefix;
+	type DbWeight = RocksDbWeight;
 }
 
 impl mock_origin::Config for Test {
@@ -67,14 +70,21 @@ impl mock_origin::Config for Test {
 parameter_types! {
 	pub const MaxEncodedValueLength: u32 = 600u32;
 	pub const MaxRatingValue: u32 = 50u32;
+	pub const EpochDuration: u64 = 1_000u64;
+	pub const MaxRetainedEpochs: u32 = 3u32;
+	pub const EpochDecay: Permill = Permill::from_percent(50);
 }
 
 impl pallet_score::Config for Test {
 	type MaxEncodedValueLength = MaxEncodedValueLength;
 	type MaxRatingValue = MaxRatingValue;
+	type EpochDuration = EpochDuration;
+	type MaxRetainedEpochs = MaxRetainedEpochs;
+	type EpochDecay = EpochDecay;
 	type RatingProviderIdOf = SubjectId;
 	type EnsureOrigin = mock_origin::EnsureDoubleOrigin<AccountId, SubjectId>;
 	type OriginSuccess = mock_origin::DoubleOrigin<AccountId, SubjectId>;
+	type RatingCategoryOrigin = EnsureRoot<AccountId>;
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = weights::SubstrateWeight<Test>;
 }
@@ -82,6 +92,8 @@ impl pallet_score::Config for Test {
 parameter_types! {
 	#[derive(Debug, Clone)]
 	pub const MaxSpaceDelegates: u32 = 5u32;
+	pub const MaxSubspaceDepth: u32 = 3u32;
+	pub const MaxDelegationExpirationsPerBlock: u32 = 5u32;
 }
 
 pub struct NetworkPermission;
@@ -97,18 +109,14 @@ impl pallet_chain_space::Config for Test {
 	type OriginSuccess = mock_origin::DoubleOrigin<AccountId, SubjectId>;
 	type SpaceCreatorId = SubjectId;
 	type MaxSpaceDelegates = MaxSpaceDelegates;
+	type MaxSubspaceDepth = MaxSubspaceDepth;
+	type MaxDelegationExpirationsPerBlock = MaxDelegationExpirationsPerBlock;
 	type ChainSpaceOrigin = EnsureRoot<AccountId>;
 	type NetworkPermission = NetworkPermission;
 	type WeightInfo = ();
 }
 
-parameter_types! {
-	pub const MaxEventsHistory: u32 = 6u32;
-}
-
-impl identifier::Config for Test {
-	type MaxEventsHistory = MaxEventsHistory;
-}
+impl identifier::Config for Test {}
 impl pallet_timestamp::Config for Test {
 	type Moment = u64;
 	type OnTimestampSet = ();
@@ -118,8 +126,11 @@ impl pallet_timestamp::Config for Test {
 
 #[allow(dead_code)]
 pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
-	let t: sp_runtime::Storage =
+	let mut t: sp_runtime::Storage =
 		frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_score::GenesisConfig::<Test>::default()
+		.assimilate_storage(&mut t)
+		.unwrap();
 
 	let mut ext = sp_io::TestExternalities::new(t);
 	#[cfg(feature = "runtime-benchmarks")]
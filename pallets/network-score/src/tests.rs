@@ -23,7 +23,7 @@ use cord_utilities::mock::{mock_origin::DoubleOrigin, SubjectId};
 use frame_support::{assert_err, assert_ok, BoundedVec};
 use frame_system::RawOrigin;
 use pallet_chain_space::SpaceCodeOf;
-use sp_runtime::{traits::Hash, AccountId32};
+use sp_runtime::{traits::Hash, AccountId32, Permill};
 use sp_std::prelude::*;
 
 pub fn generate_rating_id<T: Config>(digest: &RatingEntryHashOf<T>) -> RatingEntryIdOf {
@@ -50,7 +50,7 @@ fn check_successful_rating_creation() {
 		provider_id,
 		total_encoded_rating: 250u64,
 		count_of_txn: 7u64,
-		rating_type: RatingTypeOf::Overall,
+		rating_type: RatingTypeOf::OVERALL,
 		provider_did: creator.clone(),
 	};
 	let entry_digest =
@@ -104,7 +104,7 @@ fn register_rating_with_invalid_data_should_fail() {
 		provider_id,
 		total_encoded_rating: 0u64, // Invalid rating (0 value)
 		count_of_txn: 0u64,         // Invalid transaction count (0)
-		rating_type: RatingTypeOf::Overall,
+		rating_type: RatingTypeOf::OVERALL,
 		provider_did: creator.clone(),
 	};
 	let entry_digest =
@@ -163,7 +163,7 @@ fn revise_rating_with_invalid_values_should_fail() {
 		provider_id,
 		total_encoded_rating: 250u64, // Initially valid rating
 		count_of_txn: 7u64,           // Initially valid transaction count
-		rating_type: RatingTypeOf::Overall,
+		rating_type: RatingTypeOf::OVERALL,
 		provider_did: creator.clone(),
 	};
 	let entry_digest =
@@ -252,7 +252,7 @@ fn check_duplicate_message_id() {
 		provider_id,
 		total_encoded_rating: 250u64,
 		count_of_txn: 7u64,
-		rating_type: RatingTypeOf::Overall,
+		rating_type: RatingTypeOf::OVERALL,
 		provider_did: creator.clone(),
 	};
 	let entry_digest =
@@ -319,7 +319,7 @@ fn revise_rating_with_entry_entity_mismatch_should_fail() {
 		provider_id,
 		total_encoded_rating: 250u64,
 		count_of_txn: 7u64,
-		rating_type: RatingTypeOf::Overall,
+		rating_type: RatingTypeOf::OVERALL,
 		provider_did: creator.clone(),
 	};
 	let entry_digest =
@@ -404,7 +404,7 @@ fn register_rating_with_existing_rating_identifier_should_fail() {
 		provider_id,
 		total_encoded_rating: 250u64,
 		count_of_txn: 7u64,
-		rating_type: RatingTypeOf::Overall,
+		rating_type: RatingTypeOf::OVERALL,
 		provider_did: creator.clone(),
 	};
 	let entry_digest =
@@ -469,7 +469,7 @@ fn revoke_rating_with_existing_rating_identifier_should_fail() {
 		provider_id,
 		total_encoded_rating: 250u64,
 		count_of_txn: 7u64,
-		rating_type: RatingTypeOf::Overall,
+		rating_type: RatingTypeOf::OVERALL,
 		provider_did: creator.clone(),
 	};
 	let entry_digest =
@@ -548,7 +548,7 @@ fn revise_rating_with_existing_rating_identifier_should_fail() {
 		provider_id: provider_id.clone(),
 		total_encoded_rating: 250u64,
 		count_of_txn: 7u64,
-		rating_type: RatingTypeOf::Overall,
+		rating_type: RatingTypeOf::OVERALL,
 		provider_did: creator.clone(),
 	};
 	let entry_digest =
@@ -559,7 +559,7 @@ fn revise_rating_with_existing_rating_identifier_should_fail() {
 		provider_id: provider_id.clone(),
 		total_encoded_rating: 250u64,
 		count_of_txn: 7u64,
-		rating_type: RatingTypeOf::Overall,
+		rating_type: RatingTypeOf::OVERALL,
 		provider_did: creator.clone(),
 	};
 	let entry_revise_digest =
@@ -672,7 +672,7 @@ fn reference_identifier_not_debit_test() {
 		provider_id,
 		total_encoded_rating: 250u64,
 		count_of_txn: 7u64,
-		rating_type: RatingTypeOf::Overall,
+		rating_type: RatingTypeOf::OVERALL,
 		provider_did: creator.clone(),
 	};
 	let entry_digest =
@@ -750,7 +750,7 @@ fn rating_identifier_not_found_test() {
 		provider_id,
 		total_encoded_rating: 250u64,
 		count_of_txn: 7u64,
-		rating_type: RatingTypeOf::Overall,
+		rating_type: RatingTypeOf::OVERALL,
 		provider_did: creator.clone(),
 	};
 	let entry_digest =
@@ -831,7 +831,7 @@ fn reference_identifier_not_found_test() {
 		provider_id: provider_id.clone(),
 		total_encoded_rating: 250u64,
 		count_of_txn: 7u64,
-		rating_type: RatingTypeOf::Overall,
+		rating_type: RatingTypeOf::OVERALL,
 		provider_did: creator.clone(),
 	};
 
@@ -915,3 +915,380 @@ fn reference_identifier_not_found_test() {
 		<MessageIdentifiers<Test>>::remove(message_id_revise.clone(), creator.clone());
 	});
 }
+
+#[test]
+fn scores_should_be_aggregated_by_epoch_window() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let entity_id: EntityIdentifierOf<Test> = BoundedVec::try_from([73u8; 10].to_vec()).unwrap();
+	let provider_id = BoundedVec::try_from([74u8; 10].to_vec()).unwrap();
+
+	let entry_with = |count_of_txn: u64, total_encoded_rating: u64| RatingInputEntryOf::<Test> {
+		entity_id: entity_id.clone(),
+		provider_id: provider_id.clone(),
+		total_encoded_rating,
+		count_of_txn,
+		rating_type: RatingTypeOf::OVERALL,
+		provider_did: creator.clone(),
+	};
+	// (timestamp, entry, message identifier)
+	let ratings = vec![
+		(500u64, entry_with(2, 100), [80u8; 10]),
+		(1_500u64, entry_with(4, 120), [81u8; 10]),
+		(2_500u64, entry_with(1, 40), [82u8; 10]),
+	];
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: AuthorizationIdOf =
+		Ss58Identifier::create_identifier(&auth_digest.encode()[..], IdentifierType::Authorization)
+			.unwrap();
+
+	let score = |window: ScoreWindow| {
+		Score::score(&entity_id, &RatingTypeOf::OVERALL, window)
+			.map(|aggregate| (aggregate.count_of_txn, aggregate.total_encoded_rating))
+	};
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest
+		));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), 10u64));
+
+		assert_eq!(score(ScoreWindow::AllTime), None);
+
+		let mut revoked = None;
+		for (moment, entry, message) in ratings {
+			TimeStamp::set_timestamp(moment);
+			let message_id: MessageIdentifierOf<Test> =
+				BoundedVec::try_from(message.to_vec()).unwrap();
+			let entry_digest = <Test as frame_system::Config>::Hashing::hash(&entry.encode()[..]);
+			let id_digest = <Test as frame_system::Config>::Hashing::hash(
+				&[
+					&entry_digest.encode()[..],
+					&entry.entity_id.encode()[..],
+					&message_id.encode()[..],
+					&space_id.encode()[..],
+					&creator.encode()[..],
+				]
+				.concat()[..],
+			);
+			if moment == 1_500 {
+				revoked = Some((generate_rating_id::<Test>(&id_digest), entry_digest));
+			}
+
+			assert_ok!(Score::register_rating(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				entry,
+				entry_digest,
+				message_id,
+				authorization_id.clone(),
+			));
+		}
+
+		// Ratings were recorded in epochs 0, 1 and 2; epoch 2 is current.
+		assert_eq!(score(ScoreWindow::AllTime), Some((7, 260)));
+		assert_eq!(score(ScoreWindow::LastEpochs(1)), Some((1, 40)));
+		assert_eq!(score(ScoreWindow::LastEpochs(3)), Some((7, 260)));
+		// Each epoch of age halves the weight of the ratings.
+		assert_eq!(score(ScoreWindow::Decayed(3)), Some((3, 125)));
+
+		// Epoch 0 leaves the retention window once epoch 3 starts.
+		TimeStamp::set_timestamp(3_500);
+		assert_eq!(score(ScoreWindow::LastEpochs(10)), Some((5, 160)));
+
+		// Revoking a rating removes it from the epoch it was recorded in.
+		let (rating_id, entry_digest) = revoked.expect("rating should be registered");
+		assert_ok!(Score::revoke_rating(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			rating_id,
+			BoundedVec::try_from([83u8; 10].to_vec()).unwrap(),
+			entry_digest,
+			authorization_id,
+		));
+		assert_eq!(score(ScoreWindow::LastEpochs(3)), Some((1, 40)));
+		assert_eq!(score(ScoreWindow::AllTime), Some((3, 140)));
+		assert_eq!(
+			EpochScores::<Test>::get(&entity_id, RatingTypeOf::OVERALL)
+				.iter()
+				.map(|bucket| bucket.epoch)
+				.collect::<Vec<_>>(),
+			vec![1, 2]
+		);
+	});
+}
+
+#[test]
+fn ratings_should_use_registered_categories_of_their_space() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let entity_id: EntityIdentifierOf<Test> = BoundedVec::try_from([73u8; 10].to_vec()).unwrap();
+	let provider_id = BoundedVec::try_from([74u8; 10].to_vec()).unwrap();
+	let name: RatingCategoryNameOf<Test> = BoundedVec::try_from(b"Quality".to_vec()).unwrap();
+
+	let space_and_authorization = |raw_space: [u8; 256]| {
+		let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+		let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+			&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+		);
+		let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+		let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+			&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+		);
+		let authorization_id: AuthorizationIdOf = Ss58Identifier::create_identifier(
+			&auth_digest.encode()[..],
+			IdentifierType::Authorization,
+		)
+		.unwrap();
+		(space_digest, space_id, authorization_id)
+	};
+	let (space_digest, space_id, authorization_id) = space_and_authorization([2u8; 256]);
+	let (other_digest, other_space_id, other_authorization_id) =
+		space_and_authorization([3u8; 256]);
+
+	let entry_with =
+		|rating_type: RatingTypeOf, total_encoded_rating: u64| RatingInputEntryOf::<Test> {
+			entity_id: entity_id.clone(),
+			provider_id: provider_id.clone(),
+			total_encoded_rating,
+			count_of_txn: 7u64,
+			rating_type,
+			provider_did: creator.clone(),
+		};
+	let register = |entry: RatingInputEntryOf<Test>, message: u8| {
+		let entry_digest = <Test as frame_system::Config>::Hashing::hash(&entry.encode()[..]);
+		Score::register_rating(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			entry,
+			entry_digest,
+			BoundedVec::try_from([message; 10].to_vec()).unwrap(),
+			authorization_id.clone(),
+		)
+	};
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest
+		));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), 10u64));
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			other_digest
+		));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), other_space_id.clone(), 10u64));
+
+		// The default categories are registered at genesis.
+		assert_eq!(
+			RatingCategories::<Test>::get(RatingTypeOf::DELIVERY).map(|c| (c.space, c.scale)),
+			Some((None, 50))
+		);
+
+		assert_err!(
+			Score::register_network_rating_category(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				name.clone(),
+				10,
+				Permill::from_percent(30),
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_err!(
+			Score::register_network_rating_category(
+				RawOrigin::Root.into(),
+				name.clone(),
+				51,
+				Permill::from_percent(30),
+			),
+			Error::<Test>::InvalidRatingScale
+		);
+
+		assert_ok!(Score::register_rating_category(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			name.clone(),
+			10,
+			Permill::from_percent(30),
+			authorization_id.clone(),
+		));
+		assert_ok!(Score::register_rating_category(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			name.clone(),
+			10,
+			Permill::from_percent(30),
+			other_authorization_id,
+		));
+		let quality = RatingTypeOf(2);
+		let other_quality = RatingTypeOf(3);
+		assert_eq!(
+			RatingCategories::<Test>::get(quality),
+			Some(RatingCategoryOf::<Test> {
+				space: Some(space_id),
+				name,
+				scale: 10,
+				weight: Permill::from_percent(30),
+			})
+		);
+
+		// Ratings are bounded by the scale of their category.
+		assert_err!(register(entry_with(quality, 71), 80u8), Error::<Test>::InvalidRatingValue);
+		assert_ok!(register(entry_with(quality, 70), 81u8));
+		assert_eq!(
+			AggregateScores::<Test>::get(&entity_id, quality)
+				.map(|a| (a.count_of_txn, a.total_encoded_rating)),
+			Some((7, 70))
+		);
+
+		// Categories of other spaces and unknown categories are rejected.
+		assert_err!(
+			register(entry_with(other_quality, 70), 82u8),
+			Error::<Test>::InvalidRatingType
+		);
+		assert_err!(
+			register(entry_with(RatingTypeOf(9), 70), 83u8),
+			Error::<Test>::InvalidRatingType
+		);
+
+		// Combined scores rescale each category to the maximum rating value
+		// and weight it: the quality ratings count as 70 * 50 / 10 = 350 at
+		// 30%, next to the overall ratings at full weight.
+		assert_ok!(register(entry_with(RatingTypeOf::OVERALL, 280), 84u8));
+		assert_eq!(
+			Score::combined_score(&entity_id, ScoreWindow::AllTime)
+				.map(|a| (a.count_of_txn, a.total_encoded_rating)),
+			Some((7 + 2, 280 + 105))
+		);
+		assert_eq!(
+			Score::combined_score(
+				&BoundedVec::try_from([99u8; 10].to_vec()).unwrap(),
+				ScoreWindow::AllTime
+			),
+			None
+		);
+	});
+}
+
+#[test]
+fn rating_categories_migration_should_re_key_scores_over_several_steps() {
+	use frame_support::{
+		migrations::SteppedMigration,
+		traits::{GetStorageVersion, StorageVersion},
+		weights::WeightMeter,
+	};
+	use migrations::v1::{
+		old, MigrateToRatingCategories, OldRatingTypeOf, PendingAggregateScores, PendingEpochScores,
+	};
+
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let entity_id: EntityIdentifierOf<Test> = BoundedVec::try_from([73u8; 10].to_vec()).unwrap();
+	let other_entity_id: EntityIdentifierOf<Test> =
+		BoundedVec::try_from([75u8; 10].to_vec()).unwrap();
+	let space_id: SpaceIdOf = generate_space_id::<Test>(
+		&<Test as frame_system::Config>::Hashing::hash(&[2u8; 256].encode()[..]),
+	);
+	let score = AggregatedEntryOf { count_of_txn: 7, total_encoded_rating: 250 };
+	let buckets: BoundedVec<EpochAggregate, MaxRetainedEpochs> =
+		BoundedVec::try_from(vec![EpochAggregate { epoch: 0, aggregate: score.clone() }]).unwrap();
+
+	let old_entry =
+		|entity_id: &EntityIdentifierOf<Test>, rating_type: OldRatingTypeOf| RatingEntry {
+			entry: RatingInputEntry {
+				entity_id: entity_id.clone(),
+				provider_id: BoundedVec::try_from([74u8; 10].to_vec()).unwrap(),
+				count_of_txn: 7,
+				total_encoded_rating: 250,
+				rating_type,
+				provider_did: creator.clone(),
+			},
+			digest: <Test as frame_system::Config>::Hashing::hash(&entity_id.encode()[..]),
+			message_id: BoundedVec::try_from([72u8; 10].to_vec()).unwrap(),
+			space: space_id.clone(),
+			creator_id: author.clone(),
+			entry_type: EntryTypeOf::Credit,
+			reference_id: None,
+			created_at: 0,
+		};
+	let rating_id = generate_rating_id::<Test>(&<Test as frame_system::Config>::Hashing::hash(
+		&entity_id.encode()[..],
+	));
+	let other_rating_id = generate_rating_id::<Test>(
+		&<Test as frame_system::Config>::Hashing::hash(&other_entity_id.encode()[..]),
+	);
+
+	new_test_ext().execute_with(|| {
+		// Rewind to the version 0 layout, without rating categories.
+		let _ = RatingCategories::<Test>::clear(u32::MAX, None);
+		NextRatingCategory::<Test>::kill();
+		StorageVersion::new(0).put::<Score>();
+
+		old::RatingEntries::<Test>::insert(
+			&rating_id,
+			old_entry(&entity_id, OldRatingTypeOf::Overall),
+		);
+		old::RatingEntries::<Test>::insert(
+			&other_rating_id,
+			old_entry(&other_entity_id, OldRatingTypeOf::Delivery),
+		);
+		old::AggregateScores::<Test>::insert(&entity_id, OldRatingTypeOf::Overall, score.clone());
+		old::AggregateScores::<Test>::insert(
+			&other_entity_id,
+			OldRatingTypeOf::Delivery,
+			score.clone(),
+		);
+		old::EpochScores::<Test>::insert(&entity_id, OldRatingTypeOf::Overall, buckets.clone());
+		old::EpochScores::<Test>::insert(
+			&other_entity_id,
+			OldRatingTypeOf::Delivery,
+			buckets.clone(),
+		);
+
+		// Run the migration with room for two iterations per step.
+		let mut cursor = None;
+		let mut steps = 0;
+		loop {
+			let mut meter = WeightMeter::with_limit(
+				MigrateToRatingCategories::<Test>::step_weight().saturating_mul(2),
+			);
+			cursor = MigrateToRatingCategories::<Test>::step(cursor, &mut meter)
+				.expect("migration step should have enough weight");
+			steps += 1;
+			if cursor.is_none() {
+				break;
+			}
+		}
+		assert!(steps > 1);
+		assert_eq!(Score::on_chain_storage_version(), 1);
+
+		assert!(RatingCategories::<Test>::contains_key(RatingTypeOf::OVERALL));
+		assert!(RatingCategories::<Test>::contains_key(RatingTypeOf::DELIVERY));
+		assert_eq!(
+			RatingEntries::<Test>::get(&other_rating_id).map(|r| r.entry.rating_type),
+			Some(RatingTypeOf::DELIVERY)
+		);
+		assert_eq!(
+			AggregateScores::<Test>::get(&entity_id, RatingTypeOf::OVERALL),
+			Some(score.clone())
+		);
+		assert_eq!(
+			AggregateScores::<Test>::get(&other_entity_id, RatingTypeOf::DELIVERY),
+			Some(score)
+		);
+		assert_eq!(EpochScores::<Test>::get(&other_entity_id, RatingTypeOf::DELIVERY), buckets);
+		assert_eq!(AggregateScores::<Test>::iter().count(), 2);
+		assert_eq!(EpochScores::<Test>::iter().count(), 2);
+		assert_eq!(PendingAggregateScores::<Test>::iter().count(), 0);
+		assert_eq!(PendingEpochScores::<Test>::iter().count(), 0);
+	});
+}
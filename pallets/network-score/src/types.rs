@@ -18,7 +18,7 @@
 
 use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
-use sp_runtime::RuntimeDebug;
+use sp_runtime::{Permill, RuntimeDebug};
 
 #[derive(
 	Encode, Decode, MaxEncodedLen, Clone, RuntimeDebug, PartialEq, Eq, PartialOrd, Ord, TypeInfo,
@@ -42,16 +42,34 @@ pub struct RatingInputEntry<EntityIdentifier, RatingProviderId, RatingTypeOf> {
 	pub count_of_txn: u64,
 	/// Cumulative sum of ratings for the entity
 	pub total_encoded_rating: u64,
-	/// Type of rating (overall/delivery)
+	/// Rating category of the entry
 	pub rating_type: RatingTypeOf,
 	/// DID identifier of the provider
 	pub provider_did: RatingProviderId,
 }
 
+/// Identifier of a rating category.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct RatingTypeOf(pub u32);
+
+impl RatingTypeOf {
+	/// Network-wide category for the overall experience
+	pub const OVERALL: Self = Self(0);
+	/// Network-wide category for the delivery experience
+	pub const DELIVERY: Self = Self(1);
+}
+
+/// A rating category registered for the whole network or for a chain space.
 #[derive(Encode, Decode, MaxEncodedLen, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
-pub enum RatingTypeOf {
-	Overall,
-	Delivery,
+pub struct RatingCategory<SpaceIdOf, RatingCategoryName> {
+	/// Space the category belongs to, `None` for network-wide categories
+	pub space: Option<SpaceIdOf>,
+	/// Human readable name of the category
+	pub name: RatingCategoryName,
+	/// Highest rating value a single transaction can carry
+	pub scale: u32,
+	/// Weight of the category when scores of several categories are combined
+	pub weight: Permill,
 }
 
 #[derive(Encode, Decode, MaxEncodedLen, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
@@ -60,12 +78,6 @@ pub enum EntryTypeOf {
 	Debit,
 }
 
-impl RatingTypeOf {
-	pub fn is_valid_rating_type(&self) -> bool {
-		matches!(self, Self::Overall | Self::Delivery)
-	}
-}
-
 #[derive(
 	Encode, Decode, MaxEncodedLen, Clone, RuntimeDebug, PartialEq, Eq, PartialOrd, Ord, TypeInfo,
 )]
@@ -107,3 +119,26 @@ pub struct AggregatedEntryOf {
 	/// aggregated rating
 	pub total_encoded_rating: u64,
 }
+
+/// Ratings aggregated for an entity within a single epoch.
+#[derive(
+	Encode, Decode, MaxEncodedLen, Clone, RuntimeDebug, PartialEq, Eq, PartialOrd, Ord, TypeInfo,
+)]
+pub struct EpochAggregate {
+	/// Index of the epoch, counted from the Unix epoch
+	pub epoch: u64,
+	/// aggregated ratings recorded in the epoch
+	pub aggregate: AggregatedEntryOf,
+}
+
+/// Window over which the ratings of an entity are aggregated.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub enum ScoreWindow {
+	/// Every rating ever recorded for the entity
+	AllTime,
+	/// Ratings recorded in the given number of most recent epochs
+	LastEpochs(u32),
+	/// Ratings recorded in the given number of most recent epochs, with
+	/// older epochs weighing less than recent ones
+	Decayed(u32),
+}
@@ -52,6 +52,8 @@ pub trait WeightInfo {
 	fn register_rating(l: u32, ) -> Weight;
 	fn revoke_rating(l: u32, ) -> Weight;
 	fn revise_rating(l: u32, ) -> Weight;
+	fn register_rating_category() -> Weight;
+	fn register_network_rating_category() -> Weight;
 }
 
 /// Weights for `pallet_network_score` using the CORD node and recommended hardware.
@@ -66,22 +68,24 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `NetworkScore::RatingEntries` (r:1 w:1)
 	/// Proof: `NetworkScore::RatingEntries` (`max_values`: None, `max_size`: Some(679), added: 3154, mode: `MaxEncodedLen`)
 	/// Storage: `NetworkScore::AggregateScores` (r:1 w:1)
-	/// Proof: `NetworkScore::AggregateScores` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	/// Proof: `NetworkScore::AggregateScores` (`max_values`: None, `max_size`: Some(174), added: 2649, mode: `MaxEncodedLen`)
+	/// Storage: `NetworkScore::EpochScores` (r:1 w:1)
+	/// Proof: `NetworkScore::EpochScores` (`max_values`: None, `max_size`: Some(8945), added: 11420, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
-	/// The range of component `l` is `[1, 15360]`.
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[1, 128]`.
 	fn register_rating(l: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `801`
-		//  Estimated: `4144`
-		// Minimum execution time: 51_020_000 picoseconds.
-		Weight::from_parts(53_034_543, 4144)
-			// Standard Error: 6
+		//  Estimated: `28335`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(53_034_543, 28335)
 			.saturating_add(Weight::from_parts(9, 0).saturating_mul(l.into()))
-			.saturating_add(T::DbWeight::get().reads(7_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -92,22 +96,24 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `NetworkScore::MessageIdentifiers` (r:1 w:1)
 	/// Proof: `NetworkScore::MessageIdentifiers` (`max_values`: None, `max_size`: Some(236), added: 2711, mode: `MaxEncodedLen`)
 	/// Storage: `NetworkScore::AggregateScores` (r:1 w:1)
-	/// Proof: `NetworkScore::AggregateScores` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	/// Proof: `NetworkScore::AggregateScores` (`max_values`: None, `max_size`: Some(174), added: 2649, mode: `MaxEncodedLen`)
+	/// Storage: `NetworkScore::EpochScores` (r:1 w:1)
+	/// Proof: `NetworkScore::EpochScores` (`max_values`: None, `max_size`: Some(8945), added: 11420, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:2 w:2)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
-	/// The range of component `l` is `[1, 15360]`.
+	/// Storage: `Identifier::TimelineLength` (r:2 w:2)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:2)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[1, 128]`.
 	fn revoke_rating(l: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1328`
-		//  Estimated: `7298`
-		// Minimum execution time: 66_730_000 picoseconds.
-		Weight::from_parts(69_315_996, 7298)
-			// Standard Error: 8
+		//  Estimated: `34047`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(69_315_996, 34047)
 			.saturating_add(Weight::from_parts(21, 0).saturating_mul(l.into()))
-			.saturating_add(T::DbWeight::get().reads(9_u64))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -118,22 +124,52 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `NetworkScore::MessageIdentifiers` (r:1 w:1)
 	/// Proof: `NetworkScore::MessageIdentifiers` (`max_values`: None, `max_size`: Some(236), added: 2711, mode: `MaxEncodedLen`)
 	/// Storage: `NetworkScore::AggregateScores` (r:1 w:1)
-	/// Proof: `NetworkScore::AggregateScores` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	/// Proof: `NetworkScore::AggregateScores` (`max_values`: None, `max_size`: Some(174), added: 2649, mode: `MaxEncodedLen`)
+	/// Storage: `NetworkScore::EpochScores` (r:1 w:1)
+	/// Proof: `NetworkScore::EpochScores` (`max_values`: None, `max_size`: Some(8945), added: 11420, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:2 w:2)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
-	/// The range of component `l` is `[1, 15360]`.
+	/// Storage: `Identifier::TimelineLength` (r:2 w:2)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:2)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[1, 128]`.
 	fn revise_rating(l: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1427`
-		//  Estimated: `7298`
-		// Minimum execution time: 68_890_000 picoseconds.
-		Weight::from_parts(70_972_982, 7298)
-			// Standard Error: 8
+		//  Estimated: `34047`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(70_972_982, 34047)
 			.saturating_add(Weight::from_parts(37, 0).saturating_mul(l.into()))
-			.saturating_add(T::DbWeight::get().reads(9_u64))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
+	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Spaces` (r:1 w:0)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `NetworkScore::NextRatingCategory` (r:1 w:1)
+	/// Proof: `NetworkScore::NextRatingCategory` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `NetworkScore::RatingCategories` (r:0 w:1)
+	/// Proof: `NetworkScore::RatingCategories` (`max_values`: None, `max_size`: Some(201), added: 2676, mode: `MaxEncodedLen`)
+	fn register_rating_category() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `5839`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(22_000_000, 5839)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NetworkScore::NextRatingCategory` (r:1 w:1)
+	/// Proof: `NetworkScore::NextRatingCategory` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `NetworkScore::RatingCategories` (r:0 w:1)
+	/// Proof: `NetworkScore::RatingCategories` (`max_values`: None, `max_size`: Some(201), added: 2676, mode: `MaxEncodedLen`)
+	fn register_network_rating_category() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `499`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(12_000_000, 499)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 }
 
@@ -148,22 +184,24 @@ impl WeightInfo for () {
 	/// Storage: `NetworkScore::RatingEntries` (r:1 w:1)
 	/// Proof: `NetworkScore::RatingEntries` (`max_values`: None, `max_size`: Some(679), added: 3154, mode: `MaxEncodedLen`)
 	/// Storage: `NetworkScore::AggregateScores` (r:1 w:1)
-	/// Proof: `NetworkScore::AggregateScores` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	/// Proof: `NetworkScore::AggregateScores` (`max_values`: None, `max_size`: Some(174), added: 2649, mode: `MaxEncodedLen`)
+	/// Storage: `NetworkScore::EpochScores` (r:1 w:1)
+	/// Proof: `NetworkScore::EpochScores` (`max_values`: None, `max_size`: Some(8945), added: 11420, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
-	/// The range of component `l` is `[1, 15360]`.
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[1, 128]`.
 	fn register_rating(l: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `801`
-		//  Estimated: `4144`
-		// Minimum execution time: 51_020_000 picoseconds.
-		Weight::from_parts(53_034_543, 4144)
-			// Standard Error: 6
+		//  Estimated: `28335`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(53_034_543, 28335)
 			.saturating_add(Weight::from_parts(9, 0).saturating_mul(l.into()))
-			.saturating_add(RocksDbWeight::get().reads(7_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -174,22 +212,24 @@ impl WeightInfo for () {
 	/// Storage: `NetworkScore::MessageIdentifiers` (r:1 w:1)
 	/// Proof: `NetworkScore::MessageIdentifiers` (`max_values`: None, `max_size`: Some(236), added: 2711, mode: `MaxEncodedLen`)
 	/// Storage: `NetworkScore::AggregateScores` (r:1 w:1)
-	/// Proof: `NetworkScore::AggregateScores` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	/// Proof: `NetworkScore::AggregateScores` (`max_values`: None, `max_size`: Some(174), added: 2649, mode: `MaxEncodedLen`)
+	/// Storage: `NetworkScore::EpochScores` (r:1 w:1)
+	/// Proof: `NetworkScore::EpochScores` (`max_values`: None, `max_size`: Some(8945), added: 11420, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:2 w:2)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
-	/// The range of component `l` is `[1, 15360]`.
+	/// Storage: `Identifier::TimelineLength` (r:2 w:2)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:2)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[1, 128]`.
 	fn revoke_rating(l: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1328`
-		//  Estimated: `7298`
-		// Minimum execution time: 66_730_000 picoseconds.
-		Weight::from_parts(69_315_996, 7298)
-			// Standard Error: 8
+		//  Estimated: `34047`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(69_315_996, 34047)
 			.saturating_add(Weight::from_parts(21, 0).saturating_mul(l.into()))
-			.saturating_add(RocksDbWeight::get().reads(9_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -200,21 +240,51 @@ impl WeightInfo for () {
 	/// Storage: `NetworkScore::MessageIdentifiers` (r:1 w:1)
 	/// Proof: `NetworkScore::MessageIdentifiers` (`max_values`: None, `max_size`: Some(236), added: 2711, mode: `MaxEncodedLen`)
 	/// Storage: `NetworkScore::AggregateScores` (r:1 w:1)
-	/// Proof: `NetworkScore::AggregateScores` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	/// Proof: `NetworkScore::AggregateScores` (`max_values`: None, `max_size`: Some(174), added: 2649, mode: `MaxEncodedLen`)
+	/// Storage: `NetworkScore::EpochScores` (r:1 w:1)
+	/// Proof: `NetworkScore::EpochScores` (`max_values`: None, `max_size`: Some(8945), added: 11420, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:2 w:2)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
-	/// The range of component `l` is `[1, 15360]`.
+	/// Storage: `Identifier::TimelineLength` (r:2 w:2)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:2)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[1, 128]`.
 	fn revise_rating(l: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1427`
-		//  Estimated: `7298`
-		// Minimum execution time: 68_890_000 picoseconds.
-		Weight::from_parts(70_972_982, 7298)
-			// Standard Error: 8
+		//  Estimated: `34047`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(70_972_982, 34047)
 			.saturating_add(Weight::from_parts(37, 0).saturating_mul(l.into()))
-			.saturating_add(RocksDbWeight::get().reads(9_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
+	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Spaces` (r:1 w:0)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `NetworkScore::NextRatingCategory` (r:1 w:1)
+	/// Proof: `NetworkScore::NextRatingCategory` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `NetworkScore::RatingCategories` (r:0 w:1)
+	/// Proof: `NetworkScore::RatingCategories` (`max_values`: None, `max_size`: Some(201), added: 2676, mode: `MaxEncodedLen`)
+	fn register_rating_category() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `5839`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(22_000_000, 5839)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NetworkScore::NextRatingCategory` (r:1 w:1)
+	/// Proof: `NetworkScore::NextRatingCategory` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `NetworkScore::RatingCategories` (r:0 w:1)
+	/// Proof: `NetworkScore::RatingCategories` (`max_values`: None, `max_size`: Some(201), added: 2676, mode: `MaxEncodedLen`)
+	fn register_network_rating_category() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `499`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(12_000_000, 499)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 }
@@ -0,0 +1,357 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use codec::Encode;
+use cord_utilities::traits::GenerateBenchmarkOrigin;
+use frame_benchmarking::{account, benchmarks};
+use frame_support::{
+	sp_runtime::{traits::Hash, DispatchError},
+	traits::Get,
+	BoundedVec,
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use sp_std::{convert::TryFrom, vec};
+
+const SEED: u32 = 0;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+pub fn generate_registry_id<T: Config>(digest: &RegistryHashOf<T>) -> RegistryIdOf {
+	Ss58Identifier::create_identifier(&(digest).encode()[..], IdentifierType::Registries).unwrap()
+}
+
+pub fn generate_authorization_id<T: Config>(digest: &RegistryHashOf<T>) -> AuthorizationIdOf {
+	Ss58Identifier::create_identifier(&(digest).encode()[..], IdentifierType::RegistryAuthorization)
+		.unwrap()
+}
+
+/// Generates a registry blob of `length` bytes.
+pub fn generate_blob<T: Config>(length: u32, fill: u8) -> RegistryBlobOf<T> {
+	BoundedVec::try_from(vec![fill; length as usize])
+		.expect("Test blob should fit into the expected input length of the test runtime.")
+}
+
+/// Creates a registry of `creator` with a blob of `length` bytes, deposited
+/// by `caller`, returning the identifier of the registry and the authorization
+/// of its creator.
+fn create_registry<T: Config>(
+	caller: &T::AccountId,
+	creator: &RegistryCreatorOf<T>,
+	length: u32,
+) -> Result<(RegistryIdOf, AuthorizationIdOf), DispatchError>
+where
+	<T as Config>::EnsureOrigin:
+		GenerateBenchmarkOrigin<T::RuntimeOrigin, T::AccountId, T::RegistryCreatorId>,
+{
+	let blob = generate_blob::<T>(length, 1);
+	let digest = <T as frame_system::Config>::Hashing::hash(&blob[..]);
+	let id_digest = <T as frame_system::Config>::Hashing::hash(
+		&[&digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let registry_id: RegistryIdOf = generate_registry_id::<T>(&id_digest);
+	let authorization_id = delegate_authorization_id::<T>(&registry_id, creator, creator);
+
+	T::Consideration::ensure_successful(caller, Footprint::from_parts(1, length as usize));
+	Pallet::<T>::create(
+		<T as Config>::EnsureOrigin::generate_origin(caller.clone(), creator.clone()),
+		registry_id.clone(),
+		digest,
+		None,
+		Some(blob),
+	)?;
+
+	Ok((registry_id, authorization_id))
+}
+
+/// Returns the authorization of `delegate` on a registry, as delegated by `creator`.
+pub fn delegate_authorization_id<T: Config>(
+	registry_id: &RegistryIdOf,
+	delegate: &RegistryCreatorOf<T>,
+	creator: &RegistryCreatorOf<T>,
+) -> AuthorizationIdOf {
+	let digest = <T as frame_system::Config>::Hashing::hash(
+		&[&registry_id.encode()[..], &delegate.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	generate_authorization_id::<T>(&digest)
+}
+
+/// Queues `len` delegations without limits to be pruned at block `at`.
+pub fn fill_expirations<T: Config>(at: BlockNumberFor<T>, len: u32) {
+	let ids: Vec<AuthorizationIdOf> = (0..len)
+		.map(|i| {
+			let digest = <T as frame_system::Config>::Hashing::hash(&i.encode()[..]);
+			generate_authorization_id::<T>(&digest)
+		})
+		.collect();
+	AuthorizationExpirations::<T>::insert(
+		at,
+		BoundedVec::try_from(ids).expect("Expirations should fit."),
+	);
+}
+
+/// Creates a schema of `creator`, returning its identifier.
+pub fn create_schema<T: Config>(creator: &T::AccountId) -> Result<SchemaIdOf, DispatchError> {
+	let schema: pallet_schema_accounts::InputSchemaOf<T> = BoundedVec::try_from(vec![b'a'; 64])
+		.expect("Test schema should fit into the expected input length of the test runtime.");
+	let id_digest = <T as frame_system::Config>::Hashing::hash(&schema.encode()[..]);
+	let schema_id =
+		Ss58Identifier::create_identifier(&id_digest.encode()[..], IdentifierType::SchemaAccounts)
+			.unwrap();
+
+	pallet_schema_accounts::Pallet::<T>::create(RawOrigin::Signed(creator.clone()).into(), schema)?;
+
+	Ok(schema_id)
+}
+
+benchmarks! {
+	where_clause {
+		where
+		<T as Config>::EnsureOrigin: GenerateBenchmarkOrigin<T::RuntimeOrigin, T::AccountId, T::RegistryCreatorId>,
+	}
+
+	add_delegate {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let creator: T::RegistryCreatorId = account("did", 0, SEED);
+		let delegate: T::RegistryCreatorId = account("did", 1, SEED);
+		let (registry_id, authorization_id) = create_registry::<T>(&caller, &creator, 1)?;
+		let delegate_authorization_id =
+			delegate_authorization_id::<T>(&registry_id, &delegate, &creator);
+		let expires_at = frame_system::Pallet::<T>::block_number() + BlockNumberFor::<T>::from(10u32);
+		let origin = <T as Config>::EnsureOrigin::generate_origin(caller, creator.clone());
+	}: _<T::RuntimeOrigin>(origin, registry_id.clone(), delegate.clone(), authorization_id, Some(expires_at), Some(1))
+	verify {
+		assert_last_event::<T>(Event::<T>::Authorization { registry_id, authorization: delegate_authorization_id, delegate }.into());
+	}
+
+	add_admin_delegate {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let creator: T::RegistryCreatorId = account("did", 0, SEED);
+		let delegate: T::RegistryCreatorId = account("did", 1, SEED);
+		let (registry_id, authorization_id) = create_registry::<T>(&caller, &creator, 1)?;
+		let delegate_authorization_id =
+			delegate_authorization_id::<T>(&registry_id, &delegate, &creator);
+		let expires_at = frame_system::Pallet::<T>::block_number() + BlockNumberFor::<T>::from(10u32);
+		let origin = <T as Config>::EnsureOrigin::generate_origin(caller, creator.clone());
+	}: _<T::RuntimeOrigin>(origin, registry_id.clone(), delegate.clone(), authorization_id, Some(expires_at), Some(1))
+	verify {
+		assert_last_event::<T>(Event::<T>::Authorization { registry_id, authorization: delegate_authorization_id, delegate }.into());
+	}
+
+	add_delegator {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let creator: T::RegistryCreatorId = account("did", 0, SEED);
+		let delegate: T::RegistryCreatorId = account("did", 1, SEED);
+		let (registry_id, authorization_id) = create_registry::<T>(&caller, &creator, 1)?;
+		let delegate_authorization_id =
+			delegate_authorization_id::<T>(&registry_id, &delegate, &creator);
+		let expires_at = frame_system::Pallet::<T>::block_number() + BlockNumberFor::<T>::from(10u32);
+		let origin = <T as Config>::EnsureOrigin::generate_origin(caller, creator.clone());
+	}: _<T::RuntimeOrigin>(origin, registry_id.clone(), delegate.clone(), authorization_id, Some(expires_at), Some(1))
+	verify {
+		assert_last_event::<T>(Event::<T>::Authorization { registry_id, authorization: delegate_authorization_id, delegate }.into());
+	}
+
+	remove_delegate {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let creator: T::RegistryCreatorId = account("did", 0, SEED);
+		let delegate: T::RegistryCreatorId = account("did", 1, SEED);
+		let (registry_id, authorization_id) = create_registry::<T>(&caller, &creator, 1)?;
+		Pallet::<T>::add_delegate(
+			<T as Config>::EnsureOrigin::generate_origin(caller.clone(), creator.clone()),
+			registry_id.clone(),
+			delegate.clone(),
+			authorization_id.clone(),
+			None,
+			Some(1),
+		)?;
+		let delegate_authorization_id =
+			delegate_authorization_id::<T>(&registry_id, &delegate, &creator);
+		let origin = <T as Config>::EnsureOrigin::generate_origin(caller, creator.clone());
+	}: _<T::RuntimeOrigin>(origin, registry_id.clone(), delegate_authorization_id.clone(), authorization_id)
+	verify {
+		assert_last_event::<T>(Event::<T>::Deauthorization { registry_id, authorization: delegate_authorization_id }.into());
+	}
+
+	create {
+		let l in 1 .. T::MaxRegistryBlobSize::get();
+
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let creator: T::RegistryCreatorId = account("did", 0, SEED);
+		let blob = generate_blob::<T>(l, 1);
+		let digest = <T as frame_system::Config>::Hashing::hash(&blob[..]);
+		let id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&digest.encode()[..], &creator.encode()[..]].concat()[..],
+		);
+		let registry_id: RegistryIdOf = generate_registry_id::<T>(&id_digest);
+		let authorization_id = delegate_authorization_id::<T>(&registry_id, &creator, &creator);
+
+		T::Consideration::ensure_successful(&caller, Footprint::from_parts(1, l as usize));
+		let origin = <T as Config>::EnsureOrigin::generate_origin(caller, creator.clone());
+	}: _<T::RuntimeOrigin>(origin, registry_id.clone(), digest, None, Some(blob))
+	verify {
+		assert_last_event::<T>(Event::<T>::Create { registry_id, creator, authorization: authorization_id }.into());
+	}
+
+	revoke {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let creator: T::RegistryCreatorId = account("did", 0, SEED);
+		let (registry_id, authorization_id) = create_registry::<T>(&caller, &creator, 1)?;
+		let origin = <T as Config>::EnsureOrigin::generate_origin(caller, creator.clone());
+	}: _<T::RuntimeOrigin>(origin, registry_id.clone(), authorization_id)
+	verify {
+		assert_last_event::<T>(Event::<T>::Revoke { registry_id, authority: creator }.into());
+	}
+
+	reinstate {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let creator: T::RegistryCreatorId = account("did", 0, SEED);
+		let (registry_id, authorization_id) = create_registry::<T>(&caller, &creator, 1)?;
+		Pallet::<T>::revoke(
+			<T as Config>::EnsureOrigin::generate_origin(caller.clone(), creator.clone()),
+			registry_id.clone(),
+			authorization_id.clone(),
+		)?;
+		let origin = <T as Config>::EnsureOrigin::generate_origin(caller, creator.clone());
+	}: _<T::RuntimeOrigin>(origin, registry_id.clone(), authorization_id)
+	verify {
+		assert_last_event::<T>(Event::<T>::Reinstate { registry_id, authority: creator }.into());
+	}
+
+	update {
+		let l in 1 .. T::MaxRegistryBlobSize::get();
+
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let creator: T::RegistryCreatorId = account("did", 0, SEED);
+		let (registry_id, authorization_id) = create_registry::<T>(&caller, &creator, 1)?;
+
+		/* The previous blob of the registry is released and a new one stored */
+		let blob = generate_blob::<T>(l, 2);
+		let digest = <T as frame_system::Config>::Hashing::hash(&blob[..]);
+		T::Consideration::ensure_successful(&caller, Footprint::from_parts(1, l as usize));
+		let origin = <T as Config>::EnsureOrigin::generate_origin(caller, creator.clone());
+	}: _<T::RuntimeOrigin>(origin, registry_id.clone(), digest, Some(blob), authorization_id.clone())
+	verify {
+		assert_last_event::<T>(Event::<T>::Update { registry_id, updater: creator, authorization: authorization_id }.into());
+	}
+
+	archive {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let creator: T::RegistryCreatorId = account("did", 0, SEED);
+		let (registry_id, authorization_id) = create_registry::<T>(&caller, &creator, 1)?;
+		let origin = <T as Config>::EnsureOrigin::generate_origin(caller, creator.clone());
+	}: _<T::RuntimeOrigin>(origin, registry_id.clone(), authorization_id)
+	verify {
+		assert_last_event::<T>(Event::<T>::Archive { registry_id, authority: creator }.into());
+	}
+
+	restore {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let creator: T::RegistryCreatorId = account("did", 0, SEED);
+		let (registry_id, authorization_id) = create_registry::<T>(&caller, &creator, 1)?;
+		Pallet::<T>::archive(
+			<T as Config>::EnsureOrigin::generate_origin(caller.clone(), creator.clone()),
+			registry_id.clone(),
+			authorization_id.clone(),
+		)?;
+		let origin = <T as Config>::EnsureOrigin::generate_origin(caller, creator.clone());
+	}: _<T::RuntimeOrigin>(origin, registry_id.clone(), authorization_id)
+	verify {
+		assert_last_event::<T>(Event::<T>::Restore { registry_id, authority: creator }.into());
+	}
+
+	update_schema {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let creator: T::RegistryCreatorId = account("did", 0, SEED);
+		let (registry_id, authorization_id) = create_registry::<T>(&caller, &creator, 1)?;
+		let schema_id = create_schema::<T>(&caller)?;
+		let origin = <T as Config>::EnsureOrigin::generate_origin(caller, creator.clone());
+	}: _<T::RuntimeOrigin>(origin, registry_id.clone(), schema_id.clone(), true, authorization_id)
+	verify {
+		assert_last_event::<T>(Event::<T>::SchemaUpdate { registry_id, schema_id, enforced: true, authority: creator }.into());
+	}
+
+	consume_delegation {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let creator: T::RegistryCreatorId = account("did", 0, SEED);
+		let delegate: T::RegistryCreatorId = account("did", 1, SEED);
+		let (registry_id, authorization_id) = create_registry::<T>(&caller, &creator, 1)?;
+		let delegate_authorization_id =
+			delegate_authorization_id::<T>(&registry_id, &delegate, &creator);
+		let origin = <T as Config>::EnsureOrigin::generate_origin(caller, creator);
+		Pallet::<T>::add_delegate(origin, registry_id, delegate, authorization_id, None, Some(1))?;
+
+		// The use exhausts the quota, which queues the delegation in an almost full bucket.
+		let prune_at = frame_system::Pallet::<T>::block_number() + One::one();
+		fill_expirations::<T>(prune_at, T::MaxDelegationExpirationsPerBlock::get() - 1);
+	}: { Pallet::<T>::consume_delegation(&delegate_authorization_id).expect("Delegation should be usable.") }
+	verify {
+		assert_eq!(
+			AuthorizationExpirations::<T>::get(prune_at).len() as u32,
+			T::MaxDelegationExpirationsPerBlock::get()
+		);
+	}
+
+	visit_expiration_block {
+		let block = frame_system::Pallet::<T>::block_number();
+		fill_expirations::<T>(block, T::MaxDelegationExpirationsPerBlock::get());
+		ExpirationCursor::<T>::put(block);
+
+		// Only leave room for visiting the block, so the full bucket is read and written back.
+		let limit = <T as Config>::WeightInfo::visit_expiration_block();
+	}: { Pallet::<T>::prune_lapsed_delegations(block, limit) }
+	verify {
+		assert_eq!(
+			AuthorizationExpirations::<T>::get(block).len() as u32,
+			T::MaxDelegationExpirationsPerBlock::get()
+		);
+	}
+
+	prune_lapsed_delegation {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let creator: T::RegistryCreatorId = account("did", 0, SEED);
+		let delegate: T::RegistryCreatorId = account("did", 1, SEED);
+		let (registry_id, authorization_id) = create_registry::<T>(&caller, &creator, 1)?;
+		let delegate_authorization_id =
+			delegate_authorization_id::<T>(&registry_id, &delegate, &creator);
+		let expires_at = frame_system::Pallet::<T>::block_number() + One::one();
+		let origin = <T as Config>::EnsureOrigin::generate_origin(caller, creator);
+		Pallet::<T>::add_delegate(origin, registry_id.clone(), delegate.clone(), authorization_id, Some(expires_at), None)?;
+
+		// The lapsed delegate is found at the end of a full list of delegates.
+		let mut delegates: Vec<T::RegistryCreatorId> = (2..T::MaxRegistryDelegates::get())
+			.map(|i| account("did", i, SEED))
+			.collect();
+		delegates.push(delegate.clone());
+		Delegates::<T>::insert(
+			&registry_id,
+			BoundedVec::try_from(delegates).expect("Delegates should fit."),
+		);
+		frame_system::Pallet::<T>::set_block_number(expires_at);
+	}: { Pallet::<T>::prune_delegation(delegate_authorization_id.clone(), &expires_at) }
+	verify {
+		assert!(!Pallet::<T>::is_a_delegate(&registry_id, delegate));
+		assert_last_event::<T>(Event::<T>::AuthorizationLapsed { registry_id, authorization: delegate_authorization_id }.into());
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
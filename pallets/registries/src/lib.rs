@@ -33,6 +33,21 @@
 //! blockchain environment. Registry are created with a unique identifier and can
 //! be managed by appointed delegates.
 //!
+//! Calls are authorized through `EnsureOrigin`, so registries are owned and
+//! delegated to by the subject of the origin, such as a DID dispatching through
+//! `pallet_did::submit_did_call`, rather than by the account submitting the
+//! transaction.
+//!
+//! ### Upgrading from account-owned registries
+//!
+//! Registries created before ownership moved to DIDs record the account that
+//! created them, and the accounts they were delegated to, as their creator and
+//! delegates. As a `DidIdentifier` is an `AccountId`, these records decode
+//! unchanged as the DIDs sharing the identifier of those accounts, and no
+//! storage migration is needed. An account keeps control of its registries
+//! and authorizations by creating its DID with `pallet_did::create_from_account`
+//! and dispatching through it.
+//!
 //! ## Interface
 //!
 //! The pallet provides dispatchable functions for registry management:
@@ -43,6 +58,8 @@
 //! - `reinstate`: Changes the status of the registry, returning it to active status.
 //! - `archive`: Marks a registry as archived, effectively changing it to archived status.
 //! - `restore`: Changes the status of the registry, returning it to non-archival status.
+//! - `update_schema`: Links the registry to a newer schema, optionally requiring entries to
+//!   declare conformance to it.
 //! - `add_delegate`: Adds a delegate to a registry, granting them specific permissions.
 //! - `add_admin_delegate`: Adds an admin delegate to a registry, granting them administrative
 //!   permissions.
@@ -56,6 +73,29 @@
 //! can perform within a registry. Permissions are granular and can be assigned to
 //! different roles, such as an admin or a regular delegate.
 //!
+//! ## Delegation Limits
+//!
+//! Registry delegations may carry an expiry block and a quota of authorized
+//! operations. A delegation that has lapsed or used up its quota can no
+//! longer be used, and is removed by the `on_initialize` hook of the pallet.
+//!
+//! ## Blob Storage
+//!
+//! A registry may carry a blob, such as the list of accredited labs it
+//! describes, so that small registries can be read from the chain without an
+//! off-chain store. The blob must hash to the digest of the registry and is
+//! stored under that digest, bounded by `MaxRegistryBlobSize`. The account
+//! storing a blob holds a deposit through `Consideration`, which is released
+//! once no registry or registry entry references the blob anymore. Registry
+//! entries store their blobs the same way, backed by the same deposit.
+//!
+//! ## Schema Enforcement
+//!
+//! A registry may require every entry to declare conformance to its schema,
+//! whose existence is verified through the Schema Accounts pallet. A registry
+//! can migrate to a newer schema, after which existing entries remain pinned
+//! to the schema they declared conformance to.
+//!
 //! ## Data Privacy
 //!
 //! The Registries pallet is designed with data privacy as a core consideration.
@@ -95,15 +135,31 @@ pub mod mock;
 #[cfg(test)]
 mod tests;
 
-use frame_support::{ensure, storage::types::StorageMap, BoundedVec};
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+use frame_support::{
+	ensure,
+	pallet_prelude::{DispatchResult, Weight},
+	storage::types::StorageMap,
+	traits::{Consideration, Footprint, Get},
+	weights::WeightMeter,
+	BoundedVec,
+};
 pub mod types;
 pub use crate::{pallet::*, types::*};
 use codec::Encode;
+use cord_utilities::delegation::{remove_delegate, DelegationLimits, DelegationLimitsError};
+use frame_system::pallet_prelude::BlockNumberFor;
 use identifier::{
 	types::{CallTypeOf, IdentifierTypeOf, Timepoint},
 	EventEntryOf,
 };
-use sp_runtime::traits::{Hash, UniqueSaturatedInto};
+use sp_runtime::traits::{Hash, One, Saturating, UniqueSaturatedInto};
+use sp_std::vec::Vec;
 
 /// Authorization Identifier
 pub type AuthorizationIdOf = Ss58Identifier;
@@ -111,8 +167,10 @@ pub type AuthorizationIdOf = Ss58Identifier;
 pub type RegistryIdOf = Ss58Identifier;
 /// Tyoe of the Registry Digest
 pub type RegistryHashOf<T> = <T as frame_system::Config>::Hash;
+/// Type of a CORD account.
+pub(crate) type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 /// Type of the Registry Creator
-pub type RegistryCreatorOf<T> = <T as frame_system::Config>::AccountId;
+pub type RegistryCreatorOf<T> = <T as Config>::RegistryCreatorId;
 /// Type of the Registry Template Id
 pub type TemplateIdOf<T> = BoundedVec<u8, <T as Config>::MaxEncodedInputLength>;
 /// Type of the Schema Id
@@ -121,9 +179,14 @@ pub type SchemaIdOf = Ss58Identifier;
 pub type MaxRegistryBlobSizeOf<T> = <T as crate::Config>::MaxRegistryBlobSize;
 /// Type of Registry Blob
 pub type RegistryBlobOf<T> = BoundedVec<u8, MaxRegistryBlobSizeOf<T>>;
+/// Type of a Registry Blob stored on chain, with its deposit
+pub type RegistryBlobDetailsOf<T> =
+	BlobDetails<RegistryBlobOf<T>, AccountIdOf<T>, <T as Config>::Consideration>;
 /// Type of the Registry Authorization Details
 pub type RegistryAuthorizationOf<T> =
 	RegistryAuthorization<RegistryIdOf, RegistryCreatorOf<T>, Permissions>;
+/// Type of the limits attached to a registry delegation.
+pub type DelegationLimitsOf<T> = DelegationLimits<BlockNumberFor<T>>;
 /// Type of Registry Details
 pub type RegistryDetailsOf<T> =
 	RegistryDetails<RegistryCreatorOf<T>, StatusOf, RegistryHashOf<T>, SchemaIdOf>;
@@ -132,9 +195,9 @@ pub type RegistryDetailsOf<T> =
 pub mod pallet {
 	use super::*;
 	pub use cord_primitives::{IsPermissioned, StatusOf};
+	use cord_utilities::traits::CallSources;
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
-	pub use frame_system::WeightInfo;
 	pub use identifier::{
 		CordIdentifierType, IdentifierCreator, IdentifierTimeline, IdentifierType, Ss58Identifier,
 	};
@@ -143,8 +206,16 @@ pub mod pallet {
 	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
 	#[pallet::config]
-	pub trait Config: frame_system::Config + identifier::Config {
+	pub trait Config:
+		frame_system::Config + identifier::Config + pallet_schema_accounts::Config
+	{
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		type EnsureOrigin: EnsureOrigin<
+			<Self as frame_system::Config>::RuntimeOrigin,
+			Success = <Self as Config>::OriginSuccess,
+		>;
+		type OriginSuccess: CallSources<AccountIdOf<Self>, RegistryCreatorOf<Self>>;
+		type RegistryCreatorId: Parameter + MaxEncodedLen;
 
 		#[pallet::constant]
 		type MaxRegistryDelegates: Get<u32>;
@@ -155,6 +226,13 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxEncodedInputLength: Get<u32>;
 
+		/// The maximum number of delegations that can lapse in a single block.
+		#[pallet::constant]
+		type MaxDelegationExpirationsPerBlock: Get<u32>;
+
+		/// The deposit held for blobs stored on chain.
+		type Consideration: Consideration<Self::AccountId, Footprint>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -164,7 +242,11 @@ pub mod pallet {
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::prune_lapsed_delegations(n, remaining_weight)
+		}
+	}
 
 	/// Registry information stored on chain.
 	/// It maps from an identifier to its details.
@@ -172,6 +254,18 @@ pub mod pallet {
 	pub type RegistryInfo<T> =
 		StorageMap<_, Blake2_128Concat, RegistryIdOf, RegistryDetailsOf<T>, OptionQuery>;
 
+	/// Registry blobs stored on chain.
+	/// It maps from a blob digest to the blob and its deposit.
+	#[pallet::storage]
+	pub type RegistryBlobs<T> =
+		StorageMap<_, Identity, RegistryHashOf<T>, RegistryBlobDetailsOf<T>, OptionQuery>;
+
+	/// Digests of the blobs referenced by registries.
+	/// It maps from a registry identifier to the digest of its blob.
+	#[pallet::storage]
+	pub type RegistryBlobDigests<T> =
+		StorageMap<_, Blake2_128Concat, RegistryIdOf, RegistryHashOf<T>, OptionQuery>;
+
 	/// Registry authorizations stored on-chain.
 	/// It maps from an identifier to delegates.
 	#[pallet::storage]
@@ -190,6 +284,40 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Limits of time-limited or quota-limited registry delegations.
+	/// It maps from an authorization identifier to its expiry and quota.
+	#[pallet::storage]
+	pub type AuthorizationLimits<T> =
+		StorageMap<_, Blake2_128Concat, AuthorizationIdOf, DelegationLimitsOf<T>, OptionQuery>;
+
+	/// maps block number to the list of delegations to be pruned at this block
+	#[pallet::storage]
+	pub type AuthorizationExpirations<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<AuthorizationIdOf, T::MaxDelegationExpirationsPerBlock>,
+		ValueQuery,
+	>;
+
+	/// The earliest block whose queued delegations have not all been pruned
+	/// yet.
+	#[pallet::storage]
+	pub type ExpirationCursor<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+	/// Registries requiring their entries to declare conformance to the
+	/// schema of the registry.
+	#[pallet::storage]
+	pub type SchemaEnforcement<T> = StorageMap<_, Blake2_128Concat, RegistryIdOf, bool, ValueQuery>;
+
+	/// A reason for the pallet placing a hold on funds.
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		/// Funds are held for a blob stored on chain.
+		#[codec(index = 0)]
+		BlobStorage,
+	}
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -229,6 +357,17 @@ pub mod pallet {
 		/// A registry has been restored.
 		/// \[registry identifier, authority\]
 		Restore { registry_id: RegistryIdOf, authority: RegistryCreatorOf<T> },
+		/// The schema of a registry has been updated.
+		/// \[registry identifier, schema identifier, enforced, authority\]
+		SchemaUpdate {
+			registry_id: RegistryIdOf,
+			schema_id: SchemaIdOf,
+			enforced: StatusOf,
+			authority: RegistryCreatorOf<T>,
+		},
+		/// An expired or exhausted registry authorization has been pruned.
+		/// \[registry identifier, authorization\]
+		AuthorizationLapsed { registry_id: RegistryIdOf, authorization: AuthorizationIdOf },
 	}
 
 	#[pallet::error]
@@ -244,6 +383,10 @@ pub mod pallet {
 		InvalidIdentifier,
 		/// Invalid Identifier Length
 		InvalidIdentifierLength,
+		/// Registry identifier is not a valid SS58 identifier of type `Registries`
+		InvalidRegistryIdentifier,
+		/// Registry identifier does not match the one derived from the digest and creator
+		RegistryIdentifierMismatch,
 		/// Registry delegation limit exceeded
 		RegistryDelegatesLimitExceeded,
 		/// Authority already added
@@ -264,6 +407,18 @@ pub mod pallet {
 		RegistryAlreadyArchived,
 		/// Registry not archived.
 		RegistryArchived,
+		/// The delegation has expired
+		AuthorizationExpired,
+		/// The delegate has used up its quota of authorized operations
+		AuthorizationQuotaExhausted,
+		/// The delegation expiry must be a future block
+		InvalidDelegationExpiry,
+		/// The delegation quota must be greater than zero
+		InvalidDelegationQuota,
+		/// Too many delegations are set to lapse at the given block
+		MaxDelegationExpirationsExceeded,
+		/// The blob does not hash to the supplied digest
+		BlobDigestMismatch,
 	}
 
 	#[pallet::call]
@@ -288,6 +443,8 @@ pub mod pallet {
 		///   permission.
 		/// - `authorization`: The authorization ID used to validate the caller's permission to add
 		///   a delegate.
+		/// - `expires_at`: (Optional) The block from which the delegation is no longer usable.
+		/// - `quota`: (Optional) The number of authorized operations granted to the delegate.
 		///
 		/// # Returns
 		/// Returns `Ok(())` if the delegate is successfully added with `ASSERT`
@@ -299,21 +456,30 @@ pub mod pallet {
 		///   for the registry.
 		/// - Propagates errors from `registry_delegate_addition` if the addition fails.
 		#[pallet::call_index(0)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_delegate().saturating_add(Pallet::<T>::authorization_weight()))]
 		pub fn add_delegate(
 			origin: OriginFor<T>,
 			registry_id: RegistryIdOf,
 			delegate: RegistryCreatorOf<T>,
 			authorization: AuthorizationIdOf,
+			expires_at: Option<BlockNumberFor<T>>,
+			quota: Option<u32>,
 		) -> DispatchResult {
-			let creator = ensure_signed(origin)?;
+			let creator = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
 
 			let auth_registry_id =
 				Self::ensure_authorization_delegator_origin(&authorization, &creator)?;
 			ensure!(auth_registry_id == registry_id, Error::<T>::UnauthorizedOperation);
 
 			let permissions = Permissions::ASSERT;
-			Self::registry_delegate_addition(auth_registry_id, delegate, creator, permissions)?;
+			Self::registry_delegate_addition(
+				auth_registry_id,
+				delegate,
+				creator,
+				permissions,
+				expires_at,
+				quota,
+			)?;
 
 			Ok(())
 		}
@@ -338,6 +504,8 @@ pub mod pallet {
 		/// - `delegate`: The account identifier of the delegate being granted admin permissions.
 		/// - `authorization`: The authorization ID used to validate the caller's permission to add
 		///   an admin delegate to the specified registry.
+		/// - `expires_at`: (Optional) The block from which the delegation is no longer usable.
+		/// - `quota`: (Optional) The number of authorized operations granted to the delegate.
 		///
 		/// # Returns
 		/// Returns `Ok(())` if the admin delegate is successfully added, or an `Err`
@@ -349,14 +517,16 @@ pub mod pallet {
 		///   registry.
 		/// - Propagates errors from `registry_delegate_addition` if delegate addition fails.
 		#[pallet::call_index(1)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_admin_delegate().saturating_add(Pallet::<T>::authorization_weight()))]
 		pub fn add_admin_delegate(
 			origin: OriginFor<T>,
 			registry_id: RegistryIdOf,
 			delegate: RegistryCreatorOf<T>,
 			authorization: AuthorizationIdOf,
+			expires_at: Option<BlockNumberFor<T>>,
+			quota: Option<u32>,
 		) -> DispatchResult {
-			let creator = ensure_signed(origin)?;
+			let creator = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
 
 			let auth_registry_id =
 				Self::ensure_authorization_admin_origin(&authorization, &creator)?;
@@ -364,7 +534,14 @@ pub mod pallet {
 			ensure!(auth_registry_id == registry_id, Error::<T>::UnauthorizedOperation);
 
 			let permissions = Permissions::ADMIN;
-			Self::registry_delegate_addition(auth_registry_id, delegate, creator, permissions)?;
+			Self::registry_delegate_addition(
+				auth_registry_id,
+				delegate,
+				creator,
+				permissions,
+				expires_at,
+				quota,
+			)?;
 
 			Ok(())
 		}
@@ -387,6 +564,8 @@ pub mod pallet {
 		/// - `delegate`: The account identifier of the delegate being granted audit permissions.
 		/// - `authorization`: The authorization ID used to validate the caller's permission to add
 		///   the audit delegate.
+		/// - `expires_at`: (Optional) The block from which the delegation is no longer usable.
+		/// - `quota`: (Optional) The number of authorized operations granted to the delegate.
 		///
 		/// # Returns
 		/// Returns `Ok(())` if the audit delegate is successfully added, or an `Err`
@@ -398,14 +577,16 @@ pub mod pallet {
 		///   for the registry.
 		/// - Propagates errors from `registry_delegate_addition` if delegate addition fails.
 		#[pallet::call_index(2)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_delegator().saturating_add(Pallet::<T>::authorization_weight()))]
 		pub fn add_delegator(
 			origin: OriginFor<T>,
 			registry_id: RegistryIdOf,
 			delegate: RegistryCreatorOf<T>,
 			authorization: AuthorizationIdOf,
+			expires_at: Option<BlockNumberFor<T>>,
+			quota: Option<u32>,
 		) -> DispatchResult {
-			let creator = ensure_signed(origin)?;
+			let creator = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
 
 			let auth_registry_id =
 				Self::ensure_authorization_admin_origin(&authorization, &creator)?;
@@ -413,7 +594,14 @@ pub mod pallet {
 			ensure!(auth_registry_id == registry_id, Error::<T>::UnauthorizedOperation);
 
 			let permissions = Permissions::DELEGATE;
-			Self::registry_delegate_addition(auth_registry_id, delegate, creator, permissions)?;
+			Self::registry_delegate_addition(
+				auth_registry_id,
+				delegate,
+				creator,
+				permissions,
+				expires_at,
+				quota,
+			)?;
 
 			Ok(())
 		}
@@ -454,14 +642,14 @@ pub mod pallet {
 		/// - `Deauthorization`: Emitted when a delegate is successfully removed from the registry.
 		///   The event includes the registry ID and the authorization ID of the removed delegate.
 		#[pallet::call_index(3)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_delegate().saturating_add(Pallet::<T>::authorization_weight()))]
 		pub fn remove_delegate(
 			origin: OriginFor<T>,
 			registry_id: RegistryIdOf,
 			remove_authorization: AuthorizationIdOf,
 			authorization: AuthorizationIdOf,
 		) -> DispatchResult {
-			let creator = ensure_signed(origin)?;
+			let creator = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
 			let auth_registry_id =
 				Self::ensure_authorization_admin_remove_origin(&authorization, &creator)?;
 
@@ -481,6 +669,7 @@ pub mod pallet {
 				Delegates::<T>::insert(&registry_id, delegates);
 
 				Authorizations::<T>::remove(&remove_authorization);
+				AuthorizationLimits::<T>::remove(&remove_authorization);
 
 				Self::update_activity(
 					&registry_id,
@@ -502,69 +691,81 @@ pub mod pallet {
 		/// Creates a new registry with a unique identifier based on the provided
 		/// registry digest and the creator's identity.
 		///
-		/// This function generates a unique identifier for the registry by hashing
-		/// the encoded digest of the registry and the creator's identifier. It ensures that the
-		/// generated registry identifier is not already in use. An authorization
+		/// This function validates the registry identifier supplied by the caller against the
+		/// one derived by hashing the encoded digest of the registry and the creator's
+		/// identifier. It ensures that the registry identifier is not already in use. An authorization
 		/// ID is also created for the new registry, which is used to manage
 		/// delegations. The creator is automatically added as a delegate with
 		/// full permissions.
 		///
 		/// # Parameters
 		/// - `origin`: The origin of the transaction, signed by the creator.
-		/// - `registry_id`: A unique code created to identify the registry.
+		/// - `registry_id`: A unique code created to identify the registry. It must be an SS58
+		///   identifier of type `Registries` derived from the digest and the creator.
 		/// - `digest`: The digest representing the registry data to be created.
 		/// - `schema_id`: (Optional) A unique code represnting the Schema.
-		/// - `blob`: (Optional) Metadata or data associated with the registry.
+		/// - `blob`: (Optional) Metadata or data associated with the registry. It is stored on
+		///   chain under the digest, which it must hash to, against a deposit held from the
+		///   account submitting the transaction.
 		///
 		/// # Returns
 		/// - `DispatchResult`: Returns `Ok(())` if the registry is successfully created, or an
 		///   error (`DispatchError`) if:
-		///   - The generated registry identifier is already in use.
+		///   - The registry identifier is malformed or not derived from the digest and creator.
+		///   - The registry identifier is already in use.
 		///   - The generated authorization ID has an invalid length.
 		///   - The registry exceeds the allowed delegate limit.
 		///
 		/// # Errors
 		/// - `InvalidIdentifierLength`: If the generated identifiers for the registry or
 		///   authorization have invalid lengths.
+		/// - `InvalidRegistryIdentifier`: If the registry identifier is not a valid SS58
+		///   identifier of type `Registries`.
+		/// - `RegistryIdentifierMismatch`: If the registry identifier is not the one derived from
+		///   the digest and the creator.
 		/// - `RegistryAlreadyAnchored`: If the registry identifier already exists.
 		/// - `RegistryDelegatesLimitExceeded`: If the registry exceeds the maximum number of
 		///   allowed delegates.
+		/// - `BlobDigestMismatch`: If the blob does not hash to the digest.
 		///
 		/// # Events
 		/// - `Create`: Emitted when a new registry is successfully created. It includes the
 		///   registry identifier, the creator's identifier, and the authorization ID.
 		#[pallet::call_index(4)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::create(blob.as_ref().map_or(0, |blob| blob.len() as u32)))]
 		pub fn create(
 			origin: OriginFor<T>,
-			_registry_id: RegistryIdOf,
+			registry_id: RegistryIdOf,
 			digest: RegistryHashOf<T>,
 			schema_id: Option<SchemaIdOf>,
-			_blob: Option<RegistryBlobOf<T>>,
+			blob: Option<RegistryBlobOf<T>>,
 		) -> DispatchResult {
-			let creator = ensure_signed(origin)?;
+			let tx_authors = <T as Config>::EnsureOrigin::ensure_origin(origin)?;
+			let creator = tx_authors.subject();
+			let depositor = tx_authors.sender();
+
+			/* Ensure that registry_id is of valid ss58 format,
+			 * and also the type matches to be of `Registries`.
+			 */
+			ensure!(
+				Self::is_valid_ss58_format(&registry_id),
+				Error::<T>::InvalidRegistryIdentifier
+			);
 
-			// TODO: Create the identifier at SDK level & validate at chain level.
 			// Id Digest = concat (H(<scale_encoded_registry_input_digest>,
 			// <scale_encoded_creator_identifier>))
 			let id_digest = <T as frame_system::Config>::Hashing::hash(
 				&[&digest.encode()[..], &creator.encode()[..]].concat()[..],
 			);
 
-			// /* Ensure that registry_id is of valid ss58 format,
-			//  * and also the type matches to be of `Registries`.
-			//  */
-			// ensure!(
-			// 	Self::is_valid_ss58_format(&registry_id),
-			// 	Error::<T>::InvalidRegistryIdentifier
-			// );
-
 			let identifier = Ss58Identifier::create_identifier(
 				&id_digest.encode()[..],
 				IdentifierType::Registries,
 			)
 			.map_err(|_| Error::<T>::InvalidIdentifierLength)?;
 
+			ensure!(identifier == registry_id, Error::<T>::RegistryIdentifierMismatch);
+
 			ensure!(
 				!<RegistryInfo<T>>::contains_key(&identifier),
 				Error::<T>::RegistryAlreadyAnchored
@@ -590,6 +791,10 @@ pub mod pallet {
 				.try_push(creator.clone())
 				.map_err(|_| Error::<T>::RegistryDelegatesLimitExceeded)?;
 
+			if let Some(blob) = blob {
+				Self::attach_blob(&identifier, &depositor, &digest, blob)?;
+			}
+
 			Delegates::<T>::insert(&identifier, delegates);
 
 			Authorizations::<T>::insert(
@@ -656,13 +861,13 @@ pub mod pallet {
 		/// - `Revoke`: Emitted when a registry is successfully revoked. It includes the registry ID
 		///   and the authority who performed the revocation.
 		#[pallet::call_index(6)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::revoke().saturating_add(Pallet::<T>::authorization_weight()))]
 		pub fn revoke(
 			origin: OriginFor<T>,
 			registry_id: RegistryIdOf,
 			authorization: AuthorizationIdOf,
 		) -> DispatchResult {
-			let creator = ensure_signed(origin)?;
+			let creator = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
 
 			let auth_registry_id =
 				Self::ensure_authorization_admin_origin(&authorization, &creator)?;
@@ -718,13 +923,13 @@ pub mod pallet {
 		/// - `Reinstate`: Emitted when a registry is successfully reinstated. It includes the
 		///   registry ID and the authority who performed the reinstatement.
 		#[pallet::call_index(7)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::reinstate().saturating_add(Pallet::<T>::authorization_weight()))]
 		pub fn reinstate(
 			origin: OriginFor<T>,
 			registry_id: RegistryIdOf,
 			authorization: AuthorizationIdOf,
 		) -> DispatchResult {
-			let creator = ensure_signed(origin)?;
+			let creator = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
 
 			let auth_registry_id =
 				Self::ensure_authorization_reinstate_origin(&authorization, &creator)?;
@@ -765,8 +970,9 @@ pub mod pallet {
 		///   admin with the appropriate authority.
 		/// - `registry_id`: The identifier of the registry to be updated.
 		/// - `digest`: The new digest (hash) to be assigned to the registry.
-		/// - `blob`: An optional new blob (data) to be assigned to the registry. If `None`, the
-		///   existing blob remains unchanged.
+		/// - `blob`: An optional new blob (data) to be assigned to the registry. It must hash to
+		///   the new digest and replaces the stored blob of the registry. If `None` and the digest
+		///   changes, the stored blob no longer matches the registry and is dropped.
 		/// - `authorization`: An identifier for the authorization being used to validate the
 		///   update.
 		///
@@ -780,20 +986,23 @@ pub mod pallet {
 		/// - `RegistryNotFound`: If the specified registry ID does not correspond to an existing
 		///   registry.
 		/// - `UnauthorizedOperation`: If the caller is not authorized to update the registry.
+		/// - `BlobDigestMismatch`: If the blob does not hash to the new digest.
 		///
 		/// # Events
 		/// - `Update`: Emitted when a registry is successfully updated. It includes the registry
 		///   ID, the updater, and the authorization used.
 		#[pallet::call_index(8)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::update(blob.as_ref().map_or(0, |blob| blob.len() as u32)).saturating_add(Pallet::<T>::authorization_weight()))]
 		pub fn update(
 			origin: OriginFor<T>,
 			registry_id: RegistryIdOf,
 			digest: RegistryHashOf<T>,
-			_blob: Option<RegistryBlobOf<T>>,
+			blob: Option<RegistryBlobOf<T>>,
 			authorization: AuthorizationIdOf,
 		) -> DispatchResult {
-			let creator = ensure_signed(origin)?;
+			let tx_authors = <T as Config>::EnsureOrigin::ensure_origin(origin)?;
+			let creator = tx_authors.subject();
+			let depositor = tx_authors.sender();
 
 			let mut registry =
 				RegistryInfo::<T>::get(&registry_id).ok_or(Error::<T>::RegistryNotFound)?;
@@ -802,6 +1011,12 @@ pub mod pallet {
 				Self::ensure_authorization_admin_origin(&authorization, &creator)?;
 			ensure!(auth_registry_id == registry_id, Error::<T>::UnauthorizedOperation);
 
+			match blob {
+				Some(blob) => Self::attach_blob(&registry_id, &depositor, &digest, blob)?,
+				None if registry.digest != digest => Self::detach_blob(&registry_id)?,
+				None => {},
+			}
+
 			registry.digest = digest;
 
 			<RegistryInfo<T>>::insert(&registry_id, registry);
@@ -849,13 +1064,13 @@ pub mod pallet {
 		/// - `Archive`: Emitted when a registry is successfully archived. It includes the registry
 		///   ID and the authority who performed the archival.
 		#[pallet::call_index(9)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::archive().saturating_add(Pallet::<T>::authorization_weight()))]
 		pub fn archive(
 			origin: OriginFor<T>,
 			registry_id: RegistryIdOf,
 			authorization: AuthorizationIdOf,
 		) -> DispatchResult {
-			let creator = ensure_signed(origin)?;
+			let creator = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
 
 			let auth_registry_id =
 				Self::ensure_authorization_admin_origin(&authorization, &creator)?;
@@ -911,13 +1126,13 @@ pub mod pallet {
 		/// - `Restore`: Emitted when a registry is successfully restored. It includes the registry
 		///   ID and the authority who performed the restoration.
 		#[pallet::call_index(10)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::restore().saturating_add(Pallet::<T>::authorization_weight()))]
 		pub fn restore(
 			origin: OriginFor<T>,
 			registry_id: RegistryIdOf,
 			authorization: AuthorizationIdOf,
 		) -> DispatchResult {
-			let creator = ensure_signed(origin)?;
+			let creator = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
 
 			let auth_registry_id =
 				Self::ensure_authorization_restore_origin(&authorization, &creator)?;
@@ -941,6 +1156,88 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Links a registry to a schema, optionally requiring its entries to
+		/// declare conformance to it.
+		///
+		/// This function allows the creator or an admin with the appropriate authority
+		/// to set the schema of an existing registry, or migrate it to a newer schema. The
+		/// existence of the schema is verified through the Schema Accounts pallet. Entries
+		/// that declared conformance to the previous schema remain pinned to it.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the transaction, which must be signed by the creator or an
+		///   admin with the appropriate authority.
+		/// - `registry_id`: The identifier of the registry whose schema is updated.
+		/// - `schema_id`: The identifier of the schema of the registry.
+		/// - `enforce`: Whether new entries must declare conformance to the schema.
+		/// - `authorization`: An identifier for the authorization being used to validate the
+		///   update.
+		///
+		/// # Errors
+		/// - `RegistryNotFound`: If the specified registry ID does not correspond to an existing
+		///   registry.
+		/// - `UnauthorizedOperation`: If the caller is not authorized to update the registry.
+		/// - `SchemaNotFound`: If the schema does not exist.
+		///
+		/// # Events
+		/// - `SchemaUpdate`: Emitted when the schema of a registry is successfully updated. It
+		///   includes the registry ID, the schema ID, whether the schema is enforced and the
+		///   authority who performed the update.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::update_schema().saturating_add(Pallet::<T>::authorization_weight()))]
+		pub fn update_schema(
+			origin: OriginFor<T>,
+			registry_id: RegistryIdOf,
+			schema_id: SchemaIdOf,
+			enforce: bool,
+			authorization: AuthorizationIdOf,
+		) -> DispatchResult {
+			let creator = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			let registry_details =
+				RegistryInfo::<T>::get(&registry_id).ok_or(Error::<T>::RegistryNotFound)?;
+
+			let auth_registry_id =
+				Self::ensure_authorization_admin_origin(&authorization, &creator)?;
+			ensure!(auth_registry_id == registry_id, Error::<T>::UnauthorizedOperation);
+
+			pallet_schema_accounts::Pallet::<T>::is_valid(&schema_id)?;
+
+			<RegistryInfo<T>>::insert(
+				&registry_id,
+				RegistryDetailsOf::<T> { schema_id: Some(schema_id.clone()), ..registry_details },
+			);
+
+			if enforce {
+				SchemaEnforcement::<T>::insert(&registry_id, true);
+			} else {
+				SchemaEnforcement::<T>::remove(&registry_id);
+			}
+
+			Self::update_activity(&registry_id, IdentifierTypeOf::Registries, CallTypeOf::Update)
+				.map_err(Error::<T>::from)?;
+
+			Self::deposit_event(Event::SchemaUpdate {
+				registry_id,
+				schema_id,
+				enforced: enforce,
+				authority: creator,
+			});
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> From<DelegationLimitsError> for Error<T> {
+	fn from(error: DelegationLimitsError) -> Self {
+		match error {
+			DelegationLimitsError::InvalidExpiry => Error::<T>::InvalidDelegationExpiry,
+			DelegationLimitsError::InvalidQuota => Error::<T>::InvalidDelegationQuota,
+			DelegationLimitsError::Expired => Error::<T>::AuthorizationExpired,
+			DelegationLimitsError::QuotaExhausted => Error::<T>::AuthorizationQuotaExhausted,
+		}
 	}
 }
 
@@ -951,12 +1248,15 @@ impl<T: Config> Pallet<T> {
 	/// the delegate's information, and the required permissions. It constructs
 	/// an authorization ID based on the registry ID, delegate, and creator,
 	/// ensuring that the delegate is not already added. It also checks that the
-	/// registry is not archived and is not revoked.
+	/// registry is not archived and is not revoked. The delegation can be
+	/// limited to an expiry block and a quota of authorized operations.
 	fn registry_delegate_addition(
 		registry_id: RegistryIdOf,
 		delegate: RegistryCreatorOf<T>,
 		creator: RegistryCreatorOf<T>,
 		permissions: Permissions,
+		expires_at: Option<BlockNumberFor<T>>,
+		quota: Option<u32>,
 	) -> Result<(), Error<T>> {
 		// Id Digest = concat (H(<scale_encoded_registry_identifier>,
 		// <scale_encoded_creator_identifier>, <scale_encoded_delegate_identifier>))
@@ -975,6 +1275,8 @@ impl<T: Config> Pallet<T> {
 			Error::<T>::DelegateAlreadyAdded
 		);
 
+		Self::set_delegation_limits(&delegate_authorization_id, expires_at, quota)?;
+
 		let mut delegates = Delegates::<T>::get(&registry_id);
 		delegates
 			.try_push(delegate.clone())
@@ -1029,6 +1331,31 @@ impl<T: Config> Pallet<T> {
 
 		ensure!(d.delegate == *delegate, Error::<T>::UnauthorizedOperation);
 
+		Self::consume_delegation(authorization_id)?;
+
+		Self::validate_registry_for_transaction(&d.registry_id)?;
+
+		ensure!(d.permissions.contains(Permissions::ASSERT), Error::<T>::UnauthorizedOperation);
+
+		Ok(d.registry_id)
+	}
+
+	/// Verifies if a given delegate has a specific authorization, without
+	/// counting the use against the quota of the delegation.
+	///
+	/// Batch calls check the authorization once with this function, and count
+	/// each operation of the batch against the quota with `consume_delegation`.
+	pub fn ensure_authorization_batch_origin(
+		authorization_id: &AuthorizationIdOf,
+		delegate: &RegistryCreatorOf<T>,
+	) -> Result<RegistryIdOf, Error<T>> {
+		let d =
+			<Authorizations<T>>::get(authorization_id).ok_or(Error::<T>::AuthorizationNotFound)?;
+
+		ensure!(d.delegate == *delegate, Error::<T>::UnauthorizedOperation);
+
+		Self::ensure_delegation_usable(authorization_id)?;
+
 		Self::validate_registry_for_transaction(&d.registry_id)?;
 
 		ensure!(d.permissions.contains(Permissions::ASSERT), Error::<T>::UnauthorizedOperation);
@@ -1050,6 +1377,8 @@ impl<T: Config> Pallet<T> {
 
 		ensure!(d.delegate == *delegate, Error::<T>::UnauthorizedOperation);
 
+		Self::consume_delegation(authorization_id)?;
+
 		Self::validate_registry_for_reinstate_transaction(&d.registry_id)?;
 
 		ensure!(d.permissions.contains(Permissions::ADMIN), Error::<T>::UnauthorizedOperation);
@@ -1072,6 +1401,8 @@ impl<T: Config> Pallet<T> {
 
 		ensure!(d.delegate == *delegate, Error::<T>::UnauthorizedOperation);
 
+		Self::consume_delegation(authorization_id)?;
+
 		Self::validate_registry_for_restore_transaction(&d.registry_id)?;
 
 		ensure!(d.permissions.contains(Permissions::ADMIN), Error::<T>::UnauthorizedOperation);
@@ -1095,6 +1426,8 @@ impl<T: Config> Pallet<T> {
 
 		ensure!(d.delegate == *delegate, Error::<T>::UnauthorizedOperation);
 
+		Self::consume_delegation(authorization_id)?;
+
 		Self::validate_registry_for_transaction(&d.registry_id)?;
 
 		ensure!(d.permissions.contains(Permissions::ADMIN), Error::<T>::UnauthorizedOperation);
@@ -1118,6 +1451,8 @@ impl<T: Config> Pallet<T> {
 
 		ensure!(d.delegate == *delegate, Error::<T>::UnauthorizedOperation);
 
+		Self::consume_delegation(authorization_id)?;
+
 		Self::validate_registry_for_transaction(&d.registry_id)?;
 
 		ensure!(
@@ -1143,6 +1478,8 @@ impl<T: Config> Pallet<T> {
 
 		ensure!(d.delegate == *delegate, Error::<T>::UnauthorizedOperation);
 
+		Self::consume_delegation(authorization_id)?;
+
 		Self::validate_registry_for_transaction(&d.registry_id)?;
 
 		ensure!(d.permissions.contains(Permissions::ADMIN), Error::<T>::UnauthorizedOperation);
@@ -1150,6 +1487,159 @@ impl<T: Config> Pallet<T> {
 		Ok(d.registry_id)
 	}
 
+	/// Stores the expiry and quota of a registry delegation. A delegation with
+	/// an expiry is queued in `AuthorizationExpirations` for pruning.
+	///
+	/// Any limits stored earlier are cleared when neither limit is supplied.
+	///
+	/// # Errors
+	/// - `InvalidDelegationQuota`: If the quota is zero.
+	/// - `InvalidDelegationExpiry`: If the expiry is not after the current block.
+	/// - `MaxDelegationExpirationsExceeded`: If no more delegations can lapse at the expiry block.
+	pub fn set_delegation_limits(
+		authorization_id: &AuthorizationIdOf,
+		expires_at: Option<BlockNumberFor<T>>,
+		quota: Option<u32>,
+	) -> Result<(), Error<T>> {
+		let now = frame_system::Pallet::<T>::block_number();
+		let Some(limits) = DelegationLimitsOf::<T>::new(expires_at, quota, &now)? else {
+			AuthorizationLimits::<T>::remove(authorization_id);
+			return Ok(());
+		};
+
+		if let Some(expiry) = limits.expires_at {
+			AuthorizationExpirations::<T>::try_mutate(expiry, |ids| {
+				ids.try_push(authorization_id.clone())
+					.map_err(|_| Error::<T>::MaxDelegationExpirationsExceeded)
+			})?;
+		}
+
+		AuthorizationLimits::<T>::insert(authorization_id, limits);
+
+		Ok(())
+	}
+
+	/// Checks the limits of a registry delegation before it is used, counting
+	/// the use against its quota.
+	///
+	/// Once the quota is used up the delegation is queued for pruning in the
+	/// following block.
+	///
+	/// # Errors
+	/// - `AuthorizationExpired`: If the delegation has reached its expiry block.
+	/// - `AuthorizationQuotaExhausted`: If no operations are left in the quota.
+	pub fn consume_delegation(authorization_id: &AuthorizationIdOf) -> Result<(), Error<T>> {
+		let Some(mut limits) = AuthorizationLimits::<T>::get(authorization_id) else {
+			return Ok(());
+		};
+
+		let now = frame_system::Pallet::<T>::block_number();
+		if limits.consume(&now)? {
+			if limits.is_exhausted() {
+				// The exhausted delegation is rejected regardless, so a full
+				// bucket merely leaves it in storage until it is removed.
+				let _ = AuthorizationExpirations::<T>::try_mutate(
+					now.saturating_add(One::one()),
+					|ids| ids.try_push(authorization_id.clone()),
+				);
+			}
+			AuthorizationLimits::<T>::insert(authorization_id, limits);
+		}
+
+		Ok(())
+	}
+
+	/// Checks the limits of a registry delegation without counting a use
+	/// against its quota.
+	///
+	/// # Errors
+	/// - `AuthorizationExpired`: If the delegation has reached its expiry block.
+	/// - `AuthorizationQuotaExhausted`: If no operations are left in the quota.
+	pub fn ensure_delegation_usable(authorization_id: &AuthorizationIdOf) -> Result<(), Error<T>> {
+		let Some(limits) = AuthorizationLimits::<T>::get(authorization_id) else {
+			return Ok(());
+		};
+
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(!limits.is_expired(&now), Error::<T>::AuthorizationExpired);
+		ensure!(!limits.is_exhausted(), Error::<T>::AuthorizationQuotaExhausted);
+
+		Ok(())
+	}
+
+	/// The weight of checking the limits of a registry delegation that the
+	/// benchmarks of the calling extrinsics do not cover, for a use that
+	/// exhausts the quota of the delegation.
+	///
+	/// Calls add this to their weight once for every authorization they check.
+	pub fn authorization_weight() -> Weight {
+		<T as Config>::WeightInfo::consume_delegation()
+	}
+
+	/// Prunes the registry delegations queued up to block `n` that have
+	/// expired or used up their quota, emitting an `AuthorizationLapsed` event
+	/// for each.
+	///
+	/// Pruning stops once `limit` is used up and resumes from
+	/// `ExpirationCursor` in a later block. Lapsed delegations are rejected
+	/// by `consume_delegation` until they are pruned. Queued delegations that
+	/// have since been removed, or granted again with new limits, are
+	/// skipped.
+	fn prune_lapsed_delegations(n: BlockNumberFor<T>, limit: Weight) -> Weight {
+		let mut meter = WeightMeter::with_limit(limit);
+		let visit_weight = <T as Config>::WeightInfo::visit_expiration_block();
+		let prune_weight = <T as Config>::WeightInfo::prune_lapsed_delegation();
+
+		let mut block = ExpirationCursor::<T>::get().unwrap_or(n);
+		while block <= n && meter.try_consume(visit_weight).is_ok() {
+			let mut queued = AuthorizationExpirations::<T>::take(block);
+			while let Some(authorization_id) = queued.last().cloned() {
+				if meter.try_consume(prune_weight).is_err() {
+					AuthorizationExpirations::<T>::insert(block, queued);
+					ExpirationCursor::<T>::put(block);
+					return meter.consumed();
+				}
+				queued.pop();
+				Self::prune_delegation(authorization_id, &n);
+			}
+			block.saturating_inc();
+		}
+
+		ExpirationCursor::<T>::put(block);
+		meter.consumed()
+	}
+
+	/// Removes a queued registry delegation if it has lapsed at block `now`.
+	fn prune_delegation(authorization_id: AuthorizationIdOf, now: &BlockNumberFor<T>) {
+		let Some(limits) = AuthorizationLimits::<T>::get(&authorization_id) else {
+			return;
+		};
+		if !limits.has_lapsed(now) {
+			return;
+		}
+		let Some(details) = Authorizations::<T>::get(&authorization_id) else {
+			AuthorizationLimits::<T>::remove(&authorization_id);
+			return;
+		};
+
+		Delegates::<T>::mutate(&details.registry_id, |delegates| {
+			remove_delegate(delegates, &details.delegate)
+		});
+		Authorizations::<T>::remove(&authorization_id);
+		AuthorizationLimits::<T>::remove(&authorization_id);
+
+		let _ = Self::update_activity(
+			&details.registry_id,
+			IdentifierTypeOf::RegistryAuthorization,
+			CallTypeOf::Deauthorization,
+		);
+
+		Self::deposit_event(Event::AuthorizationLapsed {
+			registry_id: details.registry_id,
+			authorization: authorization_id,
+		});
+	}
+
 	/// Validates that a registry is eligible for a new transaction.
 	///
 	/// This function ensures that a registry is not archived, is not revoked.
@@ -1244,6 +1734,68 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Stores `blob` as the blob of a registry, replacing the blob it
+	/// referenced before.
+	///
+	/// The blob must hash to `digest`. A blob already stored under the
+	/// digest is shared, otherwise a deposit for it is held from `who`.
+	pub fn attach_blob(
+		registry_id: &RegistryIdOf,
+		who: &AccountIdOf<T>,
+		digest: &RegistryHashOf<T>,
+		blob: RegistryBlobOf<T>,
+	) -> DispatchResult {
+		ensure!(T::Hashing::hash(&blob[..]) == *digest, Error::<T>::BlobDigestMismatch);
+
+		if <RegistryBlobDigests<T>>::get(registry_id).as_ref() == Some(digest) {
+			return Ok(());
+		}
+		Self::detach_blob(registry_id)?;
+
+		let details = match <RegistryBlobs<T>>::get(digest) {
+			Some(details) => RegistryBlobDetailsOf::<T> {
+				references: details.references.saturating_add(1),
+				..details
+			},
+			None => {
+				let ticket = T::Consideration::new(who, Footprint::from_parts(1, blob.len()))?;
+				RegistryBlobDetailsOf::<T> { blob, depositor: who.clone(), ticket, references: 1 }
+			},
+		};
+		<RegistryBlobs<T>>::insert(digest, details);
+		<RegistryBlobDigests<T>>::insert(registry_id, digest);
+
+		Ok(())
+	}
+
+	/// Drops the reference of a registry to its blob. The blob is removed
+	/// and its deposit released once it is no longer referenced.
+	pub fn detach_blob(registry_id: &RegistryIdOf) -> DispatchResult {
+		let digest = match <RegistryBlobDigests<T>>::take(registry_id) {
+			Some(digest) => digest,
+			None => return Ok(()),
+		};
+
+		if let Some(details) = <RegistryBlobs<T>>::get(&digest) {
+			if details.references > 1 {
+				<RegistryBlobs<T>>::insert(
+					&digest,
+					RegistryBlobDetailsOf::<T> { references: details.references - 1, ..details },
+				);
+			} else {
+				<RegistryBlobs<T>>::remove(&digest);
+				details.ticket.drop(&details.depositor)?;
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Returns the registry blob stored under `digest`, if any.
+	pub fn blob(digest: &RegistryHashOf<T>) -> Option<Vec<u8>> {
+		<RegistryBlobs<T>>::get(digest).map(|details| details.blob.into_inner())
+	}
+
 	/// Method to check if the input identifier calculated from sdk
 	/// is actually a valid SS58 Identifier Format and of valid type `Registries`.
 	pub fn is_valid_ss58_format(identifier: &Ss58Identifier) -> bool {
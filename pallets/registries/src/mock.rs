@@ -78,23 +78,23 @@ parameter_types! {
 parameter_types! {
 	pub const MaxRegistryBlobSize: u32 = 4 * 1024;
 	pub const MaxEncodedInputLength: u32 = 30;
+	pub const MaxDelegationExpirationsPerBlock: u32 = 5u32;
 }
 
 impl pallet_registries::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
+	type EnsureOrigin = mock_origin::EnsureDoubleOrigin<AccountId, SubjectId>;
+	type OriginSuccess = mock_origin::DoubleOrigin<AccountId, SubjectId>;
+	type RegistryCreatorId = SubjectId;
 	type MaxRegistryDelegates = MaxRegistryDelegates;
 	type MaxEncodedInputLength = MaxEncodedInputLength;
 	type MaxRegistryBlobSize = MaxRegistryBlobSize;
+	type MaxDelegationExpirationsPerBlock = MaxDelegationExpirationsPerBlock;
+	type Consideration = ();
 	type WeightInfo = ();
 }
 
-parameter_types! {
-	pub const MaxEventsHistory: u32 = 6u32;
-}
-
-impl identifier::Config for Test {
-	type MaxEventsHistory = MaxEventsHistory;
-}
+impl identifier::Config for Test {}
 
 #[allow(dead_code)]
 pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
@@ -1,9 +1,10 @@
 use super::*;
 use crate::mock::*;
 use codec::Encode;
-use frame_support::{assert_err, assert_ok};
+use cord_utilities::mock::{mock_origin::DoubleOrigin, SubjectId};
+use frame_support::{assert_err, assert_ok, error::BadOrigin, traits::Hooks};
 use pallet_schema_accounts::{InputSchemaOf, SchemaHashOf};
-use sp_runtime::traits::Hash;
+use sp_runtime::{traits::Hash, AccountId32};
 use sp_std::prelude::*;
 
 pub fn generate_registry_id<T: Config>(digest: &RegistryHashOf<T>) -> RegistryIdOf {
@@ -20,14 +21,17 @@ pub fn generate_schema_id<T: Config>(digest: &SchemaHashOf<T>) -> SchemaIdOf {
 		.unwrap()
 }
 
+pub(crate) const DID_00: SubjectId = SubjectId(AccountId32::new([1u8; 32]));
+pub(crate) const DID_01: SubjectId = SubjectId(AccountId32::new([2u8; 32]));
+pub(crate) const DID_02: SubjectId = SubjectId(AccountId32::new([3u8; 32]));
 pub(crate) const ACCOUNT_00: AccountId = AccountId::new([1u8; 32]);
 pub(crate) const ACCOUNT_01: AccountId = AccountId::new([2u8; 32]);
-pub(crate) const ACCOUNT_02: AccountId = AccountId::new([3u8; 32]);
 
 #[test]
 fn add_delegate_should_succeed() {
-	let creator = ACCOUNT_00;
-	let delegate = ACCOUNT_01;
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let delegate = DID_01;
 	let registry = [2u8; 256].to_vec();
 
 	let raw_blob = [2u8; 256].to_vec();
@@ -57,7 +61,7 @@ fn add_delegate_should_succeed() {
 
 	new_test_ext().execute_with(|| {
 		assert_ok!(Registries::create(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_id.clone(),
 			registry_digest,
 			Some(schema_id),
@@ -66,18 +70,21 @@ fn add_delegate_should_succeed() {
 
 		//Admin should be able to add the delegate
 		assert_ok!(Registries::add_delegate(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_id.clone(),
 			delegate.clone(),
 			authorization_id.clone(),
+			None,
+			None,
 		));
 	});
 }
 
 #[test]
 fn add_admin_delegate_should_succeed() {
-	let creator = ACCOUNT_00;
-	let delegate = ACCOUNT_01;
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let delegate = DID_01;
 	let registry = [2u8; 256].to_vec();
 
 	let raw_blob = [2u8; 256].to_vec();
@@ -107,7 +114,7 @@ fn add_admin_delegate_should_succeed() {
 
 	new_test_ext().execute_with(|| {
 		assert_ok!(Registries::create(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_id.clone(),
 			registry_digest,
 			Some(schema_id),
@@ -116,18 +123,21 @@ fn add_admin_delegate_should_succeed() {
 
 		//Admin should be able to add the delegate
 		assert_ok!(Registries::add_admin_delegate(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_id,
 			delegate,
 			authorization_id,
+			None,
+			None,
 		));
 	});
 }
 
 #[test]
 fn add_admin_delegate_should_fail_if_admin_delegate_already_exists() {
-	let creator = ACCOUNT_00;
-	let delegate = ACCOUNT_01;
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let delegate = DID_01;
 	let registry = [2u8; 256].to_vec();
 
 	let raw_blob = [2u8; 256].to_vec();
@@ -157,7 +167,7 @@ fn add_admin_delegate_should_fail_if_admin_delegate_already_exists() {
 
 	new_test_ext().execute_with(|| {
 		assert_ok!(Registries::create(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_id.clone(),
 			registry_digest,
 			Some(schema_id),
@@ -166,18 +176,22 @@ fn add_admin_delegate_should_fail_if_admin_delegate_already_exists() {
 
 		//Admin should be able to add the delegate
 		assert_ok!(Registries::add_admin_delegate(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_id.clone(),
 			delegate.clone(),
 			authorization_id.clone(),
+			None,
+			None,
 		));
 
 		assert_err!(
 			Registries::add_admin_delegate(
-				frame_system::RawOrigin::Signed(creator.clone()).into(),
+				DoubleOrigin(author.clone(), creator.clone()).into(),
 				registry_id,
 				delegate,
 				authorization_id,
+				None,
+				None,
 			),
 			Error::<Test>::DelegateAlreadyAdded
 		);
@@ -186,8 +200,9 @@ fn add_admin_delegate_should_fail_if_admin_delegate_already_exists() {
 
 #[test]
 fn add_delegator_should_succeed() {
-	let creator = ACCOUNT_00;
-	let delegate = ACCOUNT_01;
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let delegate = DID_01;
 	let registry = [2u8; 256].to_vec();
 
 	let raw_blob = [2u8; 256].to_vec();
@@ -217,7 +232,7 @@ fn add_delegator_should_succeed() {
 
 	new_test_ext().execute_with(|| {
 		assert_ok!(Registries::create(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_id.clone(),
 			registry_digest,
 			Some(schema_id),
@@ -225,18 +240,21 @@ fn add_delegator_should_succeed() {
 		));
 
 		assert_ok!(Registries::add_delegator(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_id,
 			delegate,
 			authorization_id,
+			None,
+			None,
 		));
 	});
 }
 
 #[test]
 fn add_delegator_should_fail_if_delegator_already_exists() {
-	let creator = ACCOUNT_00;
-	let delegate = ACCOUNT_01;
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let delegate = DID_01;
 	let registry = [2u8; 256].to_vec();
 
 	let raw_blob = [2u8; 256].to_vec();
@@ -266,7 +284,7 @@ fn add_delegator_should_fail_if_delegator_already_exists() {
 
 	new_test_ext().execute_with(|| {
 		assert_ok!(Registries::create(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_id.clone(),
 			registry_digest,
 			Some(schema_id),
@@ -274,18 +292,22 @@ fn add_delegator_should_fail_if_delegator_already_exists() {
 		));
 
 		assert_ok!(Registries::add_delegator(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_id.clone(),
 			delegate.clone(),
 			authorization_id.clone(),
+			None,
+			None,
 		));
 
 		assert_err!(
 			Registries::add_delegator(
-				frame_system::RawOrigin::Signed(creator.clone()).into(),
+				DoubleOrigin(author.clone(), creator.clone()).into(),
 				registry_id,
 				delegate,
 				authorization_id,
+				None,
+				None,
 			),
 			Error::<Test>::DelegateAlreadyAdded
 		);
@@ -294,8 +316,9 @@ fn add_delegator_should_fail_if_delegator_already_exists() {
 
 #[test]
 fn add_delegate_should_fail_if_registries_is_not_created() {
-	let creator = ACCOUNT_00;
-	let delegate = ACCOUNT_01;
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let delegate = DID_01;
 	let registry = [2u8; 256].to_vec();
 
 	let registry_digest = <Test as frame_system::Config>::Hashing::hash(&registry.encode()[..]);
@@ -315,10 +338,12 @@ fn add_delegate_should_fail_if_registries_is_not_created() {
 		//Should throw Error if registry is not created or found
 		assert_err!(
 			Registries::add_delegate(
-				frame_system::RawOrigin::Signed(creator.clone()).into(),
+				DoubleOrigin(author.clone(), creator.clone()).into(),
 				registry_id,
 				delegate,
 				authorization_id,
+				None,
+				None,
 			),
 			Error::<Test>::AuthorizationNotFound
 		);
@@ -327,8 +352,9 @@ fn add_delegate_should_fail_if_registries_is_not_created() {
 
 #[test]
 fn add_admin_delegate_should_fail_if_registries_is_not_created() {
-	let creator = ACCOUNT_00;
-	let delegate = ACCOUNT_01;
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let delegate = DID_01;
 	let registry = [2u8; 256].to_vec();
 
 	let registry_digest = <Test as frame_system::Config>::Hashing::hash(&registry.encode()[..]);
@@ -348,10 +374,12 @@ fn add_admin_delegate_should_fail_if_registries_is_not_created() {
 		//Should throw Error if registry is not created or found
 		assert_err!(
 			Registries::add_admin_delegate(
-				frame_system::RawOrigin::Signed(creator.clone()).into(),
+				DoubleOrigin(author.clone(), creator.clone()).into(),
 				registry_id,
 				delegate,
 				authorization_id,
+				None,
+				None,
 			),
 			Error::<Test>::AuthorizationNotFound
 		);
@@ -360,8 +388,9 @@ fn add_admin_delegate_should_fail_if_registries_is_not_created() {
 
 #[test]
 fn add_delegator_should_fail_if_registries_is_not_created() {
-	let creator = ACCOUNT_00;
-	let delegate = ACCOUNT_01;
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let delegate = DID_01;
 	let registry = [2u8; 256].to_vec();
 
 	let registry_digest = <Test as frame_system::Config>::Hashing::hash(&registry.encode()[..]);
@@ -381,10 +410,12 @@ fn add_delegator_should_fail_if_registries_is_not_created() {
 		//Should throw Error if registry is not created or found
 		assert_err!(
 			Registries::add_delegator(
-				frame_system::RawOrigin::Signed(creator.clone()).into(),
+				DoubleOrigin(author.clone(), creator.clone()).into(),
 				registry_id,
 				delegate,
 				authorization_id,
+				None,
+				None,
 			),
 			Error::<Test>::AuthorizationNotFound
 		);
@@ -393,8 +424,9 @@ fn add_delegator_should_fail_if_registries_is_not_created() {
 
 #[test]
 fn add_delegate_should_fail_if_the_regisrty_is_revoked() {
-	let creator = ACCOUNT_00;
-	let delegate = ACCOUNT_01;
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let delegate = DID_01;
 	let registry = [2u8; 256].to_vec();
 
 	let raw_blob = [2u8; 256].to_vec();
@@ -424,7 +456,7 @@ fn add_delegate_should_fail_if_the_regisrty_is_revoked() {
 
 	new_test_ext().execute_with(|| {
 		assert_ok!(Registries::create(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_id.clone(),
 			registry_digest,
 			Some(schema_id),
@@ -432,17 +464,19 @@ fn add_delegate_should_fail_if_the_regisrty_is_revoked() {
 		));
 
 		assert_ok!(Registries::revoke(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_id.clone(),
 			authorization_id.clone(),
 		));
 
 		assert_err!(
 			Registries::add_delegate(
-				frame_system::RawOrigin::Signed(creator.clone()).into(),
+				DoubleOrigin(author.clone(), creator.clone()).into(),
 				registry_id,
 				delegate,
 				authorization_id,
+				None,
+				None,
 			),
 			Error::<Test>::RegistryRevoked
 		);
@@ -451,9 +485,10 @@ fn add_delegate_should_fail_if_the_regisrty_is_revoked() {
 
 #[test]
 fn add_delegate_should_fail_if_a_non_delegate_tries_to_add() {
-	let creator = ACCOUNT_00;
-	let delegate = ACCOUNT_01;
-	let creator1 = ACCOUNT_02;
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let delegate = DID_01;
+	let creator1 = DID_02;
 	let registry = [2u8; 256].to_vec();
 
 	let raw_blob = [2u8; 256].to_vec();
@@ -483,7 +518,7 @@ fn add_delegate_should_fail_if_a_non_delegate_tries_to_add() {
 
 	new_test_ext().execute_with(|| {
 		assert_ok!(Registries::create(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_id.clone(),
 			registry_digest,
 			Some(schema_id),
@@ -492,10 +527,12 @@ fn add_delegate_should_fail_if_a_non_delegate_tries_to_add() {
 
 		assert_err!(
 			Registries::add_delegate(
-				frame_system::RawOrigin::Signed(creator1.clone()).into(),
+				DoubleOrigin(author.clone(), creator1.clone()).into(),
 				registry_id,
 				delegate,
 				authorization_id,
+				None,
+				None,
 			),
 			Error::<Test>::UnauthorizedOperation
 		);
@@ -504,8 +541,9 @@ fn add_delegate_should_fail_if_a_non_delegate_tries_to_add() {
 
 #[test]
 fn add_delegate_should_fail_if_delegate_already_exists() {
-	let creator = ACCOUNT_00;
-	let delegate = ACCOUNT_01;
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let delegate = DID_01;
 	let registry = [2u8; 256].to_vec();
 
 	let raw_blob = [2u8; 256].to_vec();
@@ -535,7 +573,7 @@ fn add_delegate_should_fail_if_delegate_already_exists() {
 
 	new_test_ext().execute_with(|| {
 		assert_ok!(Registries::create(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_id.clone(),
 			registry_digest,
 			Some(schema_id),
@@ -543,18 +581,22 @@ fn add_delegate_should_fail_if_delegate_already_exists() {
 		));
 
 		assert_ok!(Registries::add_delegate(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_id.clone(),
 			delegate.clone(),
 			authorization_id.clone(),
+			None,
+			None,
 		));
 
 		assert_err!(
 			Registries::add_delegate(
-				frame_system::RawOrigin::Signed(creator.clone()).into(),
+				DoubleOrigin(author.clone(), creator.clone()).into(),
 				registry_id.clone(),
 				delegate.clone(),
 				authorization_id.clone(),
+				None,
+				None,
 			),
 			Error::<Test>::DelegateAlreadyAdded
 		);
@@ -563,7 +605,8 @@ fn add_delegate_should_fail_if_delegate_already_exists() {
 
 #[test]
 fn creating_a_new_registries_should_succeed() {
-	let creator = ACCOUNT_00;
+	let creator = DID_00;
+	let author = ACCOUNT_00;
 	let registry = [2u8; 256].to_vec();
 
 	let raw_blob = [2u8; 256].to_vec();
@@ -587,7 +630,7 @@ fn creating_a_new_registries_should_succeed() {
 
 	new_test_ext().execute_with(|| {
 		assert_ok!(Registries::create(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_id.clone(),
 			registry_digest,
 			Some(schema_id),
@@ -598,7 +641,8 @@ fn creating_a_new_registries_should_succeed() {
 
 #[test]
 fn creating_a_duplicate_registries_should_fail() {
-	let creator = ACCOUNT_00;
+	let creator = DID_00;
+	let author = ACCOUNT_00;
 	let registry = [2u8; 256].to_vec();
 
 	let raw_blob = [2u8; 256].to_vec();
@@ -622,7 +666,7 @@ fn creating_a_duplicate_registries_should_fail() {
 
 	new_test_ext().execute_with(|| {
 		assert_ok!(Registries::create(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_id.clone(),
 			registry_digest,
 			Some(schema_id),
@@ -631,7 +675,7 @@ fn creating_a_duplicate_registries_should_fail() {
 
 		assert_err!(
 			Registries::create(
-				frame_system::RawOrigin::Signed(creator.clone()).into(),
+				DoubleOrigin(author.clone(), creator.clone()).into(),
 				registry_id.clone(),
 				registry_digest,
 				None,
@@ -644,7 +688,8 @@ fn creating_a_duplicate_registries_should_fail() {
 
 #[test]
 fn revoking_a_registry_should_succeed() {
-	let creator = ACCOUNT_00;
+	let creator = DID_00;
+	let author = ACCOUNT_00;
 	let registry = [2u8; 256].to_vec();
 
 	let raw_blob = [2u8; 256].to_vec();
@@ -674,7 +719,7 @@ fn revoking_a_registry_should_succeed() {
 
 	new_test_ext().execute_with(|| {
 		assert_ok!(Registries::create(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_id.clone(),
 			registry_digest,
 			Some(schema_id),
@@ -682,7 +727,7 @@ fn revoking_a_registry_should_succeed() {
 		));
 
 		assert_ok!(Registries::revoke(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_id.clone(),
 			authorization_id.clone(),
 		));
@@ -691,8 +736,9 @@ fn revoking_a_registry_should_succeed() {
 
 #[test]
 fn reinstating_an_revoked_a_registry_should_succeed() {
-	let creator = ACCOUNT_00;
-	let delegate = ACCOUNT_01;
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let delegate = DID_01;
 	let registry = [2u8; 256].to_vec();
 
 	let raw_blob = [2u8; 256].to_vec();
@@ -722,7 +768,7 @@ fn reinstating_an_revoked_a_registry_should_succeed() {
 
 	new_test_ext().execute_with(|| {
 		assert_ok!(Registries::create(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_id.clone(),
 			registry_digest,
 			Some(schema_id),
@@ -730,29 +776,32 @@ fn reinstating_an_revoked_a_registry_should_succeed() {
 		));
 
 		assert_ok!(Registries::revoke(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_id.clone(),
 			authorization_id.clone(),
 		));
 
 		assert_ok!(Registries::reinstate(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_id.clone(),
 			authorization_id.clone(),
 		));
 
 		assert_ok!(Registries::add_delegate(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_id.clone(),
 			delegate.clone(),
 			authorization_id.clone(),
+			None,
+			None,
 		));
 	});
 }
 
 #[test]
 fn reinstating_an_non_revoked_a_registry_should_fail() {
-	let creator = ACCOUNT_00;
+	let creator = DID_00;
+	let author = ACCOUNT_00;
 	let registry = [2u8; 256].to_vec();
 
 	let raw_blob = [2u8; 256].to_vec();
@@ -782,7 +831,7 @@ fn reinstating_an_non_revoked_a_registry_should_fail() {
 
 	new_test_ext().execute_with(|| {
 		assert_ok!(Registries::create(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_id.clone(),
 			registry_digest,
 			Some(schema_id),
@@ -791,7 +840,7 @@ fn reinstating_an_non_revoked_a_registry_should_fail() {
 
 		assert_err!(
 			Registries::reinstate(
-				frame_system::RawOrigin::Signed(creator.clone()).into(),
+				DoubleOrigin(author.clone(), creator.clone()).into(),
 				registry_id.clone(),
 				authorization_id.clone(),
 			),
@@ -802,7 +851,8 @@ fn reinstating_an_non_revoked_a_registry_should_fail() {
 
 #[test]
 fn archiving_a_registry_should_succeed() {
-	let creator = ACCOUNT_00;
+	let creator = DID_00;
+	let author = ACCOUNT_00;
 	let registry = [2u8; 256].to_vec();
 
 	let raw_blob = [2u8; 256].to_vec();
@@ -832,7 +882,7 @@ fn archiving_a_registry_should_succeed() {
 
 	new_test_ext().execute_with(|| {
 		assert_ok!(Registries::create(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_id.clone(),
 			registry_digest,
 			Some(schema_id),
@@ -840,7 +890,7 @@ fn archiving_a_registry_should_succeed() {
 		));
 
 		assert_ok!(Registries::archive(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_id.clone(),
 			authorization_id.clone(),
 		));
@@ -849,8 +899,9 @@ fn archiving_a_registry_should_succeed() {
 
 #[test]
 fn restoring_an_archived_a_registry_should_succeed() {
-	let creator = ACCOUNT_00;
-	let delegate = ACCOUNT_01;
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let delegate = DID_01;
 	let registry = [2u8; 256].to_vec();
 
 	let raw_blob = [2u8; 256].to_vec();
@@ -880,7 +931,7 @@ fn restoring_an_archived_a_registry_should_succeed() {
 
 	new_test_ext().execute_with(|| {
 		assert_ok!(Registries::create(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_id.clone(),
 			registry_digest,
 			Some(schema_id),
@@ -888,29 +939,32 @@ fn restoring_an_archived_a_registry_should_succeed() {
 		));
 
 		assert_ok!(Registries::archive(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_id.clone(),
 			authorization_id.clone(),
 		));
 
 		assert_ok!(Registries::restore(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_id.clone(),
 			authorization_id.clone(),
 		));
 
 		assert_ok!(Registries::add_delegate(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_id.clone(),
 			delegate.clone(),
 			authorization_id.clone(),
+			None,
+			None,
 		));
 	});
 }
 
 #[test]
 fn restoring_an_non_archived_a_registry_should_fail() {
-	let creator = ACCOUNT_00;
+	let creator = DID_00;
+	let author = ACCOUNT_00;
 	let registry = [2u8; 256].to_vec();
 
 	let raw_blob = [2u8; 256].to_vec();
@@ -940,7 +994,7 @@ fn restoring_an_non_archived_a_registry_should_fail() {
 
 	new_test_ext().execute_with(|| {
 		assert_ok!(Registries::create(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_id.clone(),
 			registry_digest,
 			Some(schema_id),
@@ -949,7 +1003,7 @@ fn restoring_an_non_archived_a_registry_should_fail() {
 
 		assert_err!(
 			Registries::restore(
-				frame_system::RawOrigin::Signed(creator.clone()).into(),
+				DoubleOrigin(author.clone(), creator.clone()).into(),
 				registry_id.clone(),
 				authorization_id.clone(),
 			),
@@ -960,7 +1014,8 @@ fn restoring_an_non_archived_a_registry_should_fail() {
 
 #[test]
 fn add_delegate_should_fail_if_registry_delegates_limit_exceeded() {
-	let creator = ACCOUNT_00;
+	let creator = DID_00;
+	let author = ACCOUNT_00;
 	let registry = [2u8; 256].to_vec();
 
 	let raw_blob = [2u8; 256].to_vec();
@@ -985,7 +1040,7 @@ fn add_delegate_should_fail_if_registry_delegates_limit_exceeded() {
 	new_test_ext().execute_with(|| {
 		// Create the Registries
 		assert_ok!(Registries::create(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_id.clone(),
 			registry_digest,
 			Some(schema_id),
@@ -996,7 +1051,7 @@ fn add_delegate_should_fail_if_registry_delegates_limit_exceeded() {
 		for delegate_count in 2..6 {
 			assert_ok!(Registries::registry_delegate_addition(
 				registry_id.clone(),
-				AccountId::new([delegate_count; 32]),
+				SubjectId(AccountId32::new([delegate_count; 32])),
 				creator.clone(),
 				Permissions::all(),
 			));
@@ -1007,7 +1062,7 @@ fn add_delegate_should_fail_if_registry_delegates_limit_exceeded() {
 		assert_err!(
 			Registries::registry_delegate_addition(
 				registry_id.clone(),
-				AccountId::new([6u8; 32]),
+				SubjectId(AccountId32::new([6u8; 32])),
 				creator.clone(),
 				Permissions::all(),
 			),
@@ -1018,8 +1073,9 @@ fn add_delegate_should_fail_if_registry_delegates_limit_exceeded() {
 
 #[test]
 fn remove_delegate_should_succeed() {
-	let creator = ACCOUNT_00;
-	let delegate = ACCOUNT_01;
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let delegate = DID_01;
 	let registry = [2u8; 256].to_vec();
 
 	let raw_blob = [2u8; 256].to_vec();
@@ -1056,7 +1112,7 @@ fn remove_delegate_should_succeed() {
 
 	new_test_ext().execute_with(|| {
 		assert_ok!(Registries::create(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_id.clone(),
 			registry_digest,
 			Some(schema_id),
@@ -1064,14 +1120,16 @@ fn remove_delegate_should_succeed() {
 		));
 
 		assert_ok!(Registries::add_delegate(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_id.clone(),
 			delegate.clone(),
 			authorization_id.clone(),
+			None,
+			None,
 		));
 
 		assert_ok!(Registries::remove_delegate(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_id.clone(),
 			delegate_authorization_id,
 			authorization_id.clone(),
@@ -1081,8 +1139,9 @@ fn remove_delegate_should_succeed() {
 
 #[test]
 fn remove_delegate_should_fail_for_creator_removing_themselves() {
-	let creator = ACCOUNT_00;
-	let delegate = ACCOUNT_01;
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let delegate = DID_01;
 	let registry = [2u8; 256].to_vec();
 
 	let raw_blob = [2u8; 256].to_vec();
@@ -1112,7 +1171,7 @@ fn remove_delegate_should_fail_for_creator_removing_themselves() {
 
 	new_test_ext().execute_with(|| {
 		assert_ok!(Registries::create(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_id.clone(),
 			registry_digest,
 			Some(schema_id),
@@ -1120,15 +1179,17 @@ fn remove_delegate_should_fail_for_creator_removing_themselves() {
 		));
 
 		assert_ok!(Registries::add_delegate(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_id.clone(),
 			delegate.clone(),
 			authorization_id.clone(),
+			None,
+			None,
 		));
 
 		assert_err!(
 			Registries::remove_delegate(
-				frame_system::RawOrigin::Signed(creator.clone()).into(),
+				DoubleOrigin(author.clone(), creator.clone()).into(),
 				registry_id.clone(),
 				authorization_id.clone(),
 				authorization_id.clone(),
@@ -1140,7 +1201,8 @@ fn remove_delegate_should_fail_for_creator_removing_themselves() {
 
 #[test]
 fn update_registry_should_succeed() {
-	let creator = ACCOUNT_00;
+	let creator = DID_00;
+	let author = ACCOUNT_00;
 	let registry = [2u8; 256].to_vec();
 	let new_digest =
 		<Test as frame_system::Config>::Hashing::hash(&[3u8; 256].to_vec().encode()[..]);
@@ -1149,7 +1211,7 @@ fn update_registry_should_succeed() {
 	let initial_blob: RegistryBlobOf<Test> = BoundedVec::try_from(raw_blob.clone())
 		.expect("Test Blob should fit into the expected input length of for the test runtime.");
 
-	let new_raw_blob = [4u8; 256].to_vec();
+	let new_raw_blob = [3u8; 256].to_vec();
 	let new_blob: RegistryBlobOf<Test> = BoundedVec::try_from(new_raw_blob.clone())
 		.expect("New Test Blob should fit into the expected input length of for the test runtime.");
 
@@ -1176,7 +1238,7 @@ fn update_registry_should_succeed() {
 
 	new_test_ext().execute_with(|| {
 		assert_ok!(Registries::create(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_id.clone(),
 			registry_digest,
 			Some(schema_id),
@@ -1184,7 +1246,7 @@ fn update_registry_should_succeed() {
 		));
 
 		assert_ok!(Registries::update(
-			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			registry_id.clone(),
 			new_digest,
 			Some(new_blob.clone()),
@@ -1205,3 +1267,483 @@ fn update_registry_should_succeed() {
 		);
 	});
 }
+
+#[test]
+fn lapsed_registry_delegations_should_be_rejected_and_pruned() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let contractor = DID_01;
+	let auditor = DID_02;
+	let registry = [2u8; 256].to_vec();
+	let registry_digest = <Test as frame_system::Config>::Hashing::hash(&registry.encode()[..]);
+	let new_digest = <Test as frame_system::Config>::Hashing::hash(&[3u8; 256].encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let registry_id: RegistryIdOf = generate_registry_id::<Test>(&id_digest);
+
+	let auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: AuthorizationIdOf = generate_authorization_id::<Test>(&auth_id_digest);
+
+	let contractor_auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_id.encode()[..], &contractor.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let contractor_authorization_id: AuthorizationIdOf =
+		generate_authorization_id::<Test>(&contractor_auth_id_digest);
+
+	let auditor_auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_id.encode()[..], &auditor.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let auditor_authorization_id: AuthorizationIdOf =
+		generate_authorization_id::<Test>(&auditor_auth_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Registries::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			registry_id.clone(),
+			registry_digest,
+			None,
+			None,
+		));
+
+		assert_err!(
+			Registries::add_admin_delegate(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				registry_id.clone(),
+				contractor.clone(),
+				authorization_id.clone(),
+				None,
+				Some(0),
+			),
+			Error::<Test>::InvalidDelegationQuota
+		);
+
+		assert_ok!(Registries::add_admin_delegate(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			registry_id.clone(),
+			contractor.clone(),
+			authorization_id.clone(),
+			Some(10),
+			Some(1),
+		));
+		assert_ok!(Registries::add_admin_delegate(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			registry_id.clone(),
+			auditor.clone(),
+			authorization_id.clone(),
+			Some(4),
+			None,
+		));
+
+		// The contractor may perform a single operation.
+		assert_ok!(Registries::update(
+			DoubleOrigin(author.clone(), contractor.clone()).into(),
+			registry_id.clone(),
+			new_digest,
+			None,
+			contractor_authorization_id.clone(),
+		));
+		assert_err!(
+			Registries::update(
+				DoubleOrigin(author.clone(), contractor.clone()).into(),
+				registry_id.clone(),
+				registry_digest,
+				None,
+				contractor_authorization_id.clone(),
+			),
+			Error::<Test>::AuthorizationQuotaExhausted
+		);
+
+		Registries::on_idle(2, Weight::MAX);
+		assert!(Authorizations::<Test>::get(&contractor_authorization_id).is_none());
+		assert!(!Registries::is_a_delegate(&registry_id, contractor));
+		System::assert_last_event(
+			Event::AuthorizationLapsed {
+				registry_id: registry_id.clone(),
+				authorization: contractor_authorization_id,
+			}
+			.into(),
+		);
+
+		// The auditor delegation lapses at block 4.
+		System::set_block_number(4);
+		assert_err!(
+			Registries::update(
+				DoubleOrigin(author.clone(), auditor.clone()).into(),
+				registry_id.clone(),
+				registry_digest,
+				None,
+				auditor_authorization_id.clone(),
+			),
+			Error::<Test>::AuthorizationExpired
+		);
+
+		Registries::on_idle(4, Weight::MAX);
+		assert!(Authorizations::<Test>::get(&auditor_authorization_id).is_none());
+		assert!(AuthorizationLimits::<Test>::get(&auditor_authorization_id).is_none());
+		assert!(!Registries::is_a_delegate(&registry_id, auditor));
+	});
+}
+
+#[test]
+fn registry_blobs_should_be_stored_by_digest_and_shared() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let other_creator = DID_01;
+	let other_author = ACCOUNT_01;
+	let blob: RegistryBlobOf<Test> = BoundedVec::try_from(b"accredited labs".to_vec())
+		.expect("Test blob should fit into the expected input length of for the test runtime.");
+	let new_blob: RegistryBlobOf<Test> = BoundedVec::try_from(b"accredited labs v2".to_vec())
+		.expect("Test blob should fit into the expected input length of for the test runtime.");
+	let digest = <Test as frame_system::Config>::Hashing::hash(&blob[..]);
+	let new_digest = <Test as frame_system::Config>::Hashing::hash(&new_blob[..]);
+
+	let registry_and_authorization = |creator: &SubjectId| {
+		let id_digest = <Test as frame_system::Config>::Hashing::hash(
+			&[&digest.encode()[..], &creator.encode()[..]].concat()[..],
+		);
+		let registry_id: RegistryIdOf = generate_registry_id::<Test>(&id_digest);
+		let auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+			&[&registry_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+		);
+		(registry_id, generate_authorization_id::<Test>(&auth_id_digest))
+	};
+	let (registry_id, authorization_id) = registry_and_authorization(&creator);
+	let (other_registry_id, other_authorization_id) = registry_and_authorization(&other_creator);
+
+	new_test_ext().execute_with(|| {
+		// The blob must hash to the digest of the registry.
+		assert_err!(
+			Registries::create(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				registry_id.clone(),
+				digest,
+				None,
+				Some(new_blob.clone()),
+			),
+			Error::<Test>::BlobDigestMismatch
+		);
+
+		assert_ok!(Registries::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			registry_id.clone(),
+			digest,
+			None,
+			Some(blob.clone()),
+		));
+		assert_ok!(Registries::create(
+			DoubleOrigin(other_author.clone(), other_creator.clone()).into(),
+			other_registry_id.clone(),
+			digest,
+			None,
+			Some(blob.clone()),
+		));
+
+		// Identical blobs are stored once, with the deposit of the first account.
+		let details = RegistryBlobs::<Test>::get(digest).expect("Blob should be stored");
+		assert_eq!((details.depositor, details.references), (author.clone(), 2));
+		assert_eq!(Registries::blob(&digest), Some(blob.to_vec()));
+		assert_eq!(RegistryBlobDigests::<Test>::get(&other_registry_id), Some(digest));
+
+		assert_ok!(Registries::update(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			registry_id.clone(),
+			new_digest,
+			Some(new_blob.clone()),
+			authorization_id.clone(),
+		));
+		assert_eq!(RegistryBlobs::<Test>::get(digest).map(|d| d.references), Some(1));
+		assert_eq!(Registries::blob(&new_digest), Some(new_blob.to_vec()));
+
+		// The blob is removed once no registry references it.
+		assert_ok!(Registries::update(
+			DoubleOrigin(other_author.clone(), other_creator.clone()).into(),
+			other_registry_id.clone(),
+			new_digest,
+			Some(new_blob.clone()),
+			other_authorization_id.clone(),
+		));
+		assert!(RegistryBlobs::<Test>::get(digest).is_none());
+		assert_eq!(RegistryBlobs::<Test>::get(new_digest).map(|d| d.references), Some(2));
+
+		// Changing the digest without a new blob drops the blob that no longer matches.
+		let other_digest = <Test as frame_system::Config>::Hashing::hash(&[3u8; 256][..]);
+		assert_ok!(Registries::update(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			registry_id.clone(),
+			other_digest,
+			None,
+			authorization_id,
+		));
+		assert!(RegistryBlobDigests::<Test>::get(&registry_id).is_none());
+		assert_eq!(RegistryBlobs::<Test>::get(new_digest).map(|d| d.references), Some(1));
+
+		assert_ok!(Registries::update(
+			DoubleOrigin(other_author.clone(), other_creator.clone()).into(),
+			other_registry_id.clone(),
+			other_digest,
+			None,
+			other_authorization_id,
+		));
+		assert!(RegistryBlobDigests::<Test>::get(&other_registry_id).is_none());
+		assert!(RegistryBlobs::<Test>::get(new_digest).is_none());
+		assert!(Registries::blob(&other_digest).is_none());
+	});
+}
+
+#[test]
+fn creating_a_registry_with_an_invalid_identifier_should_fail() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let registry = [2u8; 256].to_vec();
+	let registry_digest = <Test as frame_system::Config>::Hashing::hash(&registry.encode()[..]);
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let registry_id: RegistryIdOf = generate_registry_id::<Test>(&id_digest);
+
+	/* An identifier of another type, and one derived for another creator */
+	let authorization_id: AuthorizationIdOf = generate_authorization_id::<Test>(&id_digest);
+	let other_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_digest.encode()[..], &DID_01.encode()[..]].concat()[..],
+	);
+	let other_registry_id: RegistryIdOf = generate_registry_id::<Test>(&other_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_err!(
+			Registries::create(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				authorization_id,
+				registry_digest,
+				None,
+				None,
+			),
+			Error::<Test>::InvalidRegistryIdentifier
+		);
+
+		assert_err!(
+			Registries::create(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				other_registry_id,
+				registry_digest,
+				None,
+				None,
+			),
+			Error::<Test>::RegistryIdentifierMismatch
+		);
+
+		assert_ok!(Registries::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			registry_id.clone(),
+			registry_digest,
+			None,
+			None,
+		));
+		assert!(RegistryInfo::<Test>::contains_key(&registry_id));
+	});
+}
+
+#[test]
+fn updating_the_schema_of_a_registry_should_work() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let delegate = DID_01;
+	let registry = [2u8; 256].to_vec();
+	let registry_digest = <Test as frame_system::Config>::Hashing::hash(&registry.encode()[..]);
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let registry_id: RegistryIdOf = generate_registry_id::<Test>(&id_digest);
+	let auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: AuthorizationIdOf = generate_authorization_id::<Test>(&auth_id_digest);
+	let delegate_auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_id.encode()[..], &delegate.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let delegate_authorization_id: AuthorizationIdOf =
+		generate_authorization_id::<Test>(&delegate_auth_id_digest);
+
+	let schema: InputSchemaOf<Test> = BoundedVec::try_from([2u8; 256].to_vec())
+		.expect("Test Schema should fit into the expected input length of for the test runtime.");
+	let schema_id: SchemaIdOf = generate_schema_id::<Test>(
+		&<Test as frame_system::Config>::Hashing::hash(&schema.encode()[..]),
+	);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Registries::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			registry_id.clone(),
+			registry_digest,
+			None,
+			None,
+		));
+
+		/* The schema must exist */
+		assert_err!(
+			Registries::update_schema(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				registry_id.clone(),
+				schema_id.clone(),
+				true,
+				authorization_id.clone(),
+			),
+			pallet_schema_accounts::Error::<Test>::SchemaNotFound
+		);
+
+		assert_ok!(SchemaAccounts::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			schema
+		));
+
+		/* Only an admin can update the schema */
+		assert_ok!(Registries::add_delegate(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			registry_id.clone(),
+			delegate.clone(),
+			authorization_id.clone(),
+			None,
+			None,
+		));
+		assert_err!(
+			Registries::update_schema(
+				DoubleOrigin(author.clone(), delegate.clone()).into(),
+				registry_id.clone(),
+				schema_id.clone(),
+				true,
+				delegate_authorization_id,
+			),
+			Error::<Test>::UnauthorizedOperation
+		);
+
+		assert_ok!(Registries::update_schema(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			registry_id.clone(),
+			schema_id.clone(),
+			true,
+			authorization_id.clone(),
+		));
+		assert_eq!(
+			RegistryInfo::<Test>::get(&registry_id).and_then(|r| r.schema_id),
+			Some(schema_id.clone())
+		);
+		assert!(SchemaEnforcement::<Test>::get(&registry_id));
+
+		assert_ok!(Registries::update_schema(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			registry_id.clone(),
+			schema_id,
+			false,
+			authorization_id,
+		));
+		assert!(!SchemaEnforcement::<Test>::get(&registry_id));
+	});
+}
+
+#[test]
+fn creating_a_registry_with_a_signed_origin_should_fail() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let registry = [2u8; 256].to_vec();
+	let registry_digest = <Test as frame_system::Config>::Hashing::hash(&registry.encode()[..]);
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let registry_id: RegistryIdOf = generate_registry_id::<Test>(&id_digest);
+
+	new_test_ext().execute_with(|| {
+		/* Registries are owned by the subject of the origin, not the signing account */
+		assert_err!(
+			Registries::create(
+				frame_system::RawOrigin::Signed(author).into(),
+				registry_id.clone(),
+				registry_digest,
+				None,
+				None,
+			),
+			BadOrigin
+		);
+		assert!(RegistryInfo::<Test>::get(&registry_id).is_none());
+	});
+}
+
+#[test]
+fn registries_created_by_accounts_should_be_managed_by_the_dids_of_those_accounts() {
+	use frame_support::storage::unhashed;
+
+	let account = ACCOUNT_00;
+	let creator = SubjectId::from(account.clone());
+	let delegate = DID_01;
+	let registry = [2u8; 256].to_vec();
+	let registry_digest = <Test as frame_system::Config>::Hashing::hash(&registry.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_digest.encode()[..], &account.encode()[..]].concat()[..],
+	);
+	let registry_id: RegistryIdOf = generate_registry_id::<Test>(&id_digest);
+
+	let auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_id.encode()[..], &account.encode()[..], &account.encode()[..]].concat()[..],
+	);
+	let authorization_id: AuthorizationIdOf = generate_authorization_id::<Test>(&auth_id_digest);
+
+	new_test_ext().execute_with(|| {
+		// A registry created by a signed account before registries were owned
+		// by DIDs, stored with the account as its creator and sole delegate.
+		unhashed::put(
+			&RegistryInfo::<Test>::hashed_key_for(&registry_id),
+			&RegistryDetails {
+				creator: account.clone(),
+				revoked: false,
+				archived: false,
+				digest: registry_digest,
+				schema_id: None::<SchemaIdOf>,
+			},
+		);
+		unhashed::put(
+			&Authorizations::<Test>::hashed_key_for(&authorization_id),
+			&RegistryAuthorization {
+				registry_id: registry_id.clone(),
+				delegate: account.clone(),
+				permissions: Permissions::all(),
+				delegator: account.clone(),
+			},
+		);
+		unhashed::put(&Delegates::<Test>::hashed_key_for(&registry_id), &vec![account.clone()]);
+
+		// The records decode with the DID of the same identifier in place of
+		// the account.
+		assert_eq!(
+			RegistryInfo::<Test>::get(&registry_id).map(|registry| registry.creator),
+			Some(creator.clone())
+		);
+		assert_eq!(Delegates::<Test>::get(&registry_id).to_vec(), vec![creator.clone()]);
+
+		// Other DIDs cannot act on the account's authorization.
+		assert_err!(
+			Registries::add_delegate(
+				DoubleOrigin(account.clone(), DID_02).into(),
+				registry_id.clone(),
+				delegate.clone(),
+				authorization_id.clone(),
+				None,
+				None,
+			),
+			Error::<Test>::UnauthorizedOperation
+		);
+
+		// The DID of the account keeps managing the registry.
+		assert_ok!(Registries::add_delegate(
+			DoubleOrigin(account, creator).into(),
+			registry_id.clone(),
+			delegate.clone(),
+			authorization_id,
+			None,
+			None,
+		));
+		assert!(Delegates::<Test>::get(&registry_id).contains(&delegate));
+	});
+}
@@ -102,3 +102,23 @@ pub struct RegistryAuthorization<RegistryIdOf, RegistryCreatorOf, Permissions> {
 	pub permissions: Permissions,
 	pub delegator: RegistryCreatorOf,
 }
+
+/// A blob stored on chain for one or more registries or registry entries.
+///
+/// Blobs are keyed by their digest, so identical blobs are stored once. The
+/// account that first stored the blob holds the deposit backing it, which is
+/// released once no registry or entry references the blob anymore.
+///
+/// ## Fields
+///
+/// - `blob`: The content of the blob.
+/// - `depositor`: The account holding the deposit for the blob.
+/// - `ticket`: The ticket of the deposit held for the blob.
+/// - `references`: The number of registries or entries referencing the blob.
+#[derive(Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct BlobDetails<Blob, AccountId, Ticket> {
+	pub blob: Blob,
+	pub depositor: AccountId,
+	pub ticket: Ticket,
+	pub references: u32,
+}
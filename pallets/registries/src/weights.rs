@@ -0,0 +1,607 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placeholder weights for `pallet_registries`
+//!
+//! THESE WEIGHTS HAVE NOT BEEN BENCHMARKED. They are estimated from the storage
+//! accesses of each call so that no call is free to dispatch, and have to be
+//! replaced by the output of `cord benchmark pallet --pallet=pallet_registries` on the
+//! reference hardware before they are relied upon.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_registries`.
+pub trait WeightInfo {
+	fn add_delegate() -> Weight;
+	fn add_admin_delegate() -> Weight;
+	fn add_delegator() -> Weight;
+	fn remove_delegate() -> Weight;
+	fn create(l: u32, ) -> Weight;
+	fn revoke() -> Weight;
+	fn reinstate() -> Weight;
+	fn update(l: u32, ) -> Weight;
+	fn archive() -> Weight;
+	fn restore() -> Weight;
+	fn update_schema() -> Weight;
+	fn consume_delegation() -> Weight;
+	fn visit_expiration_block() -> Weight;
+	fn prune_lapsed_delegation() -> Weight;
+}
+
+/// Weights for `pallet_registries` using the CORD node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `Registries::Authorizations` (r:2 w:1)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationLimits` (r:1 w:1)
+	/// Proof: `Registries::AuthorizationLimits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryInfo` (r:1 w:0)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(183), added: 2658, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationExpirations` (r:1 w:1)
+	/// Proof: `Registries::AuthorizationExpirations` (`max_values`: None, `max_size`: Some(50014), added: 52489, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::Delegates` (r:1 w:1)
+	/// Proof: `Registries::Delegates` (`max_values`: None, `max_size`: Some(320069), added: 322544, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn add_delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `388122`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(31_240_000, 388122)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Registries::Authorizations` (r:2 w:1)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationLimits` (r:1 w:1)
+	/// Proof: `Registries::AuthorizationLimits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryInfo` (r:1 w:0)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(183), added: 2658, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationExpirations` (r:1 w:1)
+	/// Proof: `Registries::AuthorizationExpirations` (`max_values`: None, `max_size`: Some(50014), added: 52489, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::Delegates` (r:1 w:1)
+	/// Proof: `Registries::Delegates` (`max_values`: None, `max_size`: Some(320069), added: 322544, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn add_admin_delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `388122`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(31_470_000, 388122)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Registries::Authorizations` (r:2 w:1)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationLimits` (r:1 w:1)
+	/// Proof: `Registries::AuthorizationLimits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryInfo` (r:1 w:0)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(183), added: 2658, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationExpirations` (r:1 w:1)
+	/// Proof: `Registries::AuthorizationExpirations` (`max_values`: None, `max_size`: Some(50014), added: 52489, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::Delegates` (r:1 w:1)
+	/// Proof: `Registries::Delegates` (`max_values`: None, `max_size`: Some(320069), added: 322544, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn add_delegator() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `388122`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(31_380_000, 388122)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Registries::Authorizations` (r:2 w:1)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationLimits` (r:1 w:1)
+	/// Proof: `Registries::AuthorizationLimits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryInfo` (r:1 w:0)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(183), added: 2658, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::Delegates` (r:1 w:1)
+	/// Proof: `Registries::Delegates` (`max_values`: None, `max_size`: Some(320069), added: 322544, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn remove_delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `335633`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(29_860_000, 335633)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Registries::RegistryInfo` (r:1 w:1)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(183), added: 2658, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryBlobDigests` (r:1 w:1)
+	/// Proof: `Registries::RegistryBlobDigests` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryBlobs` (r:1 w:1)
+	/// Proof: `Registries::RegistryBlobs` (`max_values`: None, `max_size`: Some(4182), added: 6657, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::Delegates` (r:0 w:1)
+	/// Proof: `Registries::Delegates` (`max_values`: None, `max_size`: Some(320069), added: 322544, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::Authorizations` (r:0 w:1)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[1, 4096]`.
+	fn create(l: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `16988`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(42_160_000, 16988)
+			.saturating_add(Weight::from_parts(1_618, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Registries::Authorizations` (r:1 w:0)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationLimits` (r:1 w:1)
+	/// Proof: `Registries::AuthorizationLimits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryInfo` (r:1 w:1)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(183), added: 2658, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn revoke() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `10430`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(22_730_000, 10430)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Registries::Authorizations` (r:1 w:0)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationLimits` (r:1 w:1)
+	/// Proof: `Registries::AuthorizationLimits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryInfo` (r:1 w:1)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(183), added: 2658, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn reinstate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `10430`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(22_940_000, 10430)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Registries::Authorizations` (r:1 w:0)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationLimits` (r:1 w:1)
+	/// Proof: `Registries::AuthorizationLimits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryInfo` (r:1 w:1)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(183), added: 2658, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryBlobDigests` (r:1 w:1)
+	/// Proof: `Registries::RegistryBlobDigests` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryBlobs` (r:2 w:2)
+	/// Proof: `Registries::RegistryBlobs` (`max_values`: None, `max_size`: Some(4182), added: 6657, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[1, 4096]`.
+	fn update(l: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `28859`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(51_370_000, 28859)
+			.saturating_add(Weight::from_parts(1_624, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Registries::Authorizations` (r:1 w:0)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationLimits` (r:1 w:1)
+	/// Proof: `Registries::AuthorizationLimits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryInfo` (r:1 w:1)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(183), added: 2658, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn archive() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `10430`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(22_610_000, 10430)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Registries::Authorizations` (r:1 w:0)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationLimits` (r:1 w:1)
+	/// Proof: `Registries::AuthorizationLimits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryInfo` (r:1 w:1)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(183), added: 2658, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn restore() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `10430`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(22_820_000, 10430)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Registries::RegistryInfo` (r:1 w:1)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(183), added: 2658, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::Authorizations` (r:1 w:0)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationLimits` (r:1 w:1)
+	/// Proof: `Registries::AuthorizationLimits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `SchemaAccounts::Schemas` (r:1 w:0)
+	/// Proof: `SchemaAccounts::Schemas` (`max_values`: None, `max_size`: Some(15492), added: 17967, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::SchemaEnforcement` (r:0 w:1)
+	/// Proof: `Registries::SchemaEnforcement` (`max_values`: None, `max_size`: Some(50), added: 2525, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn update_schema() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `28397`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(26_380_000, 28397)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Registries::AuthorizationLimits` (r:1 w:1)
+	/// Proof: `Registries::AuthorizationLimits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationExpirations` (r:1 w:1)
+	/// Proof: `Registries::AuthorizationExpirations` (`max_values`: None, `max_size`: Some(50014), added: 52489, mode: `MaxEncodedLen`)
+	fn consume_delegation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `55044`
+		// Estimated from the storage accesses of a use that exhausts the quota, pending benchmarking.
+		Weight::from_parts(14_000_000, 55044)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Registries::ExpirationCursor` (r:1 w:1)
+	/// Proof: `Registries::ExpirationCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationExpirations` (r:1 w:1)
+	/// Proof: `Registries::AuthorizationExpirations` (`max_values`: None, `max_size`: Some(50014), added: 52489, mode: `MaxEncodedLen`)
+	fn visit_expiration_block() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `52988`
+		// Estimated from the storage accesses of a full expiry bucket, pending benchmarking.
+		Weight::from_parts(9_000_000, 52988)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Registries::AuthorizationLimits` (r:1 w:1)
+	/// Proof: `Registries::AuthorizationLimits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::Authorizations` (r:1 w:1)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::Delegates` (r:1 w:1)
+	/// Proof: `Registries::Delegates` (`max_values`: None, `max_size`: Some(320069), added: 322544, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn prune_lapsed_delegation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `330316`
+		// Estimated from the storage accesses with a full `Delegates` list, pending benchmarking.
+		Weight::from_parts(300_000_000, 330316)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `Registries::Authorizations` (r:2 w:1)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationLimits` (r:1 w:1)
+	/// Proof: `Registries::AuthorizationLimits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryInfo` (r:1 w:0)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(183), added: 2658, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationExpirations` (r:1 w:1)
+	/// Proof: `Registries::AuthorizationExpirations` (`max_values`: None, `max_size`: Some(50014), added: 52489, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::Delegates` (r:1 w:1)
+	/// Proof: `Registries::Delegates` (`max_values`: None, `max_size`: Some(320069), added: 322544, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn add_delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `388122`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(31_240_000, 388122)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Registries::Authorizations` (r:2 w:1)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationLimits` (r:1 w:1)
+	/// Proof: `Registries::AuthorizationLimits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryInfo` (r:1 w:0)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(183), added: 2658, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationExpirations` (r:1 w:1)
+	/// Proof: `Registries::AuthorizationExpirations` (`max_values`: None, `max_size`: Some(50014), added: 52489, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::Delegates` (r:1 w:1)
+	/// Proof: `Registries::Delegates` (`max_values`: None, `max_size`: Some(320069), added: 322544, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn add_admin_delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `388122`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(31_470_000, 388122)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Registries::Authorizations` (r:2 w:1)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationLimits` (r:1 w:1)
+	/// Proof: `Registries::AuthorizationLimits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryInfo` (r:1 w:0)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(183), added: 2658, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationExpirations` (r:1 w:1)
+	/// Proof: `Registries::AuthorizationExpirations` (`max_values`: None, `max_size`: Some(50014), added: 52489, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::Delegates` (r:1 w:1)
+	/// Proof: `Registries::Delegates` (`max_values`: None, `max_size`: Some(320069), added: 322544, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn add_delegator() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `388122`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(31_380_000, 388122)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Registries::Authorizations` (r:2 w:1)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationLimits` (r:1 w:1)
+	/// Proof: `Registries::AuthorizationLimits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryInfo` (r:1 w:0)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(183), added: 2658, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::Delegates` (r:1 w:1)
+	/// Proof: `Registries::Delegates` (`max_values`: None, `max_size`: Some(320069), added: 322544, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn remove_delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `335633`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(29_860_000, 335633)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Registries::RegistryInfo` (r:1 w:1)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(183), added: 2658, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryBlobDigests` (r:1 w:1)
+	/// Proof: `Registries::RegistryBlobDigests` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryBlobs` (r:1 w:1)
+	/// Proof: `Registries::RegistryBlobs` (`max_values`: None, `max_size`: Some(4182), added: 6657, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::Delegates` (r:0 w:1)
+	/// Proof: `Registries::Delegates` (`max_values`: None, `max_size`: Some(320069), added: 322544, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::Authorizations` (r:0 w:1)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[1, 4096]`.
+	fn create(l: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `16988`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(42_160_000, 16988)
+			.saturating_add(Weight::from_parts(1_618, 0).saturating_mul(l.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Registries::Authorizations` (r:1 w:0)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationLimits` (r:1 w:1)
+	/// Proof: `Registries::AuthorizationLimits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryInfo` (r:1 w:1)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(183), added: 2658, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn revoke() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `10430`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(22_730_000, 10430)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Registries::Authorizations` (r:1 w:0)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationLimits` (r:1 w:1)
+	/// Proof: `Registries::AuthorizationLimits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryInfo` (r:1 w:1)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(183), added: 2658, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn reinstate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `10430`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(22_940_000, 10430)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Registries::Authorizations` (r:1 w:0)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationLimits` (r:1 w:1)
+	/// Proof: `Registries::AuthorizationLimits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryInfo` (r:1 w:1)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(183), added: 2658, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryBlobDigests` (r:1 w:1)
+	/// Proof: `Registries::RegistryBlobDigests` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryBlobs` (r:2 w:2)
+	/// Proof: `Registries::RegistryBlobs` (`max_values`: None, `max_size`: Some(4182), added: 6657, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[1, 4096]`.
+	fn update(l: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `28859`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(51_370_000, 28859)
+			.saturating_add(Weight::from_parts(1_624, 0).saturating_mul(l.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Registries::Authorizations` (r:1 w:0)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationLimits` (r:1 w:1)
+	/// Proof: `Registries::AuthorizationLimits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryInfo` (r:1 w:1)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(183), added: 2658, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn archive() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `10430`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(22_610_000, 10430)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Registries::Authorizations` (r:1 w:0)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationLimits` (r:1 w:1)
+	/// Proof: `Registries::AuthorizationLimits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryInfo` (r:1 w:1)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(183), added: 2658, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn restore() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `10430`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(22_820_000, 10430)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Registries::RegistryInfo` (r:1 w:1)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(183), added: 2658, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::Authorizations` (r:1 w:0)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationLimits` (r:1 w:1)
+	/// Proof: `Registries::AuthorizationLimits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `SchemaAccounts::Schemas` (r:1 w:0)
+	/// Proof: `SchemaAccounts::Schemas` (`max_values`: None, `max_size`: Some(15492), added: 17967, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::SchemaEnforcement` (r:0 w:1)
+	/// Proof: `Registries::SchemaEnforcement` (`max_values`: None, `max_size`: Some(50), added: 2525, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn update_schema() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `28397`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(26_380_000, 28397)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Registries::AuthorizationLimits` (r:1 w:1)
+	/// Proof: `Registries::AuthorizationLimits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationExpirations` (r:1 w:1)
+	/// Proof: `Registries::AuthorizationExpirations` (`max_values`: None, `max_size`: Some(50014), added: 52489, mode: `MaxEncodedLen`)
+	fn consume_delegation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `55044`
+		// Estimated from the storage accesses of a use that exhausts the quota, pending benchmarking.
+		Weight::from_parts(14_000_000, 55044)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Registries::ExpirationCursor` (r:1 w:1)
+	/// Proof: `Registries::ExpirationCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationExpirations` (r:1 w:1)
+	/// Proof: `Registries::AuthorizationExpirations` (`max_values`: None, `max_size`: Some(50014), added: 52489, mode: `MaxEncodedLen`)
+	fn visit_expiration_block() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `52988`
+		// Estimated from the storage accesses of a full expiry bucket, pending benchmarking.
+		Weight::from_parts(9_000_000, 52988)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Registries::AuthorizationLimits` (r:1 w:1)
+	/// Proof: `Registries::AuthorizationLimits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::Authorizations` (r:1 w:1)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::Delegates` (r:1 w:1)
+	/// Proof: `Registries::Delegates` (`max_values`: None, `max_size`: Some(320069), added: 322544, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn prune_lapsed_delegation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `330316`
+		// Estimated from the storage accesses with a full `Delegates` list, pending benchmarking.
+		Weight::from_parts(300_000_000, 330316)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+}
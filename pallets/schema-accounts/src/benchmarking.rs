@@ -0,0 +1,54 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use codec::Encode;
+use frame_benchmarking::{account, benchmarks};
+use frame_support::{sp_runtime::traits::Hash, traits::Get, BoundedVec};
+use frame_system::RawOrigin;
+use sp_std::{convert::TryFrom, vec};
+
+const SEED: u32 = 0;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+pub fn generate_schema_id<T: Config>(digest: &SchemaHashOf<T>) -> SchemaIdOf {
+	Ss58Identifier::create_identifier(&(digest).encode()[..], IdentifierType::SchemaAccounts)
+		.unwrap()
+}
+
+benchmarks! {
+	create {
+		let l in 1 .. T::MaxEncodedSchemaLength::get();
+
+		let creator: T::AccountId = account("creator", 0, SEED);
+		let schema: InputSchemaOf<T> = BoundedVec::try_from(vec![b'a'; l as usize])
+			.expect("Test Schema should fit into the expected input length of the test runtime.");
+		let id_digest = <T as frame_system::Config>::Hashing::hash(&schema.encode()[..]);
+		let schema_id: SchemaIdOf = generate_schema_id::<T>(&id_digest);
+	}: _(RawOrigin::Signed(creator.clone()), schema)
+	verify {
+		assert_last_event::<T>(Event::<T>::Created { identifier: schema_id, creator }.into());
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
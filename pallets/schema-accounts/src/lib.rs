@@ -48,6 +48,12 @@ pub mod mock;
 #[cfg(test)]
 pub mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
 use identifier::{
 	types::{CallTypeOf, IdentifierTypeOf, Timepoint},
 	EventEntryOf,
@@ -66,7 +72,6 @@ pub mod pallet {
 	pub use cord_utilities::traits::CallSources;
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
-	pub use frame_system::WeightInfo;
 	pub use identifier::{IdentifierCreator, IdentifierTimeline, IdentifierType, Ss58Identifier};
 	use sp_runtime::traits::Hash;
 
@@ -148,7 +153,7 @@ pub mod pallet {
 		///
 		/// DispatchResult
 		#[pallet::call_index(0)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::create(tx_schema.len() as u32))]
 		pub fn create(origin: OriginFor<T>, tx_schema: InputSchemaOf<T>) -> DispatchResult {
 			let creator = ensure_signed(origin)?;
 
@@ -0,0 +1,78 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placeholder weights for `pallet_schema_accounts`
+//!
+//! THESE WEIGHTS HAVE NOT BEEN BENCHMARKED. They are estimated from the storage
+//! accesses of each call so that no call is free to dispatch, and have to be
+//! replaced by the output of `cord benchmark pallet --pallet=pallet_schema_accounts` on the
+//! reference hardware before they are relied upon.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_schema_accounts`.
+pub trait WeightInfo {
+	fn create(l: u32, ) -> Weight;
+}
+
+/// Weights for `pallet_schema_accounts` using the CORD node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `SchemaAccounts::Schemas` (r:1 w:1)
+	/// Proof: `SchemaAccounts::Schemas` (`max_values`: None, `max_size`: Some(15492), added: 17967, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[1, 15360]`.
+	fn create(l: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `20525`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(21_870_000, 20525)
+			.saturating_add(Weight::from_parts(3_229, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `SchemaAccounts::Schemas` (r:1 w:1)
+	/// Proof: `SchemaAccounts::Schemas` (`max_values`: None, `max_size`: Some(15492), added: 17967, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[1, 15360]`.
+	fn create(l: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `20525`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(21_870_000, 20525)
+			.saturating_add(Weight::from_parts(3_229, 0).saturating_mul(l.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+}
@@ -24,10 +24,7 @@ use cord_utilities::traits::GenerateBenchmarkOrigin;
 use frame_benchmarking::{account, benchmarks};
 use frame_support::{sp_runtime::traits::Hash, traits::Get, BoundedVec};
 use frame_system::RawOrigin;
-use sp_std::{
-	convert::{TryFrom, TryInto},
-	vec::Vec,
-};
+use sp_std::{convert::TryFrom, vec, vec::Vec};
 
 const SEED: u32 = 0;
 
@@ -50,6 +47,55 @@ pub fn generate_authorization_id<T: Config>(digest: &SchemaHashOf<T>) -> Authori
 		.unwrap()
 }
 
+/// Generates a JSON-Schema document of `length` bytes, so that the schema
+/// passes structural validation when the runtime enables it.
+pub fn generate_schema<T: Config>(length: u32) -> InputSchemaOf<T> {
+	let prefix = br#"{"type":"object","description":""#.to_vec();
+	let length = length as usize;
+	let raw_schema: Vec<u8> = if length >= prefix.len() + 2 {
+		let padding = length - prefix.len() - 2;
+		[&prefix[..], &vec![b'a'; padding][..], &br#""}"#[..]].concat()
+	} else {
+		[&b"{}"[..], &vec![b' '; length.saturating_sub(2)][..]].concat()
+	};
+	BoundedVec::try_from(raw_schema)
+		.expect("Test Schema should fit into the expected input length of the test runtime.")
+}
+
+/// Generates the JSON-Schema document of `length` bytes that is the most
+/// expensive to validate.
+///
+/// Subschemas are nested through `not` up to the maximum depth, and the
+/// innermost schema spends the remaining bytes on an `allOf` of empty
+/// subschemas, three bytes each, every one of which is parsed and validated
+/// on its own. Lengths too short for the nesting fall back to
+/// [`generate_schema`].
+pub fn generate_worst_case_schema<T: Config>(length: u32) -> InputSchemaOf<T> {
+	let open = br#"{"not":"#;
+	let (inner_open, inner_close) = (br#"{"allOf":[{}"#, br#"]}"#);
+	let depth = (validation::MAX_SCHEMA_DEPTH - 2) as usize;
+	let fixed = depth * (open.len() + 1) + inner_open.len() + inner_close.len();
+
+	let length = length as usize;
+	if length < fixed {
+		return generate_schema::<T>(length as u32);
+	}
+	let items = (length - fixed) / 3;
+	let padding = (length - fixed) % 3;
+
+	let raw_schema: Vec<u8> = [
+		&open.repeat(depth)[..],
+		&inner_open[..],
+		&b",{}".repeat(items)[..],
+		&inner_close[..],
+		&vec![b'}'; depth][..],
+		&vec![b' '; padding][..],
+	]
+	.concat();
+	BoundedVec::try_from(raw_schema)
+		.expect("Test Schema should fit into the expected input length of the test runtime.")
+}
+
 benchmarks! {
 	where_clause {
 		where
@@ -57,7 +103,7 @@ benchmarks! {
 		// T::ChainSpaceOrigin: EnsureOrigin<T::RuntimeOrigin>,
 	}
 	create {
-		let l in 1 .. T::MaxEncodedSchemaLength::get();
+		let l in 2 .. T::MaxEncodedSchemaLength::get();
 
 		let caller: T::AccountId = account("caller", 0, SEED);
 		let did: T::SchemaCreatorId = account("did", 0, SEED);
@@ -71,9 +117,7 @@ benchmarks! {
 		);
 		let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
 
-		let raw_schema: Vec<u8> = (0u8..u8::MAX).cycle().take(l.try_into().unwrap()).collect();
-		let schema = BoundedVec::try_from(raw_schema)
-		.expect("Test Schema should fit into the expected input length of the test runtime.");
+		let schema = generate_worst_case_schema::<T>(l);
 		let digest = <T as frame_system::Config>::Hashing::hash(&schema[..]);
 		let schema_id_digest = <T as frame_system::Config>::Hashing::hash(
 			&[&schema.encode()[..], &space_id.encode()[..], &did.encode()[..]].concat()[..],
@@ -96,6 +140,83 @@ benchmarks! {
 	verify {
 		assert_last_event::<T>(Event::<T>::Created { identifier: schema_id, creator: did1 }.into());
 	}
+	supersede {
+		let l in 2 .. T::MaxEncodedSchemaLength::get();
+
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let did: T::SchemaCreatorId = account("did", 0, SEED);
+		let did1: T::SpaceCreatorId = account("did", 0, SEED);
+		let capacity = 5u64;
+
+		let raw_space = [2u8; 256].to_vec();
+		let space_digest = <T as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+		let space_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
+
+		let previous_schema: InputSchemaOf<T> = BoundedVec::try_from(br#"{"type":"string"}"#.to_vec())
+			.expect("Test Schema should fit into the expected input length of the test runtime.");
+		let previous_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&previous_schema.encode()[..], &space_id.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let previous_schema_id: SchemaIdOf = generate_schema_id::<T>(&previous_id_digest);
+
+		let schema = generate_worst_case_schema::<T>(l);
+
+		let auth_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+		);
+
+		let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
+
+		let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did);
+		let chain_space_origin = RawOrigin::Root.into();
+
+		pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
+		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity ).expect("Approval should not fail.");
+		Pallet::<T>::create(origin.clone(), previous_schema, authorization_id.clone())?;
+
+	}: _<T::RuntimeOrigin>(origin, previous_schema_id.clone(), schema, authorization_id, true)
+	verify {
+		assert_last_event::<T>(Event::<T>::Deprecated { identifier: previous_schema_id, creator: did1 }.into());
+	}
+	deprecate {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let did: T::SchemaCreatorId = account("did", 0, SEED);
+		let did1: T::SpaceCreatorId = account("did", 0, SEED);
+		let capacity = 3u64;
+
+		let raw_space = [2u8; 256].to_vec();
+		let space_digest = <T as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+		let space_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
+
+		let schema: InputSchemaOf<T> = generate_schema::<T>(64);
+		let schema_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&schema.encode()[..], &space_id.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let schema_id: SchemaIdOf = generate_schema_id::<T>(&schema_id_digest);
+
+		let auth_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+		);
+
+		let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
+
+		let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did);
+		let chain_space_origin = RawOrigin::Root.into();
+
+		pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
+		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity ).expect("Approval should not fail.");
+		Pallet::<T>::create(origin.clone(), schema, authorization_id.clone())?;
+
+	}: _<T::RuntimeOrigin>(origin, schema_id.clone(), authorization_id)
+	verify {
+		assert_last_event::<T>(Event::<T>::Deprecated { identifier: schema_id, creator: did1 }.into());
+	}
 	impl_benchmark_test_suite! (
 		Pallet,
 		crate::mock::new_test_ext(),
@@ -33,12 +33,29 @@
 //!   allows all parties to reference data in a known way. An identifier can optionally link to a
 //!   valid schema identifier.
 //!
+//! ### Versioning
+//!
+//! A schema can be superseded by a new version through `supersede`, which
+//! anchors the new schema, links it to its predecessor and records the next
+//! version number of the lineage. The creator of a schema can deprecate it,
+//! either while superseding it or later through `deprecate`. Deprecated
+//! schemas remain resolvable, but other pallets may refuse to use them.
+//!
+//! ### Structural Validation
+//!
+//! When [`Config::ValidateSchemaStructure`] is enabled, every schema is
+//! parsed as JSON and checked against the subset of the JSON-Schema
+//! meta-schema implemented in [`validation`] before it is anchored. The
+//! draft declared through `$schema` is recorded in the schema entry.
+//!
 //! ## Assumptions
 //!
 //! - The Schema hash was created using CORD SDK.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub mod migrations;
+pub mod validation;
 pub mod weights;
 pub use pallet::*;
 
@@ -56,12 +73,16 @@ use identifier::{
 	types::{CallTypeOf, IdentifierTypeOf, Timepoint},
 	EventEntryOf,
 };
-use sp_runtime::traits::UniqueSaturatedInto;
+use sp_runtime::{
+	traits::{Hash, UniqueSaturatedInto},
+	DispatchError,
+};
 
 /// Extra Types for Schema
 pub mod types;
 
 pub use crate::{types::*, weights::WeightInfo};
+use codec::Encode;
 use frame_support::ensure;
 
 #[frame_support::pallet]
@@ -74,7 +95,7 @@ pub mod pallet {
 	use sp_runtime::{traits::Hash, SaturatedConversion};
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	/// Space Identifier
 	pub type SpaceIdOf = Ss58Identifier;
@@ -93,6 +114,8 @@ pub mod pallet {
 	/// Type for a schema entry
 	pub type SchemaEntryOf<T> =
 		SchemaEntry<InputSchemaOf<T>, SchemaHashOf<T>, SchemaCreatorOf<T>, SpaceIdOf>;
+	/// Type for the version details of a schema
+	pub type SchemaVersionOf = SchemaVersion<SchemaIdOf>;
 
 	#[pallet::config]
 	pub trait Config:
@@ -107,6 +130,9 @@ pub mod pallet {
 		type SchemaCreatorId: Parameter + MaxEncodedLen;
 		#[pallet::constant]
 		type MaxEncodedSchemaLength: Get<u32>;
+		/// Whether schemas must be valid JSON-Schema documents
+		#[pallet::constant]
+		type ValidateSchemaStructure: Get<bool>;
 		type WeightInfo: WeightInfo;
 	}
 
@@ -122,12 +148,24 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type Schemas<T> = StorageMap<_, Blake2_128Concat, SchemaIdOf, SchemaEntryOf<T>>;
 
+	/// schema versions stored on chain.
+	/// It maps from a schema identifier to its position in a lineage. Schemas
+	/// without an entry are version 1 of their own lineage.
+	#[pallet::storage]
+	pub type SchemaVersions<T> = StorageMap<_, Blake2_128Concat, SchemaIdOf, SchemaVersionOf>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// A new schema has been created.
 		/// \[schema identifier, digest, author\]
 		Created { identifier: SchemaIdOf, creator: SchemaCreatorOf<T> },
+		/// A schema has been superseded by a new version.
+		/// \[schema identifier, predecessor, version\]
+		Superseded { identifier: SchemaIdOf, predecessor: SchemaIdOf, version: u32 },
+		/// A schema has been deprecated.
+		/// \[schema identifier, author\]
+		Deprecated { identifier: SchemaIdOf, creator: SchemaCreatorOf<T> },
 	}
 
 	#[pallet::error]
@@ -146,6 +184,26 @@ pub mod pallet {
 		MaxEncodedSchemaLimitExceeded,
 		/// Empty transaction.
 		EmptyTransaction,
+		/// Only the creator of a schema can supersede or deprecate it.
+		UnauthorizedOperation,
+		/// The schema has already been superseded by another version.
+		SchemaAlreadySuperseded,
+		/// The schema has already been deprecated.
+		SchemaAlreadyDeprecated,
+		/// The schema lineage has reached the maximum version number.
+		SchemaVersionOverflow,
+		/// The schema is not a valid JSON document.
+		SchemaNotJson,
+		/// The root of the schema is not a JSON object.
+		SchemaNotAnObject,
+		/// The `$schema` keyword names an unsupported JSON-Schema draft.
+		UnsupportedSchemaDraft,
+		/// The `type` keyword does not name valid JSON-Schema types.
+		InvalidSchemaType,
+		/// A JSON-Schema keyword carries a value of the wrong kind.
+		InvalidSchemaKeyword,
+		/// The schema nests subschemas too deeply.
+		SchemaNestingTooDeep,
 	}
 
 	#[pallet::call]
@@ -162,7 +220,7 @@ pub mod pallet {
 		///
 		/// DispatchResult
 		#[pallet::call_index(0)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::create(tx_schema.len().saturated_into()))]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::create(tx_schema.len().saturated_into()).saturating_add(pallet_chain_space::Pallet::<T>::authorization_weight()))]
 		pub fn create(
 			origin: OriginFor<T>,
 			tx_schema: InputSchemaOf<T>,
@@ -170,66 +228,223 @@ pub mod pallet {
 		) -> DispatchResult {
 			let creator = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
 
-			ensure!(tx_schema.len() > 0, Error::<T>::EmptyTransaction);
-			ensure!(
-				tx_schema.len() <= T::MaxEncodedSchemaLength::get() as usize,
-				Error::<T>::MaxEncodedSchemaLimitExceeded
-			);
+			let (identifier, _) = Self::anchor_schema(tx_schema, &authorization, &creator)?;
 
-			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_origin(
-				&authorization,
-				&creator,
-			)
-			.map_err(<pallet_chain_space::Error<T>>::from)?;
+			Self::deposit_event(Event::Created { identifier, creator });
 
-			// Id Digest = concat (H(<scale_encoded_schema_input>,
-			// <<scale_encoded_space_identifier>, scale_encoded_creator_identifier>))
-			let id_digest = <T as frame_system::Config>::Hashing::hash(
-				&[&tx_schema.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()
-					[..],
-			);
+			Ok(())
+		}
 
-			let identifier = Ss58Identifier::create_identifier(
-				&(id_digest).encode()[..],
-				IdentifierType::Schema,
-			)
-			.map_err(|_| Error::<T>::InvalidIdentifierLength)?;
+		/// Anchors a new version of an existing schema.
+		///
+		/// The new schema is anchored in the same way as through `create` and
+		/// is linked to `schema_id` as its successor, carrying the next version
+		/// number of the lineage. Only the creator of `schema_id` can supersede
+		/// it, and every schema can be superseded only once.
+		///
+		/// Arguments:
+		///
+		/// * `origin`: The origin of the transaction.
+		/// * `schema_id`: The identifier of the schema being superseded.
+		/// * `tx_schema`: The new version of the schema.
+		/// * `authorization`: The authorization of the creator in the space of `schema_id`.
+		/// * `deprecate`: Whether `schema_id` should be marked as deprecated.
+		///
+		/// Returns:
+		///
+		/// DispatchResult
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::supersede(tx_schema.len().saturated_into()).saturating_add(pallet_chain_space::Pallet::<T>::authorization_weight()))]
+		pub fn supersede(
+			origin: OriginFor<T>,
+			schema_id: SchemaIdOf,
+			tx_schema: InputSchemaOf<T>,
+			authorization: AuthorizationIdOf,
+			deprecate: bool,
+		) -> DispatchResult {
+			let creator = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
 
-			ensure!(!<Schemas<T>>::contains_key(&identifier), Error::<T>::SchemaAlreadyAnchored);
+			let predecessor = <Schemas<T>>::get(&schema_id).ok_or(Error::<T>::SchemaNotFound)?;
+			ensure!(predecessor.creator == creator, Error::<T>::UnauthorizedOperation);
 
-			let digest = <T as frame_system::Config>::Hashing::hash(&tx_schema[..]);
-			let block_number = frame_system::Pallet::<T>::block_number();
+			let mut predecessor_version = <SchemaVersions<T>>::get(&schema_id).unwrap_or_default();
+			ensure!(predecessor_version.successor.is_none(), Error::<T>::SchemaAlreadySuperseded);
+			let version = predecessor_version
+				.version
+				.checked_add(1)
+				.ok_or(Error::<T>::SchemaVersionOverflow)?;
 
-			log::debug!(
-				"Schema created with identifier: {:?}, schema: {:?} digest: {:?}, creator:
-			{:?}, block_number: {:?}",
-				identifier,
-				tx_schema,
-				digest,
-				creator,
-				block_number
-			);
+			let (identifier, space_id) = Self::anchor_schema(tx_schema, &authorization, &creator)?;
+			ensure!(space_id == predecessor.space, Error::<T>::UnauthorizedOperation);
 
-			<Schemas<T>>::insert(
+			<SchemaVersions<T>>::insert(
 				&identifier,
-				SchemaEntryOf::<T> {
-					schema: tx_schema,
-					digest,
-					creator: creator.clone(),
-					space: space_id,
+				SchemaVersionOf {
+					version,
+					predecessor: Some(schema_id.clone()),
+					successor: None,
+					deprecated: false,
 				},
 			);
 
-			Self::update_activity(&identifier, CallTypeOf::Genesis).map_err(<Error<T>>::from)?;
+			let newly_deprecated = deprecate && !predecessor_version.deprecated;
+			predecessor_version.successor = Some(identifier.clone());
+			predecessor_version.deprecated |= deprecate;
+			<SchemaVersions<T>>::insert(&schema_id, predecessor_version);
 
-			Self::deposit_event(Event::Created { identifier, creator });
+			Self::update_activity(&schema_id, CallTypeOf::Update).map_err(<Error<T>>::from)?;
+
+			Self::deposit_event(Event::Created {
+				identifier: identifier.clone(),
+				creator: creator.clone(),
+			});
+			Self::deposit_event(Event::Superseded {
+				identifier,
+				predecessor: schema_id.clone(),
+				version,
+			});
+			if newly_deprecated {
+				Self::deposit_event(Event::Deprecated { identifier: schema_id, creator });
+			}
+
+			Ok(())
+		}
+
+		/// Marks a schema as deprecated.
+		///
+		/// Deprecated schemas remain on chain and keep their place in the
+		/// lineage, but pallets consuming schemas may refuse to reference them.
+		/// Only the creator of the schema can deprecate it.
+		///
+		/// Arguments:
+		///
+		/// * `origin`: The origin of the transaction.
+		/// * `schema_id`: The identifier of the schema being deprecated.
+		/// * `authorization`: The authorization of the creator in the space of the schema.
+		///
+		/// Returns:
+		///
+		/// DispatchResult
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::deprecate().saturating_add(pallet_chain_space::Pallet::<T>::authorization_weight()))]
+		pub fn deprecate(
+			origin: OriginFor<T>,
+			schema_id: SchemaIdOf,
+			authorization: AuthorizationIdOf,
+		) -> DispatchResult {
+			let creator = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_permission_origin(
+				&authorization,
+				&creator,
+				pallet_chain_space::Permissions::SCHEMA_CREATE,
+			)
+			.map_err(<pallet_chain_space::Error<T>>::from)?;
+
+			let schema = <Schemas<T>>::get(&schema_id).ok_or(Error::<T>::SchemaNotFound)?;
+			ensure!(
+				schema.space == space_id && schema.creator == creator,
+				Error::<T>::UnauthorizedOperation
+			);
+
+			let mut version = <SchemaVersions<T>>::get(&schema_id).unwrap_or_default();
+			ensure!(!version.deprecated, Error::<T>::SchemaAlreadyDeprecated);
+			version.deprecated = true;
+			<SchemaVersions<T>>::insert(&schema_id, version);
+
+			Self::update_activity(&schema_id, CallTypeOf::Archive).map_err(<Error<T>>::from)?;
+
+			Self::deposit_event(Event::Deprecated { identifier: schema_id, creator });
 
 			Ok(())
 		}
 	}
 }
 
+impl<T: Config> From<validation::ValidationError> for Error<T> {
+	fn from(error: validation::ValidationError) -> Self {
+		match error {
+			validation::ValidationError::NotJson => Error::<T>::SchemaNotJson,
+			validation::ValidationError::NotAnObject => Error::<T>::SchemaNotAnObject,
+			validation::ValidationError::UnsupportedDraft => Error::<T>::UnsupportedSchemaDraft,
+			validation::ValidationError::InvalidType => Error::<T>::InvalidSchemaType,
+			validation::ValidationError::InvalidKeyword => Error::<T>::InvalidSchemaKeyword,
+			validation::ValidationError::TooDeep => Error::<T>::SchemaNestingTooDeep,
+		}
+	}
+}
+
 impl<T: Config> Pallet<T> {
+	/// Anchors `tx_schema` in the space of `authorization` and returns the
+	/// identifier of the new schema together with the space identifier.
+	///
+	/// This is shared by `create` and `supersede`, which deposit their own
+	/// events.
+	fn anchor_schema(
+		tx_schema: InputSchemaOf<T>,
+		authorization: &AuthorizationIdOf,
+		creator: &SchemaCreatorOf<T>,
+	) -> Result<(SchemaIdOf, SpaceIdOf), DispatchError> {
+		ensure!(tx_schema.len() > 0, Error::<T>::EmptyTransaction);
+		ensure!(
+			tx_schema.len() <= T::MaxEncodedSchemaLength::get() as usize,
+			Error::<T>::MaxEncodedSchemaLimitExceeded
+		);
+
+		let draft = if <T as Config>::ValidateSchemaStructure::get() {
+			validation::validate_schema(&tx_schema[..]).map_err(<Error<T>>::from)?
+		} else {
+			None
+		};
+
+		let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_permission_origin(
+			authorization,
+			creator,
+			pallet_chain_space::Permissions::SCHEMA_CREATE,
+		)
+		.map_err(<pallet_chain_space::Error<T>>::from)?;
+
+		// Id Digest = concat (H(<scale_encoded_schema_input>,
+		// <<scale_encoded_space_identifier>, scale_encoded_creator_identifier>))
+		let id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&tx_schema.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+		);
+
+		let identifier =
+			Ss58Identifier::create_identifier(&(id_digest).encode()[..], IdentifierType::Schema)
+				.map_err(|_| Error::<T>::InvalidIdentifierLength)?;
+
+		ensure!(!<Schemas<T>>::contains_key(&identifier), Error::<T>::SchemaAlreadyAnchored);
+
+		let digest = <T as frame_system::Config>::Hashing::hash(&tx_schema[..]);
+		let block_number = frame_system::Pallet::<T>::block_number();
+
+		log::debug!(
+			"Schema created with identifier: {:?}, schema: {:?} digest: {:?}, creator:
+		{:?}, block_number: {:?}",
+			identifier,
+			tx_schema,
+			digest,
+			creator,
+			block_number
+		);
+
+		<Schemas<T>>::insert(
+			&identifier,
+			SchemaEntryOf::<T> {
+				schema: tx_schema,
+				digest,
+				creator: creator.clone(),
+				space: space_id.clone(),
+				draft,
+			},
+		);
+
+		Self::update_activity(&identifier, CallTypeOf::Genesis).map_err(<Error<T>>::from)?;
+
+		Ok((identifier, space_id))
+	}
+
 	/// `ensure!` is a macro that takes a boolean expression and an error type.
 	/// If the expression is false, it returns the error
 	///
@@ -245,6 +460,21 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Returns the position of a schema in its lineage, or `None` if the
+	/// schema does not exist. Schemas that were never superseded are reported
+	/// as version 1.
+	pub fn schema_version(tx_ident: &SchemaIdOf) -> Option<SchemaVersionOf> {
+		if !<Schemas<T>>::contains_key(tx_ident) {
+			return None;
+		}
+		Some(<SchemaVersions<T>>::get(tx_ident).unwrap_or_default())
+	}
+
+	/// Returns `true` if the schema has been deprecated by its creator.
+	pub fn is_deprecated(tx_ident: &SchemaIdOf) -> bool {
+		<SchemaVersions<T>>::get(tx_ident).map_or(false, |version| version.deprecated)
+	}
+
 	/// Updates the global timeline with a new activity event for a schema.
 	///
 	/// An `EventEntryOf` struct is created, encapsulating the type of action
@@ -0,0 +1,100 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Storage migrations for the schema pallet.
+
+use crate::*;
+
+pub mod v2 {
+	use super::*;
+	use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade};
+	use sp_std::marker::PhantomData;
+
+	/// Schema entry layout before version 2 of the pallet.
+	#[derive(Encode, Decode)]
+	pub struct OldSchemaEntry<InputSchemaOf, SchemaHashOf, SchemaCreatorOf, SpaceIdOf> {
+		pub schema: InputSchemaOf,
+		pub digest: SchemaHashOf,
+		pub creator: SchemaCreatorOf,
+		pub space: SpaceIdOf,
+	}
+
+	pub type OldSchemaEntryOf<T> =
+		OldSchemaEntry<InputSchemaOf<T>, SchemaHashOf<T>, SchemaCreatorOf<T>, SpaceIdOf>;
+
+	/// Adds the JSON-Schema draft to existing schema entries. Schemas anchored
+	/// before structural validation was introduced were never parsed, so no
+	/// draft is recorded for them.
+	pub struct MigrateToSchemaDraft<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToSchemaDraft<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_version = Pallet::<T>::on_chain_storage_version();
+			if on_chain_version != 1 {
+				log::info!(
+					target: "runtime::schema",
+					"skipping schema draft migration, on-chain storage version is {:?}",
+					on_chain_version
+				);
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut reads = 1u64;
+			let mut writes = 0u64;
+
+			Schemas::<T>::translate_values::<OldSchemaEntryOf<T>, _>(|old| {
+				reads = reads.saturating_add(1);
+				writes = writes.saturating_add(1);
+				Some(SchemaEntryOf::<T> {
+					schema: old.schema,
+					digest: old.digest,
+					creator: old.creator,
+					space: old.space,
+					draft: None,
+				})
+			});
+
+			StorageVersion::new(2).put::<Pallet<T>>();
+			writes = writes.saturating_add(1);
+
+			log::info!(target: "runtime::schema", "migrated schema entries to version 2");
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			let schemas = Schemas::<T>::iter_keys().count() as u64;
+			Ok(schemas.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let expected = u64::decode(&mut &state[..])
+				.map_err(|_| sp_runtime::TryRuntimeError::Other("invalid pre-upgrade state"))?;
+			let migrated = Schemas::<T>::iter_values().count() as u64;
+
+			ensure!(migrated == expected, "every schema entry should be decodable");
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 2,
+				"storage version should be updated"
+			);
+			Ok(())
+		}
+	}
+}
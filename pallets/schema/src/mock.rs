@@ -64,6 +64,7 @@ impl mock_origin::Config for Test {
 
 parameter_types! {
 	pub const MaxEncodedSchemaLength: u32 = 15_360;
+	pub static ValidateSchemaStructure: bool = false;
 }
 
 impl pallet_schema::Config for Test {
@@ -73,11 +74,14 @@ impl pallet_schema::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
 	type MaxEncodedSchemaLength = MaxEncodedSchemaLength;
+	type ValidateSchemaStructure = ValidateSchemaStructure;
 }
 
 parameter_types! {
 	#[derive(Debug, Clone)]
 	pub const MaxSpaceDelegates: u32 = 5u32;
+	pub const MaxSubspaceDepth: u32 = 3u32;
+	pub const MaxDelegationExpirationsPerBlock: u32 = 5u32;
 }
 
 pub struct NetworkPermission;
@@ -93,18 +97,14 @@ impl pallet_chain_space::Config for Test {
 	type OriginSuccess = mock_origin::DoubleOrigin<AccountId, SubjectId>;
 	type SpaceCreatorId = SubjectId;
 	type MaxSpaceDelegates = MaxSpaceDelegates;
+	type MaxSubspaceDepth = MaxSubspaceDepth;
+	type MaxDelegationExpirationsPerBlock = MaxDelegationExpirationsPerBlock;
 	type ChainSpaceOrigin = EnsureRoot<AccountId>;
 	type NetworkPermission = NetworkPermission;
 	type WeightInfo = ();
 }
 
-parameter_types! {
-	pub const MaxEventsHistory: u32 = 6u32;
-}
-
-impl identifier::Config for Test {
-	type MaxEventsHistory = MaxEventsHistory;
-}
+impl identifier::Config for Test {}
 
 #[allow(dead_code)]
 pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
@@ -97,6 +97,7 @@ pub fn generate_authorization_id<T: Config>(digest: &SchemaHashOf<T>) -> Authori
 
 // submit_schema_creation_operation
 pub(crate) const DID_00: SubjectId = SubjectId(AccountId32::new([1u8; 32]));
+pub(crate) const DID_01: SubjectId = SubjectId(AccountId32::new([5u8; 32]));
 pub(crate) const ACCOUNT_00: AccountId = AccountId::new([1u8; 32]);
 
 // This test verifies the successful creation of a schema.
@@ -314,3 +315,245 @@ fn test_schema_lookup() {
 		}
 	});
 }
+
+// This test supersedes a schema and checks that the new version is linked to
+// its predecessor, carries the next version number and that the predecessor
+// cannot be superseded a second time.
+#[test]
+fn superseding_a_schema_should_link_versions() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 5u64;
+
+	let schema: InputSchemaOf<Test> = BoundedVec::try_from([1u8; 256].to_vec()).unwrap();
+	let next_schema: InputSchemaOf<Test> = BoundedVec::try_from([2u8; 256].to_vec()).unwrap();
+	let other_schema: InputSchemaOf<Test> = BoundedVec::try_from([3u8; 256].to_vec()).unwrap();
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let schema_id: SchemaIdOf =
+		generate_schema_id::<Test>(&<Test as frame_system::Config>::Hashing::hash(
+			&[&schema.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+		));
+	let next_schema_id: SchemaIdOf =
+		generate_schema_id::<Test>(&<Test as frame_system::Config>::Hashing::hash(
+			&[&next_schema.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+		));
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		assert_ok!(Schema::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			schema,
+			authorization_id.clone()
+		));
+		assert_eq!(Schema::schema_version(&schema_id), Some(SchemaVersionOf::default()));
+
+		assert_ok!(Schema::supersede(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			schema_id.clone(),
+			next_schema,
+			authorization_id.clone(),
+			true,
+		));
+
+		let previous = Schema::schema_version(&schema_id).expect("Schema should be versioned.");
+		assert_eq!(previous.successor, Some(next_schema_id.clone()));
+		assert!(previous.deprecated);
+		assert!(Schema::is_deprecated(&schema_id));
+
+		let current = Schema::schema_version(&next_schema_id).expect("Schema should be versioned.");
+		assert_eq!(current.version, 2);
+		assert_eq!(current.predecessor, Some(schema_id.clone()));
+		assert!(!Schema::is_deprecated(&next_schema_id));
+
+		assert_noop!(
+			Schema::supersede(
+				DoubleOrigin(author, creator).into(),
+				schema_id,
+				other_schema,
+				authorization_id,
+				false,
+			),
+			Error::<Test>::SchemaAlreadySuperseded
+		);
+	});
+}
+
+// This test checks that only the creator of a schema can supersede or
+// deprecate it, and that a schema cannot be deprecated twice.
+#[test]
+fn only_the_schema_creator_should_supersede_or_deprecate() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let delegate = DID_01;
+	let capacity = 5u64;
+
+	let schema: InputSchemaOf<Test> = BoundedVec::try_from([1u8; 256].to_vec()).unwrap();
+	let next_schema: InputSchemaOf<Test> = BoundedVec::try_from([2u8; 256].to_vec()).unwrap();
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let schema_id: SchemaIdOf =
+		generate_schema_id::<Test>(&<Test as frame_system::Config>::Hashing::hash(
+			&[&schema.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+		));
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let delegate_auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &delegate.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let delegate_authorization_id: Ss58Identifier =
+		generate_authorization_id::<Test>(&delegate_auth_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+		assert_ok!(Space::add_delegate_with_permissions(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_id,
+			delegate.clone(),
+			pallet_chain_space::Permissions::SCHEMA_CREATE,
+			authorization_id.clone(),
+			None,
+			None,
+		));
+
+		assert_ok!(Schema::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			schema,
+			authorization_id.clone()
+		));
+
+		assert_noop!(
+			Schema::supersede(
+				DoubleOrigin(author.clone(), delegate.clone()).into(),
+				schema_id.clone(),
+				next_schema,
+				delegate_authorization_id.clone(),
+				false,
+			),
+			Error::<Test>::UnauthorizedOperation
+		);
+		assert_noop!(
+			Schema::deprecate(
+				DoubleOrigin(author.clone(), delegate).into(),
+				schema_id.clone(),
+				delegate_authorization_id,
+			),
+			Error::<Test>::UnauthorizedOperation
+		);
+
+		assert_ok!(Schema::deprecate(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			schema_id.clone(),
+			authorization_id.clone(),
+		));
+		assert!(Schema::is_deprecated(&schema_id));
+
+		assert_noop!(
+			Schema::deprecate(DoubleOrigin(author, creator).into(), schema_id, authorization_id),
+			Error::<Test>::SchemaAlreadyDeprecated
+		);
+	});
+}
+
+// This test enables structural validation and checks that a well-formed
+// JSON-Schema is anchored with the draft it declares, while malformed
+// schemas are rejected with a descriptive error.
+#[test]
+fn structural_validation_should_record_draft_and_reject_malformed_schemas() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 10u64;
+
+	let schema: InputSchemaOf<Test> = BoundedVec::try_from(
+		br#"{"$schema":"https://json-schema.org/draft/2020-12/schema","type":"object","properties":{"name":{"type":"string","minLength":1}},"required":["name"]}"#
+			.to_vec(),
+	)
+	.unwrap();
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let schema_id: SchemaIdOf =
+		generate_schema_id::<Test>(&<Test as frame_system::Config>::Hashing::hash(
+			&[&schema.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+		));
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let malformed: Vec<(&[u8], Error<Test>)> = vec![
+		(&[2u8; 16][..], Error::<Test>::SchemaNotJson),
+		(&b"[]"[..], Error::<Test>::SchemaNotAnObject),
+		(&br#"{"$schema":"https://example.com/s"}"#[..], Error::<Test>::UnsupportedSchemaDraft),
+		(&br#"{"type":"text"}"#[..], Error::<Test>::InvalidSchemaType),
+		(&br#"{"properties":{"name":"string"}}"#[..], Error::<Test>::InvalidSchemaKeyword),
+	];
+
+	new_test_ext().execute_with(|| {
+		ValidateSchemaStructure::set(true);
+
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id, capacity));
+
+		assert_ok!(Schema::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			schema,
+			authorization_id.clone()
+		));
+		let stored_schema = Schemas::<Test>::get(&schema_id)
+			.expect("Schema Identifier should be present on chain.");
+		assert_eq!(stored_schema.draft, Some(SchemaDraft::Draft202012));
+
+		for (raw_schema, error) in malformed {
+			assert_noop!(
+				Schema::create(
+					DoubleOrigin(author.clone(), creator.clone()).into(),
+					BoundedVec::try_from(raw_schema.to_vec()).unwrap(),
+					authorization_id.clone()
+				),
+				error
+			);
+		}
+
+		ValidateSchemaStructure::set(false);
+	});
+}
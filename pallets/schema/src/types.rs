@@ -30,4 +30,62 @@ pub struct SchemaEntry<InputSchemaOf, SchemaHashOf, SchemaCreatorOf, SpaceIdOf>
 	pub creator: SchemaCreatorOf,
 	/// Identifier of the space asscoaiated with the schema
 	pub space: SpaceIdOf,
+	/// JSON-Schema draft declared by the schema, if it was validated.
+	pub draft: Option<SchemaDraft>,
+}
+
+/// JSON-Schema drafts that can be declared through the `$schema` keyword.
+#[derive(Encode, Decode, Clone, Copy, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub enum SchemaDraft {
+	/// `http://json-schema.org/draft-04/schema#`
+	Draft4,
+	/// `http://json-schema.org/draft-06/schema#`
+	Draft6,
+	/// `http://json-schema.org/draft-07/schema#`
+	Draft7,
+	/// `https://json-schema.org/draft/2019-09/schema`
+	Draft201909,
+	/// `https://json-schema.org/draft/2020-12/schema`
+	Draft202012,
+}
+
+impl SchemaDraft {
+	/// Maps a `$schema` URI to the draft it names. Both `http` and `https`
+	/// URIs are accepted, with or without an empty fragment.
+	pub fn from_uri(uri: &str) -> Option<Self> {
+		let uri = uri.strip_suffix('#').unwrap_or(uri);
+		let path = uri.strip_prefix("https://").or_else(|| uri.strip_prefix("http://"))?;
+		match path {
+			"json-schema.org/draft-04/schema" => Some(SchemaDraft::Draft4),
+			"json-schema.org/draft-06/schema" => Some(SchemaDraft::Draft6),
+			"json-schema.org/draft-07/schema" => Some(SchemaDraft::Draft7),
+			"json-schema.org/draft/2019-09/schema" => Some(SchemaDraft::Draft201909),
+			"json-schema.org/draft/2020-12/schema" => Some(SchemaDraft::Draft202012),
+			_ => None,
+		}
+	}
+}
+
+/// Version details of a schema that is part of a lineage.
+///
+/// Schemas anchored through `create` start a new lineage at version 1.
+/// Each schema anchored through `supersede` links back to its predecessor
+/// and carries the next version number.
+#[derive(Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct SchemaVersion<SchemaIdOf> {
+	/// Version of the schema within its lineage.
+	pub version: u32,
+	/// The schema this version supersedes.
+	pub predecessor: Option<SchemaIdOf>,
+	/// The schema superseding this version.
+	pub successor: Option<SchemaIdOf>,
+	/// Whether the creator has deprecated the schema.
+	pub deprecated: bool,
+}
+
+impl<SchemaIdOf> Default for SchemaVersion<SchemaIdOf> {
+	/// The version details of a schema that starts a lineage.
+	fn default() -> Self {
+		SchemaVersion { version: 1, predecessor: None, successor: None, deprecated: false }
+	}
 }
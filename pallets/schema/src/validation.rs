@@ -0,0 +1,256 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Structural validation of JSON-Schema documents.
+//!
+//! The checks cover the subset of the JSON-Schema meta-schema that is shared
+//! by drafts 4 to 2020-12: every known keyword must carry a value of the
+//! right kind and every subschema is validated recursively. Unknown keywords
+//! are ignored, as required by the specification. Nothing here depends on
+//! `std`, so the same checks run inside the runtime.
+
+use crate::types::SchemaDraft;
+use serde_json::{Map, Value};
+use sp_std::{collections::btree_set::BTreeSet, prelude::*};
+
+/// Maximum nesting of subschemas accepted in a single schema.
+pub const MAX_SCHEMA_DEPTH: u32 = 32;
+
+/// Keywords whose value must be a string.
+const STRING_KEYWORDS: [&str; 10] = [
+	"$id",
+	"$ref",
+	"$comment",
+	"$anchor",
+	"$schema",
+	"id",
+	"title",
+	"description",
+	"pattern",
+	"format",
+];
+
+/// Keywords whose value must be a non-negative integer.
+const COUNT_KEYWORDS: [&str; 8] = [
+	"maxLength",
+	"minLength",
+	"maxItems",
+	"minItems",
+	"maxProperties",
+	"minProperties",
+	"maxContains",
+	"minContains",
+];
+
+/// Keywords whose value must be a number.
+const NUMBER_KEYWORDS: [&str; 3] = ["multipleOf", "minimum", "maximum"];
+
+/// Keywords whose value must be a subschema.
+const SCHEMA_KEYWORDS: [&str; 10] = [
+	"not",
+	"additionalProperties",
+	"additionalItems",
+	"contains",
+	"propertyNames",
+	"if",
+	"then",
+	"else",
+	"unevaluatedItems",
+	"unevaluatedProperties",
+];
+
+/// Keywords whose value must be a non-empty array of subschemas.
+const SCHEMA_ARRAY_KEYWORDS: [&str; 4] = ["allOf", "anyOf", "oneOf", "prefixItems"];
+
+/// Keywords whose value must be an object mapping names to subschemas.
+const SCHEMA_MAP_KEYWORDS: [&str; 5] =
+	["properties", "patternProperties", "definitions", "$defs", "dependentSchemas"];
+
+/// The primitive types a `type` keyword can name.
+const PRIMITIVE_TYPES: [&str; 7] =
+	["null", "boolean", "object", "array", "number", "string", "integer"];
+
+/// Reasons a schema fails structural validation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValidationError {
+	/// The input is not a JSON document.
+	NotJson,
+	/// The root of the document is not a JSON object.
+	NotAnObject,
+	/// The `$schema` keyword names a draft that is not supported.
+	UnsupportedDraft,
+	/// The `type` keyword does not name valid primitive types.
+	InvalidType,
+	/// A keyword carries a value of the wrong kind.
+	InvalidKeyword,
+	/// Subschemas are nested deeper than [`MAX_SCHEMA_DEPTH`].
+	TooDeep,
+}
+
+/// Parses `input` as JSON and validates it as a JSON-Schema document.
+///
+/// Returns the draft named by the root `$schema` keyword, or `None` if the
+/// schema does not declare one.
+pub fn validate_schema(input: &[u8]) -> Result<Option<SchemaDraft>, ValidationError> {
+	let document: Value = serde_json::from_slice(input).map_err(|_| ValidationError::NotJson)?;
+	let root = document.as_object().ok_or(ValidationError::NotAnObject)?;
+
+	let draft = match root.get("$schema") {
+		Some(uri) => Some(
+			uri.as_str()
+				.and_then(SchemaDraft::from_uri)
+				.ok_or(ValidationError::UnsupportedDraft)?,
+		),
+		None => None,
+	};
+
+	validate_object(root, draft, 0)?;
+	Ok(draft)
+}
+
+/// Validates a subschema, which is either an object or, from draft 6
+/// onwards, a boolean.
+fn validate_subschema(
+	value: &Value,
+	draft: Option<SchemaDraft>,
+	depth: u32,
+) -> Result<(), ValidationError> {
+	match value {
+		Value::Object(schema) => validate_object(schema, draft, depth),
+		Value::Bool(_) if draft != Some(SchemaDraft::Draft4) => Ok(()),
+		_ => Err(ValidationError::InvalidKeyword),
+	}
+}
+
+/// Validates every known keyword of a schema object.
+fn validate_object(
+	schema: &Map<String, Value>,
+	draft: Option<SchemaDraft>,
+	depth: u32,
+) -> Result<(), ValidationError> {
+	if depth >= MAX_SCHEMA_DEPTH {
+		return Err(ValidationError::TooDeep);
+	}
+	let next = depth + 1;
+
+	for (keyword, value) in schema.iter() {
+		let keyword = keyword.as_str();
+		let valid = if keyword == "type" {
+			if !is_valid_type(value) {
+				return Err(ValidationError::InvalidType);
+			}
+			true
+		} else if STRING_KEYWORDS.contains(&keyword) {
+			value.is_string()
+		} else if COUNT_KEYWORDS.contains(&keyword) {
+			value.is_u64()
+		} else if NUMBER_KEYWORDS.contains(&keyword) {
+			value.is_number()
+		} else if keyword == "exclusiveMinimum" || keyword == "exclusiveMaximum" {
+			// Draft 4 uses booleans, later drafts use numbers.
+			value.is_number() || value.is_boolean()
+		} else if keyword == "uniqueItems" {
+			value.is_boolean()
+		} else if keyword == "required" {
+			is_unique_string_array(value)
+		} else if keyword == "enum" {
+			value.as_array().is_some_and(|values| !values.is_empty())
+		} else if keyword == "items" {
+			match value {
+				Value::Array(schemas) => {
+					for schema in schemas {
+						validate_subschema(schema, draft, next)?;
+					}
+					true
+				},
+				schema => {
+					validate_subschema(schema, draft, next)?;
+					true
+				},
+			}
+		} else if keyword == "dependencies" {
+			match value.as_object() {
+				Some(dependencies) => {
+					for dependency in dependencies.values() {
+						if !is_unique_string_array(dependency) {
+							validate_subschema(dependency, draft, next)?;
+						}
+					}
+					true
+				},
+				None => false,
+			}
+		} else if SCHEMA_KEYWORDS.contains(&keyword) {
+			validate_subschema(value, draft, next)?;
+			true
+		} else if SCHEMA_ARRAY_KEYWORDS.contains(&keyword) {
+			match value.as_array() {
+				Some(schemas) if !schemas.is_empty() => {
+					for schema in schemas {
+						validate_subschema(schema, draft, next)?;
+					}
+					true
+				},
+				_ => false,
+			}
+		} else if SCHEMA_MAP_KEYWORDS.contains(&keyword) {
+			match value.as_object() {
+				Some(schemas) => {
+					for schema in schemas.values() {
+						validate_subschema(schema, draft, next)?;
+					}
+					true
+				},
+				None => false,
+			}
+		} else {
+			true
+		};
+
+		if !valid {
+			return Err(ValidationError::InvalidKeyword);
+		}
+	}
+
+	Ok(())
+}
+
+/// Returns `true` if `value` is a primitive type name or a non-empty array
+/// of distinct primitive type names.
+fn is_valid_type(value: &Value) -> bool {
+	match value {
+		Value::String(name) => PRIMITIVE_TYPES.contains(&name.as_str()),
+		Value::Array(names) => {
+			let known = names
+				.iter()
+				.all(|name| name.as_str().is_some_and(|n| PRIMITIVE_TYPES.contains(&n)));
+			known && !names.is_empty() && is_unique_string_array(value)
+		},
+		_ => false,
+	}
+}
+
+/// Returns `true` if `value` is an array of distinct strings.
+///
+/// Distinctness is checked through an ordered set, so that the cost grows
+/// with `n log n` rather than quadratically with the length of the array.
+fn is_unique_string_array(value: &Value) -> bool {
+	let Some(values) = value.as_array() else { return false };
+	let mut seen = BTreeSet::new();
+	values.iter().all(|item| item.as_str().is_some_and(|s| seen.insert(s)))
+}
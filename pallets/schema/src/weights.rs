@@ -50,6 +50,8 @@ use core::marker::PhantomData;
 /// Weight functions needed for `pallet_schema`.
 pub trait WeightInfo {
 	fn create(l: u32, ) -> Weight;
+	fn supersede(l: u32, ) -> Weight;
+	fn deprecate() -> Weight;
 }
 
 /// Weights for `pallet_schema` using the CORD node and recommended hardware.
@@ -61,18 +63,62 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Schema::Schemas` (r:1 w:1)
 	/// Proof: `Schema::Schemas` (`max_values`: None, `max_size`: Some(15542), added: 18017, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// The range of component `l` is `[1, 15360]`.
 	fn create(l: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `719`
-		//  Estimated: `19007`
-		// Minimum execution time: 35_920_000 picoseconds.
-		Weight::from_parts(37_960_558, 19007)
-			// Standard Error: 10
-			.saturating_add(Weight::from_parts(3_232, 0).saturating_mul(l.into()))
+		//  Estimated: `25915`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(37_960_558, 25915)
+			// Includes 40_000 picoseconds per byte for structural validation of the schema.
+			.saturating_add(Weight::from_parts(43_232, 0).saturating_mul(l.into()))
 			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Schema::Schemas` (r:2 w:1)
+	/// Proof: `Schema::Schemas` (`max_values`: None, `max_size`: Some(15542), added: 18017, mode: `MaxEncodedLen`)
+	/// Storage: `Schema::SchemaVersions` (r:1 w:2)
+	/// Proof: `Schema::SchemaVersions` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
+	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:2 w:2)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:2)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[1, 15360]`.
+	fn supersede(l: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `49117`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(54_118_270, 49117)
+			// Includes 40_000 picoseconds per byte for structural validation of the schema.
+			.saturating_add(Weight::from_parts(43_241, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
+	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Spaces` (r:1 w:0)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `Schema::Schemas` (r:1 w:0)
+	/// Proof: `Schema::Schemas` (`max_values`: None, `max_size`: Some(15542), added: 18017, mode: `MaxEncodedLen`)
+	/// Storage: `Schema::SchemaVersions` (r:1 w:1)
+	/// Proof: `Schema::SchemaVersions` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn deprecate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `28542`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(29_870_000, 28542)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 }
@@ -85,18 +131,62 @@ impl WeightInfo for () {
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Schema::Schemas` (r:1 w:1)
 	/// Proof: `Schema::Schemas` (`max_values`: None, `max_size`: Some(15542), added: 18017, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// The range of component `l` is `[1, 15360]`.
 	fn create(l: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `719`
-		//  Estimated: `19007`
-		// Minimum execution time: 35_920_000 picoseconds.
-		Weight::from_parts(37_960_558, 19007)
-			// Standard Error: 10
-			.saturating_add(Weight::from_parts(3_232, 0).saturating_mul(l.into()))
+		//  Estimated: `25915`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(37_960_558, 25915)
+			// Includes 40_000 picoseconds per byte for structural validation of the schema.
+			.saturating_add(Weight::from_parts(43_232, 0).saturating_mul(l.into()))
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Schema::Schemas` (r:2 w:1)
+	/// Proof: `Schema::Schemas` (`max_values`: None, `max_size`: Some(15542), added: 18017, mode: `MaxEncodedLen`)
+	/// Storage: `Schema::SchemaVersions` (r:1 w:2)
+	/// Proof: `Schema::SchemaVersions` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
+	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:2 w:2)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:2)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[1, 15360]`.
+	fn supersede(l: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `49117`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(54_118_270, 49117)
+			// Includes 40_000 picoseconds per byte for structural validation of the schema.
+			.saturating_add(Weight::from_parts(43_241, 0).saturating_mul(l.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
+	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Spaces` (r:1 w:0)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `Schema::Schemas` (r:1 w:0)
+	/// Proof: `Schema::Schemas` (`max_values`: None, `max_size`: Some(15542), added: 18017, mode: `MaxEncodedLen`)
+	/// Storage: `Schema::SchemaVersions` (r:1 w:1)
+	/// Proof: `Schema::SchemaVersions` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn deprecate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `28542`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(29_870_000, 28542)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 }
@@ -4,10 +4,10 @@ use super::*;
 use codec::Encode;
 use cord_utilities::traits::GenerateBenchmarkOrigin;
 use frame_benchmarking::{account, benchmarks};
-use frame_support::sp_runtime::traits::Hash;
+use frame_support::{sp_runtime::traits::Hash, BoundedVec};
 use frame_system::RawOrigin;
 use identifier::{IdentifierType, Ss58Identifier};
-use pallet_chain_space::SpaceCodeOf;
+use pallet_chain_space::{Permissions, SpaceCodeOf};
 
 const SEED: u32 = 0;
 const MAX_PAYLOAD_BYTE_LENGTH: u32 = 5 * 1024;
@@ -28,6 +28,65 @@ pub fn generate_authorization_id<T: Config>(digest: &SpaceCodeOf<T>) -> Authoriz
 		.unwrap()
 }
 
+/// Adds `n` delegates with `permissions` to a space and returns them along
+/// with their authorization IDs.
+pub fn add_revocation_approvers<T: Config>(
+	origin: T::RuntimeOrigin,
+	space_id: &SpaceIdOf,
+	creator: &T::SpaceCreatorId,
+	authorization_id: &AuthorizationIdOf,
+	n: u32,
+) -> Vec<(T::SpaceCreatorId, AuthorizationIdOf)> {
+	(0..n)
+		.map(|i| {
+			let approver: T::SpaceCreatorId = account("approver", i, SEED);
+			pallet_chain_space::Pallet::<T>::add_delegate_with_permissions(
+				origin.clone(),
+				space_id.clone(),
+				approver.clone(),
+				Permissions::REVOKE | Permissions::UPDATE,
+				authorization_id.clone(),
+				None,
+				None,
+			)
+			.expect("Adding a delegate should not fail.");
+			let auth_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&space_id.encode()[..], &approver.encode()[..], &creator.encode()[..]].concat()[..],
+			);
+			(approver, generate_authorization_id::<T>(&auth_digest))
+		})
+		.collect()
+}
+
+/// Builds presentation metadata with every field filled to its maximum length.
+pub fn max_presentation_metadata<T: Config>() -> PresentationMetadataOf<T> {
+	let mut mime_type = b"application/".to_vec();
+	mime_type.resize(255, b'x');
+	let language = b"abcdefgh-abcdefgh-abcdefgh-abcdefgh".to_vec();
+	let uri = vec![b'u'; T::MaxPresentationUriLength::get() as usize];
+
+	PresentationMetadata {
+		mime_type: Some(mime_type.try_into().expect("MIME type should fit.")),
+		language: Some(language.try_into().expect("Language tag should fit.")),
+		location: Some(PresentationLocation::Uri(uri.try_into().expect("URI should fit."))),
+	}
+}
+
+/// Fills the expiry bucket of block `until` with `len` statements, so that
+/// setting a validity window scans and writes a full bucket.
+pub fn fill_expirations<T: Config>(until: BlockNumberFor<T>, len: u32) {
+	let ids = (0..len)
+		.map(|i| {
+			let digest = <T as frame_system::Config>::Hashing::hash(&i.encode()[..]);
+			generate_statement_id::<T>(&digest)
+		})
+		.collect::<Vec<_>>();
+	<Expirations<T>>::insert(
+		until,
+		BoundedVec::try_from(ids).expect("Expiry bucket should fit the statements."),
+	);
+}
+
 fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
 	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 }
@@ -73,9 +132,15 @@ benchmarks! {
 		pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
 		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity ).expect("Approval should not fail.");
 
-	}: _<T::RuntimeOrigin>(origin, statement_digest, authorization_id, None)
+		/* Both bounds are set and the expiry bucket is one statement short of full */
+		let valid_from = frame_system::Pallet::<T>::block_number() + 1u32.into();
+		let valid_until = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		fill_expirations::<T>(valid_until, T::MaxExpirationsPerBlock::get() - 1);
+
+	}: _<T::RuntimeOrigin>(origin, statement_digest, authorization_id, None, Some(valid_from), Some(valid_until))
 	verify {
 		assert_last_event::<T>(Event::Register { identifier, digest: statement_digest, author: did}.into());
+		assert_eq!(<Expirations<T>>::get(valid_until).len() as u32, T::MaxExpirationsPerBlock::get());
 	}
 
 	update {
@@ -117,12 +182,20 @@ benchmarks! {
 		pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
 		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity ).expect("Approval should not fail.");
 
-		/* register the entry before update */
-		let _ = Pallet::<T>::register(origin.clone(), statement_digest, authorization_id.clone(), None);
+		/* register the entry before update, last in a full expiry bucket */
+		let valid_from = frame_system::Pallet::<T>::block_number() + 1u32.into();
+		let valid_until = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		fill_expirations::<T>(valid_until, T::MaxExpirationsPerBlock::get() - 1);
+		Pallet::<T>::register(origin.clone(), statement_digest, authorization_id.clone(), None, Some(valid_from), Some(valid_until))?;
+
+		/* the update moves the statement to another bucket that is one short of full */
+		let new_valid_until = valid_until + 10u32.into();
+		fill_expirations::<T>(new_valid_until, T::MaxExpirationsPerBlock::get() - 1);
 
-	}: _<T::RuntimeOrigin>(origin, identifier.clone(), update_digest, authorization_id)
+	}: _<T::RuntimeOrigin>(origin, identifier.clone(), update_digest, authorization_id, Some(valid_from), Some(new_valid_until))
 	verify {
 		assert_last_event::<T>(Event::Update { identifier, digest: update_digest, author: did}.into());
+		assert_eq!(<Expirations<T>>::get(new_valid_until).len() as u32, T::MaxExpirationsPerBlock::get());
 	}
 
 	revoke {
@@ -158,7 +231,7 @@ benchmarks! {
 		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity ).expect("Approval should not fail.");
 
 		/* register the entry before update */
-		let _ = Pallet::<T>::register(origin.clone(), statement_digest, authorization_id.clone(), None);
+		let _ = Pallet::<T>::register(origin.clone(), statement_digest, authorization_id.clone(), None, None, None);
 
 	}: _<T::RuntimeOrigin>(origin, identifier.clone(), authorization_id)
 	verify {
@@ -198,7 +271,7 @@ benchmarks! {
 		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity ).expect("Approval should not fail.");
 
 		/* register the entry before update */
-		let _ = Pallet::<T>::register(origin.clone(), statement_digest, authorization_id.clone(), None);
+		let _ = Pallet::<T>::register(origin.clone(), statement_digest, authorization_id.clone(), None, None, None);
 		let _ = Pallet::<T>::revoke(origin.clone(), identifier.clone(), authorization_id.clone());
 
 	}: _<T::RuntimeOrigin>(origin, identifier.clone(), authorization_id)
@@ -241,7 +314,7 @@ benchmarks! {
 		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity ).expect("Approval should not fail.");
 
 		/* register the entry before update */
-		let _ = Pallet::<T>::register(origin.clone(), statement_digest, authorization_id.clone(), None);
+		let _ = Pallet::<T>::register(origin.clone(), statement_digest, authorization_id.clone(), None, None, None);
 
 	}: _<T::RuntimeOrigin>(origin, identifier.clone(), authorization_id)
 	verify {
@@ -320,9 +393,10 @@ benchmarks! {
 		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity ).expect("Approval should not fail.");
 
 		/* register the entry before update */
-		let _ = Pallet::<T>::register(origin.clone(), statement_digest, authorization_id.clone(), None);
+		let _ = Pallet::<T>::register(origin.clone(), statement_digest, authorization_id.clone(), None, None, None);
 
-	}: _<T::RuntimeOrigin>(origin, identifier.clone(), statement_digest, PresentationTypeOf::PDF, authorization_id)
+		let metadata = max_presentation_metadata::<T>();
+	}: _<T::RuntimeOrigin>(origin, identifier.clone(), statement_digest, PresentationTypeOf::PDF, Some(metadata), authorization_id)
 	verify {
 		assert_last_event::<T>(Event::PresentationAdded { identifier, digest: statement_digest, author: did}.into());
 	}
@@ -361,13 +435,303 @@ benchmarks! {
 		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity ).expect("Approval should not fail.");
 
 		/* register the entry before update */
-		let _ = Pallet::<T>::register(origin.clone(), statement_digest, authorization_id.clone(), None);
-		let _ = Pallet::<T>::add_presentation(origin.clone(), identifier.clone(), statement_digest, PresentationTypeOf::PDF, authorization_id.clone());
+		let _ = Pallet::<T>::register(origin.clone(), statement_digest, authorization_id.clone(), None, None, None);
+		let _ = Pallet::<T>::add_presentation(origin.clone(), identifier.clone(), statement_digest, PresentationTypeOf::PDF, Some(max_presentation_metadata::<T>()), authorization_id.clone());
 	}: _<T::RuntimeOrigin>(origin, identifier.clone(), statement_digest, authorization_id)
 	verify {
 		assert_last_event::<T>(Event::PresentationRemoved { identifier, digest: statement_digest, author: did}.into());
 	}
 
+	set_revocation_policy {
+		let n in 1 .. T::MaxRevocationApprovers::get();
+
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let did: T::SpaceCreatorId = account("did", 0, SEED);
+		let capacity = 100u64;
+
+		let raw_space = [56u8; 256].to_vec();
+		let space_digest = <T as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+		let space_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
+
+		let auth_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
+
+		let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did.clone());
+		let chain_space_origin = RawOrigin::Root.into();
+
+		pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
+		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id.clone(), capacity ).expect("Approval should not fail.");
+
+		let approvers: Vec<T::SpaceCreatorId> = add_revocation_approvers::<T>(origin.clone(), &space_id, &did, &authorization_id, n)
+			.into_iter()
+			.map(|(approver, _)| approver)
+			.collect();
+
+	}: _<T::RuntimeOrigin>(origin, authorization_id, n, approvers, true)
+	verify {
+		assert_last_event::<T>(Event::RevocationPolicySet { space: space_id, threshold: n, include_updates: true, author: did}.into());
+	}
+
+	remove_revocation_policy {
+
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let did: T::SpaceCreatorId = account("did", 0, SEED);
+		let capacity = 100u64;
+
+		let raw_space = [56u8; 256].to_vec();
+		let space_digest = <T as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+		let space_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
+
+		let auth_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
+
+		let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did.clone());
+		let chain_space_origin = RawOrigin::Root.into();
+
+		pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
+		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id.clone(), capacity ).expect("Approval should not fail.");
+
+		Pallet::<T>::set_revocation_policy(origin.clone(), authorization_id.clone(), 1, vec![did.clone()], false)?;
+
+	}: _<T::RuntimeOrigin>(origin, authorization_id)
+	verify {
+		assert_last_event::<T>(Event::RevocationPolicyRemoved { space: space_id, author: did}.into());
+	}
+
+	approve_revocation {
+
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let did: T::SpaceCreatorId = account("did", 0, SEED);
+		let capacity = 100u64;
+
+		let raw_space = [56u8; 256].to_vec();
+		let space_digest = <T as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+		let space_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
+
+		let statement = [77u8; 32].to_vec();
+		let statement_digest = <T as frame_system::Config>::Hashing::hash(&statement[..]);
+		let statement_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&statement_digest.encode()[..], &space_id.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let identifier = generate_statement_id::<T>(&statement_id_digest);
+
+		let auth_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
+
+		let statement_update = [12u8; 32].to_vec();
+		let update_digest = <T as frame_system::Config>::Hashing::hash(&statement_update[..]);
+
+		let origin =  <T as Config>::EnsureOrigin::generate_origin(caller.clone(), did.clone());
+		let chain_space_origin = RawOrigin::Root.into();
+
+		pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
+		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id.clone(), capacity ).expect("Approval should not fail.");
+
+		let (approver, approver_authorization_id) = add_revocation_approvers::<T>(origin.clone(), &space_id, &did, &authorization_id, 1).remove(0);
+		let approver_origin = <T as Config>::EnsureOrigin::generate_origin(caller, approver.clone());
+
+		Pallet::<T>::register(origin.clone(), statement_digest, authorization_id.clone(), None, None, None)?;
+		Pallet::<T>::set_revocation_policy(origin.clone(), authorization_id.clone(), 2, vec![did.clone(), approver], true)?;
+
+		/* propose the update, which awaits a second approval */
+		Pallet::<T>::update(origin, identifier.clone(), update_digest, authorization_id, None, None)?;
+
+	}: _<T::RuntimeOrigin>(approver_origin, identifier.clone(), approver_authorization_id)
+	verify {
+		assert_last_event::<T>(Event::Update { identifier, digest: update_digest, author: did}.into());
+	}
+
+	cancel_revocation {
+
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let did: T::SpaceCreatorId = account("did", 0, SEED);
+		let capacity = 100u64;
+
+		let raw_space = [56u8; 256].to_vec();
+		let space_digest = <T as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+		let space_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
+
+		let statement = [77u8; 32].to_vec();
+		let statement_digest = <T as frame_system::Config>::Hashing::hash(&statement[..]);
+		let statement_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&statement_digest.encode()[..], &space_id.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let identifier = generate_statement_id::<T>(&statement_id_digest);
+
+		let auth_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
+
+		let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did.clone());
+		let chain_space_origin = RawOrigin::Root.into();
+
+		pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
+		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id.clone(), capacity ).expect("Approval should not fail.");
+
+		let (approver, _) = add_revocation_approvers::<T>(origin.clone(), &space_id, &did, &authorization_id, 1).remove(0);
+
+		Pallet::<T>::register(origin.clone(), statement_digest, authorization_id.clone(), None, None, None)?;
+		Pallet::<T>::set_revocation_policy(origin.clone(), authorization_id.clone(), 2, vec![did.clone(), approver], false)?;
+		Pallet::<T>::revoke(origin.clone(), identifier.clone(), authorization_id.clone())?;
+
+	}: _<T::RuntimeOrigin>(origin, identifier.clone(), authorization_id)
+	verify {
+		assert_last_event::<T>(Event::RevocationCancelled { identifier, author: did}.into());
+	}
+
+	suspend {
+
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let did: T::SpaceCreatorId = account("did", 0, SEED);
+		let capacity = 5u64;
+
+		let raw_space = [56u8; 256].to_vec();
+		let space_digest = <T as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+		let space_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
+
+		let statement = [77u8; 32].to_vec();
+		let statement_digest = <T as frame_system::Config>::Hashing::hash(&statement[..]);
+		let statement_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&statement_digest.encode()[..], &space_id.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let identifier = generate_statement_id::<T>(&statement_id_digest);
+
+		let auth_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
+
+		let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did.clone());
+		let chain_space_origin = RawOrigin::Root.into();
+
+		pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
+		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity ).expect("Approval should not fail.");
+
+		Pallet::<T>::register(origin.clone(), statement_digest, authorization_id.clone(), None, None, None)?;
+		let lift_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+
+	}: _<T::RuntimeOrigin>(origin, identifier.clone(), 1, Some(lift_at), authorization_id)
+	verify {
+		assert_last_event::<T>(Event::Suspend { identifier, reason: 1, lift_at: Some(lift_at), author: did}.into());
+	}
+
+	unsuspend {
+
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let did: T::SpaceCreatorId = account("did", 0, SEED);
+		let capacity = 5u64;
+
+		let raw_space = [56u8; 256].to_vec();
+		let space_digest = <T as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+		let space_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
+
+		let statement = [77u8; 32].to_vec();
+		let statement_digest = <T as frame_system::Config>::Hashing::hash(&statement[..]);
+		let statement_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&statement_digest.encode()[..], &space_id.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let identifier = generate_statement_id::<T>(&statement_id_digest);
+
+		let auth_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
+
+		let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did.clone());
+		let chain_space_origin = RawOrigin::Root.into();
+
+		pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
+		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity ).expect("Approval should not fail.");
+
+		Pallet::<T>::register(origin.clone(), statement_digest, authorization_id.clone(), None, None, None)?;
+		let lift_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		Pallet::<T>::suspend(origin.clone(), identifier.clone(), 1, Some(lift_at), authorization_id.clone())?;
+
+	}: _<T::RuntimeOrigin>(origin, identifier.clone(), authorization_id)
+	verify {
+		assert_last_event::<T>(Event::Unsuspend { identifier, author: did}.into());
+	}
+
+	transfer_space {
+		let n in 1 .. T::MaxRemoveEntries::get() as u32;
+
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let did: T::SpaceCreatorId = account("did", 0, SEED);
+		let capacity = 10_000u64;
+
+		let raw_space = [56u8; 256].to_vec();
+		let space_digest = <T as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+		let space_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
+
+		let raw_target_space = [57u8; 256].to_vec();
+		let target_space_digest = <T as frame_system::Config>::Hashing::hash(&raw_target_space.encode()[..]);
+		let target_space_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&target_space_digest.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let target_space_id: SpaceIdOf = generate_space_id::<T>(&target_space_id_digest);
+
+		let statement = [77u8; 32].to_vec();
+		let statement_digest = <T as frame_system::Config>::Hashing::hash(&statement[..]);
+		let statement_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&statement_digest.encode()[..], &space_id.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let identifier = generate_statement_id::<T>(&statement_id_digest);
+
+		let auth_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
+		let target_auth_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&target_space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let target_authorization_id: Ss58Identifier = generate_authorization_id::<T>(&target_auth_digest);
+
+		let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did.clone());
+
+		pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
+		pallet_chain_space::Pallet::<T>::approve(RawOrigin::Root.into(), space_id.clone(), capacity ).expect("Approval should not fail.");
+		pallet_chain_space::Pallet::<T>::create(origin.clone(), target_space_digest )?;
+		pallet_chain_space::Pallet::<T>::approve(RawOrigin::Root.into(), target_space_id.clone(), capacity ).expect("Approval should not fail.");
+
+		Pallet::<T>::register(origin.clone(), statement_digest, authorization_id.clone(), None, None, None)?;
+		for i in 1 .. n {
+			let presentation_digest = <T as frame_system::Config>::Hashing::hash(&i.encode()[..]);
+			Pallet::<T>::add_presentation(origin.clone(), identifier.clone(), presentation_digest, PresentationTypeOf::PDF, None, authorization_id.clone())?;
+		}
+
+	}: _<T::RuntimeOrigin>(origin, identifier.clone(), authorization_id, target_authorization_id)
+	verify {
+		assert_last_event::<T>(Event::Transfer { identifier, from: space_id, to: target_space_id, author: did}.into());
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 
 }
@@ -54,6 +54,14 @@
 //! - `revoke`: Marks a statement's reference as inactive.
 //! - `restore`: Reactivates a revoked statement's reference.
 //! - `remove`: Removes a statement's reference from the blockchain.
+//! - `set_revocation_policy`: Requires revocations within a space to be approved by M of N
+//!   designated delegates. Statements under a policy cannot be removed, restored or moved to
+//!   another space.
+//! - `approve_revocation`: Approves a pending revocation, executing it once the threshold is met.
+//! - `cancel_revocation`: Withdraws a pending revocation.
+//! - `suspend`: Places a temporary hold on a statement, optionally lifted at a given block.
+//! - `unsuspend`: Lifts the hold placed on a statement.
+//! - `transfer_space`: Moves a statement, with its entries and presentations, to another space.
 //!
 //!## Related Modules
 //!
@@ -81,7 +89,12 @@ pub mod mock;
 pub mod tests;
 
 use cord_primitives::StatusOf;
-use frame_support::{ensure, storage::types::StorageMap};
+use frame_support::{
+	ensure,
+	pallet_prelude::Weight,
+	storage::{types::StorageMap, with_storage_layer},
+	traits::Get,
+};
 use sp_runtime::traits::UniqueSaturatedInto;
 use sp_std::{prelude::Clone, str};
 pub mod types;
@@ -133,10 +146,59 @@ pub mod pallet {
 		StatementDigestOf<T>,
 		SpaceIdOf,
 	>;
+	/// Type for the MIME type of a presentation
+	pub type PresentationMimeTypeOf = BoundedVec<u8, ConstU32<255>>;
+	/// Type for the BCP 47 language tag of a presentation
+	pub type PresentationLanguageOf = BoundedVec<u8, ConstU32<35>>;
+	/// Type for the URI of a rendered presentation
+	pub type PresentationUriOf<T> = BoundedVec<u8, <T as Config>::MaxPresentationUriLength>;
+	/// Type for the location of a rendered presentation
+	pub type PresentationLocationOf<T> =
+		PresentationLocation<StatementDigestOf<T>, PresentationUriOf<T>>;
+	/// Type for the metadata of a presentation
+	pub type PresentationMetadataOf<T> = PresentationMetadata<
+		PresentationMimeTypeOf,
+		PresentationLanguageOf,
+		PresentationLocationOf<T>,
+	>;
+	/// Type for the presentation information returned by the runtime API
+	pub type StatementPresentationInfoOf<T> =
+		StatementPresentationInfo<StatementDigestOf<T>, StatementCreatorOf<T>>;
+	/// Type for the statement validity window
+	pub type StatementValidityOf<T> = StatementValidity<BlockNumberFor<T>>;
+	/// Type for the statement information returned by the runtime API
+	pub type StatementInfoOf<T> = StatementInfo<
+		StatementIdOf,
+		StatementDigestOf<T>,
+		StatementCreatorOf<T>,
+		BlockNumberFor<T>,
+	>;
+	/// Application-defined code describing why a statement was suspended
+	pub type SuspensionReasonOf = u16;
+	/// Type for the suspension of a statement
+	pub type StatementSuspensionOf<T> =
+		StatementSuspension<SuspensionReasonOf, StatementCreatorOf<T>, BlockNumberFor<T>>;
+	/// Type for the delegates designated to approve revocations
+	pub type RevocationApproversOf<T> =
+		BoundedVec<StatementCreatorOf<T>, <T as Config>::MaxRevocationApprovers>;
+	/// Type for the revocation policy of a space
+	pub type RevocationPolicyOf<T> = RevocationPolicy<RevocationApproversOf<T>>;
+	/// Type for the action of a revocation proposal
+	pub type ProposedActionOf<T> = ProposedAction<StatementDigestOf<T>, StatementValidityOf<T>>;
+	/// Type for a pending revocation proposal
+	pub type RevocationProposalOf<T> = RevocationProposal<
+		ProposedActionOf<T>,
+		StatementCreatorOf<T>,
+		RevocationApproversOf<T>,
+		BlockNumberFor<T>,
+	>;
 
 	#[pallet::config]
 	pub trait Config:
-		frame_system::Config + pallet_chain_space::Config + identifier::Config
+		frame_system::Config
+		+ pallet_chain_space::Config
+		+ pallet_schema::Config
+		+ identifier::Config
 	{
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 		type EnsureOrigin: EnsureOrigin<
@@ -150,6 +212,22 @@ pub mod pallet {
 		/// Maximum removals per call
 		#[pallet::constant]
 		type MaxRemoveEntries: Get<u16>;
+		/// Maximum number of statements that can lapse in a single block
+		#[pallet::constant]
+		type MaxExpirationsPerBlock: Get<u32>;
+		/// Whether statements may not be registered against deprecated schemas
+		#[pallet::constant]
+		type RejectDeprecatedSchemas: Get<bool>;
+		/// Maximum number of delegates that can be designated to approve
+		/// revocations within a space
+		#[pallet::constant]
+		type MaxRevocationApprovers: Get<u32>;
+		/// Number of blocks after which a pending revocation proposal lapses
+		#[pallet::constant]
+		type RevocationProposalLifetime: Get<BlockNumberFor<Self>>;
+		/// Maximum length of the URI of a rendered presentation
+		#[pallet::constant]
+		type MaxPresentationUriLength: Get<u32>;
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -159,7 +237,13 @@ pub mod pallet {
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			Self::expire_statements(n)
+				.saturating_add(Self::expire_revocation_proposals(n))
+				.saturating_add(Self::lift_suspensions(n))
+		}
+	}
 
 	/// statement identifiers stored on chain.
 	/// It maps from an identifier to its details.
@@ -194,6 +278,20 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Metadata of statement presentations stored on chain.
+	/// It maps from a statement identifier and presentation digest to the
+	/// metadata describing the presentation.
+	#[pallet::storage]
+	pub type PresentationsMetadata<T> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		StatementIdOf,
+		Blake2_128Concat,
+		StatementDigestOf<T>,
+		PresentationMetadataOf<T>,
+		OptionQuery,
+	>;
+
 	/// Revocation registry of statement entries stored on chain.
 	/// It maps from a statement identifier and hash to its details.
 	#[pallet::storage]
@@ -220,6 +318,64 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Validity windows of statements stored on chain.
+	/// It maps from a statement identifier to the period within which its
+	/// latest state is considered valid.
+	#[pallet::storage]
+	pub type ValidityWindows<T> =
+		StorageMap<_, Blake2_128Concat, StatementIdOf, StatementValidityOf<T>, OptionQuery>;
+
+	/// maps block number to the list of statements set to expire at this block
+	#[pallet::storage]
+	pub type Expirations<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<StatementIdOf, T::MaxExpirationsPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Suspensions of statements stored on chain.
+	/// It maps from a statement identifier to the hold placed on it.
+	#[pallet::storage]
+	pub type Suspensions<T> =
+		StorageMap<_, Blake2_128Concat, StatementIdOf, StatementSuspensionOf<T>, OptionQuery>;
+
+	/// maps block number to the list of statements whose suspension is lifted
+	/// at this block
+	#[pallet::storage]
+	pub type SuspensionLifts<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<StatementIdOf, T::MaxExpirationsPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Revocation policies of spaces stored on chain.
+	/// It maps from a space identifier to the approvals required to revoke
+	/// its statements.
+	#[pallet::storage]
+	pub type RevocationPolicies<T> =
+		StorageMap<_, Blake2_128Concat, SpaceIdOf, RevocationPolicyOf<T>, OptionQuery>;
+
+	/// Pending revocation proposals stored on chain.
+	/// It maps from a statement identifier to the proposal awaiting approval.
+	#[pallet::storage]
+	pub type RevocationProposals<T> =
+		StorageMap<_, Blake2_128Concat, StatementIdOf, RevocationProposalOf<T>, OptionQuery>;
+
+	/// maps block number to the list of revocation proposals set to lapse at
+	/// this block
+	#[pallet::storage]
+	pub type RevocationProposalExpirations<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<StatementIdOf, T::MaxExpirationsPerBlock>,
+		ValueQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -272,6 +428,62 @@ pub mod pallet {
 			indices: Vec<u16>,
 			author: StatementCreatorOf<T>,
 		},
+		/// The validity window of a statement has ended.
+		/// \[statement identifier\]
+		Expired { identifier: StatementIdOf },
+		/// A statement has been suspended.
+		/// \[statement identifier, reason, lift block, controller\]
+		Suspend {
+			identifier: StatementIdOf,
+			reason: SuspensionReasonOf,
+			lift_at: Option<BlockNumberFor<T>>,
+			author: StatementCreatorOf<T>,
+		},
+		/// The suspension of a statement has been lifted.
+		/// \[statement identifier, controller\]
+		Unsuspend { identifier: StatementIdOf, author: StatementCreatorOf<T> },
+		/// The suspension of a statement has lapsed at its lift block.
+		/// \[statement identifier\]
+		SuspensionLapsed { identifier: StatementIdOf },
+		/// A revocation policy has been set for a space.
+		/// \[space identifier, threshold, include updates, controller\]
+		RevocationPolicySet {
+			space: SpaceIdOf,
+			threshold: u32,
+			include_updates: bool,
+			author: StatementCreatorOf<T>,
+		},
+		/// The revocation policy of a space has been removed.
+		/// \[space identifier, controller\]
+		RevocationPolicyRemoved { space: SpaceIdOf, author: StatementCreatorOf<T> },
+		/// A revocation or update of a statement awaits approval.
+		/// \[statement identifier, controller\]
+		RevocationProposed { identifier: StatementIdOf, author: StatementCreatorOf<T> },
+		/// A pending revocation proposal has been approved.
+		/// \[statement identifier, approvals, controller\]
+		RevocationApproved {
+			identifier: StatementIdOf,
+			approvals: u32,
+			author: StatementCreatorOf<T>,
+		},
+		/// A pending revocation proposal has been cancelled.
+		/// \[statement identifier, controller\]
+		RevocationCancelled { identifier: StatementIdOf, author: StatementCreatorOf<T> },
+		/// A pending revocation proposal lapsed without enough approvals.
+		/// \[statement identifier\]
+		RevocationProposalExpired { identifier: StatementIdOf },
+		/// An approved revocation proposal could no longer be executed and has
+		/// been discarded.
+		/// \[statement identifier, error\]
+		RevocationProposalFailed { identifier: StatementIdOf, error: DispatchError },
+		/// A statement has been moved to another space.
+		/// \[statement identifier, source space, target space, controller\]
+		Transfer {
+			identifier: StatementIdOf,
+			from: SpaceIdOf,
+			to: SpaceIdOf,
+			author: StatementCreatorOf<T>,
+		},
 	}
 
 	#[pallet::error]
@@ -336,6 +548,49 @@ pub mod pallet {
 		PresentationNotFound,
 		/// Statement digest already present on the chain.
 		StatementDigestAlreadyAnchored,
+		/// The validity window is empty or already over.
+		InvalidValidityWindow,
+		/// Maximum number of statements expiring in a block reached.
+		MaxExpirationsExceededForTheBlock,
+		/// The referenced schema has been deprecated.
+		SchemaDeprecated,
+		/// Statement is suspended
+		StatementSuspended,
+		/// Statement is not suspended
+		StatementNotSuspended,
+		/// The lift block of a suspension is already over.
+		InvalidSuspensionLift,
+		/// The threshold is zero or exceeds the number of approvers.
+		InvalidRevocationThreshold,
+		/// More than the maximum number of approvers.
+		TooManyRevocationApprovers,
+		/// An approver is listed more than once.
+		DuplicateRevocationApprover,
+		/// An approver is not a delegate of the space.
+		RevocationApproverNotADelegate,
+		/// The space has no revocation policy.
+		RevocationPolicyNotFound,
+		/// A revocation proposal is already pending for the statement.
+		RevocationProposalAlreadyPending,
+		/// Revocation proposal not found
+		RevocationProposalNotFound,
+		/// Only designated approvers may approve a revocation.
+		NotARevocationApprover,
+		/// The revocation proposal has already been approved by the approver.
+		RevocationAlreadyApproved,
+		/// The MIME type of a presentation is malformed.
+		InvalidPresentationMimeType,
+		/// The language tag of a presentation is malformed.
+		InvalidPresentationLanguage,
+		/// The URI of a presentation is malformed.
+		InvalidPresentationUri,
+		/// The source and target spaces of a transfer are the same.
+		TransferToSameSpace,
+		/// The statement has more entries and presentations than can be moved in
+		/// a single transfer.
+		MaxTransferEntriesExceeded,
+		/// The revocation policy of the space does not admit the operation.
+		RevocationPolicyInEffect,
 	}
 
 	#[pallet::call]
@@ -370,6 +625,8 @@ pub mod pallet {
 		/// - `digest`: The digest of the statement, serving as a unique identifier.
 		/// - `authorization`: The authorization ID, verifying the creator's delegation status.
 		/// - `schema_id`: An optional schema identifier to be associated with the statement.
+		/// - `valid_from`: An optional block from which the statement is valid.
+		/// - `valid_until`: An optional block at which the statement expires.
 		///
 		/// # Returns
 		/// A `DispatchResult` indicating the success or failure of the
@@ -379,20 +636,22 @@ pub mod pallet {
 		/// # Errors
 		/// The function can fail for several reasons including unauthorized
 		/// origin, the creator not being a delegate, space capacity being
-		/// exceeded, invalid statement identifier, or the statement already
-		/// being anchored. Errors related to incrementing space usage or
-		/// updating the activity log may also occur.
+		/// exceeded, invalid statement identifier, an invalid validity window, or
+		/// the statement already being anchored. Errors related to incrementing
+		/// space usage or updating the activity log may also occur.
 		///
 		/// # Events
 		/// - `Create`: Emitted when a statement is successfully created, containing the
 		///   `identifier`, `digest`, and `author` (creator).
 		#[pallet::call_index(0)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::register())]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::register().saturating_add(pallet_chain_space::Pallet::<T>::authorization_weight()))]
 		pub fn register(
 			origin: OriginFor<T>,
 			digest: StatementDigestOf<T>,
 			authorization: AuthorizationIdOf,
 			schema_id: Option<SchemaIdOf>,
+			valid_from: Option<BlockNumberFor<T>>,
+			valid_until: Option<BlockNumberFor<T>>,
 		) -> DispatchResult {
 			let creator = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
 			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_origin(
@@ -401,6 +660,10 @@ pub mod pallet {
 			)
 			.map_err(<pallet_chain_space::Error<T>>::from)?;
 
+			Self::ensure_schema_not_deprecated(&schema_id)?;
+
+			let validity = Self::validity_window(valid_from, valid_until)?;
+
 			// Id Digest = concat (H(<scale_encoded_statement_digest>,
 			// <scale_encoded_space_identifier>, <scale_encoded_creator_identifier>))
 			let id_digest = <T as frame_system::Config>::Hashing::hash(
@@ -430,6 +693,8 @@ pub mod pallet {
 			<Entries<T>>::insert(&identifier, digest, creator.clone());
 			<IdentifierLookup<T>>::insert(digest, &space_id, &identifier);
 
+			Self::set_validity(&identifier, validity)?;
+
 			Self::update_activity(&identifier, CallTypeOf::Genesis).map_err(<Error<T>>::from)?;
 
 			Self::deposit_event(Event::Register { identifier, digest, author: creator });
@@ -461,12 +726,24 @@ pub mod pallet {
 		/// emitted, which includes the statement identifier, the new digest,
 		/// and the authoring updater's details.
 		///
+		/// If the space has a revocation policy that includes updates, the
+		/// update is not applied right away. A `RevocationProposed` event is
+		/// emitted instead and the update is executed by `approve_revocation`
+		/// once enough designated delegates have approved it. As an update
+		/// revokes the previous digest, updates are refused under a policy
+		/// that does not include them.
+		///
 		/// # Parameters
 		/// - `origin`: The origin of the dispatch call, which should be a signed message from the
 		///   updater.
 		/// - `statement_id`: The identifier of the statement to be updated.
 		/// - `new_statement_digest`: The new digest to replace the existing one for the statement.
 		/// - `authorization`: The authorization ID, verifying the updater's delegation status.
+		/// - `valid_from`: An optional block from which the updated statement is valid.
+		/// - `valid_until`: An optional block at which the updated statement expires.
+		///
+		/// The validity window supplied here replaces any window recorded for
+		/// the previous state of the statement.
 		///
 		/// # Returns
 		/// A `DispatchResult` indicating the success or failure of the update
@@ -476,28 +753,35 @@ pub mod pallet {
 		/// # Errors
 		/// The function can fail due to several reasons including an
 		/// unauthorized origin, the statement not found, the statement being
-		/// revoked, the new digest being the same as the existing one, or the
-		/// updater not being authorized for the operation.
+		/// revoked, the new digest being the same as the existing one, an
+		/// invalid validity window, the updater not being authorized for the
+		/// operation, or a revocation policy of the space that does not include
+		/// updates (`RevocationPolicyInEffect`).
 		///
 		/// # Events
 		/// - `Update`: Emitted when a statement is successfully updated, containing the
 		///   `identifier`, `digest`, and `author`
 		/// (updater).
 		#[pallet::call_index(1)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::update())]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::update().saturating_add(pallet_chain_space::Pallet::<T>::authorization_weight()))]
 		pub fn update(
 			origin: OriginFor<T>,
 			statement_id: StatementIdOf,
 			new_statement_digest: StatementDigestOf<T>,
 			authorization: AuthorizationIdOf,
+			valid_from: Option<BlockNumberFor<T>>,
+			valid_until: Option<BlockNumberFor<T>>,
 		) -> DispatchResult {
 			let updater = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
-			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_origin(
+			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_permission_origin(
 				&authorization,
 				&updater,
+				pallet_chain_space::Permissions::UPDATE,
 			)
 			.map_err(<pallet_chain_space::Error<T>>::from)?;
 
+			let validity = Self::validity_window(valid_from, valid_until)?;
+
 			let statement_details =
 				<Statements<T>>::get(&statement_id).ok_or(Error::<T>::StatementNotFound)?;
 
@@ -508,37 +792,31 @@ pub mod pallet {
 				Error::<T>::StatementRevoked
 			);
 
+			ensure!(!Self::is_suspended(&statement_id), Error::<T>::StatementSuspended);
+
 			ensure!(
 				!<Entries<T>>::contains_key(&statement_id, new_statement_digest),
 				Error::<T>::StatementDigestAlreadyAnchored
 			);
 
-			<RevocationList<T>>::insert(
-				&statement_id,
-				statement_details.digest,
-				StatementEntryStatusOf::<T> { creator: updater.clone(), revoked: true },
-			);
-
-			<Entries<T>>::insert(&statement_id, new_statement_digest, updater.clone());
-
-			<IdentifierLookup<T>>::insert(
-				new_statement_digest,
-				statement_details.space.clone(),
-				&statement_id,
-			);
+			if let Some(policy) = <RevocationPolicies<T>>::get(&space_id) {
+				ensure!(policy.include_updates, Error::<T>::RevocationPolicyInEffect);
+				Self::propose_revocation(
+					&statement_id,
+					ProposedAction::Update { digest: new_statement_digest, validity },
+					updater,
+					&policy,
+				)?;
+				return Ok(());
+			}
 
-			<Statements<T>>::insert(
+			Self::update_statement(
 				&statement_id,
-				StatementDetailsOf::<T> { digest: new_statement_digest, ..statement_details },
-			);
-
-			Self::update_activity(&statement_id, CallTypeOf::Update).map_err(<Error<T>>::from)?;
-
-			Self::deposit_event(Event::Update {
-				identifier: statement_id,
-				digest: new_statement_digest,
-				author: updater,
-			});
+				statement_details,
+				new_statement_digest,
+				validity,
+				updater,
+			)?;
 
 			Ok(())
 		}
@@ -569,6 +847,11 @@ pub mod pallet {
 		/// the statement with the statement identifier and the
 		/// updater's information.
 		///
+		/// If the space has a revocation policy, the revocation is proposed
+		/// instead and executed by `approve_revocation` once enough designated
+		/// delegates have approved it. The approval of the updater counts
+		/// towards the threshold if they are one of the approvers.
+		///
 		/// # Parameters
 		/// - `origin`: The origin of the dispatch call, which should be a signed message from the
 		///   updater.
@@ -593,16 +876,17 @@ pub mod pallet {
 		///   `identifier` of the statement and
 		/// the `author` who is the updater.
 		#[pallet::call_index(2)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::revoke())]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::revoke().saturating_add(pallet_chain_space::Pallet::<T>::authorization_weight()))]
 		pub fn revoke(
 			origin: OriginFor<T>,
 			statement_id: StatementIdOf,
 			authorization: AuthorizationIdOf,
 		) -> DispatchResult {
 			let updater = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
-			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_origin(
+			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_permission_origin(
 				&authorization,
 				&updater,
+				pallet_chain_space::Permissions::REVOKE,
 			)
 			.map_err(<pallet_chain_space::Error<T>>::from)?;
 
@@ -616,14 +900,12 @@ pub mod pallet {
 
 			ensure!(statement_details.space == space_id, Error::<T>::UnauthorizedOperation);
 
-			<RevocationList<T>>::insert(
-				&statement_id,
-				statement_details.digest,
-				StatementEntryStatusOf::<T> { creator: updater.clone(), revoked: true },
-			);
+			if let Some(policy) = <RevocationPolicies<T>>::get(&space_id) {
+				Self::propose_revocation(&statement_id, ProposedAction::Revoke, updater, &policy)?;
+				return Ok(());
+			}
 
-			Self::update_activity(&statement_id, CallTypeOf::Revoke).map_err(<Error<T>>::from)?;
-			Self::deposit_event(Event::Revoke { identifier: statement_id, author: updater });
+			Self::revoke_statement(&statement_id, statement_details.digest, updater)?;
 
 			Ok(())
 		}
@@ -669,24 +951,27 @@ pub mod pallet {
 		///
 		/// # Errors
 		/// The function can fail for several reasons including the statement
-		/// not being found, not being revoked, or the updater lacking the
-		/// authority to perform the restoration.
+		/// not being found, not being revoked, the updater lacking the
+		/// authority to perform the restoration, or the space having a
+		/// revocation policy (`RevocationPolicyInEffect`), as a single
+		/// delegate could otherwise undo a revocation its approvers agreed on.
 		///
 		/// # Events
 		/// - `Restored`: Emitted when a statement is successfully restored, containing the
 		///   `identifier` of the statement
 		/// and the `author` who is the updater.
 		#[pallet::call_index(3)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::restore())]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::restore().saturating_add(pallet_chain_space::Pallet::<T>::authorization_weight()))]
 		pub fn restore(
 			origin: OriginFor<T>,
 			statement_id: StatementIdOf,
 			authorization: AuthorizationIdOf,
 		) -> DispatchResult {
 			let updater = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
-			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_origin(
+			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_permission_origin(
 				&authorization,
 				&updater,
+				pallet_chain_space::Permissions::REVOKE,
 			)
 			.map_err(<pallet_chain_space::Error<T>>::from)?;
 
@@ -700,6 +985,11 @@ pub mod pallet {
 
 			ensure!(statement_details.space == space_id, Error::<T>::UnauthorizedOperation);
 
+			ensure!(
+				!<RevocationPolicies<T>>::contains_key(&space_id),
+				Error::<T>::RevocationPolicyInEffect
+			);
+
 			<RevocationList<T>>::remove(&statement_id, statement_details.digest);
 
 			Self::update_activity(&statement_id, CallTypeOf::Restore).map_err(<Error<T>>::from)?;
@@ -732,6 +1022,10 @@ pub mod pallet {
 		/// entries are removed, and the space usage is decremented by the
 		/// number of entries actually removed.
 		///
+		/// Statements of a space with a revocation policy cannot be removed, as
+		/// the removal would discard their revocations without approval. The
+		/// policy has to be removed first.
+		///
 		/// After the removal process, the function updates the activity log to
 		/// record the event. It then emits either a `Removed` event for a
 		/// complete removal or a `PartialRemoval` event for a partial removal,
@@ -752,8 +1046,9 @@ pub mod pallet {
 		///
 		/// # Errors
 		/// The function can fail for several reasons including the statement
-		/// not being found or the updater lacking the authority to perform the
-		/// removal.
+		/// not being found, the updater lacking the authority to perform the
+		/// removal, or the space having a revocation policy
+		/// (`RevocationPolicyInEffect`).
 		///
 		/// # Events
 		/// - `Removed`: Emitted when a statement and all its entries are completely removed.
@@ -761,16 +1056,17 @@ pub mod pallet {
 		///   the number of entries
 		/// removed.
 		#[pallet::call_index(4)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove(T::MaxRemoveEntries::get() as u32))]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove(T::MaxRemoveEntries::get() as u32).saturating_add(pallet_chain_space::Pallet::<T>::authorization_weight().saturating_mul(2)))]
 		pub fn remove(
 			origin: OriginFor<T>,
 			statement_id: StatementIdOf,
 			authorization: AuthorizationIdOf,
 		) -> DispatchResultWithPostInfo {
 			let updater = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
-			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_origin(
+			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_permission_origin(
 				&authorization,
 				&updater,
+				pallet_chain_space::Permissions::REMOVE,
 			)
 			.map_err(<pallet_chain_space::Error<T>>::from)?;
 
@@ -779,6 +1075,11 @@ pub mod pallet {
 
 			ensure!(statement_details.space == space_id, Error::<T>::UnauthorizedOperation);
 
+			ensure!(
+				!<RevocationPolicies<T>>::contains_key(&space_id),
+				Error::<T>::RevocationPolicyInEffect
+			);
+
 			// Count the entries in `Entries`.
 			let entries_count = <Entries<T>>::iter_prefix(&statement_id).count();
 			let max_removals = T::MaxRemoveEntries::get() as usize;
@@ -797,6 +1098,11 @@ pub mod pallet {
 					<RevocationList<T>>::clear_prefix(&statement_id, entries_count as u32, None);
 				let _ = <Entries<T>>::clear_prefix(&statement_id, entries_count as u32, None);
 				<Statements<T>>::remove(&statement_id);
+				Self::set_validity(&statement_id, None)?;
+				if let Some(proposal) = <RevocationProposals<T>>::get(&statement_id) {
+					Self::discard_proposal(&statement_id, proposal.expires_at);
+				}
+				Self::clear_suspension(&statement_id);
 				pallet_chain_space::Pallet::<T>::decrement_usage_entries(
 					&space_id,
 					entries_count as u16,
@@ -885,7 +1191,7 @@ pub mod pallet {
 		/// - `BatchCreate`: Emitted upon the completion of the batch operation, providing details
 		///   of the outcome.
 		#[pallet::call_index(5)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::register_batch(digests.len().saturated_into()))]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::register_batch(digests.len().saturated_into()).saturating_add(pallet_chain_space::Pallet::<T>::authorization_weight().saturating_mul(2)))]
 		#[rustfmt::skip]
 		pub fn register_batch(
 			origin: OriginFor<T>,
@@ -905,6 +1211,8 @@ pub mod pallet {
 				Error::<T>::MaxDigestLimitExceeded
 			);
 
+			Self::ensure_schema_not_deprecated(&schema_id)?;
+
 			let mut success = 0u32;
 			let mut fail = 0u32;
 			let mut indices: Vec<u16> = Vec::new();
@@ -989,6 +1297,8 @@ pub mod pallet {
 		///   added.
 		/// - `presentation_digest`: The digest that uniquely identifies the new presentation.
 		/// - `presentation_type`: The type categorization of the presentation.
+		/// - `metadata`: Optional MIME type, language tag and location of the rendered
+		///   presentation, distinguishing presentations of the same type.
 		/// - `authorization`: The authorization identifier for the creator, required to perform the
 		///   addition.
 		///
@@ -1001,22 +1311,26 @@ pub mod pallet {
 		///   associated space.
 		/// - Returns `PresentationDigestAlreadyAnchored` if the `presentation_digest` is not
 		///   unique.
+		/// - Returns `InvalidPresentationMimeType`, `InvalidPresentationLanguage` or
+		///   `InvalidPresentationUri` if the `metadata` is malformed.
 		///
 		/// # Events
 		/// - Emits `PresentationAdded` upon the successful addition of the presentation.
 		#[pallet::call_index(6)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_presentation( ))]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_presentation( ).saturating_add(pallet_chain_space::Pallet::<T>::authorization_weight()))]
 		pub fn add_presentation(
 			origin: OriginFor<T>,
 			statement_id: StatementIdOf,
 			presentation_digest: StatementDigestOf<T>,
 			presentation_type: PresentationTypeOf,
+			metadata: Option<PresentationMetadataOf<T>>,
 			authorization: AuthorizationIdOf,
 		) -> DispatchResult {
 			let creator = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
-			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_origin(
+			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_permission_origin(
 				&authorization,
 				&creator,
+				pallet_chain_space::Permissions::PRESENTATION,
 			)
 			.map_err(<pallet_chain_space::Error<T>>::from)?;
 
@@ -1037,6 +1351,10 @@ pub mod pallet {
 				Error::<T>::PresentationDigestAlreadyAnchored
 			);
 
+			if let Some(ref metadata) = metadata {
+				Self::ensure_valid_presentation_metadata(metadata)?;
+			}
+
 			<IdentifierLookup<T>>::insert(presentation_digest, &space_id, &statement_id);
 
 			<Presentations<T>>::insert(
@@ -1050,6 +1368,10 @@ pub mod pallet {
 				},
 			);
 
+			if let Some(metadata) = metadata {
+				<PresentationsMetadata<T>>::insert(&statement_id, presentation_digest, metadata);
+			}
+
 			Self::update_activity(&statement_id, CallTypeOf::PresentationAdded)
 				.map_err(<Error<T>>::from)?;
 
@@ -1087,7 +1409,7 @@ pub mod pallet {
 		/// # Events
 		/// - Emits `PresentationRemoved` upon the successful removal of the presentation.
 		#[pallet::call_index(7)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_presentation( ))]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_presentation( ).saturating_add(pallet_chain_space::Pallet::<T>::authorization_weight().saturating_mul(2)))]
 		pub fn remove_presentation(
 			origin: OriginFor<T>,
 			statement_id: StatementIdOf,
@@ -1095,9 +1417,10 @@ pub mod pallet {
 			authorization: AuthorizationIdOf,
 		) -> DispatchResult {
 			let remover = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
-			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_origin(
+			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_permission_origin(
 				&authorization,
 				&remover,
+				pallet_chain_space::Permissions::PRESENTATION,
 			)
 			.map_err(<pallet_chain_space::Error<T>>::from)?;
 
@@ -1107,6 +1430,7 @@ pub mod pallet {
 			ensure!(presentation_details.space == space_id, Error::<T>::UnauthorizedOperation);
 
 			Presentations::<T>::remove(&statement_id, presentation_digest);
+			PresentationsMetadata::<T>::remove(&statement_id, presentation_digest);
 			IdentifierLookup::<T>::remove(presentation_digest, &space_id);
 
 			pallet_chain_space::Pallet::<T>::decrement_usage(&space_id)
@@ -1122,6 +1446,537 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Sets the revocation policy of a space, so that revocations, and
+		/// optionally updates, of its statements need the approval of
+		/// `threshold` of the designated `approvers`.
+		///
+		/// The caller must be an admin of the space. Every approver must be a
+		/// delegate of the space and may only be listed once. Replacing a
+		/// policy does not affect the approvals already collected by pending
+		/// proposals, but the new threshold and approvers apply to them from
+		/// then on.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the dispatch call, which should be a signed message from the
+		///   space admin.
+		/// - `authorization`: The admin authorization of the caller for the space.
+		/// - `threshold`: The number of approvals required.
+		/// - `approvers`: The delegates whose approval counts towards the threshold.
+		/// - `include_updates`: Whether updates of statements also need approval. If not, updates
+		///   are refused while the policy is in effect, as they revoke the previous digest.
+		///
+		/// # Errors
+		/// - `InvalidRevocationThreshold` if the threshold is zero or exceeds the number of
+		///   approvers.
+		/// - `TooManyRevocationApprovers` if more than `MaxRevocationApprovers` are listed.
+		/// - `DuplicateRevocationApprover` if an approver is listed twice.
+		/// - `RevocationApproverNotADelegate` if an approver is not a delegate of the space.
+		///
+		/// # Events
+		/// - `RevocationPolicySet`: Emitted with the space identifier, threshold and the admin.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_revocation_policy(approvers.len().saturated_into()).saturating_add(pallet_chain_space::Pallet::<T>::authorization_weight()))]
+		pub fn set_revocation_policy(
+			origin: OriginFor<T>,
+			authorization: AuthorizationIdOf,
+			threshold: u32,
+			approvers: Vec<StatementCreatorOf<T>>,
+			include_updates: bool,
+		) -> DispatchResult {
+			let creator = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_admin_origin(
+				&authorization,
+				&creator,
+			)
+			.map_err(<pallet_chain_space::Error<T>>::from)?;
+
+			let approvers: RevocationApproversOf<T> =
+				approvers.try_into().map_err(|_| Error::<T>::TooManyRevocationApprovers)?;
+
+			ensure!(
+				threshold > 0 && threshold as usize <= approvers.len(),
+				Error::<T>::InvalidRevocationThreshold
+			);
+
+			for (index, approver) in approvers.iter().enumerate() {
+				ensure!(
+					!approvers[..index].contains(approver),
+					Error::<T>::DuplicateRevocationApprover
+				);
+				ensure!(
+					pallet_chain_space::Pallet::<T>::is_a_delegate(&space_id, approver.clone()),
+					Error::<T>::RevocationApproverNotADelegate
+				);
+			}
+
+			<RevocationPolicies<T>>::insert(
+				&space_id,
+				RevocationPolicyOf::<T> { threshold, approvers, include_updates },
+			);
+
+			Self::deposit_event(Event::RevocationPolicySet {
+				space: space_id,
+				threshold,
+				include_updates,
+				author: creator,
+			});
+
+			Ok(())
+		}
+
+		/// Removes the revocation policy of a space, so that its statements
+		/// can again be revoked by a single authorized delegate.
+		///
+		/// Pending proposals are kept until they are cancelled or lapse, but
+		/// can no longer be approved.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the dispatch call, which should be a signed message from the
+		///   space admin.
+		/// - `authorization`: The admin authorization of the caller for the space.
+		///
+		/// # Errors
+		/// - `RevocationPolicyNotFound` if the space has no revocation policy.
+		///
+		/// # Events
+		/// - `RevocationPolicyRemoved`: Emitted with the space identifier and the admin.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_revocation_policy().saturating_add(pallet_chain_space::Pallet::<T>::authorization_weight()))]
+		pub fn remove_revocation_policy(
+			origin: OriginFor<T>,
+			authorization: AuthorizationIdOf,
+		) -> DispatchResult {
+			let creator = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_admin_origin(
+				&authorization,
+				&creator,
+			)
+			.map_err(<pallet_chain_space::Error<T>>::from)?;
+
+			ensure!(
+				<RevocationPolicies<T>>::contains_key(&space_id),
+				Error::<T>::RevocationPolicyNotFound
+			);
+
+			<RevocationPolicies<T>>::remove(&space_id);
+
+			Self::deposit_event(Event::RevocationPolicyRemoved {
+				space: space_id,
+				author: creator,
+			});
+
+			Ok(())
+		}
+
+		/// Approves a pending revocation, or update, of a statement.
+		///
+		/// The approver must be one of the approvers designated by the
+		/// revocation policy of the space, and must hold the permission the
+		/// proposed action needs. Once the number of approvals reaches the
+		/// threshold of the policy, the proposed action is executed on behalf
+		/// of the proposer and the proposal is discarded. A proposal whose
+		/// action the statement no longer admits, for instance because it has
+		/// been revoked meanwhile, is discarded without being executed.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the dispatch call, which should be a signed message from the
+		///   approver.
+		/// - `statement_id`: The identifier of the statement with a pending proposal.
+		/// - `authorization`: The authorization ID, verifying the approver's delegation status.
+		///
+		/// # Errors
+		/// - `RevocationProposalNotFound` if no proposal is pending for the statement.
+		/// - `RevocationPolicyNotFound` if the policy of the space has been removed.
+		/// - `NotARevocationApprover` if the caller is not a designated approver.
+		/// - `RevocationAlreadyApproved` if the caller has already approved the proposal.
+		///
+		/// # Events
+		/// - `RevocationApproved`: Emitted with the statement identifier and the number of
+		///   approvals.
+		/// - `Revoke` or `Update`: Emitted once the threshold is reached and the action executed.
+		/// - `RevocationProposalFailed`: Emitted once the threshold is reached if the action could
+		///   not be executed.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::approve_revocation().saturating_add(pallet_chain_space::Pallet::<T>::authorization_weight()))]
+		pub fn approve_revocation(
+			origin: OriginFor<T>,
+			statement_id: StatementIdOf,
+			authorization: AuthorizationIdOf,
+		) -> DispatchResult {
+			let approver = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			let mut proposal = <RevocationProposals<T>>::get(&statement_id)
+				.ok_or(Error::<T>::RevocationProposalNotFound)?;
+
+			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_permission_origin(
+				&authorization,
+				&approver,
+				Self::proposal_permission(&proposal.action),
+			)
+			.map_err(<pallet_chain_space::Error<T>>::from)?;
+
+			let statement_details =
+				<Statements<T>>::get(&statement_id).ok_or(Error::<T>::StatementNotFound)?;
+			ensure!(statement_details.space == space_id, Error::<T>::UnauthorizedOperation);
+
+			let policy = <RevocationPolicies<T>>::get(&space_id)
+				.ok_or(Error::<T>::RevocationPolicyNotFound)?;
+			ensure!(policy.approvers.contains(&approver), Error::<T>::NotARevocationApprover);
+			ensure!(!proposal.approvals.contains(&approver), Error::<T>::RevocationAlreadyApproved);
+
+			proposal
+				.approvals
+				.try_push(approver.clone())
+				.map_err(|_| Error::<T>::TooManyRevocationApprovers)?;
+
+			let approvals = proposal.approvals.len() as u32;
+
+			Self::deposit_event(Event::RevocationApproved {
+				identifier: statement_id.clone(),
+				approvals,
+				author: approver,
+			});
+
+			if approvals >= policy.threshold {
+				Self::discard_proposal(&statement_id, proposal.expires_at);
+				// A failed action is rolled back on its own, so that the proposal
+				// is still discarded rather than left pending until it lapses.
+				if let Err(error) = with_storage_layer(|| {
+					Self::execute_proposal(&statement_id, proposal).map_err(DispatchError::from)
+				}) {
+					Self::deposit_event(Event::RevocationProposalFailed {
+						identifier: statement_id,
+						error,
+					});
+				}
+			} else {
+				<RevocationProposals<T>>::insert(&statement_id, proposal);
+			}
+
+			Ok(())
+		}
+
+		/// Cancels a pending revocation, or update, of a statement.
+		///
+		/// Only the delegate that proposed the action may cancel it.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the dispatch call, which should be a signed message from the
+		///   proposer.
+		/// - `statement_id`: The identifier of the statement with a pending proposal.
+		/// - `authorization`: The authorization ID, verifying the proposer's delegation status.
+		///
+		/// # Errors
+		/// - `RevocationProposalNotFound` if no proposal is pending for the statement.
+		/// - `UnauthorizedOperation` if the caller is not the proposer.
+		///
+		/// # Events
+		/// - `RevocationCancelled`: Emitted with the statement identifier and the proposer.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_revocation().saturating_add(pallet_chain_space::Pallet::<T>::authorization_weight()))]
+		pub fn cancel_revocation(
+			origin: OriginFor<T>,
+			statement_id: StatementIdOf,
+			authorization: AuthorizationIdOf,
+		) -> DispatchResult {
+			let proposer = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			let proposal = <RevocationProposals<T>>::get(&statement_id)
+				.ok_or(Error::<T>::RevocationProposalNotFound)?;
+
+			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_permission_origin(
+				&authorization,
+				&proposer,
+				Self::proposal_permission(&proposal.action),
+			)
+			.map_err(<pallet_chain_space::Error<T>>::from)?;
+
+			let statement_details =
+				<Statements<T>>::get(&statement_id).ok_or(Error::<T>::StatementNotFound)?;
+			ensure!(statement_details.space == space_id, Error::<T>::UnauthorizedOperation);
+			ensure!(proposal.proposer == proposer, Error::<T>::UnauthorizedOperation);
+
+			Self::discard_proposal(&statement_id, proposal.expires_at);
+
+			Self::deposit_event(Event::RevocationCancelled {
+				identifier: statement_id,
+				author: proposer,
+			});
+
+			Ok(())
+		}
+
+		/// Suspends a statement, placing a temporary hold on it that is
+		/// reported separately from a permanent revocation.
+		///
+		/// The suspension carries an application-defined `reason` code and is
+		/// lifted either explicitly through `unsuspend` or automatically when
+		/// block `lift_at` is initialized. While suspended, the statement is
+		/// reported as `Suspended` and cannot be updated. It can still be
+		/// revoked, in which case it is reported as `Revoked`.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the dispatch call, which should be a signed message from the
+		///   updater.
+		/// - `statement_id`: The identifier of the statement to be suspended.
+		/// - `reason`: The reason code of the suspension.
+		/// - `lift_at`: An optional block at which the suspension is lifted.
+		/// - `authorization`: The authorization ID, verifying the updater's delegation status.
+		///
+		/// # Errors
+		/// - `StatementNotFound` if the statement does not exist.
+		/// - `StatementRevoked` if the statement has been revoked.
+		/// - `StatementSuspended` if the statement is already suspended.
+		/// - `InvalidSuspensionLift` if `lift_at` is not in the future.
+		/// - `MaxExpirationsExceededForTheBlock` if the lift bucket of `lift_at` is full.
+		///
+		/// # Events
+		/// - `Suspend`: Emitted with the statement identifier, reason, lift block and the updater.
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::suspend().saturating_add(pallet_chain_space::Pallet::<T>::authorization_weight()))]
+		pub fn suspend(
+			origin: OriginFor<T>,
+			statement_id: StatementIdOf,
+			reason: SuspensionReasonOf,
+			lift_at: Option<BlockNumberFor<T>>,
+			authorization: AuthorizationIdOf,
+		) -> DispatchResult {
+			let updater = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_permission_origin(
+				&authorization,
+				&updater,
+				pallet_chain_space::Permissions::REVOKE,
+			)
+			.map_err(<pallet_chain_space::Error<T>>::from)?;
+
+			let statement_details =
+				<Statements<T>>::get(&statement_id).ok_or(Error::<T>::StatementNotFound)?;
+
+			ensure!(statement_details.space == space_id, Error::<T>::UnauthorizedOperation);
+
+			ensure!(
+				!<RevocationList<T>>::contains_key(&statement_id, statement_details.digest),
+				Error::<T>::StatementRevoked
+			);
+
+			ensure!(!Self::is_suspended(&statement_id), Error::<T>::StatementSuspended);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			if let Some(lift_at) = lift_at {
+				ensure!(lift_at > now, Error::<T>::InvalidSuspensionLift);
+			}
+
+			// A suspension that lapsed without its lift block being processed
+			// yet is replaced.
+			Self::clear_suspension(&statement_id);
+
+			if let Some(lift_at) = lift_at {
+				<SuspensionLifts<T>>::try_mutate(lift_at, |ids| {
+					ids.try_push(statement_id.clone())
+						.map_err(|_| Error::<T>::MaxExpirationsExceededForTheBlock)
+				})?;
+			}
+
+			<Suspensions<T>>::insert(
+				&statement_id,
+				StatementSuspensionOf::<T> {
+					reason,
+					author: updater.clone(),
+					suspended_at: now,
+					lift_at,
+				},
+			);
+
+			Self::update_activity(&statement_id, CallTypeOf::Suspend)?;
+
+			Self::deposit_event(Event::Suspend {
+				identifier: statement_id,
+				reason,
+				lift_at,
+				author: updater,
+			});
+
+			Ok(())
+		}
+
+		/// Lifts the suspension of a statement before its lift block.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the dispatch call, which should be a signed message from the
+		///   updater.
+		/// - `statement_id`: The identifier of the suspended statement.
+		/// - `authorization`: The authorization ID, verifying the updater's delegation status.
+		///
+		/// # Errors
+		/// - `StatementNotFound` if the statement does not exist.
+		/// - `StatementNotSuspended` if the statement is not suspended.
+		///
+		/// # Events
+		/// - `Unsuspend`: Emitted with the statement identifier and the updater.
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::unsuspend().saturating_add(pallet_chain_space::Pallet::<T>::authorization_weight()))]
+		pub fn unsuspend(
+			origin: OriginFor<T>,
+			statement_id: StatementIdOf,
+			authorization: AuthorizationIdOf,
+		) -> DispatchResult {
+			let updater = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_permission_origin(
+				&authorization,
+				&updater,
+				pallet_chain_space::Permissions::REVOKE,
+			)
+			.map_err(<pallet_chain_space::Error<T>>::from)?;
+
+			let statement_details =
+				<Statements<T>>::get(&statement_id).ok_or(Error::<T>::StatementNotFound)?;
+
+			ensure!(statement_details.space == space_id, Error::<T>::UnauthorizedOperation);
+
+			ensure!(Self::is_suspended(&statement_id), Error::<T>::StatementNotSuspended);
+
+			Self::clear_suspension(&statement_id);
+
+			Self::update_activity(&statement_id, CallTypeOf::Unsuspend)?;
+
+			Self::deposit_event(Event::Unsuspend { identifier: statement_id, author: updater });
+
+			Ok(())
+		}
+
+		/// Moves a statement, along with its entries, revocations and
+		/// presentations, from its current space to another space.
+		///
+		/// The updater must hold `ADMIN` authorizations on both the source and
+		/// the target space. The usage of every entry and presentation is
+		/// released from the source space and charged to the target space,
+		/// which must have the capacity to accommodate them. The digest
+		/// lookups are re-keyed to the target space, so that the statement is
+		/// resolved through the target space afterwards.
+		///
+		/// Statements of a space with a revocation policy cannot be moved, as
+		/// the target space need not enforce the same policy. The policy has
+		/// to be removed first.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the dispatch call, which should be a signed message from the
+		///   updater.
+		/// - `statement_id`: The identifier of the statement to be moved.
+		/// - `source_authorization`: The `ADMIN` authorization ID of the updater within the space
+		///   the statement belongs to.
+		/// - `target_authorization`: The `ADMIN` authorization ID of the updater within the space
+		///   the statement is moved to.
+		///
+		/// # Errors
+		/// - `StatementNotFound` if the statement does not exist.
+		/// - `UnauthorizedOperation` if the statement does not belong to the source space.
+		/// - `TransferToSameSpace` if both authorizations refer to the same space.
+		/// - `RevocationPolicyInEffect` if the source space has a revocation policy.
+		/// - `RevocationProposalAlreadyPending` if a revocation of the statement awaits approval
+		///   under the policy of the source space.
+		/// - `MaxTransferEntriesExceeded` if the statement has more than `MaxRemoveEntries` entries
+		///   and presentations.
+		/// - `StatementDigestAlreadyAnchored` if one of the digests is already anchored in the
+		///   target space.
+		///
+		/// # Events
+		/// - `Transfer`: Emitted with the statement identifier, both spaces and the updater.
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::transfer_space(T::MaxRemoveEntries::get() as u32).saturating_add(pallet_chain_space::Pallet::<T>::authorization_weight().saturating_mul(4)))]
+		pub fn transfer_space(
+			origin: OriginFor<T>,
+			statement_id: StatementIdOf,
+			source_authorization: AuthorizationIdOf,
+			target_authorization: AuthorizationIdOf,
+		) -> DispatchResultWithPostInfo {
+			let updater = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+			let source_space = pallet_chain_space::Pallet::<T>::ensure_authorization_admin_origin(
+				&source_authorization,
+				&updater,
+			)
+			.map_err(<pallet_chain_space::Error<T>>::from)?;
+			let target_space = pallet_chain_space::Pallet::<T>::ensure_authorization_admin_origin(
+				&target_authorization,
+				&updater,
+			)
+			.map_err(<pallet_chain_space::Error<T>>::from)?;
+
+			ensure!(source_space != target_space, Error::<T>::TransferToSameSpace);
+
+			let mut statement_details =
+				<Statements<T>>::get(&statement_id).ok_or(Error::<T>::StatementNotFound)?;
+
+			ensure!(statement_details.space == source_space, Error::<T>::UnauthorizedOperation);
+
+			ensure!(
+				!<RevocationPolicies<T>>::contains_key(&source_space),
+				Error::<T>::RevocationPolicyInEffect
+			);
+
+			ensure!(
+				!<RevocationProposals<T>>::contains_key(&statement_id),
+				Error::<T>::RevocationProposalAlreadyPending
+			);
+
+			// Read at most one digest beyond the limit, which is enough to
+			// tell that the statement cannot be moved.
+			let max_moved = T::MaxRemoveEntries::get() as usize;
+			let entries: Vec<_> = <Entries<T>>::iter_key_prefix(&statement_id)
+				.take(max_moved.saturating_add(1))
+				.collect();
+			let presentations: Vec<_> = <Presentations<T>>::iter_key_prefix(&statement_id)
+				.take(max_moved.saturating_add(1).saturating_sub(entries.len()))
+				.collect();
+			let moved = entries.len().saturating_add(presentations.len());
+
+			ensure!(moved <= max_moved, Error::<T>::MaxTransferEntriesExceeded);
+
+			pallet_chain_space::Pallet::<T>::validate_space_for_transaction_entries(
+				&target_space,
+				moved as u16,
+			)
+			.map_err(<pallet_chain_space::Error<T>>::from)?;
+
+			for digest in entries.iter().chain(presentations.iter()) {
+				ensure!(
+					!<IdentifierLookup<T>>::contains_key(digest, &target_space),
+					Error::<T>::StatementDigestAlreadyAnchored
+				);
+			}
+
+			for digest in entries.iter().chain(presentations.iter()) {
+				<IdentifierLookup<T>>::remove(digest, &source_space);
+				<IdentifierLookup<T>>::insert(digest, &target_space, &statement_id);
+			}
+
+			for presentation_digest in presentations {
+				<Presentations<T>>::mutate(&statement_id, presentation_digest, |details| {
+					if let Some(details) = details {
+						details.space = target_space.clone();
+					}
+				});
+			}
+
+			statement_details.space = target_space.clone();
+			<Statements<T>>::insert(&statement_id, statement_details);
+
+			pallet_chain_space::Pallet::<T>::decrement_usage_entries(&source_space, moved as u16)
+				.map_err(<pallet_chain_space::Error<T>>::from)?;
+			pallet_chain_space::Pallet::<T>::increment_usage_entries(&target_space, moved as u16)
+				.map_err(<pallet_chain_space::Error<T>>::from)?;
+
+			Self::update_activity(&statement_id, CallTypeOf::Transfer)?;
+
+			Self::deposit_event(Event::Transfer {
+				identifier: statement_id,
+				from: source_space,
+				to: target_space,
+				author: updater,
+			});
+
+			Ok(Some(<T as Config>::WeightInfo::transfer_space(moved as u32)).into())
+		}
 	}
 }
 
@@ -1159,6 +2014,365 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Ensures that an optional schema referenced by a new statement has not
+	/// been deprecated, when the runtime is configured to reject deprecated
+	/// schemas.
+	///
+	/// # Errors
+	/// Returns `Error::<T>::SchemaDeprecated` if the schema is deprecated.
+	pub fn ensure_schema_not_deprecated(schema_id: &Option<SchemaIdOf>) -> Result<(), Error<T>> {
+		if let Some(schema_id) = schema_id {
+			ensure!(
+				!(<T as Config>::RejectDeprecatedSchemas::get() &&
+					pallet_schema::Pallet::<T>::is_deprecated(schema_id)),
+				Error::<T>::SchemaDeprecated
+			);
+		}
+		Ok(())
+	}
+
+	/// Validates an optional validity window supplied with a statement.
+	///
+	/// The window must not be empty and must not already be over at the
+	/// current block. `None` is returned when neither bound is supplied.
+	///
+	/// # Errors
+	/// Returns `Error::<T>::InvalidValidityWindow` if the window is invalid.
+	pub fn validity_window(
+		valid_from: Option<BlockNumberFor<T>>,
+		valid_until: Option<BlockNumberFor<T>>,
+	) -> Result<Option<StatementValidityOf<T>>, Error<T>> {
+		if valid_from.is_none() && valid_until.is_none() {
+			return Ok(None);
+		}
+
+		if let Some(until) = valid_until {
+			ensure!(
+				until > frame_system::Pallet::<T>::block_number(),
+				Error::<T>::InvalidValidityWindow
+			);
+			if let Some(from) = valid_from {
+				ensure!(from < until, Error::<T>::InvalidValidityWindow);
+			}
+		}
+
+		Ok(Some(StatementValidity { valid_from, valid_until }))
+	}
+
+	/// Records the validity window of a statement, replacing any earlier
+	/// window and moving the statement to the matching expiry bucket.
+	///
+	/// Passing `None` clears the window of the statement.
+	///
+	/// # Errors
+	/// Returns `Error::<T>::MaxExpirationsExceededForTheBlock` if the expiry
+	/// bucket of the new `valid_until` block is full.
+	pub fn set_validity(
+		statement_id: &StatementIdOf,
+		validity: Option<StatementValidityOf<T>>,
+	) -> Result<(), Error<T>> {
+		if let Some(until) = <ValidityWindows<T>>::get(statement_id).and_then(|v| v.valid_until) {
+			<Expirations<T>>::mutate(until, |ids| {
+				if let Some(pos) = ids.iter().position(|id| id == statement_id) {
+					ids.swap_remove(pos);
+				}
+			});
+		}
+
+		match validity {
+			Some(validity) => {
+				if let Some(until) = validity.valid_until {
+					<Expirations<T>>::try_mutate(until, |ids| {
+						ids.try_push(statement_id.clone())
+							.map_err(|_| Error::<T>::MaxExpirationsExceededForTheBlock)
+					})?;
+				}
+				<ValidityWindows<T>>::insert(statement_id, validity);
+			},
+			None => <ValidityWindows<T>>::remove(statement_id),
+		}
+
+		Ok(())
+	}
+
+	/// Emits an `Expired` event for every statement whose validity window
+	/// ends at block `n`. The window itself is kept so that the status of the
+	/// statement continues to be reported as `Expired`.
+	fn expire_statements(n: BlockNumberFor<T>) -> Weight {
+		let expiring = <Expirations<T>>::take(n);
+		let count = expiring.len() as u64;
+
+		for identifier in expiring {
+			Self::deposit_event(Event::Expired { identifier });
+		}
+
+		T::DbWeight::get().reads_writes(1, 1_u64.saturating_add(count))
+	}
+
+	/// Marks the latest digest of a statement as revoked and emits a
+	/// `Revoke` event on behalf of `author`.
+	pub fn revoke_statement(
+		statement_id: &StatementIdOf,
+		digest: StatementDigestOf<T>,
+		author: StatementCreatorOf<T>,
+	) -> Result<(), Error<T>> {
+		<RevocationList<T>>::insert(
+			statement_id,
+			digest,
+			StatementEntryStatusOf::<T> { creator: author.clone(), revoked: true },
+		);
+
+		Self::update_activity(statement_id, CallTypeOf::Revoke)?;
+		Self::deposit_event(Event::Revoke { identifier: statement_id.clone(), author });
+
+		Ok(())
+	}
+
+	/// Revokes the latest digest of a statement, anchors `digest` as its new
+	/// state along with the validity window and emits an `Update` event on
+	/// behalf of `author`.
+	///
+	/// # Errors
+	/// Returns `Error::<T>::MaxExpirationsExceededForTheBlock` if the expiry
+	/// bucket of the new validity window is full.
+	pub fn update_statement(
+		statement_id: &StatementIdOf,
+		statement_details: StatementDetailsOf<T>,
+		digest: StatementDigestOf<T>,
+		validity: Option<StatementValidityOf<T>>,
+		author: StatementCreatorOf<T>,
+	) -> Result<(), Error<T>> {
+		<RevocationList<T>>::insert(
+			statement_id,
+			statement_details.digest,
+			StatementEntryStatusOf::<T> { creator: author.clone(), revoked: true },
+		);
+
+		<Entries<T>>::insert(statement_id, digest, author.clone());
+
+		<IdentifierLookup<T>>::insert(digest, statement_details.space.clone(), statement_id);
+
+		<Statements<T>>::insert(
+			statement_id,
+			StatementDetailsOf::<T> { digest, ..statement_details },
+		);
+
+		Self::set_validity(statement_id, validity)?;
+
+		Self::update_activity(statement_id, CallTypeOf::Update)?;
+
+		Self::deposit_event(Event::Update { identifier: statement_id.clone(), digest, author });
+
+		Ok(())
+	}
+
+	/// Opens a proposal for an action on a statement that is subject to the
+	/// revocation policy of its space.
+	///
+	/// The approval of the proposer counts towards the threshold if they are
+	/// one of the designated approvers, in which case a threshold of one
+	/// executes the action right away.
+	///
+	/// # Errors
+	/// - `RevocationProposalAlreadyPending` if a proposal is pending for the statement.
+	/// - `MaxExpirationsExceededForTheBlock` if the expiry bucket of the proposal is full.
+	pub fn propose_revocation(
+		statement_id: &StatementIdOf,
+		action: ProposedActionOf<T>,
+		proposer: StatementCreatorOf<T>,
+		policy: &RevocationPolicyOf<T>,
+	) -> Result<(), Error<T>> {
+		ensure!(
+			!<RevocationProposals<T>>::contains_key(statement_id),
+			Error::<T>::RevocationProposalAlreadyPending
+		);
+
+		let mut approvals = RevocationApproversOf::<T>::default();
+		if policy.approvers.contains(&proposer) {
+			approvals
+				.try_push(proposer.clone())
+				.map_err(|_| Error::<T>::TooManyRevocationApprovers)?;
+		}
+
+		let expires_at = frame_system::Pallet::<T>::block_number()
+			.saturating_add(<T as Config>::RevocationProposalLifetime::get());
+		let proposal = RevocationProposalOf::<T> { action, proposer, approvals, expires_at };
+
+		if proposal.approvals.len() as u32 >= policy.threshold {
+			return Self::execute_proposal(statement_id, proposal);
+		}
+
+		<RevocationProposalExpirations<T>>::try_mutate(expires_at, |ids| {
+			ids.try_push(statement_id.clone())
+				.map_err(|_| Error::<T>::MaxExpirationsExceededForTheBlock)
+		})?;
+
+		Self::deposit_event(Event::RevocationProposed {
+			identifier: statement_id.clone(),
+			author: proposal.proposer.clone(),
+		});
+
+		<RevocationProposals<T>>::insert(statement_id, proposal);
+
+		Ok(())
+	}
+
+	/// Executes the action of an approved proposal on behalf of its
+	/// proposer, after checking that the statement still admits it.
+	///
+	/// # Errors
+	/// - `StatementNotFound` if the statement has been removed.
+	/// - `StatementRevoked` if the latest digest of the statement has been revoked.
+	/// - `StatementDigestAlreadyAnchored` if the proposed digest has been anchored meanwhile.
+	/// - `InvalidValidityWindow` if the proposed validity window is already over.
+	pub fn execute_proposal(
+		statement_id: &StatementIdOf,
+		proposal: RevocationProposalOf<T>,
+	) -> Result<(), Error<T>> {
+		let statement_details =
+			<Statements<T>>::get(statement_id).ok_or(Error::<T>::StatementNotFound)?;
+
+		ensure!(
+			!<RevocationList<T>>::contains_key(statement_id, statement_details.digest),
+			Error::<T>::StatementRevoked
+		);
+
+		match proposal.action {
+			ProposedAction::Revoke =>
+				Self::revoke_statement(statement_id, statement_details.digest, proposal.proposer),
+			ProposedAction::Update { digest, validity } => {
+				ensure!(!Self::is_suspended(statement_id), Error::<T>::StatementSuspended);
+				ensure!(
+					!<Entries<T>>::contains_key(statement_id, digest),
+					Error::<T>::StatementDigestAlreadyAnchored
+				);
+				let validity = match validity {
+					Some(validity) =>
+						Self::validity_window(validity.valid_from, validity.valid_until)?,
+					None => None,
+				};
+				Self::update_statement(
+					statement_id,
+					statement_details,
+					digest,
+					validity,
+					proposal.proposer,
+				)
+			},
+		}
+	}
+
+	/// Removes the pending proposal of a statement along with its entry in
+	/// the expiry bucket of block `expires_at`.
+	pub fn discard_proposal(statement_id: &StatementIdOf, expires_at: BlockNumberFor<T>) {
+		<RevocationProposals<T>>::remove(statement_id);
+		<RevocationProposalExpirations<T>>::mutate(expires_at, |ids| {
+			if let Some(pos) = ids.iter().position(|id| id == statement_id) {
+				ids.swap_remove(pos);
+			}
+		});
+	}
+
+	/// Returns the space permission a delegate needs to approve or cancel a
+	/// proposal for `action`.
+	pub fn proposal_permission(action: &ProposedActionOf<T>) -> pallet_chain_space::Permissions {
+		match action {
+			ProposedAction::Revoke => pallet_chain_space::Permissions::REVOKE,
+			ProposedAction::Update { .. } => pallet_chain_space::Permissions::UPDATE,
+		}
+	}
+
+	/// Ensures the metadata of a presentation is well formed: the MIME type is
+	/// a printable ASCII `type/subtype`, the language tag is made of
+	/// alphanumeric subtags of up to eight characters separated by `-`, and
+	/// the URI is non-empty printable ASCII.
+	pub fn ensure_valid_presentation_metadata(
+		metadata: &PresentationMetadataOf<T>,
+	) -> Result<(), Error<T>> {
+		if let Some(mime_type) = &metadata.mime_type {
+			let mut parts = mime_type.split(|b| *b == b'/');
+			let valid = mime_type.iter().all(|b| b.is_ascii_graphic()) &&
+				matches!(
+					(parts.next(), parts.next(), parts.next()),
+					(Some(kind), Some(subtype), None) if !kind.is_empty() && !subtype.is_empty()
+				);
+			ensure!(valid, Error::<T>::InvalidPresentationMimeType);
+		}
+
+		if let Some(language) = &metadata.language {
+			let valid = language.split(|b| *b == b'-').all(|subtag| {
+				(1..=8).contains(&subtag.len()) && subtag.iter().all(|b| b.is_ascii_alphanumeric())
+			});
+			ensure!(valid, Error::<T>::InvalidPresentationLanguage);
+		}
+
+		if let Some(PresentationLocation::Uri(uri)) = &metadata.location {
+			ensure!(
+				!uri.is_empty() && uri.iter().all(|b| b.is_ascii_graphic()),
+				Error::<T>::InvalidPresentationUri
+			);
+		}
+
+		Ok(())
+	}
+
+	/// Returns whether a suspension is in place on a statement at the current
+	/// block.
+	pub fn is_suspended(statement_id: &StatementIdOf) -> bool {
+		let now = frame_system::Pallet::<T>::block_number();
+		<Suspensions<T>>::get(statement_id)
+			.map_or(false, |suspension| suspension.lift_at.map_or(true, |lift_at| now < lift_at))
+	}
+
+	/// Removes the suspension of a statement, if any, along with its entry in
+	/// the lift bucket.
+	pub fn clear_suspension(statement_id: &StatementIdOf) {
+		if let Some(lift_at) = <Suspensions<T>>::take(statement_id).and_then(|s| s.lift_at) {
+			<SuspensionLifts<T>>::mutate(lift_at, |ids| {
+				if let Some(pos) = ids.iter().position(|id| id == statement_id) {
+					ids.swap_remove(pos);
+				}
+			});
+		}
+	}
+
+	/// Lifts every suspension that lapses at block `n`, recording an
+	/// `Unsuspend` activity and emitting a `SuspensionLapsed` event for each.
+	///
+	/// Besides the bucket, each lifted suspension reads the timeline length of
+	/// the statement and writes the suspension, the timeline length, the
+	/// timeline entry and the event.
+	fn lift_suspensions(n: BlockNumberFor<T>) -> Weight {
+		let lifting = <SuspensionLifts<T>>::take(n);
+		let count = lifting.len() as u64;
+
+		for identifier in lifting {
+			<Suspensions<T>>::remove(&identifier);
+			let _ = Self::update_activity(&identifier, CallTypeOf::Unsuspend);
+			Self::deposit_event(Event::SuspensionLapsed { identifier });
+		}
+
+		T::DbWeight::get().reads_writes(
+			1_u64.saturating_add(count),
+			1_u64.saturating_add(count.saturating_mul(4)),
+		)
+	}
+
+	/// Discards every revocation proposal that lapses at block `n` without
+	/// having reached the threshold, emitting a `RevocationProposalExpired`
+	/// event for each.
+	fn expire_revocation_proposals(n: BlockNumberFor<T>) -> Weight {
+		let expiring = <RevocationProposalExpirations<T>>::take(n);
+		let count = expiring.len() as u64;
+
+		for identifier in expiring {
+			<RevocationProposals<T>>::remove(&identifier);
+			Self::deposit_event(Event::RevocationProposalExpired { identifier });
+		}
+
+		T::DbWeight::get().reads_writes(1, 1_u64.saturating_add(count))
+	}
+
 	/// Retrieves the current timepoint.
 	///
 	/// This function returns a `Timepoint` structure containing the current
@@ -1173,4 +2387,141 @@ impl<T: Config> Pallet<T> {
 			index: frame_system::Pallet::<T>::extrinsic_index().unwrap_or_default(),
 		}
 	}
+
+	/// Returns the status of a digest anchored against a statement.
+	///
+	/// A digest that is no longer the latest digest of the statement has
+	/// been replaced by an update and is reported as `Superseded`. The latest
+	/// digest is reported as `Revoked` when it is present in the
+	/// `RevocationList`, as `Suspended` while a suspension is in place, and as
+	/// `NotYetValid` or `Expired` when the current block falls outside the
+	/// validity window of the statement. `None` is returned if the digest was
+	/// never anchored against the statement.
+	///
+	/// # Parameters
+	/// - `statement_id`: The identifier of the statement.
+	/// - `digest`: The digest whose status is requested.
+	pub fn digest_status(
+		statement_id: &StatementIdOf,
+		digest: &StatementDigestOf<T>,
+	) -> Option<StatementStatus> {
+		if !<Entries<T>>::contains_key(statement_id, digest) {
+			return None;
+		}
+
+		let is_latest = <Statements<T>>::get(statement_id).map_or(false, |d| d.digest == *digest);
+		if !is_latest {
+			return Some(StatementStatus::Superseded);
+		}
+
+		if <RevocationList<T>>::contains_key(statement_id, digest) {
+			return Some(StatementStatus::Revoked);
+		}
+		if Self::is_suspended(statement_id) {
+			return Some(StatementStatus::Suspended);
+		}
+		if let Some(validity) = <ValidityWindows<T>>::get(statement_id) {
+			let now = frame_system::Pallet::<T>::block_number();
+			if validity.valid_from.map_or(false, |from| now < from) {
+				return Some(StatementStatus::NotYetValid);
+			}
+			if validity.valid_until.map_or(false, |until| now >= until) {
+				return Some(StatementStatus::Expired);
+			}
+		}
+
+		Some(StatementStatus::Active)
+	}
+
+	/// Assembles the complete view of a statement for verifiers.
+	///
+	/// This gathers the latest statement details, every digest recorded in
+	/// `Entries` together with its revocation status, and all presentations
+	/// attached to the statement.
+	///
+	/// # Parameters
+	/// - `statement_id`: The identifier of the statement.
+	///
+	/// # Returns
+	/// - `Some(StatementInfoOf<T>)` if the statement exists, `None` otherwise.
+	pub fn statement_info(statement_id: &StatementIdOf) -> Option<StatementInfoOf<T>> {
+		let details = <Statements<T>>::get(statement_id)?;
+
+		let entries = <Entries<T>>::iter_prefix(statement_id)
+			.map(|(digest, creator)| StatementEntryInfo {
+				status: Self::digest_status(statement_id, &digest)
+					.unwrap_or(StatementStatus::Active),
+				digest,
+				creator,
+			})
+			.collect();
+
+		let presentations = Self::presentations(statement_id, None);
+
+		Some(StatementInfoOf::<T> {
+			identifier: statement_id.clone(),
+			status: Self::digest_status(statement_id, &details.digest)
+				.unwrap_or(StatementStatus::Active),
+			digest: details.digest,
+			space: details.space,
+			schema: details.schema,
+			validity: <ValidityWindows<T>>::get(statement_id),
+			suspension: <Suspensions<T>>::get(statement_id),
+			entries,
+			presentations,
+		})
+	}
+
+	/// Lists the presentations attached to a statement along with their
+	/// metadata, optionally restricted to a single presentation type.
+	///
+	/// # Parameters
+	/// - `statement_id`: The identifier of the statement.
+	/// - `presentation_type`: If set, only presentations of this type are returned.
+	///
+	/// # Returns
+	/// - The matching presentations, empty if the statement has none.
+	pub fn presentations(
+		statement_id: &StatementIdOf,
+		presentation_type: Option<PresentationTypeOf>,
+	) -> Vec<StatementPresentationInfoOf<T>> {
+		<Presentations<T>>::iter_prefix(statement_id)
+			.filter(|(_, presentation)| {
+				presentation_type.map_or(true, |t| t == presentation.presentation_type)
+			})
+			.map(|(presentation_digest, presentation)| StatementPresentationInfo {
+				metadata: <PresentationsMetadata<T>>::get(statement_id, presentation_digest).map(
+					|metadata| PresentationMetadata {
+						mime_type: metadata.mime_type.map(|m| m.into_inner()),
+						language: metadata.language.map(|l| l.into_inner()),
+						location: metadata.location.map(|location| match location {
+							PresentationLocation::Uri(uri) =>
+								PresentationLocation::Uri(uri.into_inner()),
+							PresentationLocation::ContentHash(hash) =>
+								PresentationLocation::ContentHash(hash),
+						}),
+					},
+				),
+				presentation_digest,
+				creator: presentation.creator,
+				presentation_type: presentation.presentation_type,
+				digest: presentation.digest,
+			})
+			.collect()
+	}
+
+	/// Resolves a statement or presentation digest anchored within a space to
+	/// its statement, using the `IdentifierLookup` storage, and returns the
+	/// complete view of that statement.
+	///
+	/// # Parameters
+	/// - `digest`: The statement or presentation digest.
+	/// - `space_id`: The space the digest was anchored in.
+	pub fn statement_info_by_digest(
+		digest: &StatementDigestOf<T>,
+		space_id: &SpaceIdOf,
+	) -> Option<StatementInfoOf<T>> {
+		let statement_id = <IdentifierLookup<T>>::get(digest, space_id)?;
+		Self::statement_info(&statement_id)
+	}
 }
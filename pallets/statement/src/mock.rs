@@ -69,6 +69,11 @@ parameter_types! {
 	#[derive(Debug, Clone)]
 	pub const MaxDigetsPerBatch: u16 = 5u16;
 	pub const MaxRemoveEntries: u16 = 5u16;
+	pub const MaxExpirationsPerBlock: u32 = 5u32;
+	pub const RejectDeprecatedSchemas: bool = true;
+	pub const MaxRevocationApprovers: u32 = 3u32;
+	pub const RevocationProposalLifetime: u64 = 10u64;
+	pub const MaxPresentationUriLength: u32 = 64;
 }
 
 impl Config for Test {
@@ -77,12 +82,19 @@ impl Config for Test {
 	type OriginSuccess = mock_origin::DoubleOrigin<AccountId, SubjectId>;
 	type MaxDigestsPerBatch = MaxDigetsPerBatch;
 	type MaxRemoveEntries = MaxRemoveEntries;
+	type MaxExpirationsPerBlock = MaxExpirationsPerBlock;
+	type RejectDeprecatedSchemas = RejectDeprecatedSchemas;
+	type MaxRevocationApprovers = MaxRevocationApprovers;
+	type RevocationProposalLifetime = RevocationProposalLifetime;
+	type MaxPresentationUriLength = MaxPresentationUriLength;
 	type WeightInfo = weights::SubstrateWeight<Test>;
 }
 
 parameter_types! {
 	#[derive(Debug, Clone)]
 	pub const MaxSpaceDelegates: u32 = 5u32;
+	pub const MaxSubspaceDepth: u32 = 3u32;
+	pub const MaxDelegationExpirationsPerBlock: u32 = 5u32;
 }
 
 pub struct NetworkPermission;
@@ -98,6 +110,8 @@ impl pallet_chain_space::Config for Test {
 	type OriginSuccess = mock_origin::DoubleOrigin<AccountId, SubjectId>;
 	type SpaceCreatorId = SubjectId;
 	type MaxSpaceDelegates = MaxSpaceDelegates;
+	type MaxSubspaceDepth = MaxSubspaceDepth;
+	type MaxDelegationExpirationsPerBlock = MaxDelegationExpirationsPerBlock;
 	type ChainSpaceOrigin = EnsureRoot<AccountId>;
 	type NetworkPermission = NetworkPermission;
 	type WeightInfo = ();
@@ -105,6 +119,7 @@ impl pallet_chain_space::Config for Test {
 
 parameter_types! {
 	pub const MaxEncodedSchemaLength: u32 = 15_360;
+	pub const ValidateSchemaStructure: bool = false;
 }
 
 impl pallet_schema::Config for Test {
@@ -114,15 +129,10 @@ impl pallet_schema::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
 	type MaxEncodedSchemaLength = MaxEncodedSchemaLength;
+	type ValidateSchemaStructure = ValidateSchemaStructure;
 }
 
-parameter_types! {
-	pub const MaxEventsHistory: u32 = 6u32;
-}
-
-impl identifier::Config for Test {
-	type MaxEventsHistory = MaxEventsHistory;
-}
+impl identifier::Config for Test {}
 
 #[allow(dead_code)]
 pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
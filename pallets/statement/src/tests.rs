@@ -2,9 +2,9 @@ use super::*;
 use crate::mock::*;
 use codec::Encode;
 use cord_utilities::mock::{mock_origin::DoubleOrigin, SubjectId};
-use frame_support::{assert_err, assert_ok, BoundedVec};
+use frame_support::{assert_err, assert_ok, traits::Hooks, BoundedVec};
 use frame_system::RawOrigin;
-use pallet_chain_space::SpaceCodeOf;
+use pallet_chain_space::{Permissions, SpaceCodeOf};
 use pallet_schema::{InputSchemaOf, SchemaHashOf};
 use sp_runtime::{traits::Hash, AccountId32};
 
@@ -31,6 +31,7 @@ pub fn generate_authorization_id<T: Config>(digest: &SpaceCodeOf<T>) -> Authoriz
 
 pub(crate) const DID_00: SubjectId = SubjectId(AccountId32::new([1u8; 32]));
 pub(crate) const DID_01: SubjectId = SubjectId(AccountId32::new([5u8; 32]));
+pub(crate) const DID_02: SubjectId = SubjectId(AccountId32::new([6u8; 32]));
 pub(crate) const ACCOUNT_00: AccountId = AccountId::new([1u8; 32]);
 
 #[test]
@@ -79,7 +80,9 @@ fn register_statement_should_succeed() {
 			DoubleOrigin(author, creator).into(),
 			statement_digest,
 			authorization_id,
-			Some(schema_id)
+			Some(schema_id),
+			None,
+			None
 		));
 	});
 }
@@ -117,7 +120,9 @@ fn trying_to_register_statement_to_a_non_existent_space_should_fail() {
 				DoubleOrigin(author, delegate).into(),
 				statement_digest,
 				authorization_id,
-				Some(schema_id)
+				Some(schema_id),
+				None,
+				None
 			),
 			pallet_chain_space::Error::<Test>::AuthorizationNotFound
 		);
@@ -172,7 +177,9 @@ fn trying_to_register_statement_by_a_non_delegate_should_fail() {
 				DoubleOrigin(author, delegate).into(),
 				statement_digest,
 				authorization_id,
-				Some(schema_id)
+				Some(schema_id),
+				None,
+				None
 			),
 			pallet_chain_space::Error::<Test>::UnauthorizedOperation
 		);
@@ -234,7 +241,9 @@ fn updating_a_registered_statement_should_succeed() {
 			DoubleOrigin(author.clone(), creator.clone()).into(),
 			statement_digest,
 			authorization_id.clone(),
-			Some(schema_id)
+			Some(schema_id),
+			None,
+			None
 		));
 
 		assert_ok!(Statement::update(
@@ -242,6 +251,8 @@ fn updating_a_registered_statement_should_succeed() {
 			statement_id.clone(),
 			new_statement_digest,
 			authorization_id,
+			None,
+			None
 		));
 
 		let revoked_statements = RevocationList::<Test>::get(statement_id, statement_digest)
@@ -307,18 +318,23 @@ fn updating_a_registered_statement_by_a_space_delegate_should_succeed() {
 			authorization_id.clone()
 		));
 
-		assert_ok!(Space::add_delegate(
+		assert_ok!(Space::add_delegate_with_permissions(
 			DoubleOrigin(author.clone(), creator.clone()).into(),
 			space_id,
 			delegate.clone(),
+			Permissions::ASSERT | Permissions::UPDATE,
 			authorization_id.clone(),
+			None,
+			None,
 		));
 
 		assert_ok!(Statement::register(
 			DoubleOrigin(author.clone(), creator.clone()).into(),
 			statement_digest,
 			authorization_id.clone(),
-			Some(schema_id)
+			Some(schema_id),
+			None,
+			None
 		));
 
 		assert_ok!(Statement::update(
@@ -326,6 +342,8 @@ fn updating_a_registered_statement_by_a_space_delegate_should_succeed() {
 			statement_id,
 			new_statement_digest,
 			delegate_authorization_id,
+			None,
+			None
 		));
 	});
 }
@@ -390,7 +408,9 @@ fn trying_to_update_a_registered_statement_by_a_non_space_delegate_should_fail()
 			DoubleOrigin(author.clone(), creator.clone()).into(),
 			statement_digest,
 			authorization_id.clone(),
-			Some(schema_id)
+			Some(schema_id),
+			None,
+			None
 		));
 
 		assert_err!(
@@ -399,6 +419,8 @@ fn trying_to_update_a_registered_statement_by_a_non_space_delegate_should_fail()
 				statement_id,
 				new_statement_digest,
 				delegate_authorization_id,
+				None,
+				None
 			),
 			pallet_chain_space::Error::<Test>::AuthorizationNotFound
 		);
@@ -459,7 +481,9 @@ fn trying_to_update_a_non_registered_statement_should_fail() {
 			DoubleOrigin(author.clone(), creator.clone()).into(),
 			new_statement_digest,
 			authorization_id.clone(),
-			Some(schema_id)
+			Some(schema_id),
+			None,
+			None
 		));
 
 		assert_err!(
@@ -468,6 +492,8 @@ fn trying_to_update_a_non_registered_statement_should_fail() {
 				statement_id,
 				statement_digest,
 				authorization_id,
+				None,
+				None
 			),
 			Error::<Test>::StatementNotFound
 		);
@@ -527,7 +553,9 @@ fn revoking_a_registered_statement_should_succeed() {
 			DoubleOrigin(author.clone(), creator.clone()).into(),
 			statement_digest,
 			authorization_id.clone(),
-			Some(schema_id)
+			Some(schema_id),
+			None,
+			None
 		));
 
 		assert_ok!(Statement::revoke(
@@ -617,13 +645,17 @@ fn revoking_a_registered_statement_by_a_non_delegate_should_fail() {
 			space_id,
 			delegate.clone(),
 			authorization_id.clone(),
+			None,
+			None,
 		));
 
 		assert_ok!(Statement::register(
 			DoubleOrigin(author.clone(), creator.clone()).into(),
 			statement_digest,
 			authorization_id.clone(),
-			Some(schema_id)
+			Some(schema_id),
+			None,
+			None
 		));
 
 		assert_err!(
@@ -690,7 +722,9 @@ fn restoring_a_revoked_statement_should_succeed() {
 			DoubleOrigin(author.clone(), creator.clone()).into(),
 			statement_digest,
 			authorization_id.clone(),
-			Some(schema_id)
+			Some(schema_id),
+			None,
+			None
 		));
 
 		assert_ok!(Statement::revoke(
@@ -766,7 +800,9 @@ fn trying_to_restore_a_non_revoked_statement_should_fail() {
 			DoubleOrigin(author.clone(), creator.clone()).into(),
 			statement_digest,
 			authorization_id.clone(),
-			Some(schema_id)
+			Some(schema_id),
+			None,
+			None
 		));
 
 		assert_err!(
@@ -854,13 +890,17 @@ fn trying_to_restore_a_revoked_statement_by_a_non_delegate_should_fail() {
 			space_id,
 			delegate.clone(),
 			authorization_id.clone(),
+			None,
+			None,
 		));
 
 		assert_ok!(Statement::register(
 			DoubleOrigin(author.clone(), creator.clone()).into(),
 			statement_digest,
 			authorization_id.clone(),
-			Some(schema_id)
+			Some(schema_id),
+			None,
+			None
 		));
 
 		assert_ok!(Statement::revoke(
@@ -928,13 +968,17 @@ fn registering_a_statement_again_should_fail() {
 			space_id,
 			delegate.clone(),
 			authorization_id.clone(),
+			None,
+			None,
 		));
 
 		assert_ok!(Statement::register(
 			DoubleOrigin(author.clone(), creator.clone()).into(),
 			statement_digest,
 			authorization_id.clone(),
-			Some(schema_id.clone())
+			Some(schema_id.clone()),
+			None,
+			None
 		));
 
 		assert_err!(
@@ -942,7 +986,9 @@ fn registering_a_statement_again_should_fail() {
 				DoubleOrigin(author, creator).into(),
 				statement_digest,
 				authorization_id,
-				Some(schema_id)
+				Some(schema_id),
+				None,
+				None
 			),
 			Error::<Test>::StatementAlreadyAnchored
 		);
@@ -1004,7 +1050,9 @@ fn updating_a_registered_statement_again_should_fail() {
 			DoubleOrigin(author.clone(), creator.clone()).into(),
 			statement_digest,
 			authorization_id.clone(),
-			Some(schema_id)
+			Some(schema_id),
+			None,
+			None
 		));
 
 		assert_ok!(Statement::update(
@@ -1012,6 +1060,8 @@ fn updating_a_registered_statement_again_should_fail() {
 			statement_id.clone(),
 			new_statement_digest,
 			authorization_id.clone(),
+			None,
+			None
 		));
 
 		assert_err!(
@@ -1020,6 +1070,8 @@ fn updating_a_registered_statement_again_should_fail() {
 				statement_id,
 				new_statement_digest,
 				authorization_id,
+				None,
+				None
 			),
 			Error::<Test>::StatementDigestAlreadyAnchored
 		);
@@ -1064,6 +1116,8 @@ fn removing_nonexistent_presentation_should_fail() {
 			DoubleOrigin(author.clone(), creator.clone()).into(),
 			statement_digest,
 			authorization_id.clone(),
+			None,
+			None,
 			None
 		));
 
@@ -1130,7 +1184,9 @@ fn bulk_registering_statements_with_same_digest_should_fail() {
 			DoubleOrigin(author.clone(), creator.clone()).into(),
 			statement_digests[0],
 			authorization_id.clone(),
-			Some(schema_id.clone())
+			Some(schema_id.clone()),
+			None,
+			None
 		));
 
 		assert_err!(
@@ -1199,7 +1255,9 @@ fn trying_to_update_or_revoke_or_add_presentation_for_revoked_statement_should_f
 			DoubleOrigin(author.clone(), creator.clone()).into(),
 			statement_digest,
 			authorization_id.clone(),
-			Some(schema_id)
+			Some(schema_id),
+			None,
+			None
 		));
 
 		// Revoke the statement
@@ -1216,6 +1274,8 @@ fn trying_to_update_or_revoke_or_add_presentation_for_revoked_statement_should_f
 				statement_id.clone(),
 				new_statement_digest,
 				authorization_id.clone(),
+				None,
+				None
 			),
 			Error::<Test>::StatementRevoked
 		);
@@ -1237,6 +1297,7 @@ fn trying_to_update_or_revoke_or_add_presentation_for_revoked_statement_should_f
 				statement_id,
 				statement_digest,
 				PresentationTypeOf::Other,
+				None,
 				authorization_id,
 			),
 			Error::<Test>::StatementRevoked
@@ -1282,6 +1343,8 @@ fn nonexistent_presentation_should_fail() {
 			DoubleOrigin(author.clone(), creator.clone()).into(),
 			statement_digest,
 			authorization_id.clone(),
+			None,
+			None,
 			None
 		));
 
@@ -1296,3 +1359,1657 @@ fn nonexistent_presentation_should_fail() {
 		);
 	});
 }
+
+#[test]
+fn querying_statement_info_should_report_entries_and_presentations() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 5u64;
+	let statement = [77u8; 32];
+	let statement_digest: StatementDigestOf<Test> =
+		<Test as frame_system::Config>::Hashing::hash(&statement[..]);
+	let new_statement = [88u8; 32];
+	let new_statement_digest = <Test as frame_system::Config>::Hashing::hash(&new_statement[..]);
+	let presentation = [99u8; 32];
+	let presentation_digest = <Test as frame_system::Config>::Hashing::hash(&presentation[..]);
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let statement_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&statement_digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let statement_id: StatementIdOf = generate_statement_id::<Test>(&statement_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		assert_ok!(Statement::register(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_digest,
+			authorization_id.clone(),
+			None,
+			None,
+			None
+		));
+
+		assert_ok!(Statement::update(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_id.clone(),
+			new_statement_digest,
+			authorization_id.clone(),
+			None,
+			None
+		));
+
+		assert_ok!(Statement::add_presentation(
+			DoubleOrigin(author, creator.clone()).into(),
+			statement_id.clone(),
+			presentation_digest,
+			PresentationTypeOf::PDF,
+			None,
+			authorization_id,
+		));
+
+		let info = Statement::statement_info(&statement_id)
+			.expect("Statement info should be available for a registered statement.");
+
+		assert_eq!(info.digest, new_statement_digest);
+		assert_eq!(info.space, space_id);
+		assert_eq!(info.status, StatementStatus::Active);
+		assert_eq!(info.entries.len(), 2);
+		assert_eq!(info.presentations.len(), 1);
+		assert_eq!(info.presentations[0].presentation_digest, presentation_digest);
+		assert_eq!(info.presentations[0].presentation_type, PresentationTypeOf::PDF);
+
+		assert_eq!(
+			Statement::digest_status(&statement_id, &statement_digest),
+			Some(StatementStatus::Superseded)
+		);
+		assert_eq!(
+			Statement::digest_status(&statement_id, &new_statement_digest),
+			Some(StatementStatus::Active)
+		);
+		assert_eq!(Statement::digest_status(&statement_id, &presentation_digest), None);
+
+		assert_eq!(
+			Statement::statement_info_by_digest(&presentation_digest, &space_id)
+				.map(|info| info.identifier),
+			Some(statement_id)
+		);
+	});
+}
+
+#[test]
+fn statement_validity_window_should_be_reflected_in_status() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 5u64;
+	let statement = [77u8; 32];
+	let statement_digest: StatementDigestOf<Test> =
+		<Test as frame_system::Config>::Hashing::hash(&statement[..]);
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let statement_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&statement_digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let statement_id: StatementIdOf = generate_statement_id::<Test>(&statement_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		assert_err!(
+			Statement::register(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				statement_digest,
+				authorization_id.clone(),
+				None,
+				Some(10),
+				Some(5)
+			),
+			Error::<Test>::InvalidValidityWindow
+		);
+
+		assert_ok!(Statement::register(
+			DoubleOrigin(author, creator).into(),
+			statement_digest,
+			authorization_id,
+			None,
+			Some(5),
+			Some(10)
+		));
+
+		assert_eq!(
+			Statement::digest_status(&statement_id, &statement_digest),
+			Some(StatementStatus::NotYetValid)
+		);
+		assert_eq!(Expirations::<Test>::get(10).to_vec(), vec![statement_id.clone()]);
+
+		System::set_block_number(5);
+		assert_eq!(
+			Statement::digest_status(&statement_id, &statement_digest),
+			Some(StatementStatus::Active)
+		);
+
+		System::set_block_number(10);
+		Statement::on_initialize(10);
+		assert_eq!(
+			Statement::digest_status(&statement_id, &statement_digest),
+			Some(StatementStatus::Expired)
+		);
+		assert!(Expirations::<Test>::get(10).is_empty());
+		System::assert_last_event(
+			Event::<Test>::Expired { identifier: statement_id.clone() }.into(),
+		);
+
+		let info = Statement::statement_info(&statement_id)
+			.expect("Statement info should be available for a registered statement.");
+		assert_eq!(info.status, StatementStatus::Expired);
+		assert_eq!(
+			info.validity,
+			Some(StatementValidity { valid_from: Some(5), valid_until: Some(10) })
+		);
+	});
+}
+
+#[test]
+fn asserting_delegate_should_not_update_revoke_or_remove_a_statement() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let delegate = DID_01;
+	let capacity = 10u64;
+	let statement = [77u8; 32];
+	let statement_digest = <Test as frame_system::Config>::Hashing::hash(&statement[..]);
+	let new_statement = [88u8; 32];
+	let new_statement_digest = <Test as frame_system::Config>::Hashing::hash(&new_statement[..]);
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let delegate_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &delegate.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let delegate_authorization_id = generate_authorization_id::<Test>(&delegate_id_digest);
+
+	let statement_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&statement_digest.encode()[..], &space_id.encode()[..], &delegate.encode()[..]].concat()
+			[..],
+	);
+	let statement_id: StatementIdOf = generate_statement_id::<Test>(&statement_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		assert_ok!(Space::add_delegate(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_id,
+			delegate.clone(),
+			authorization_id.clone(),
+			None,
+			None,
+		));
+
+		// `ASSERT` is enough to anchor a statement...
+		assert_ok!(Statement::register(
+			DoubleOrigin(author.clone(), delegate.clone()).into(),
+			statement_digest,
+			delegate_authorization_id.clone(),
+			None,
+			None,
+			None
+		));
+
+		// ...but not to alter it.
+		assert_err!(
+			Statement::update(
+				DoubleOrigin(author.clone(), delegate.clone()).into(),
+				statement_id.clone(),
+				new_statement_digest,
+				delegate_authorization_id.clone(),
+				None,
+				None
+			),
+			pallet_chain_space::Error::<Test>::UnauthorizedOperation
+		);
+		assert_err!(
+			Statement::revoke(
+				DoubleOrigin(author.clone(), delegate.clone()).into(),
+				statement_id.clone(),
+				delegate_authorization_id.clone(),
+			),
+			pallet_chain_space::Error::<Test>::UnauthorizedOperation
+		);
+		assert_err!(
+			Statement::remove(
+				DoubleOrigin(author.clone(), delegate.clone()).into(),
+				statement_id.clone(),
+				delegate_authorization_id.clone(),
+			),
+			pallet_chain_space::Error::<Test>::UnauthorizedOperation
+		);
+
+		// The space creator holds every permission.
+		assert_ok!(Statement::revoke(
+			DoubleOrigin(author, creator).into(),
+			statement_id,
+			authorization_id,
+		));
+	});
+}
+
+#[test]
+fn registering_a_statement_against_a_deprecated_schema_should_fail() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 5u64;
+	let statement = [77u8; 32];
+	let statement_digest = <Test as frame_system::Config>::Hashing::hash(&statement[..]);
+	let other_statement = [78u8; 32];
+	let other_statement_digest =
+		<Test as frame_system::Config>::Hashing::hash(&other_statement[..]);
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let raw_schema = [11u8; 256].to_vec();
+	let schema: InputSchemaOf<Test> = BoundedVec::try_from(raw_schema)
+		.expect("Test Schema should fit into the expected input length of for the test runtime.");
+	let schema_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&schema.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let schema_id: SchemaIdOf = generate_schema_id::<Test>(&schema_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id, capacity));
+
+		assert_ok!(Schema::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			schema.clone(),
+			authorization_id.clone()
+		));
+
+		assert_ok!(Schema::deprecate(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			schema_id.clone(),
+			authorization_id.clone()
+		));
+
+		assert_err!(
+			Statement::register(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				statement_digest,
+				authorization_id.clone(),
+				Some(schema_id.clone()),
+				None,
+				None
+			),
+			Error::<Test>::SchemaDeprecated
+		);
+
+		assert_err!(
+			Statement::register_batch(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				vec![other_statement_digest],
+				authorization_id.clone(),
+				Some(schema_id),
+			),
+			Error::<Test>::SchemaDeprecated
+		);
+
+		assert_ok!(Statement::register(
+			DoubleOrigin(author, creator).into(),
+			statement_digest,
+			authorization_id,
+			None,
+			None,
+			None
+		));
+	});
+}
+
+#[test]
+fn revoking_a_statement_under_a_revocation_policy_should_need_approvals() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let approver_a = DID_01;
+	let approver_b = DID_02;
+	let capacity = 20u64;
+	let statement = [77u8; 32];
+	let statement_digest = <Test as frame_system::Config>::Hashing::hash(&statement[..]);
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let approver_a_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &approver_a.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let approver_a_authorization_id = generate_authorization_id::<Test>(&approver_a_digest);
+	let approver_b_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &approver_b.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let approver_b_authorization_id = generate_authorization_id::<Test>(&approver_b_digest);
+
+	let statement_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&statement_digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let statement_id: StatementIdOf = generate_statement_id::<Test>(&statement_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		for approver in [approver_a.clone(), approver_b.clone()] {
+			assert_ok!(Space::add_delegate_with_permissions(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				space_id.clone(),
+				approver,
+				Permissions::REVOKE,
+				authorization_id.clone(),
+				None,
+				None,
+			));
+		}
+
+		assert_ok!(Statement::register(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_digest,
+			authorization_id.clone(),
+			None,
+			None,
+			None
+		));
+
+		// The threshold must be reachable and every approver a delegate.
+		assert_err!(
+			Statement::set_revocation_policy(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				authorization_id.clone(),
+				3,
+				vec![approver_a.clone(), approver_b.clone()],
+				false,
+			),
+			Error::<Test>::InvalidRevocationThreshold
+		);
+		assert_err!(
+			Statement::set_revocation_policy(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				authorization_id.clone(),
+				2,
+				vec![approver_a.clone(), approver_a.clone()],
+				false,
+			),
+			Error::<Test>::DuplicateRevocationApprover
+		);
+		assert_err!(
+			Statement::set_revocation_policy(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				authorization_id.clone(),
+				1,
+				vec![SubjectId(AccountId32::new([9u8; 32]))],
+				false,
+			),
+			Error::<Test>::RevocationApproverNotADelegate
+		);
+		assert_ok!(Statement::set_revocation_policy(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			authorization_id.clone(),
+			2,
+			vec![approver_a.clone(), approver_b.clone()],
+			false,
+		));
+
+		// Revoking now only opens a proposal.
+		assert_ok!(Statement::revoke(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_id.clone(),
+			authorization_id.clone(),
+		));
+		System::assert_last_event(
+			Event::<Test>::RevocationProposed {
+				identifier: statement_id.clone(),
+				author: creator.clone(),
+			}
+			.into(),
+		);
+		assert_eq!(
+			Statement::digest_status(&statement_id, &statement_digest),
+			Some(StatementStatus::Active)
+		);
+		assert_err!(
+			Statement::revoke(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				statement_id.clone(),
+				authorization_id.clone(),
+			),
+			Error::<Test>::RevocationProposalAlreadyPending
+		);
+
+		assert_err!(
+			Statement::approve_revocation(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				statement_id.clone(),
+				authorization_id.clone(),
+			),
+			Error::<Test>::NotARevocationApprover
+		);
+
+		assert_ok!(Statement::approve_revocation(
+			DoubleOrigin(author.clone(), approver_a.clone()).into(),
+			statement_id.clone(),
+			approver_a_authorization_id.clone(),
+		));
+		assert_err!(
+			Statement::approve_revocation(
+				DoubleOrigin(author.clone(), approver_a.clone()).into(),
+				statement_id.clone(),
+				approver_a_authorization_id,
+			),
+			Error::<Test>::RevocationAlreadyApproved
+		);
+		assert_eq!(
+			Statement::digest_status(&statement_id, &statement_digest),
+			Some(StatementStatus::Active)
+		);
+
+		// The second approval reaches the threshold and revokes on behalf of
+		// the proposer.
+		assert_ok!(Statement::approve_revocation(
+			DoubleOrigin(author.clone(), approver_b).into(),
+			statement_id.clone(),
+			approver_b_authorization_id,
+		));
+		System::assert_last_event(
+			Event::<Test>::Revoke { identifier: statement_id.clone(), author: creator.clone() }
+				.into(),
+		);
+		assert_eq!(
+			Statement::digest_status(&statement_id, &statement_digest),
+			Some(StatementStatus::Revoked)
+		);
+		assert!(RevocationProposals::<Test>::get(&statement_id).is_none());
+		assert!(RevocationProposalExpirations::<Test>::get(11).is_empty());
+
+		assert_ok!(Statement::remove_revocation_policy(
+			DoubleOrigin(author, creator).into(),
+			authorization_id,
+		));
+		assert!(RevocationPolicies::<Test>::get(&space_id).is_none());
+	});
+}
+
+#[test]
+fn approving_a_revocation_the_statement_no_longer_admits_should_discard_it() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let approver_a = DID_01;
+	let approver_b = DID_02;
+	let capacity = 20u64;
+	let statement = [77u8; 32];
+	let statement_digest = <Test as frame_system::Config>::Hashing::hash(&statement[..]);
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let approver_a_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &approver_a.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let approver_a_authorization_id = generate_authorization_id::<Test>(&approver_a_digest);
+	let approver_b_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &approver_b.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let approver_b_authorization_id = generate_authorization_id::<Test>(&approver_b_digest);
+
+	let statement_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&statement_digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let statement_id: StatementIdOf = generate_statement_id::<Test>(&statement_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		for approver in [approver_a.clone(), approver_b.clone()] {
+			assert_ok!(Space::add_delegate_with_permissions(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				space_id.clone(),
+				approver,
+				Permissions::REVOKE,
+				authorization_id.clone(),
+				None,
+				None,
+			));
+		}
+
+		assert_ok!(Statement::register(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_digest,
+			authorization_id.clone(),
+			None,
+			None,
+			None
+		));
+		assert_ok!(Statement::set_revocation_policy(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			authorization_id.clone(),
+			2,
+			vec![approver_a.clone(), approver_b.clone()],
+			false,
+		));
+		assert_ok!(Statement::revoke(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_id.clone(),
+			authorization_id.clone(),
+		));
+
+		// The statement is revoked while the proposal is pending.
+		assert_ok!(Statement::remove_revocation_policy(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			authorization_id.clone(),
+		));
+		assert_ok!(Statement::revoke(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_id.clone(),
+			authorization_id.clone(),
+		));
+		assert_ok!(Statement::set_revocation_policy(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			authorization_id,
+			2,
+			vec![approver_a.clone(), approver_b.clone()],
+			false,
+		));
+
+		assert_ok!(Statement::approve_revocation(
+			DoubleOrigin(author.clone(), approver_a).into(),
+			statement_id.clone(),
+			approver_a_authorization_id,
+		));
+
+		// Reaching the threshold discards the proposal instead of leaving it
+		// pending until it lapses.
+		assert_ok!(Statement::approve_revocation(
+			DoubleOrigin(author, approver_b).into(),
+			statement_id.clone(),
+			approver_b_authorization_id,
+		));
+		System::assert_last_event(
+			Event::<Test>::RevocationProposalFailed {
+				identifier: statement_id.clone(),
+				error: Error::<Test>::StatementRevoked.into(),
+			}
+			.into(),
+		);
+		assert!(RevocationProposals::<Test>::get(&statement_id).is_none());
+		assert!(RevocationProposalExpirations::<Test>::get(11).is_empty());
+	});
+}
+
+#[test]
+fn pending_revocation_proposals_should_be_cancelled_or_expire() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let approver = DID_01;
+	let capacity = 20u64;
+	let statement = [77u8; 32];
+	let statement_digest = <Test as frame_system::Config>::Hashing::hash(&statement[..]);
+	let new_statement = [88u8; 32];
+	let new_statement_digest = <Test as frame_system::Config>::Hashing::hash(&new_statement[..]);
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let approver_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &approver.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let approver_authorization_id = generate_authorization_id::<Test>(&approver_digest);
+
+	let statement_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&statement_digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let statement_id: StatementIdOf = generate_statement_id::<Test>(&statement_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+		assert_ok!(Space::add_delegate_with_permissions(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_id.clone(),
+			approver.clone(),
+			Permissions::REVOKE | Permissions::UPDATE,
+			authorization_id.clone(),
+			None,
+			None,
+		));
+
+		assert_ok!(Statement::register(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_digest,
+			authorization_id.clone(),
+			None,
+			None,
+			None
+		));
+
+		assert_ok!(Statement::set_revocation_policy(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			authorization_id.clone(),
+			2,
+			vec![creator.clone(), approver.clone()],
+			true,
+		));
+
+		// Updates need approval as well, and the proposer's own approval
+		// counts towards the threshold.
+		assert_ok!(Statement::update(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_id.clone(),
+			new_statement_digest,
+			authorization_id.clone(),
+			None,
+			None
+		));
+		let proposal = RevocationProposals::<Test>::get(&statement_id)
+			.expect("Update should be pending approval.");
+		assert_eq!(proposal.approvals.to_vec(), vec![creator.clone()]);
+		assert_eq!(proposal.expires_at, 11);
+		assert_eq!(Statements::<Test>::get(&statement_id).unwrap().digest, statement_digest);
+
+		// Only the proposer may cancel.
+		assert_err!(
+			Statement::cancel_revocation(
+				DoubleOrigin(author.clone(), approver.clone()).into(),
+				statement_id.clone(),
+				approver_authorization_id.clone(),
+			),
+			Error::<Test>::UnauthorizedOperation
+		);
+		assert_ok!(Statement::cancel_revocation(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_id.clone(),
+			authorization_id.clone(),
+		));
+		System::assert_last_event(
+			Event::<Test>::RevocationCancelled {
+				identifier: statement_id.clone(),
+				author: creator.clone(),
+			}
+			.into(),
+		);
+		assert!(RevocationProposals::<Test>::get(&statement_id).is_none());
+		assert!(RevocationProposalExpirations::<Test>::get(11).is_empty());
+
+		// A proposal that does not reach the threshold lapses.
+		assert_ok!(Statement::revoke(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_id.clone(),
+			authorization_id.clone(),
+		));
+		System::set_block_number(11);
+		Statement::on_initialize(11);
+		System::assert_last_event(
+			Event::<Test>::RevocationProposalExpired { identifier: statement_id.clone() }.into(),
+		);
+		assert!(RevocationProposals::<Test>::get(&statement_id).is_none());
+		assert_err!(
+			Statement::approve_revocation(
+				DoubleOrigin(author, approver).into(),
+				statement_id.clone(),
+				approver_authorization_id,
+			),
+			Error::<Test>::RevocationProposalNotFound
+		);
+		assert_eq!(
+			Statement::digest_status(&statement_id, &statement_digest),
+			Some(StatementStatus::Active)
+		);
+	});
+}
+
+#[test]
+fn suspended_statements_should_be_reported_apart_from_revoked_ones() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 20u64;
+	let statement = [77u8; 32];
+	let statement_digest = <Test as frame_system::Config>::Hashing::hash(&statement[..]);
+	let new_statement = [88u8; 32];
+	let new_statement_digest = <Test as frame_system::Config>::Hashing::hash(&new_statement[..]);
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let statement_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&statement_digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let statement_id: StatementIdOf = generate_statement_id::<Test>(&statement_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		assert_ok!(Statement::register(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_digest,
+			authorization_id.clone(),
+			None,
+			None,
+			None
+		));
+
+		assert_err!(
+			Statement::suspend(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				statement_id.clone(),
+				7,
+				Some(1),
+				authorization_id.clone(),
+			),
+			Error::<Test>::InvalidSuspensionLift
+		);
+
+		assert_ok!(Statement::suspend(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_id.clone(),
+			7,
+			Some(5),
+			authorization_id.clone(),
+		));
+		assert_eq!(
+			Statement::digest_status(&statement_id, &statement_digest),
+			Some(StatementStatus::Suspended)
+		);
+		let info = Statement::statement_info(&statement_id)
+			.expect("Statement info should be available for a registered statement.");
+		assert_eq!(info.status, StatementStatus::Suspended);
+		assert_eq!(
+			info.suspension,
+			Some(StatementSuspension {
+				reason: 7,
+				author: creator.clone(),
+				suspended_at: 1,
+				lift_at: Some(5)
+			})
+		);
+
+		// A suspended statement cannot be suspended again or updated.
+		assert_err!(
+			Statement::suspend(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				statement_id.clone(),
+				7,
+				None,
+				authorization_id.clone(),
+			),
+			Error::<Test>::StatementSuspended
+		);
+		assert_err!(
+			Statement::update(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				statement_id.clone(),
+				new_statement_digest,
+				authorization_id.clone(),
+				None,
+				None
+			),
+			Error::<Test>::StatementSuspended
+		);
+
+		assert_ok!(Statement::unsuspend(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_id.clone(),
+			authorization_id.clone(),
+		));
+		assert_eq!(
+			Statement::digest_status(&statement_id, &statement_digest),
+			Some(StatementStatus::Active)
+		);
+		assert!(SuspensionLifts::<Test>::get(5).is_empty());
+		assert_err!(
+			Statement::unsuspend(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				statement_id.clone(),
+				authorization_id.clone(),
+			),
+			Error::<Test>::StatementNotSuspended
+		);
+
+		// The suspension is lifted automatically at its lift block.
+		assert_ok!(Statement::suspend(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_id.clone(),
+			3,
+			Some(5),
+			authorization_id.clone(),
+		));
+		System::set_block_number(5);
+		Statement::on_initialize(5);
+		System::assert_last_event(
+			Event::<Test>::SuspensionLapsed { identifier: statement_id.clone() }.into(),
+		);
+		assert!(Suspensions::<Test>::get(&statement_id).is_none());
+		assert_eq!(
+			Statement::digest_status(&statement_id, &statement_digest),
+			Some(StatementStatus::Active)
+		);
+
+		// A revocation takes precedence over a suspension.
+		assert_ok!(Statement::suspend(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_id.clone(),
+			3,
+			None,
+			authorization_id.clone(),
+		));
+		assert_ok!(Statement::revoke(
+			DoubleOrigin(author, creator).into(),
+			statement_id.clone(),
+			authorization_id,
+		));
+		assert_eq!(
+			Statement::digest_status(&statement_id, &statement_digest),
+			Some(StatementStatus::Revoked)
+		);
+	});
+}
+
+#[test]
+fn presentations_of_the_same_type_should_be_told_apart_by_metadata() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 10u64;
+	let statement = [77u8; 32];
+	let statement_digest: StatementDigestOf<Test> =
+		<Test as frame_system::Config>::Hashing::hash(&statement[..]);
+	let english_digest = <Test as frame_system::Config>::Hashing::hash(&[90u8; 32][..]);
+	let hindi_digest = <Test as frame_system::Config>::Hashing::hash(&[91u8; 32][..]);
+	let json_digest = <Test as frame_system::Config>::Hashing::hash(&[92u8; 32][..]);
+	let hindi_file_hash = <Test as frame_system::Config>::Hashing::hash(&[93u8; 32][..]);
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let statement_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&statement_digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let statement_id: StatementIdOf = generate_statement_id::<Test>(&statement_id_digest);
+
+	let metadata = |mime_type: &[u8], language: &[u8], location| PresentationMetadataOf::<Test> {
+		mime_type: Some(mime_type.to_vec().try_into().unwrap()),
+		language: Some(language.to_vec().try_into().unwrap()),
+		location,
+	};
+	let english_uri: PresentationUriOf<Test> =
+		b"https://example.com/degree-en.pdf".to_vec().try_into().unwrap();
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		assert_ok!(Statement::register(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_digest,
+			authorization_id.clone(),
+			None,
+			None,
+			None
+		));
+
+		// Malformed metadata is rejected
+		assert_err!(
+			Statement::add_presentation(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				statement_id.clone(),
+				english_digest,
+				PresentationTypeOf::PDF,
+				Some(metadata(b"application pdf", b"en", None)),
+				authorization_id.clone(),
+			),
+			Error::<Test>::InvalidPresentationMimeType
+		);
+		assert_err!(
+			Statement::add_presentation(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				statement_id.clone(),
+				english_digest,
+				PresentationTypeOf::PDF,
+				Some(metadata(b"application/pdf", b"en--IN", None)),
+				authorization_id.clone(),
+			),
+			Error::<Test>::InvalidPresentationLanguage
+		);
+		assert_err!(
+			Statement::add_presentation(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				statement_id.clone(),
+				english_digest,
+				PresentationTypeOf::PDF,
+				Some(metadata(
+					b"application/pdf",
+					b"en",
+					Some(PresentationLocation::Uri(b"degree en.pdf".to_vec().try_into().unwrap()))
+				)),
+				authorization_id.clone(),
+			),
+			Error::<Test>::InvalidPresentationUri
+		);
+
+		// One PDF per language, plus a JSON rendering without metadata
+		assert_ok!(Statement::add_presentation(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_id.clone(),
+			english_digest,
+			PresentationTypeOf::PDF,
+			Some(metadata(
+				b"application/pdf",
+				b"en-IN",
+				Some(PresentationLocation::Uri(english_uri.clone()))
+			)),
+			authorization_id.clone(),
+		));
+		assert_ok!(Statement::add_presentation(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_id.clone(),
+			hindi_digest,
+			PresentationTypeOf::PDF,
+			Some(metadata(
+				b"application/pdf",
+				b"hi-IN",
+				Some(PresentationLocation::ContentHash(hindi_file_hash))
+			)),
+			authorization_id.clone(),
+		));
+		assert_ok!(Statement::add_presentation(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_id.clone(),
+			json_digest,
+			PresentationTypeOf::JSON,
+			None,
+			authorization_id.clone(),
+		));
+
+		assert_eq!(Statement::presentations(&statement_id, None).len(), 3);
+		assert_eq!(Statement::statement_info(&statement_id).unwrap().presentations.len(), 3);
+
+		let pdfs = Statement::presentations(&statement_id, Some(PresentationTypeOf::PDF));
+		assert_eq!(pdfs.len(), 2);
+		let english = pdfs
+			.iter()
+			.find(|p| p.presentation_digest == english_digest)
+			.and_then(|p| p.metadata.clone())
+			.expect("English presentation should carry metadata.");
+		assert_eq!(english.mime_type, Some(b"application/pdf".to_vec()));
+		assert_eq!(english.language, Some(b"en-IN".to_vec()));
+		assert_eq!(english.location, Some(PresentationLocation::Uri(english_uri.into_inner())));
+		let hindi = pdfs
+			.iter()
+			.find(|p| p.presentation_digest == hindi_digest)
+			.and_then(|p| p.metadata.clone())
+			.expect("Hindi presentation should carry metadata.");
+		assert_eq!(hindi.language, Some(b"hi-IN".to_vec()));
+		assert_eq!(hindi.location, Some(PresentationLocation::ContentHash(hindi_file_hash)));
+
+		let json = Statement::presentations(&statement_id, Some(PresentationTypeOf::JSON));
+		assert_eq!(json.len(), 1);
+		assert_eq!(json[0].metadata, None);
+
+		// Removing a presentation removes its metadata
+		assert_ok!(Statement::remove_presentation(
+			DoubleOrigin(author, creator).into(),
+			statement_id.clone(),
+			english_digest,
+			authorization_id,
+		));
+		assert!(!<PresentationsMetadata<Test>>::contains_key(&statement_id, english_digest));
+		assert_eq!(Statement::presentations(&statement_id, Some(PresentationTypeOf::PDF)).len(), 1);
+	});
+}
+
+#[test]
+fn transferring_a_statement_should_move_it_to_the_target_space() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 10u64;
+	let statement = [77u8; 32];
+	let statement_digest: StatementDigestOf<Test> =
+		<Test as frame_system::Config>::Hashing::hash(&statement[..]);
+	let new_statement_digest = <Test as frame_system::Config>::Hashing::hash(&[88u8; 32][..]);
+	let presentation_digest = <Test as frame_system::Config>::Hashing::hash(&[99u8; 32][..]);
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let raw_target_space = [3u8; 256].to_vec();
+	let target_space_digest =
+		<Test as frame_system::Config>::Hashing::hash(&raw_target_space.encode()[..]);
+	let target_space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&target_space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let target_space_id: SpaceIdOf = generate_space_id::<Test>(&target_space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+	let target_auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&target_space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let target_authorization_id: Ss58Identifier =
+		generate_authorization_id::<Test>(&target_auth_digest);
+
+	let statement_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&statement_digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let statement_id: StatementIdOf = generate_statement_id::<Test>(&statement_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			target_space_digest,
+		));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), target_space_id.clone(), capacity));
+
+		assert_ok!(Statement::register(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_digest,
+			authorization_id.clone(),
+			None,
+			None,
+			None
+		));
+		assert_ok!(Statement::update(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_id.clone(),
+			new_statement_digest,
+			authorization_id.clone(),
+			None,
+			None
+		));
+		assert_ok!(Statement::add_presentation(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_id.clone(),
+			presentation_digest,
+			PresentationTypeOf::PDF,
+			None,
+			authorization_id.clone(),
+		));
+
+		// Both authorizations must refer to different spaces
+		assert_err!(
+			Statement::transfer_space(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				statement_id.clone(),
+				authorization_id.clone(),
+				authorization_id.clone(),
+			),
+			Error::<Test>::TransferToSameSpace
+		);
+
+		let source_usage = <pallet_chain_space::Spaces<Test>>::get(&space_id).unwrap().txn_count;
+		let target_usage =
+			<pallet_chain_space::Spaces<Test>>::get(&target_space_id).unwrap().txn_count;
+
+		assert_ok!(Statement::transfer_space(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_id.clone(),
+			authorization_id.clone(),
+			target_authorization_id.clone(),
+		));
+
+		// Two entries and one presentation are charged to the target space
+		assert_eq!(
+			<pallet_chain_space::Spaces<Test>>::get(&space_id).unwrap().txn_count,
+			source_usage + 1 - 3
+		);
+		assert_eq!(
+			<pallet_chain_space::Spaces<Test>>::get(&target_space_id).unwrap().txn_count,
+			target_usage + 1 + 3
+		);
+
+		assert_eq!(<Statements<Test>>::get(&statement_id).unwrap().space, target_space_id);
+		assert_eq!(
+			<Presentations<Test>>::get(&statement_id, presentation_digest).unwrap().space,
+			target_space_id
+		);
+		for digest in [statement_digest, new_statement_digest, presentation_digest] {
+			assert_eq!(<IdentifierLookup<Test>>::get(digest, &space_id), None);
+			assert_eq!(
+				<IdentifierLookup<Test>>::get(digest, &target_space_id),
+				Some(statement_id.clone())
+			);
+		}
+		assert_eq!(
+			Statement::digest_status(&statement_id, &statement_digest),
+			Some(StatementStatus::Superseded)
+		);
+
+		// The statement is now managed through the target space
+		assert_err!(
+			Statement::revoke(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				statement_id.clone(),
+				authorization_id,
+			),
+			Error::<Test>::UnauthorizedOperation
+		);
+		assert_ok!(Statement::revoke(
+			DoubleOrigin(author, creator).into(),
+			statement_id.clone(),
+			target_authorization_id,
+		));
+	});
+}
+
+#[test]
+fn removing_a_statement_under_a_revocation_policy_should_fail() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 20u64;
+	let statement = [77u8; 32];
+	let statement_digest = <Test as frame_system::Config>::Hashing::hash(&statement[..]);
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let statement_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&statement_digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let statement_id: StatementIdOf = generate_statement_id::<Test>(&statement_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		assert_ok!(Statement::register(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_digest,
+			authorization_id.clone(),
+			None,
+			None,
+			None
+		));
+		assert_ok!(Statement::set_revocation_policy(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			authorization_id.clone(),
+			1,
+			vec![creator.clone()],
+			true,
+		));
+
+		// Removing would discard the statement without approval.
+		assert_err!(
+			Statement::remove(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				statement_id.clone(),
+				authorization_id.clone(),
+			),
+			Error::<Test>::RevocationPolicyInEffect
+		);
+		assert!(<Statements<Test>>::get(&statement_id).is_some());
+
+		assert_ok!(Statement::remove_revocation_policy(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			authorization_id.clone(),
+		));
+		assert_ok!(Statement::remove(
+			DoubleOrigin(author, creator).into(),
+			statement_id.clone(),
+			authorization_id,
+		));
+		assert!(<Statements<Test>>::get(&statement_id).is_none());
+	});
+}
+
+#[test]
+fn restoring_a_statement_under_a_revocation_policy_should_fail() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 20u64;
+	let statement = [77u8; 32];
+	let statement_digest = <Test as frame_system::Config>::Hashing::hash(&statement[..]);
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let statement_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&statement_digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let statement_id: StatementIdOf = generate_statement_id::<Test>(&statement_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		assert_ok!(Statement::register(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_digest,
+			authorization_id.clone(),
+			None,
+			None,
+			None
+		));
+		assert_ok!(Statement::set_revocation_policy(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			authorization_id.clone(),
+			1,
+			vec![creator.clone()],
+			true,
+		));
+
+		// The creator is the sole approver, so the revocation executes right away.
+		assert_ok!(Statement::revoke(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_id.clone(),
+			authorization_id.clone(),
+		));
+		assert!(<RevocationList<Test>>::contains_key(&statement_id, statement_digest));
+
+		// Restoring would undo the approved revocation without approval.
+		assert_err!(
+			Statement::restore(
+				DoubleOrigin(author, creator).into(),
+				statement_id.clone(),
+				authorization_id,
+			),
+			Error::<Test>::RevocationPolicyInEffect
+		);
+		assert!(<RevocationList<Test>>::contains_key(&statement_id, statement_digest));
+	});
+}
+
+#[test]
+fn updating_a_statement_under_a_revocation_policy_without_updates_should_fail() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 20u64;
+	let statement = [77u8; 32];
+	let statement_digest = <Test as frame_system::Config>::Hashing::hash(&statement[..]);
+	let new_statement = [88u8; 32];
+	let new_statement_digest = <Test as frame_system::Config>::Hashing::hash(&new_statement[..]);
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let statement_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&statement_digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let statement_id: StatementIdOf = generate_statement_id::<Test>(&statement_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		assert_ok!(Statement::register(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_digest,
+			authorization_id.clone(),
+			None,
+			None,
+			None
+		));
+		assert_ok!(Statement::set_revocation_policy(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			authorization_id.clone(),
+			1,
+			vec![creator.clone()],
+			false,
+		));
+
+		// An update would revoke the current digest without approval.
+		assert_err!(
+			Statement::update(
+				DoubleOrigin(author, creator).into(),
+				statement_id.clone(),
+				new_statement_digest,
+				authorization_id,
+				None,
+				None
+			),
+			Error::<Test>::RevocationPolicyInEffect
+		);
+		assert!(RevocationProposals::<Test>::get(&statement_id).is_none());
+		assert_eq!(Statements::<Test>::get(&statement_id).unwrap().digest, statement_digest);
+		assert_eq!(
+			Statement::digest_status(&statement_id, &statement_digest),
+			Some(StatementStatus::Active)
+		);
+	});
+}
+
+#[test]
+fn transferring_a_statement_under_a_revocation_policy_should_fail() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 10u64;
+	let statement = [77u8; 32];
+	let statement_digest = <Test as frame_system::Config>::Hashing::hash(&statement[..]);
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let raw_target_space = [3u8; 256].to_vec();
+	let target_space_digest =
+		<Test as frame_system::Config>::Hashing::hash(&raw_target_space.encode()[..]);
+	let target_space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&target_space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let target_space_id: SpaceIdOf = generate_space_id::<Test>(&target_space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+	let target_auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&target_space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let target_authorization_id: Ss58Identifier =
+		generate_authorization_id::<Test>(&target_auth_digest);
+
+	let statement_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&statement_digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let statement_id: StatementIdOf = generate_statement_id::<Test>(&statement_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			target_space_digest,
+		));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), target_space_id.clone(), capacity));
+
+		assert_ok!(Statement::register(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_digest,
+			authorization_id.clone(),
+			None,
+			None,
+			None
+		));
+		assert_ok!(Statement::set_revocation_policy(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			authorization_id.clone(),
+			1,
+			vec![creator.clone()],
+			true,
+		));
+
+		// The target space has no policy, so the statement would escape it.
+		assert_err!(
+			Statement::transfer_space(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				statement_id.clone(),
+				authorization_id.clone(),
+				target_authorization_id.clone(),
+			),
+			Error::<Test>::RevocationPolicyInEffect
+		);
+		assert_eq!(<Statements<Test>>::get(&statement_id).unwrap().space, space_id);
+
+		assert_ok!(Statement::remove_revocation_policy(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			authorization_id.clone(),
+		));
+		assert_ok!(Statement::transfer_space(
+			DoubleOrigin(author, creator).into(),
+			statement_id.clone(),
+			authorization_id,
+			target_authorization_id,
+		));
+		assert_eq!(<Statements<Test>>::get(&statement_id).unwrap().space, target_space_id);
+	});
+}
+
+#[test]
+fn transferring_a_statement_with_too_many_digests_should_fail() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 20u64;
+	let statement = [77u8; 32];
+	let statement_digest = <Test as frame_system::Config>::Hashing::hash(&statement[..]);
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let raw_target_space = [3u8; 256].to_vec();
+	let target_space_digest =
+		<Test as frame_system::Config>::Hashing::hash(&raw_target_space.encode()[..]);
+	let target_space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&target_space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let target_space_id: SpaceIdOf = generate_space_id::<Test>(&target_space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+	let target_auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&target_space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let target_authorization_id: Ss58Identifier =
+		generate_authorization_id::<Test>(&target_auth_digest);
+
+	let statement_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&statement_digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let statement_id: StatementIdOf = generate_statement_id::<Test>(&statement_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			target_space_digest,
+		));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), target_space_id.clone(), capacity));
+
+		assert_ok!(Statement::register(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_digest,
+			authorization_id.clone(),
+			None,
+			None,
+			None
+		));
+
+		// One entry and `MaxRemoveEntries` presentations exceed the limit by one.
+		for i in 0..MaxRemoveEntries::get() {
+			assert_ok!(Statement::add_presentation(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				statement_id.clone(),
+				<Test as frame_system::Config>::Hashing::hash(&[i as u8; 32][..]),
+				PresentationTypeOf::PDF,
+				None,
+				authorization_id.clone(),
+			));
+		}
+
+		assert_err!(
+			Statement::transfer_space(
+				DoubleOrigin(author, creator).into(),
+				statement_id.clone(),
+				authorization_id,
+				target_authorization_id,
+			),
+			Error::<Test>::MaxTransferEntriesExceeded
+		);
+		assert_eq!(<Statements<Test>>::get(&statement_id).unwrap().space, space_id);
+	});
+}
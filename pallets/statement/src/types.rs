@@ -32,10 +32,23 @@
 //!
 //! The `StatementEntryStatus` type records the revocation status of a
 //! statement, indicating whether it has been revoked by a particular account.
+//!
+//! The `PresentationMetadata` type optionally describes a presentation beyond
+//! its type, with the MIME type, the language and the location of the rendered
+//! file, so that several presentations of the same type may be told apart.
+//!
+//! The `StatementSuspension` type records a temporary hold placed on a
+//! statement, which is reported separately from a permanent revocation.
+//!
+//! The `RevocationPolicy` and `RevocationProposal` types describe the
+//! multi-party approval that a space may require before its statements are
+//! revoked or updated.
 
 use crate::*;
 use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 use sp_runtime::RuntimeDebug;
 
 /// `StatementDetails` holds the essential identifiers for a statement within
@@ -105,6 +118,125 @@ pub struct StatementEntryStatus<StatementCreatorOf, StatusOf> {
 	pub revoked: StatusOf,
 }
 
+/// `StatementValidity` bounds the period during which the latest state of a
+/// statement is considered valid.
+///
+/// ## Fields
+///
+/// - `valid_from`: The block from which the statement becomes valid. Before this block the
+///   statement is reported as `NotYetValid`.
+///
+/// - `valid_until`: The block at which the statement lapses. From this block onwards the statement
+///   is reported as `Expired` and an `Expired` event is emitted when the block is initialized.
+///
+/// ## Usage
+///
+/// The validity window is optional and supplied when a statement is registered
+/// or updated. It complements the `RevocationList`, so that certificates with a
+/// known lifetime no longer require an explicit revocation once they lapse.
+#[derive(
+	Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, PartialOrd, Ord, TypeInfo,
+)]
+pub struct StatementValidity<BlockNumber> {
+	/// The block from which the statement is valid.
+	pub valid_from: Option<BlockNumber>,
+	/// The block at which the statement expires.
+	pub valid_until: Option<BlockNumber>,
+}
+
+/// `StatementSuspension` records a temporary hold placed on a statement.
+///
+/// ## Fields
+///
+/// - `reason`: An application-defined code describing why the statement was suspended.
+///
+/// - `author`: The DID identifier of the party that suspended the statement.
+///
+/// - `suspended_at`: The block at which the statement was suspended.
+///
+/// - `lift_at`: The block at which the suspension is lifted automatically. Without it the
+///   statement stays suspended until it is explicitly unsuspended.
+///
+/// ## Usage
+///
+/// Unlike an entry in the `RevocationList`, a suspension is expected to be
+/// lifted again. While it is in place the statement is reported as
+/// `Suspended` and cannot be updated.
+#[derive(
+	Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, PartialOrd, Ord, TypeInfo,
+)]
+pub struct StatementSuspension<SuspensionReasonOf, StatementCreatorOf, BlockNumber> {
+	/// The reason code of the suspension.
+	pub reason: SuspensionReasonOf,
+	/// The DID identifier of the party that suspended the statement.
+	pub author: StatementCreatorOf,
+	/// The block at which the statement was suspended.
+	pub suspended_at: BlockNumber,
+	/// The block at which the suspension lapses.
+	pub lift_at: Option<BlockNumber>,
+}
+
+/// `RevocationPolicy` requires the revocation of the statements of a space
+/// to be approved by a number of designated delegates.
+///
+/// ## Fields
+///
+/// - `threshold`: The number of approvals needed before a revocation is executed.
+///
+/// - `approvers`: The delegates of the space whose approval counts towards the threshold.
+///
+/// - `include_updates`: Whether updates of statements are subject to the same approval. Updates
+///   are refused while a policy without them is in effect.
+#[derive(
+	Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, PartialOrd, Ord, TypeInfo,
+)]
+pub struct RevocationPolicy<Approvers> {
+	/// The number of approvals required.
+	pub threshold: u32,
+	/// The delegates allowed to approve.
+	pub approvers: Approvers,
+	/// Whether updates also require approval.
+	pub include_updates: bool,
+}
+
+/// The action that is executed once a `RevocationProposal` is approved.
+#[derive(
+	Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, PartialOrd, Ord, TypeInfo,
+)]
+pub enum ProposedAction<StatementDigestOf, StatementValidityOf> {
+	/// Revoke the latest digest of the statement.
+	Revoke,
+	/// Update the statement to a new digest and validity window.
+	Update { digest: StatementDigestOf, validity: Option<StatementValidityOf> },
+}
+
+/// `RevocationProposal` tracks a pending revocation, or update, of a statement
+/// that awaits the approvals required by the `RevocationPolicy` of its space.
+///
+/// ## Fields
+///
+/// - `action`: The action executed once the threshold is reached.
+///
+/// - `proposer`: The delegate that proposed the action. It is recorded as the author once the
+///   action is executed.
+///
+/// - `approvals`: The approvers that have approved the proposal so far.
+///
+/// - `expires_at`: The block at which the proposal lapses if the threshold has not been reached.
+#[derive(
+	Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, PartialOrd, Ord, TypeInfo,
+)]
+pub struct RevocationProposal<ProposedActionOf, StatementCreatorOf, Approvals, BlockNumber> {
+	/// The proposed action.
+	pub action: ProposedActionOf,
+	/// The DID identifier of the proposer.
+	pub proposer: StatementCreatorOf,
+	/// The approvals collected so far.
+	pub approvals: Approvals,
+	/// The block at which the proposal lapses.
+	pub expires_at: BlockNumber,
+}
+
 /// Holds the details for a specific presentation of a statement.
 ///
 /// This struct captures the unique identifiers and metadata for a presentation
@@ -160,9 +292,37 @@ pub struct StatementPresentationDetails<
 	pub space: SpaceIdOf,
 }
 
+/// Where the rendered file of a presentation can be fetched from.
+#[derive(
+	Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, PartialOrd, Ord, TypeInfo,
+)]
+pub enum PresentationLocation<StatementDigestOf, Uri> {
+	/// A URI resolving to the rendered file.
+	Uri(Uri),
+	/// The content hash of the rendered file, for content-addressed storage.
+	ContentHash(StatementDigestOf),
+}
+
+/// `PresentationMetadata` describes a presentation beyond its
+/// `PresentationTypeOf`. Every field is optional; a presentation may carry
+/// only the details relevant to it, such as the language of one of several
+/// PDF renderings of a statement.
+#[derive(
+	Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, PartialOrd, Ord, TypeInfo,
+)]
+pub struct PresentationMetadata<MimeType, LanguageTag, Location> {
+	/// The MIME type of the rendered file, e.g. `application/pdf`.
+	pub mime_type: Option<MimeType>,
+	/// The BCP 47 language tag of the rendered file, e.g. `en-IN`.
+	pub language: Option<LanguageTag>,
+	/// Where the rendered file can be fetched from.
+	pub location: Option<Location>,
+}
+
 /// Enum representing various file types that could be associated with a
 /// statement's presentation.
 #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum PresentationTypeOf {
 	/// Represents any other file type not explicitly listed.
 	Other,
@@ -221,3 +381,80 @@ impl MaxEncodedLen for PresentationTypeOf {
 		1 // Since all variants are unit variants, they encode to a single byte.
 	}
 }
+
+/// The status of a statement digest as reported by the statement runtime API.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum StatementStatus {
+	/// The digest is anchored and has not been revoked.
+	Active,
+	/// The digest has been revoked.
+	Revoked,
+	/// The validity window of the statement has not started yet.
+	NotYetValid,
+	/// The validity window of the statement has ended.
+	Expired,
+	/// The statement is temporarily suspended.
+	Suspended,
+	/// The digest has been replaced by a later update of the statement.
+	Superseded,
+}
+
+/// A digest anchored against a statement, along with its current status.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct StatementEntryInfo<StatementDigestOf, StatementCreatorOf> {
+	/// The anchored digest.
+	pub digest: StatementDigestOf,
+	/// The DID identifier of the party that anchored the digest.
+	pub creator: StatementCreatorOf,
+	/// The status of the digest.
+	pub status: StatementStatus,
+}
+
+/// A presentation attached to a statement, keyed by its own digest.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct StatementPresentationInfo<StatementDigestOf, StatementCreatorOf> {
+	/// The digest of the presentation.
+	pub presentation_digest: StatementDigestOf,
+	/// The DID identifier of the party that added the presentation.
+	pub creator: StatementCreatorOf,
+	/// Type of the presentation media.
+	pub presentation_type: PresentationTypeOf,
+	/// The statement digest the presentation refers to.
+	pub digest: StatementDigestOf,
+	/// The metadata describing the presentation, if any.
+	pub metadata: Option<
+		PresentationMetadata<Vec<u8>, Vec<u8>, PresentationLocation<StatementDigestOf, Vec<u8>>>,
+	>,
+}
+
+/// `StatementInfo` gathers everything a verifier needs to know about a
+/// statement: its latest state, every digest anchored against it with the
+/// respective status, and the attached presentations.
+///
+/// It is assembled from the `Statements`, `Entries`, `RevocationList`,
+/// `Suspensions` and `Presentations` storage items so that a credential can be
+/// verified with a single runtime API call.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct StatementInfo<StatementIdOf, StatementDigestOf, StatementCreatorOf, BlockNumber> {
+	/// The statement identifier.
+	pub identifier: StatementIdOf,
+	/// The latest digest of the statement.
+	pub digest: StatementDigestOf,
+	/// Identifier for the space associated with the statement.
+	pub space: StatementIdOf,
+	/// Optional identifier for the schema of the statement.
+	pub schema: Option<StatementIdOf>,
+	/// The status of the latest digest.
+	pub status: StatementStatus,
+	/// Optional validity window of the latest digest.
+	pub validity: Option<StatementValidity<BlockNumber>>,
+	/// The suspension in place on the statement, if any.
+	pub suspension:
+		Option<StatementSuspension<SuspensionReasonOf, StatementCreatorOf, BlockNumber>>,
+	/// All digests anchored against the statement.
+	pub entries: Vec<StatementEntryInfo<StatementDigestOf, StatementCreatorOf>>,
+	/// All presentations attached to the statement.
+	pub presentations: Vec<StatementPresentationInfo<StatementDigestOf, StatementCreatorOf>>,
+}
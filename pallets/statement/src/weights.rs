@@ -57,6 +57,13 @@ pub trait WeightInfo {
 	fn register_batch(l: u32, ) -> Weight;
 	fn add_presentation() -> Weight;
 	fn remove_presentation() -> Weight;
+	fn set_revocation_policy(n: u32, ) -> Weight;
+	fn remove_revocation_policy() -> Weight;
+	fn approve_revocation() -> Weight;
+	fn cancel_revocation() -> Weight;
+	fn suspend() -> Weight;
+	fn unsuspend() -> Weight;
+	fn transfer_space(n: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_statement` using the CORD node and recommended hardware.
@@ -68,20 +75,25 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::Statements` (r:1 w:1)
 	/// Proof: `Statement::Statements` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::IdentifierLookup` (r:0 w:1)
 	/// Proof: `Statement::IdentifierLookup` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::Entries` (r:0 w:1)
 	/// Proof: `Statement::Entries` (`max_values`: None, `max_size`: Some(138), added: 2613, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::ValidityWindows` (r:1 w:1)
+	/// Proof: `Statement::ValidityWindows` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Expirations` (r:1 w:1)
+	/// Proof: `Statement::Expirations` (`max_values`: None, `max_size`: Some(49016), added: 51491, mode: `MaxEncodedLen`)
 	fn register() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `757`
-		//  Estimated: `3671`
-		// Minimum execution time: 41_811_000 picoseconds.
-		Weight::from_parts(42_809_000, 3671)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+		//  Estimated: `57807`
+		// Estimated with both validity bounds set and full expiry buckets.
+		Weight::from_parts(57_809_000, 57807)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -93,18 +105,27 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Statement::RevocationList` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::Entries` (r:1 w:1)
 	/// Proof: `Statement::Entries` (`max_values`: None, `max_size`: Some(138), added: 2613, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::RevocationPolicies` (r:1 w:0)
+	/// Proof: `Statement::RevocationPolicies` (`max_values`: None, `max_size`: Some(872), added: 3347, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Suspensions` (r:1 w:0)
+	/// Proof: `Statement::Suspensions` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::IdentifierLookup` (r:0 w:1)
 	/// Proof: `Statement::IdentifierLookup` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::ValidityWindows` (r:1 w:1)
+	/// Proof: `Statement::ValidityWindows` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Expirations` (r:2 w:2)
+	/// Proof: `Statement::Expirations` (`max_values`: None, `max_size`: Some(49016), added: 51491, mode: `MaxEncodedLen`)
 	fn update() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1158`
-		//  Estimated: `3671`
-		// Minimum execution time: 50_250_000 picoseconds.
-		Weight::from_parts(53_920_000, 3671)
-			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+		//  Estimated: `109298`
+		// Estimated with both validity bounds set and full expiry buckets.
+		Weight::from_parts(83_920_000, 109298)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -114,16 +135,19 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Statement::Statements` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::RevocationList` (r:1 w:1)
 	/// Proof: `Statement::RevocationList` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::RevocationPolicies` (r:1 w:0)
+	/// Proof: `Statement::RevocationPolicies` (`max_values`: None, `max_size`: Some(872), added: 3347, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	fn revoke() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1031`
-		//  Estimated: `3671`
-		// Minimum execution time: 40_320_000 picoseconds.
-		Weight::from_parts(41_710_000, 3671)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+		//  Estimated: `16533`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(41_710_000, 16533)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -133,16 +157,19 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Statement::Statements` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::RevocationList` (r:1 w:1)
 	/// Proof: `Statement::RevocationList` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::RevocationPolicies` (r:1 w:0)
+	/// Proof: `Statement::RevocationPolicies` (`max_values`: None, `max_size`: Some(872), added: 3347, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	fn restore() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1199`
-		//  Estimated: `3671`
-		// Minimum execution time: 41_480_000 picoseconds.
-		Weight::from_parts(42_729_000, 3671)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+		//  Estimated: `16533`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(42_729_000, 16533)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -150,21 +177,24 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::Statements` (r:1 w:1)
 	/// Proof: `Statement::Statements` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::RevocationPolicies` (r:1 w:0)
+	/// Proof: `Statement::RevocationPolicies` (`max_values`: None, `max_size`: Some(872), added: 3347, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::Entries` (r:2 w:1)
 	/// Proof: `Statement::Entries` (`max_values`: None, `max_size`: Some(138), added: 2613, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::IdentifierLookup` (r:0 w:1)
 	/// Proof: `Statement::IdentifierLookup` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
 	/// The range of component `l` is `[1, 5120]`.
 	fn remove(_l: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1200`
-		//  Estimated: `6216`
-		// Minimum execution time: 65_530_000 picoseconds.
-		Weight::from_parts(68_143_442, 6216)
-			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+		//  Estimated: `19145`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(68_143_442, 19145)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -172,8 +202,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::Statements` (r:3 w:3)
 	/// Proof: `Statement::Statements` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:3 w:3)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:3 w:3)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:3)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::IdentifierLookup` (r:0 w:3)
 	/// Proof: `Statement::IdentifierLookup` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::Entries` (r:0 w:3)
@@ -181,12 +213,11 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// The range of component `l` is `[1, 5120]`.
 	fn register_batch(_l: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `757`
-		//  Estimated: `9012`
-		// Minimum execution time: 75_360_000 picoseconds.
-		Weight::from_parts(78_226_686, 9012)
+		//  Estimated: `21036`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(78_226_686, 21036)
 			.saturating_add(T::DbWeight::get().reads(8_u64))
-			.saturating_add(T::DbWeight::get().writes(13_u64))
+			.saturating_add(T::DbWeight::get().writes(16_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -198,18 +229,21 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Statement::RevocationList` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::Presentations` (r:1 w:1)
 	/// Proof: `Statement::Presentations` (`max_values`: None, `max_size`: Some(221), added: 2696, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::PresentationsMetadata` (r:0 w:1)
+	/// Proof: `Statement::PresentationsMetadata` (`max_values`: None, `max_size`: Some(917), added: 3392, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::IdentifierLookup` (r:0 w:1)
 	/// Proof: `Statement::IdentifierLookup` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
 	fn add_presentation() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1031`
-		//  Estimated: `3686`
-		// Minimum execution time: 44_760_000 picoseconds.
-		Weight::from_parts(45_750_000, 3686)
+		//  Estimated: `15882`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(45_750_000, 15882)
 			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -217,18 +251,186 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::Presentations` (r:1 w:1)
 	/// Proof: `Statement::Presentations` (`max_values`: None, `max_size`: Some(221), added: 2696, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::PresentationsMetadata` (r:0 w:1)
+	/// Proof: `Statement::PresentationsMetadata` (`max_values`: None, `max_size`: Some(917), added: 3392, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::IdentifierLookup` (r:0 w:1)
 	/// Proof: `Statement::IdentifierLookup` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
 	fn remove_presentation() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1145`
-		//  Estimated: `3686`
-		// Minimum execution time: 42_580_000 picoseconds.
-		Weight::from_parts(43_370_000, 3686)
+		//  Estimated: `10594`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(43_370_000, 10594)
 			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
+	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Delegates` (r:1 w:0)
+	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::RevocationPolicies` (r:0 w:1)
+	/// Proof: `Statement::RevocationPolicies` (`max_values`: None, `max_size`: Some(872), added: 3347, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 25]`.
+	fn set_revocation_policy(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `327883`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(33_410_000, 327883)
+			.saturating_add(Weight::from_parts(612_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
+	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::RevocationPolicies` (r:1 w:1)
+	/// Proof: `Statement::RevocationPolicies` (`max_values`: None, `max_size`: Some(872), added: 3347, mode: `MaxEncodedLen`)
+	fn remove_revocation_policy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `8687`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(32_160_000, 8687)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Statement::RevocationProposals` (r:1 w:1)
+	/// Proof: `Statement::RevocationProposals` (`max_values`: None, `max_size`: Some(948), added: 3423, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
+	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Statements` (r:1 w:1)
+	/// Proof: `Statement::Statements` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::RevocationPolicies` (r:1 w:0)
+	/// Proof: `Statement::RevocationPolicies` (`max_values`: None, `max_size`: Some(872), added: 3347, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::RevocationProposalExpirations` (r:1 w:1)
+	/// Proof: `Statement::RevocationProposalExpirations` (`max_values`: None, `max_size`: Some(49016), added: 51491, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::RevocationList` (r:1 w:1)
+	/// Proof: `Statement::RevocationList` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Entries` (r:1 w:1)
+	/// Proof: `Statement::Entries` (`max_values`: None, `max_size`: Some(138), added: 2613, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::ValidityWindows` (r:1 w:1)
+	/// Proof: `Statement::ValidityWindows` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Expirations` (r:1 w:1)
+	/// Proof: `Statement::Expirations` (`max_values`: None, `max_size`: Some(49016), added: 51491, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Suspensions` (r:1 w:0)
+	/// Proof: `Statement::Suspensions` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::IdentifierLookup` (r:0 w:1)
+	/// Proof: `Statement::IdentifierLookup` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
+	fn approve_revocation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `130685`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(78_640_000, 130685)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
+	}
+	/// Storage: `Statement::RevocationProposals` (r:1 w:1)
+	/// Proof: `Statement::RevocationProposals` (`max_values`: None, `max_size`: Some(948), added: 3423, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
+	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Statements` (r:1 w:0)
+	/// Proof: `Statement::Statements` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::RevocationProposalExpirations` (r:1 w:1)
+	/// Proof: `Statement::RevocationProposalExpirations` (`max_values`: None, `max_size`: Some(49016), added: 51491, mode: `MaxEncodedLen`)
+	fn cancel_revocation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `62928`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(41_260_000, 62928)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
+	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Statements` (r:1 w:0)
+	/// Proof: `Statement::Statements` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::RevocationList` (r:1 w:0)
+	/// Proof: `Statement::RevocationList` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Suspensions` (r:1 w:1)
+	/// Proof: `Statement::Suspensions` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::SuspensionLifts` (r:1 w:1)
+	/// Proof: `Statement::SuspensionLifts` (`max_values`: None, `max_size`: Some(49016), added: 51491, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn suspend() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `67250`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(44_870_000, 67250)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
+	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Statements` (r:1 w:0)
+	/// Proof: `Statement::Statements` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Suspensions` (r:1 w:1)
+	/// Proof: `Statement::Suspensions` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::SuspensionLifts` (r:1 w:1)
+	/// Proof: `Statement::SuspensionLifts` (`max_values`: None, `max_size`: Some(49016), added: 51491, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn unsuspend() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `64636`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(42_350_000, 64636)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `ChainSpace::Authorizations` (r:2 w:0)
+	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Spaces` (r:2 w:2)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Statements` (r:1 w:1)
+	/// Proof: `Statement::Statements` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::RevocationPolicies` (r:1 w:0)
+	/// Proof: `Statement::RevocationPolicies` (`max_values`: None, `max_size`: Some(872), added: 3347, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::RevocationProposals` (r:1 w:0)
+	/// Proof: `Statement::RevocationProposals` (`max_values`: None, `max_size`: Some(948), added: 3423, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Entries` (r:2 w:0)
+	/// Proof: `Statement::Entries` (`max_values`: None, `max_size`: Some(138), added: 2613, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Presentations` (r:1000 w:999)
+	/// Proof: `Statement::Presentations` (`max_values`: None, `max_size`: Some(221), added: 2696, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::IdentifierLookup` (r:1000 w:2000)
+	/// Proof: `Statement::IdentifierLookup` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 1000]`.
+	fn transfer_space(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `10983 + n * (2696 ±0)`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(73_910_000, 10983)
+			.saturating_add(Weight::from_parts(14_326_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2696).saturating_mul(n.into()))
 	}
 }
 
@@ -240,20 +442,25 @@ impl WeightInfo for () {
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::Statements` (r:1 w:1)
 	/// Proof: `Statement::Statements` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::IdentifierLookup` (r:0 w:1)
 	/// Proof: `Statement::IdentifierLookup` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::Entries` (r:0 w:1)
 	/// Proof: `Statement::Entries` (`max_values`: None, `max_size`: Some(138), added: 2613, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::ValidityWindows` (r:1 w:1)
+	/// Proof: `Statement::ValidityWindows` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Expirations` (r:1 w:1)
+	/// Proof: `Statement::Expirations` (`max_values`: None, `max_size`: Some(49016), added: 51491, mode: `MaxEncodedLen`)
 	fn register() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `757`
-		//  Estimated: `3671`
-		// Minimum execution time: 41_811_000 picoseconds.
-		Weight::from_parts(42_809_000, 3671)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+		//  Estimated: `57807`
+		// Estimated with both validity bounds set and full expiry buckets.
+		Weight::from_parts(57_809_000, 57807)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -265,18 +472,27 @@ impl WeightInfo for () {
 	/// Proof: `Statement::RevocationList` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::Entries` (r:1 w:1)
 	/// Proof: `Statement::Entries` (`max_values`: None, `max_size`: Some(138), added: 2613, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::RevocationPolicies` (r:1 w:0)
+	/// Proof: `Statement::RevocationPolicies` (`max_values`: None, `max_size`: Some(872), added: 3347, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Suspensions` (r:1 w:0)
+	/// Proof: `Statement::Suspensions` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::IdentifierLookup` (r:0 w:1)
 	/// Proof: `Statement::IdentifierLookup` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::ValidityWindows` (r:1 w:1)
+	/// Proof: `Statement::ValidityWindows` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Expirations` (r:2 w:2)
+	/// Proof: `Statement::Expirations` (`max_values`: None, `max_size`: Some(49016), added: 51491, mode: `MaxEncodedLen`)
 	fn update() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1158`
-		//  Estimated: `3671`
-		// Minimum execution time: 50_250_000 picoseconds.
-		Weight::from_parts(53_920_000, 3671)
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+		//  Estimated: `109298`
+		// Estimated with both validity bounds set and full expiry buckets.
+		Weight::from_parts(83_920_000, 109298)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -286,16 +502,19 @@ impl WeightInfo for () {
 	/// Proof: `Statement::Statements` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::RevocationList` (r:1 w:1)
 	/// Proof: `Statement::RevocationList` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::RevocationPolicies` (r:1 w:0)
+	/// Proof: `Statement::RevocationPolicies` (`max_values`: None, `max_size`: Some(872), added: 3347, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	fn revoke() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1031`
-		//  Estimated: `3671`
-		// Minimum execution time: 40_320_000 picoseconds.
-		Weight::from_parts(41_710_000, 3671)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		//  Estimated: `16533`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(41_710_000, 16533)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -305,16 +524,19 @@ impl WeightInfo for () {
 	/// Proof: `Statement::Statements` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::RevocationList` (r:1 w:1)
 	/// Proof: `Statement::RevocationList` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::RevocationPolicies` (r:1 w:0)
+	/// Proof: `Statement::RevocationPolicies` (`max_values`: None, `max_size`: Some(872), added: 3347, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	fn restore() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1199`
-		//  Estimated: `3671`
-		// Minimum execution time: 41_480_000 picoseconds.
-		Weight::from_parts(42_729_000, 3671)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		//  Estimated: `16533`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(42_729_000, 16533)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -322,21 +544,24 @@ impl WeightInfo for () {
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::Statements` (r:1 w:1)
 	/// Proof: `Statement::Statements` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::RevocationPolicies` (r:1 w:0)
+	/// Proof: `Statement::RevocationPolicies` (`max_values`: None, `max_size`: Some(872), added: 3347, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::Entries` (r:2 w:1)
 	/// Proof: `Statement::Entries` (`max_values`: None, `max_size`: Some(138), added: 2613, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::IdentifierLookup` (r:0 w:1)
 	/// Proof: `Statement::IdentifierLookup` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
 	/// The range of component `l` is `[1, 5120]`.
 	fn remove(_l: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1200`
-		//  Estimated: `6216`
-		// Minimum execution time: 65_530_000 picoseconds.
-		Weight::from_parts(68_143_442, 6216)
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+		//  Estimated: `19145`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(68_143_442, 19145)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -344,8 +569,10 @@ impl WeightInfo for () {
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::Statements` (r:3 w:3)
 	/// Proof: `Statement::Statements` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:3 w:3)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:3 w:3)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:3)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::IdentifierLookup` (r:0 w:3)
 	/// Proof: `Statement::IdentifierLookup` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::Entries` (r:0 w:3)
@@ -353,12 +580,11 @@ impl WeightInfo for () {
 	/// The range of component `l` is `[1, 5120]`.
 	fn register_batch(_l: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `757`
-		//  Estimated: `9012`
-		// Minimum execution time: 75_360_000 picoseconds.
-		Weight::from_parts(78_226_686, 9012)
+		//  Estimated: `21036`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(78_226_686, 21036)
 			.saturating_add(RocksDbWeight::get().reads(8_u64))
-			.saturating_add(RocksDbWeight::get().writes(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(16_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -370,18 +596,21 @@ impl WeightInfo for () {
 	/// Proof: `Statement::RevocationList` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::Presentations` (r:1 w:1)
 	/// Proof: `Statement::Presentations` (`max_values`: None, `max_size`: Some(221), added: 2696, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::PresentationsMetadata` (r:0 w:1)
+	/// Proof: `Statement::PresentationsMetadata` (`max_values`: None, `max_size`: Some(917), added: 3392, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::IdentifierLookup` (r:0 w:1)
 	/// Proof: `Statement::IdentifierLookup` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
 	fn add_presentation() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1031`
-		//  Estimated: `3686`
-		// Minimum execution time: 44_760_000 picoseconds.
-		Weight::from_parts(45_750_000, 3686)
+		//  Estimated: `15882`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(45_750_000, 15882)
 			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -389,17 +618,185 @@ impl WeightInfo for () {
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::Presentations` (r:1 w:1)
 	/// Proof: `Statement::Presentations` (`max_values`: None, `max_size`: Some(221), added: 2696, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::PresentationsMetadata` (r:0 w:1)
+	/// Proof: `Statement::PresentationsMetadata` (`max_values`: None, `max_size`: Some(917), added: 3392, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::IdentifierLookup` (r:0 w:1)
 	/// Proof: `Statement::IdentifierLookup` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
 	fn remove_presentation() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1145`
-		//  Estimated: `3686`
-		// Minimum execution time: 42_580_000 picoseconds.
-		Weight::from_parts(43_370_000, 3686)
+		//  Estimated: `10594`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(43_370_000, 10594)
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
+	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Delegates` (r:1 w:0)
+	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::RevocationPolicies` (r:0 w:1)
+	/// Proof: `Statement::RevocationPolicies` (`max_values`: None, `max_size`: Some(872), added: 3347, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 25]`.
+	fn set_revocation_policy(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `327883`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(33_410_000, 327883)
+			.saturating_add(Weight::from_parts(612_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
+	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::RevocationPolicies` (r:1 w:1)
+	/// Proof: `Statement::RevocationPolicies` (`max_values`: None, `max_size`: Some(872), added: 3347, mode: `MaxEncodedLen`)
+	fn remove_revocation_policy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `8687`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(32_160_000, 8687)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Statement::RevocationProposals` (r:1 w:1)
+	/// Proof: `Statement::RevocationProposals` (`max_values`: None, `max_size`: Some(948), added: 3423, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
+	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Statements` (r:1 w:1)
+	/// Proof: `Statement::Statements` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::RevocationPolicies` (r:1 w:0)
+	/// Proof: `Statement::RevocationPolicies` (`max_values`: None, `max_size`: Some(872), added: 3347, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::RevocationProposalExpirations` (r:1 w:1)
+	/// Proof: `Statement::RevocationProposalExpirations` (`max_values`: None, `max_size`: Some(49016), added: 51491, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::RevocationList` (r:1 w:1)
+	/// Proof: `Statement::RevocationList` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Entries` (r:1 w:1)
+	/// Proof: `Statement::Entries` (`max_values`: None, `max_size`: Some(138), added: 2613, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::ValidityWindows` (r:1 w:1)
+	/// Proof: `Statement::ValidityWindows` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Expirations` (r:1 w:1)
+	/// Proof: `Statement::Expirations` (`max_values`: None, `max_size`: Some(49016), added: 51491, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Suspensions` (r:1 w:0)
+	/// Proof: `Statement::Suspensions` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::IdentifierLookup` (r:0 w:1)
+	/// Proof: `Statement::IdentifierLookup` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
+	fn approve_revocation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `130685`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(78_640_000, 130685)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+	}
+	/// Storage: `Statement::RevocationProposals` (r:1 w:1)
+	/// Proof: `Statement::RevocationProposals` (`max_values`: None, `max_size`: Some(948), added: 3423, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
+	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Statements` (r:1 w:0)
+	/// Proof: `Statement::Statements` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::RevocationProposalExpirations` (r:1 w:1)
+	/// Proof: `Statement::RevocationProposalExpirations` (`max_values`: None, `max_size`: Some(49016), added: 51491, mode: `MaxEncodedLen`)
+	fn cancel_revocation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `62928`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(41_260_000, 62928)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
+	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Statements` (r:1 w:0)
+	/// Proof: `Statement::Statements` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::RevocationList` (r:1 w:0)
+	/// Proof: `Statement::RevocationList` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Suspensions` (r:1 w:1)
+	/// Proof: `Statement::Suspensions` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::SuspensionLifts` (r:1 w:1)
+	/// Proof: `Statement::SuspensionLifts` (`max_values`: None, `max_size`: Some(49016), added: 51491, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn suspend() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `67250`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(44_870_000, 67250)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
+	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Statements` (r:1 w:0)
+	/// Proof: `Statement::Statements` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Suspensions` (r:1 w:1)
+	/// Proof: `Statement::Suspensions` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::SuspensionLifts` (r:1 w:1)
+	/// Proof: `Statement::SuspensionLifts` (`max_values`: None, `max_size`: Some(49016), added: 51491, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn unsuspend() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `64636`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(42_350_000, 64636)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `ChainSpace::Authorizations` (r:2 w:0)
+	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Spaces` (r:2 w:2)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Statements` (r:1 w:1)
+	/// Proof: `Statement::Statements` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::RevocationPolicies` (r:1 w:0)
+	/// Proof: `Statement::RevocationPolicies` (`max_values`: None, `max_size`: Some(872), added: 3347, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::RevocationProposals` (r:1 w:0)
+	/// Proof: `Statement::RevocationProposals` (`max_values`: None, `max_size`: Some(948), added: 3423, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Entries` (r:2 w:0)
+	/// Proof: `Statement::Entries` (`max_values`: None, `max_size`: Some(138), added: 2613, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Presentations` (r:1000 w:999)
+	/// Proof: `Statement::Presentations` (`max_values`: None, `max_size`: Some(221), added: 2696, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::IdentifierLookup` (r:1000 w:2000)
+	/// Proof: `Statement::IdentifierLookup` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 1000]`.
+	fn transfer_space(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `10983 + n * (2696 ±0)`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(73_910_000, 10983)
+			.saturating_add(Weight::from_parts(14_326_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2696).saturating_mul(n.into()))
 	}
 }
@@ -283,3 +283,18 @@ impl AsRef<[u8]> for Ss58Identifier {
 		&self.0[..]
 	}
 }
+
+impl TryFrom<Vec<u8>> for Ss58Identifier {
+	type Error = IdentifierError;
+
+	/// Builds an identifier from its SS58 string representation, ensuring the
+	/// bytes carry a known CORD identifier type.
+	fn try_from(value: Vec<u8>) -> Result<Self, Self::Error> {
+		let identifier = Ss58Identifier(
+			BoundedVec::try_from(value).map_err(|_| IdentifierError::InvalidIdentifierLength)?,
+		);
+		identifier.get_type()?;
+
+		Ok(identifier)
+	}
+}
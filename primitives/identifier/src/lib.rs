@@ -26,15 +26,14 @@ pub use crate::curi::{
 	CordIdentifierType, IdentifierCreator, IdentifierError, IdentifierTimeline, IdentifierType,
 	Ss58Identifier,
 };
-use sp_runtime::BoundedVec;
 use sp_std::{prelude::Clone, str};
+pub mod migrations;
 pub mod types;
 pub use crate::types::*;
-use frame_support::traits::Get;
 use frame_system::pallet_prelude::BlockNumberFor;
 
 pub use crate::pallet::*;
-use sp_std::vec;
+use sp_std::{vec, vec::Vec};
 
 #[cfg(any(feature = "mock", test))]
 pub mod mock;
@@ -51,7 +50,10 @@ pub mod pallet {
 	};
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
+	/// The maximum number of timeline entries returned in a single page.
+	pub const MAX_TIMELINE_PAGE_SIZE: u32 = 1_000;
 
 	/// Identifier
 	pub type IdentifierOf = Ss58Identifier;
@@ -61,13 +63,11 @@ pub mod pallet {
 	pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 	/// Timeline Events.
 	pub type EventEntryOf = EventEntry<CallTypeOf>;
+	/// A page of timeline events.
+	pub type TimelinePageOf = TimelinePage<EventEntryOf>;
 
 	#[pallet::config]
-	pub trait Config: frame_system::Config {
-		/// The maximum number of activity for a statement.
-		#[pallet::constant]
-		type MaxEventsHistory: Get<u32>;
-	}
+	pub trait Config: frame_system::Config {}
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -76,14 +76,31 @@ pub mod pallet {
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
 
+	/// Number of timeline entries recorded for an identifier.
+	/// It also serves as the index of the next entry to be appended.
 	#[pallet::storage]
-	pub type Identifiers<T: Config> = StorageDoubleMap<
+	pub type TimelineLength<T: Config> = StorageDoubleMap<
 		_,
 		Blake2_128Concat,
 		IdentifierOf,
 		Twox64Concat,
 		IdentifierTypeOf,
-		BoundedVec<EventEntryOf, T::MaxEventsHistory>,
+		u64,
+		ValueQuery,
+	>;
+
+	/// Append-only timeline of an identifier.
+	/// It maps from an identifier, its type and the entry index to the
+	/// recorded event.
+	#[pallet::storage]
+	pub type Timeline<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, IdentifierOf>,
+			NMapKey<Twox64Concat, IdentifierTypeOf>,
+			NMapKey<Twox64Concat, u64>,
+		),
+		EventEntryOf,
 		OptionQuery,
 	>;
 
@@ -106,15 +123,43 @@ impl<T: Config> IdentifierUpdate<IdentifierOf, IdentifierTypeOf, EventEntryOf, I
 		id_type: IdentifierTypeOf,
 		entry: EventEntryOf,
 	) -> Result<(), IdentifierError> {
-		Identifiers::<T>::try_mutate(id, id_type, |timeline| {
-			let events = timeline.get_or_insert_with(BoundedVec::default);
-
-			if events.len() == T::MaxEventsHistory::get() as usize {
-				events.remove(1);
-			}
-
-			events.try_push(entry).map_err(|_| IdentifierError::MaxEventsHistoryExceeded)
+		TimelineLength::<T>::try_mutate(id, id_type, |length| {
+			let index = *length;
+			*length = index.checked_add(1).ok_or(IdentifierError::MaxEventsHistoryExceeded)?;
+			Timeline::<T>::insert((id, id_type, index), entry);
+			Ok(())
 		})
-		.map_err(|_| IdentifierError::MaxEventsHistoryExceeded) // Map DispatchError to your custom Error
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Returns a page of the timeline of an identifier, oldest entry first.
+	///
+	/// # Parameters
+	/// - `id`: The identifier whose timeline is requested.
+	/// - `id_type`: The type of the identifier.
+	/// - `cursor`: The index of the first entry to return. `None` starts from the oldest entry.
+	/// - `limit`: The maximum number of entries to return, capped at `MAX_TIMELINE_PAGE_SIZE`.
+	///
+	/// # Returns
+	/// A `TimelinePageOf` holding the entries with their indices, the total
+	/// number of recorded entries and the cursor of the next page, if any.
+	pub fn timeline_page(
+		id: &IdentifierOf,
+		id_type: IdentifierTypeOf,
+		cursor: Option<u64>,
+		limit: u32,
+	) -> TimelinePageOf {
+		let total = TimelineLength::<T>::get(id, id_type);
+		let start = cursor.unwrap_or_default().min(total);
+		let end = start.saturating_add(limit.min(MAX_TIMELINE_PAGE_SIZE) as u64).min(total);
+
+		let entries: Vec<(u64, EventEntryOf)> = (start..end)
+			.filter_map(|index| {
+				Timeline::<T>::get((id, id_type, index)).map(|entry| (index, entry))
+			})
+			.collect();
+
+		TimelinePage { entries, total, next: if end < total { Some(end) } else { None } }
 	}
 }
@@ -0,0 +1,157 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Storage migrations for the identifier pallet.
+
+use crate::*;
+
+pub mod v2 {
+	use super::*;
+	use frame_support::{
+		migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+		pallet_prelude::*,
+		storage_alias,
+		weights::WeightMeter,
+		Blake2_128Concat, Twox64Concat,
+	};
+	use sp_std::marker::PhantomData;
+
+	/// The identifier of the timeline migration in the multi-block migrator.
+	const MIGRATION_ID: &[u8; 15] = b"cord-identifier";
+
+	/// The maximum number of events moved from a legacy timeline in one step.
+	pub const MAX_EVENTS_PER_STEP: u64 = 100;
+
+	/// The estimated time to decode and re-encode one byte of a legacy
+	/// timeline, pending benchmarking.
+	const REF_TIME_PER_TIMELINE_BYTE: u64 = 1_000;
+
+	/// The bounded timeline stored by version 1 of the pallet. The bound is
+	/// not part of the encoding, so the entries are decoded as a plain vector.
+	#[storage_alias]
+	pub type Identifiers<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		IdentifierOf,
+		Twox64Concat,
+		IdentifierTypeOf,
+		Vec<EventEntryOf>,
+		OptionQuery,
+	>;
+
+	/// Moves every bounded timeline from `Identifiers` into the append-only
+	/// `Timeline` storage, keeping the order of the recorded events.
+	///
+	/// The migration runs over several blocks. Each step takes at most
+	/// `MAX_EVENTS_PER_STEP` events out of a single legacy timeline and
+	/// writes the remaining events back, so that each step only decodes the
+	/// events that are still to be moved. Steps are weighed by the length
+	/// of the legacy timeline they read. The cursor
+	/// records the timeline being moved and the number of events already
+	/// moved. A legacy timeline is removed once all of its events have been
+	/// moved, and the storage version is set to 2 once none are left.
+	pub struct MigrateToPagedTimeline<T>(PhantomData<T>);
+
+	impl<T: Config> MigrateToPagedTimeline<T> {
+		/// The weight of a step over a legacy timeline of `length` events,
+		/// which is read, decoded and written back as a whole.
+		pub(crate) fn step_weight(length: u64) -> Weight {
+			let timeline_size = length.saturating_mul(EventEntryOf::max_encoded_len() as u64);
+			T::DbWeight::get()
+				.reads_writes(2, MAX_EVENTS_PER_STEP.saturating_add(3))
+				.saturating_add(Weight::from_parts(
+					timeline_size.saturating_mul(REF_TIME_PER_TIMELINE_BYTE),
+					timeline_size,
+				))
+		}
+
+		/// Records that every legacy timeline has been moved.
+		fn finish() {
+			StorageVersion::new(2).put::<Pallet<T>>();
+			log::info!(target: "runtime::identifier", "migrated timelines to version 2");
+		}
+
+		/// The first legacy timeline that is still to be moved.
+		fn next_timeline() -> Option<(IdentifierOf, IdentifierTypeOf, u64)> {
+			Identifiers::<T>::iter_keys().next().map(|(id, id_type)| (id, id_type, 0))
+		}
+	}
+
+	impl<T: Config> SteppedMigration for MigrateToPagedTimeline<T> {
+		type Cursor = (IdentifierOf, IdentifierTypeOf, u64);
+		type Identifier = MigrationId<15>;
+
+		fn id() -> Self::Identifier {
+			MigrationId { pallet_id: *MIGRATION_ID, version_from: 1, version_to: 2 }
+		}
+
+		fn step(
+			mut cursor: Option<Self::Cursor>,
+			meter: &mut WeightMeter,
+		) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+			let required = Self::step_weight(0);
+			if meter.remaining().any_lt(required) {
+				return Err(SteppedMigrationError::InsufficientWeight { required });
+			}
+
+			if Pallet::<T>::on_chain_storage_version() != 1 {
+				log::info!(
+					target: "runtime::identifier",
+					"skipping timeline migration, on-chain storage version is {:?}",
+					Pallet::<T>::on_chain_storage_version()
+				);
+				return Ok(None);
+			}
+
+			let mut stepped = false;
+			loop {
+				let Some((id, id_type, moved)) = cursor.take().or_else(Self::next_timeline) else {
+					Self::finish();
+					return Ok(None);
+				};
+
+				let length = Identifiers::<T>::decode_len(&id, id_type).unwrap_or_default() as u64;
+				let required = Self::step_weight(length);
+				if meter.try_consume(required).is_err() {
+					if !stepped {
+						return Err(SteppedMigrationError::InsufficientWeight { required });
+					}
+					return Ok(Some((id, id_type, moved)));
+				}
+				stepped = true;
+
+				let mut events = Identifiers::<T>::get(&id, id_type).unwrap_or_default();
+				let chunk = events.len().min(MAX_EVENTS_PER_STEP as usize);
+
+				let mut index = moved;
+				for entry in events.drain(..chunk) {
+					Timeline::<T>::insert((&id, id_type, index), entry);
+					index = index.saturating_add(1);
+				}
+
+				if events.is_empty() {
+					TimelineLength::<T>::insert(&id, id_type, index);
+					Identifiers::<T>::remove(&id, id_type);
+				} else {
+					Identifiers::<T>::insert(&id, id_type, events);
+					cursor = Some((id, id_type, index));
+				}
+			}
+		}
+	}
+}
@@ -24,3 +24,218 @@ fn creating_a_invalid_identifier_length_should_fail() {
 		));
 	});
 }
+
+#[test]
+fn identifier_roundtrip_from_bytes_should_work() {
+	let space = [2u8; 30].to_vec();
+
+	new_test_ext().execute_with(|| {
+		let identifier =
+			Ss58Identifier::create_identifier(&(space).encode()[..], IdentifierType::Space)
+				.unwrap();
+
+		assert_eq!(Ss58Identifier::try_from(identifier.inner().to_vec()), Ok(identifier));
+		assert_err!(
+			Ss58Identifier::try_from(b"not-an-identifier".to_vec()),
+			IdentifierError::InvalidIdentifier
+		);
+	});
+}
+
+fn timeline_entry(height: u32) -> EventEntryOf {
+	EventEntry { action: CallTypeOf::Update, location: Timepoint { height, index: 0 } }
+}
+
+#[test]
+fn timeline_should_keep_every_entry_and_page_by_cursor() {
+	let space = [2u8; 30].to_vec();
+
+	new_test_ext().execute_with(|| {
+		let identifier =
+			Ss58Identifier::create_identifier(&(space).encode()[..], IdentifierType::Space)
+				.unwrap();
+
+		for height in 1..=5 {
+			assert_ok!(IdentifierTimeline::update_timeline::<Test>(
+				&identifier,
+				IdentifierTypeOf::ChainSpace,
+				timeline_entry(height),
+			));
+		}
+
+		let first = Identifier::timeline_page(&identifier, IdentifierTypeOf::ChainSpace, None, 2);
+		assert_eq!(first.total, 5);
+		assert_eq!(first.entries, vec![(0, timeline_entry(1)), (1, timeline_entry(2))]);
+		assert_eq!(first.next, Some(2));
+
+		let last = Identifier::timeline_page(&identifier, IdentifierTypeOf::ChainSpace, Some(4), 2);
+		assert_eq!(last.entries, vec![(4, timeline_entry(5))]);
+		assert_eq!(last.next, None);
+
+		let other = Identifier::timeline_page(&identifier, IdentifierTypeOf::Statement, None, 2);
+		assert_eq!(other.total, 0);
+		assert!(other.entries.is_empty());
+	});
+}
+
+#[test]
+fn migrating_bounded_timelines_should_preserve_order() {
+	use frame_support::{
+		migrations::SteppedMigration, traits::StorageVersion, weights::WeightMeter,
+	};
+
+	let space = [2u8; 30].to_vec();
+	let statement = [3u8; 30].to_vec();
+
+	new_test_ext().execute_with(|| {
+		let identifier =
+			Ss58Identifier::create_identifier(&(space).encode()[..], IdentifierType::Space)
+				.unwrap();
+		let long_identifier =
+			Ss58Identifier::create_identifier(&(statement).encode()[..], IdentifierType::Statement)
+				.unwrap();
+
+		StorageVersion::new(1).put::<Identifier>();
+		migrations::v2::Identifiers::<Test>::insert(
+			&identifier,
+			IdentifierTypeOf::ChainSpace,
+			vec![timeline_entry(1), timeline_entry(2), timeline_entry(3)],
+		);
+		/* A timeline longer than a single step */
+		let long_timeline = (1..=250).map(timeline_entry).collect::<Vec<_>>();
+		migrations::v2::Identifiers::<Test>::insert(
+			&long_identifier,
+			IdentifierTypeOf::Statement,
+			long_timeline.clone(),
+		);
+
+		let mut cursor = None;
+		loop {
+			cursor = migrations::v2::MigrateToPagedTimeline::<Test>::step(
+				cursor,
+				&mut WeightMeter::new(),
+			)
+			.unwrap();
+			if cursor.is_none() {
+				break;
+			}
+		}
+
+		assert!(migrations::v2::Identifiers::<Test>::iter().next().is_none());
+		assert_eq!(StorageVersion::get::<Identifier>(), 2);
+
+		let page = Identifier::timeline_page(&identifier, IdentifierTypeOf::ChainSpace, None, 10);
+		assert_eq!(page.total, 3);
+		assert_eq!(
+			page.entries,
+			vec![(0, timeline_entry(1)), (1, timeline_entry(2)), (2, timeline_entry(3))]
+		);
+
+		let page =
+			Identifier::timeline_page(&long_identifier, IdentifierTypeOf::Statement, None, 1_000);
+		assert_eq!(page.total, 250);
+		assert_eq!(
+			page.entries,
+			long_timeline
+				.into_iter()
+				.enumerate()
+				.map(|(i, e)| (i as u64, e))
+				.collect::<Vec<_>>()
+		);
+	});
+}
+
+#[test]
+fn resuming_the_timeline_migration_should_continue_from_the_cursor() {
+	use frame_support::{
+		migrations::SteppedMigration, traits::StorageVersion, weights::WeightMeter,
+	};
+
+	let space = [2u8; 30].to_vec();
+
+	new_test_ext().execute_with(|| {
+		let identifier =
+			Ss58Identifier::create_identifier(&(space).encode()[..], IdentifierType::Space)
+				.unwrap();
+
+		StorageVersion::new(1).put::<Identifier>();
+		let timeline = (1..=150).map(timeline_entry).collect::<Vec<_>>();
+		migrations::v2::Identifiers::<Test>::insert(
+			&identifier,
+			IdentifierTypeOf::ChainSpace,
+			timeline[100..].to_vec(),
+		);
+
+		/* A migration that has moved the first 100 events */
+		let cursor = Some((identifier.clone(), IdentifierTypeOf::ChainSpace, 100));
+		let next =
+			migrations::v2::MigrateToPagedTimeline::<Test>::step(cursor, &mut WeightMeter::new())
+				.unwrap();
+		assert_eq!(next, None);
+
+		assert_eq!(StorageVersion::get::<Identifier>(), 2);
+		assert_eq!(TimelineLength::<Test>::get(&identifier, IdentifierTypeOf::ChainSpace), 150);
+		assert!(Timeline::<Test>::get((&identifier, IdentifierTypeOf::ChainSpace, 99)).is_none());
+		assert_eq!(
+			Timeline::<Test>::get((&identifier, IdentifierTypeOf::ChainSpace, 100)),
+			Some(timeline_entry(101))
+		);
+		assert_eq!(
+			Timeline::<Test>::get((&identifier, IdentifierTypeOf::ChainSpace, 149)),
+			Some(timeline_entry(150))
+		);
+	});
+}
+
+#[test]
+fn timeline_migration_steps_should_take_events_out_of_legacy_timelines() {
+	use frame_support::{
+		migrations::{SteppedMigration, SteppedMigrationError},
+		traits::StorageVersion,
+		weights::{Weight, WeightMeter},
+	};
+
+	let statement = [3u8; 30].to_vec();
+
+	new_test_ext().execute_with(|| {
+		let identifier =
+			Ss58Identifier::create_identifier(&(statement).encode()[..], IdentifierType::Statement)
+				.unwrap();
+
+		StorageVersion::new(1).put::<Identifier>();
+		let timeline = (1..=250).map(timeline_entry).collect::<Vec<_>>();
+		migrations::v2::Identifiers::<Test>::insert(
+			&identifier,
+			IdentifierTypeOf::Statement,
+			timeline.clone(),
+		);
+
+		/* A step is weighed by the length of the legacy timeline it reads */
+		let required = migrations::v2::MigrateToPagedTimeline::<Test>::step_weight(250);
+		assert!(required.any_gt(migrations::v2::MigrateToPagedTimeline::<Test>::step_weight(0)));
+		assert_eq!(
+			migrations::v2::MigrateToPagedTimeline::<Test>::step(
+				None,
+				&mut WeightMeter::with_limit(required.saturating_sub(Weight::from_parts(1, 1))),
+			),
+			Err(SteppedMigrationError::InsufficientWeight { required })
+		);
+
+		/* The moved events are taken out of the legacy timeline */
+		let cursor = migrations::v2::MigrateToPagedTimeline::<Test>::step(
+			None,
+			&mut WeightMeter::with_limit(required),
+		)
+		.unwrap();
+		assert_eq!(cursor, Some((identifier.clone(), IdentifierTypeOf::Statement, 100)));
+		assert_eq!(
+			migrations::v2::Identifiers::<Test>::get(&identifier, IdentifierTypeOf::Statement),
+			Some(timeline[100..].to_vec())
+		);
+		assert_eq!(
+			Timeline::<Test>::get((&identifier, IdentifierTypeOf::Statement, 99)),
+			Some(timeline_entry(100))
+		);
+		assert_eq!(StorageVersion::get::<Identifier>(), 1);
+	});
+}
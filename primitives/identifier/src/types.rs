@@ -42,6 +42,17 @@ pub struct EventEntry<CallTypeOf> {
 	pub location: Timepoint,
 }
 
+/// A page of an identifier timeline, as returned by the runtime API.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct TimelinePage<EventEntryOf> {
+	/// Timeline entries of the page along with their indices.
+	pub entries: Vec<(u64, EventEntryOf)>,
+	/// Total number of entries recorded for the identifier.
+	pub total: u64,
+	/// Cursor of the next page, if there are more entries.
+	pub next: Option<u64>,
+}
+
 /// Defining the possible actions that can be performed on a identifier.
 #[derive(Clone, Copy, RuntimeDebug, Decode, Encode, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 #[repr(u8)]
@@ -68,6 +79,8 @@ pub enum CallTypeOf {
 	Credit,
 	Issue,
 	Reinstate,
+	Suspend,
+	Unsuspend,
 }
 /// Defining the identifier target types.
 #[derive(Clone, Copy, RuntimeDebug, Decode, Encode, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
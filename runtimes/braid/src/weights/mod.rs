@@ -24,15 +24,18 @@ pub mod pallet_balances;
 pub mod pallet_chain_space;
 pub mod pallet_did;
 pub mod pallet_did_name;
+pub mod pallet_entries;
 pub mod pallet_identity;
 pub mod pallet_im_online;
 pub mod pallet_indices;
 pub mod pallet_multisig;
 pub mod pallet_network_membership;
 pub mod pallet_network_score;
+pub mod pallet_registries;
 pub mod pallet_remark;
 pub mod pallet_scheduler;
 pub mod pallet_schema;
+pub mod pallet_schema_accounts;
 pub mod pallet_session;
 pub mod pallet_statement;
 pub mod pallet_sudo;
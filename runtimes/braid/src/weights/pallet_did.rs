@@ -57,21 +57,20 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 	/// Proof: `Did::DidEndpointsCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `Did::ServiceEndpoints` (r:0 w:25)
 	/// Proof: `Did::ServiceEndpoints` (`max_values`: None, `max_size`: Some(413), added: 2888, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 10]`.
 	/// The range of component `c` is `[1, 25]`.
 	fn create_ed25519_keys(n: u32, c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `42`
-		//  Estimated: `5649`
-		// Minimum execution time: 100_350_000 picoseconds.
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
 		Weight::from_parts(90_470_670, 0)
-			.saturating_add(Weight::from_parts(0, 5649))
-			// Standard Error: 31_496
+			.saturating_add(Weight::from_parts(0, 15874))
 			.saturating_add(Weight::from_parts(762_963, 0).saturating_mul(n.into()))
-			// Standard Error: 12_177
 			.saturating_add(Weight::from_parts(6_535_800, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
 	}
 	/// Storage: `Did::DidBlacklist` (r:1 w:0)
@@ -82,21 +81,20 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 	/// Proof: `Did::DidEndpointsCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `Did::ServiceEndpoints` (r:0 w:25)
 	/// Proof: `Did::ServiceEndpoints` (`max_values`: None, `max_size`: Some(413), added: 2888, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 10]`.
 	/// The range of component `c` is `[1, 25]`.
 	fn create_sr25519_keys(n: u32, c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `42`
-		//  Estimated: `5649`
-		// Minimum execution time: 90_930_000 picoseconds.
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
 		Weight::from_parts(76_083_336, 0)
-			.saturating_add(Weight::from_parts(0, 5649))
-			// Standard Error: 53_426
+			.saturating_add(Weight::from_parts(0, 15874))
 			.saturating_add(Weight::from_parts(972_036, 0).saturating_mul(n.into()))
-			// Standard Error: 20_656
 			.saturating_add(Weight::from_parts(7_551_125, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
 	}
 	/// Storage: `Did::DidBlacklist` (r:1 w:0)
@@ -107,21 +105,20 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 	/// Proof: `Did::DidEndpointsCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `Did::ServiceEndpoints` (r:0 w:25)
 	/// Proof: `Did::ServiceEndpoints` (`max_values`: None, `max_size`: Some(413), added: 2888, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 10]`.
 	/// The range of component `c` is `[1, 25]`.
 	fn create_ecdsa_keys(n: u32, c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `42`
-		//  Estimated: `5649`
-		// Minimum execution time: 87_170_000 picoseconds.
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
 		Weight::from_parts(72_224_620, 0)
-			.saturating_add(Weight::from_parts(0, 5649))
-			// Standard Error: 35_261
+			.saturating_add(Weight::from_parts(0, 15874))
 			.saturating_add(Weight::from_parts(1_193_414, 0).saturating_mul(n.into()))
-			// Standard Error: 13_633
 			.saturating_add(Weight::from_parts(6_403_591, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
 	}
 	/// Storage: `Did::DidEndpointsCount` (r:1 w:1)
@@ -132,19 +129,25 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
 	/// Storage: `Did::DidBlacklist` (r:0 w:1)
 	/// Proof: `Did::DidBlacklist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:0 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
+	/// Storage: `Did::Controllers` (r:0 w:1)
+	/// Proof: `Did::Controllers` (`max_values`: None, `max_size`: Some(369), added: 2844, mode: `MaxEncodedLen`)
+	/// Storage: `Did::RecoveryPolicies` (r:0 w:1)
+	/// Proof: `Did::RecoveryPolicies` (`max_values`: None, `max_size`: Some(377), added: 2852, mode: `MaxEncodedLen`)
+	/// Storage: `Did::RecoveryRequests` (r:0 w:1)
+	/// Proof: `Did::RecoveryRequests` (`max_values`: None, `max_size`: Some(407), added: 2882, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 25]`.
 	fn delete(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `364 + c * (105 ±0)`
 		//  Estimated: `5649 + c * (2888 ±0)`
-		// Minimum execution time: 25_210_000 picoseconds.
+		// Estimated from the storage accesses, pending benchmarking.
 		Weight::from_parts(25_383_765, 0)
 			.saturating_add(Weight::from_parts(0, 5649))
-			// Standard Error: 3_783
 			.saturating_add(Weight::from_parts(1_219_880, 0).saturating_mul(c.into()))
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().writes(7))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
 			.saturating_add(Weight::from_parts(0, 2888).saturating_mul(c.into()))
 	}
@@ -186,255 +189,276 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn set_ed25519_authentication_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1457`
-		//  Estimated: `5649`
-		// Minimum execution time: 23_320_000 picoseconds.
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
 		Weight::from_parts(24_350_000, 0)
-			.saturating_add(Weight::from_parts(0, 5649))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 15874))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn set_sr25519_authentication_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1457`
-		//  Estimated: `5649`
-		// Minimum execution time: 23_550_000 picoseconds.
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
 		Weight::from_parts(23_970_000, 0)
-			.saturating_add(Weight::from_parts(0, 5649))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 15874))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn set_ecdsa_authentication_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1460`
-		//  Estimated: `5649`
-		// Minimum execution time: 23_440_000 picoseconds.
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
 		Weight::from_parts(24_080_000, 0)
-			.saturating_add(Weight::from_parts(0, 5649))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 15874))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn set_ed25519_delegation_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1457`
-		//  Estimated: `5649`
-		// Minimum execution time: 23_060_000 picoseconds.
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
 		Weight::from_parts(23_830_000, 0)
-			.saturating_add(Weight::from_parts(0, 5649))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 15874))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn set_sr25519_delegation_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1457`
-		//  Estimated: `5649`
-		// Minimum execution time: 23_050_000 picoseconds.
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
 		Weight::from_parts(23_930_000, 0)
-			.saturating_add(Weight::from_parts(0, 5649))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 15874))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn set_ecdsa_delegation_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1460`
-		//  Estimated: `5649`
-		// Minimum execution time: 22_910_000 picoseconds.
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
 		Weight::from_parts(23_770_000, 0)
-			.saturating_add(Weight::from_parts(0, 5649))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 15874))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn remove_ed25519_delegation_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1457`
-		//  Estimated: `5649`
-		// Minimum execution time: 21_610_000 picoseconds.
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
 		Weight::from_parts(22_300_000, 0)
-			.saturating_add(Weight::from_parts(0, 5649))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 15874))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn remove_sr25519_delegation_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1457`
-		//  Estimated: `5649`
-		// Minimum execution time: 21_940_000 picoseconds.
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
 		Weight::from_parts(22_440_000, 0)
-			.saturating_add(Weight::from_parts(0, 5649))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 15874))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn remove_ecdsa_delegation_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1460`
-		//  Estimated: `5649`
-		// Minimum execution time: 21_649_000 picoseconds.
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
 		Weight::from_parts(22_550_000, 0)
-			.saturating_add(Weight::from_parts(0, 5649))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 15874))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn set_ed25519_assertion_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1457`
-		//  Estimated: `5649`
-		// Minimum execution time: 23_310_000 picoseconds.
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
 		Weight::from_parts(23_810_000, 0)
-			.saturating_add(Weight::from_parts(0, 5649))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 15874))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn set_sr25519_assertion_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1457`
-		//  Estimated: `5649`
-		// Minimum execution time: 23_320_000 picoseconds.
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
 		Weight::from_parts(24_050_000, 0)
-			.saturating_add(Weight::from_parts(0, 5649))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 15874))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn set_ecdsa_assertion_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1460`
-		//  Estimated: `5649`
-		// Minimum execution time: 23_230_000 picoseconds.
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
 		Weight::from_parts(23_990_000, 0)
-			.saturating_add(Weight::from_parts(0, 5649))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 15874))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn remove_ed25519_assertion_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1457`
-		//  Estimated: `5649`
-		// Minimum execution time: 21_560_000 picoseconds.
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
 		Weight::from_parts(22_109_000, 0)
-			.saturating_add(Weight::from_parts(0, 5649))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 15874))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn remove_sr25519_assertion_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1457`
-		//  Estimated: `5649`
-		// Minimum execution time: 21_970_000 picoseconds.
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
 		Weight::from_parts(22_450_000, 0)
-			.saturating_add(Weight::from_parts(0, 5649))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 15874))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn remove_ecdsa_assertion_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1460`
-		//  Estimated: `5649`
-		// Minimum execution time: 21_571_000 picoseconds.
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
 		Weight::from_parts(22_629_000, 0)
-			.saturating_add(Weight::from_parts(0, 5649))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 15874))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn add_ed25519_key_agreement_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1355`
-		//  Estimated: `5649`
-		// Minimum execution time: 22_690_000 picoseconds.
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
 		Weight::from_parts(23_520_000, 0)
-			.saturating_add(Weight::from_parts(0, 5649))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 15874))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn add_sr25519_key_agreement_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1355`
-		//  Estimated: `5649`
-		// Minimum execution time: 22_930_000 picoseconds.
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
 		Weight::from_parts(23_469_000, 0)
-			.saturating_add(Weight::from_parts(0, 5649))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 15874))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn add_ecdsa_key_agreement_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1358`
-		//  Estimated: `5649`
-		// Minimum execution time: 23_060_000 picoseconds.
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
 		Weight::from_parts(23_640_000, 0)
-			.saturating_add(Weight::from_parts(0, 5649))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 15874))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn remove_ed25519_key_agreement_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1457`
-		//  Estimated: `5649`
-		// Minimum execution time: 21_980_000 picoseconds.
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
 		Weight::from_parts(22_660_000, 0)
-			.saturating_add(Weight::from_parts(0, 5649))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 15874))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn remove_sr25519_key_agreement_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1457`
-		//  Estimated: `5649`
-		// Minimum execution time: 21_890_000 picoseconds.
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
 		Weight::from_parts(22_740_000, 0)
-			.saturating_add(Weight::from_parts(0, 5649))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 15874))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn remove_ecdsa_key_agreement_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1460`
-		//  Estimated: `5649`
-		// Minimum execution time: 22_209_000 picoseconds.
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
 		Weight::from_parts(22_860_000, 0)
-			.saturating_add(Weight::from_parts(0, 5649))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 15874))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
@@ -525,14 +549,138 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 	/// Proof: `Did::DidBlacklist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
 	fn create_from_account() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `42`
-		//  Estimated: `5649`
-		// Minimum execution time: 13_430_000 picoseconds.
+		//  Estimated: `15874`
+		// Estimated from the storage accesses, pending benchmarking.
 		Weight::from_parts(14_130_000, 0)
-			.saturating_add(Weight::from_parts(0, 5649))
+			.saturating_add(Weight::from_parts(0, 15874))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Did::Did` (r:2 w:0)
+	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::Controllers` (r:1 w:1)
+	/// Proof: `Did::Controllers` (`max_values`: None, `max_size`: Some(369), added: 2844, mode: `MaxEncodedLen`)
+	fn add_controller() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `12162`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(19_310_000, 0)
+			.saturating_add(Weight::from_parts(0, 12162))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Did::Controllers` (r:1 w:1)
+	/// Proof: `Did::Controllers` (`max_values`: None, `max_size`: Some(369), added: 2844, mode: `MaxEncodedLen`)
+	fn remove_controller() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `2844`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(13_540_000, 0)
+			.saturating_add(Weight::from_parts(0, 2844))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Did::Controllers` (r:1 w:0)
+	/// Proof: `Did::Controllers` (`max_values`: None, `max_size`: Some(369), added: 2844, mode: `MaxEncodedLen`)
+	/// Storage: `Did::Did` (r:1 w:1)
+	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
+	fn controller_set_authentication_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `17728`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(32_980_000, 0)
+			.saturating_add(Weight::from_parts(0, 17728))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Did::Did` (r:1 w:0)
+	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::RecoveryRequests` (r:1 w:0)
+	/// Proof: `Did::RecoveryRequests` (`max_values`: None, `max_size`: Some(407), added: 2882, mode: `MaxEncodedLen`)
+	/// Storage: `Did::RecoveryPolicies` (r:0 w:1)
+	/// Proof: `Did::RecoveryPolicies` (`max_values`: None, `max_size`: Some(377), added: 2852, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 10]`.
+	fn set_recovery_policy(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `7541`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(17_102_441, 0)
+			.saturating_add(Weight::from_parts(0, 7541))
+			.saturating_add(Weight::from_parts(201_530, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Did::RecoveryPolicies` (r:1 w:1)
+	/// Proof: `Did::RecoveryPolicies` (`max_values`: None, `max_size`: Some(377), added: 2852, mode: `MaxEncodedLen`)
+	/// Storage: `Did::RecoveryRequests` (r:0 w:1)
+	/// Proof: `Did::RecoveryRequests` (`max_values`: None, `max_size`: Some(407), added: 2882, mode: `MaxEncodedLen`)
+	fn remove_recovery_policy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `2852`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(14_890_000, 0)
+			.saturating_add(Weight::from_parts(0, 2852))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Did::RecoveryPolicies` (r:1 w:0)
+	/// Proof: `Did::RecoveryPolicies` (`max_values`: None, `max_size`: Some(377), added: 2852, mode: `MaxEncodedLen`)
+	/// Storage: `Did::RecoveryRequests` (r:1 w:1)
+	/// Proof: `Did::RecoveryRequests` (`max_values`: None, `max_size`: Some(407), added: 2882, mode: `MaxEncodedLen`)
+	fn initiate_recovery() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `5734`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(17_760_000, 0)
+			.saturating_add(Weight::from_parts(0, 5734))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Did::RecoveryPolicies` (r:1 w:0)
+	/// Proof: `Did::RecoveryPolicies` (`max_values`: None, `max_size`: Some(377), added: 2852, mode: `MaxEncodedLen`)
+	/// Storage: `Did::RecoveryRequests` (r:1 w:1)
+	/// Proof: `Did::RecoveryRequests` (`max_values`: None, `max_size`: Some(407), added: 2882, mode: `MaxEncodedLen`)
+	fn approve_recovery() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `5734`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(18_420_000, 0)
+			.saturating_add(Weight::from_parts(0, 5734))
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Did::RecoveryRequests` (r:1 w:1)
+	/// Proof: `Did::RecoveryRequests` (`max_values`: None, `max_size`: Some(407), added: 2882, mode: `MaxEncodedLen`)
+	fn cancel_recovery() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `2882`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(12_950_000, 0)
+			.saturating_add(Weight::from_parts(0, 2882))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Did::RecoveryPolicies` (r:1 w:0)
+	/// Proof: `Did::RecoveryPolicies` (`max_values`: None, `max_size`: Some(377), added: 2852, mode: `MaxEncodedLen`)
+	/// Storage: `Did::RecoveryRequests` (r:1 w:1)
+	/// Proof: `Did::RecoveryRequests` (`max_values`: None, `max_size`: Some(407), added: 2882, mode: `MaxEncodedLen`)
+	/// Storage: `Did::Did` (r:1 w:1)
+	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:1 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(7750), added: 10225, mode: `MaxEncodedLen`)
+	fn recover() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `20618`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(35_260_000, 0)
+			.saturating_add(Weight::from_parts(0, 20618))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
 }
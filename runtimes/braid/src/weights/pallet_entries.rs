@@ -0,0 +1,214 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placeholder weights for `pallet_entries`
+//!
+//! THESE WEIGHTS HAVE NOT BEEN BENCHMARKED. They are estimated from the storage
+//! accesses of each call so that no call is free to dispatch, and have to be
+//! replaced by the output of
+//! `./scripts/run_benches_for_runtime.sh -p pallet_entries braid` on the reference
+//! hardware before they are relied upon.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_entries`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_entries::WeightInfo for WeightInfo<T> {
+	/// Storage: `Registries::Authorizations` (r:1 w:0)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationLimits` (r:1 w:1)
+	/// Proof: `Registries::AuthorizationLimits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryInfo` (r:1 w:0)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(183), added: 2658, mode: `MaxEncodedLen`)
+	/// Storage: `SchemaAccounts::Schemas` (r:1 w:0)
+	/// Proof: `SchemaAccounts::Schemas` (`max_values`: None, `max_size`: Some(15492), added: 17967, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntries` (r:1 w:1)
+	/// Proof: `Entries::RegistryEntries` (`max_values`: None, `max_size`: Some(181), added: 2656, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntryVersions` (r:1 w:1)
+	/// Proof: `Entries::RegistryEntryVersions` (`max_values`: None, `max_size`: Some(3700), added: 6175, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntrySchemas` (r:0 w:1)
+	/// Proof: `Entries::RegistryEntrySchemas` (`max_values`: None, `max_size`: Some(99), added: 2574, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntryBlobDigests` (r:1 w:1)
+	/// Proof: `Entries::RegistryEntryBlobDigests` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntryBlobs` (r:1 w:1)
+	/// Proof: `Entries::RegistryEntryBlobs` (`max_values`: None, `max_size`: Some(4182), added: 6657, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[1, 4096]`.
+	fn create(l: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `49000`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(52_470_000, 0)
+			.saturating_add(Weight::from_parts(0, 49000))
+			.saturating_add(Weight::from_parts(1_621, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(9))
+	}
+	/// Storage: `Registries::Authorizations` (r:1 w:0)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationLimits` (r:1 w:1)
+	/// Proof: `Registries::AuthorizationLimits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryInfo` (r:1 w:0)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(183), added: 2658, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntries` (r:1 w:1)
+	/// Proof: `Entries::RegistryEntries` (`max_values`: None, `max_size`: Some(181), added: 2656, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntryVersions` (r:1 w:1)
+	/// Proof: `Entries::RegistryEntryVersions` (`max_values`: None, `max_size`: Some(3700), added: 6175, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntryBlobDigests` (r:1 w:1)
+	/// Proof: `Entries::RegistryEntryBlobDigests` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntryBlobs` (r:2 w:2)
+	/// Proof: `Entries::RegistryEntryBlobs` (`max_values`: None, `max_size`: Some(4182), added: 6657, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[1, 4096]`.
+	fn update(l: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `37690`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(56_730_000, 0)
+			.saturating_add(Weight::from_parts(0, 37690))
+			.saturating_add(Weight::from_parts(1_627, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(9))
+	}
+	/// Storage: `Registries::Authorizations` (r:1 w:0)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationLimits` (r:1 w:1)
+	/// Proof: `Registries::AuthorizationLimits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryInfo` (r:1 w:0)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(183), added: 2658, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntries` (r:1 w:1)
+	/// Proof: `Entries::RegistryEntries` (`max_values`: None, `max_size`: Some(181), added: 2656, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntryVersions` (r:1 w:1)
+	/// Proof: `Entries::RegistryEntryVersions` (`max_values`: None, `max_size`: Some(3700), added: 6175, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn revoke() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `19261`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(30_290_000, 0)
+			.saturating_add(Weight::from_parts(0, 19261))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `Registries::Authorizations` (r:1 w:0)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationLimits` (r:1 w:1)
+	/// Proof: `Registries::AuthorizationLimits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryInfo` (r:1 w:0)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(183), added: 2658, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntries` (r:1 w:1)
+	/// Proof: `Entries::RegistryEntries` (`max_values`: None, `max_size`: Some(181), added: 2656, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntryVersions` (r:1 w:1)
+	/// Proof: `Entries::RegistryEntryVersions` (`max_values`: None, `max_size`: Some(3700), added: 6175, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn reinstate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `19261`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(30_460_000, 0)
+			.saturating_add(Weight::from_parts(0, 19261))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `Registries::Authorizations` (r:1 w:0)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationLimits` (r:1 w:1)
+	/// Proof: `Registries::AuthorizationLimits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationExpirations` (r:1 w:1)
+	/// Proof: `Registries::AuthorizationExpirations` (`max_values`: None, `max_size`: Some(50014), added: 52489, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryInfo` (r:1 w:0)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(183), added: 2658, mode: `MaxEncodedLen`)
+	/// Storage: `SchemaAccounts::Schemas` (r:1 w:0)
+	/// Proof: `SchemaAccounts::Schemas` (`max_values`: None, `max_size`: Some(15492), added: 17967, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntries` (r:1000 w:1000)
+	/// Proof: `Entries::RegistryEntries` (`max_values`: None, `max_size`: Some(181), added: 2656, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntryVersions` (r:1000 w:1000)
+	/// Proof: `Entries::RegistryEntryVersions` (`max_values`: None, `max_size`: Some(3700), added: 6175, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntrySchemas` (r:0 w:1000)
+	/// Proof: `Entries::RegistryEntrySchemas` (`max_values`: None, `max_size`: Some(99), added: 2574, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1000 w:1000)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1000)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 1000]`.
+	fn create_batch(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `78328`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(27_070_000, 0)
+			.saturating_add(Weight::from_parts(0, 78328))
+			.saturating_add(Weight::from_parts(24_356_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 11389).saturating_mul(n.into()))
+	}
+	/// Storage: `Registries::Authorizations` (r:1 w:0)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationLimits` (r:1 w:1)
+	/// Proof: `Registries::AuthorizationLimits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationExpirations` (r:1 w:1)
+	/// Proof: `Registries::AuthorizationExpirations` (`max_values`: None, `max_size`: Some(50014), added: 52489, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryInfo` (r:1 w:0)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(183), added: 2658, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntries` (r:1000 w:1000)
+	/// Proof: `Entries::RegistryEntries` (`max_values`: None, `max_size`: Some(181), added: 2656, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntryVersions` (r:1000 w:1000)
+	/// Proof: `Entries::RegistryEntryVersions` (`max_values`: None, `max_size`: Some(3700), added: 6175, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1000 w:1000)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1000)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 1000]`.
+	fn revoke_batch(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `60361`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(21_950_000, 0)
+			.saturating_add(Weight::from_parts(0, 60361))
+			.saturating_add(Weight::from_parts(20_215_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 11389).saturating_mul(n.into()))
+	}
+}
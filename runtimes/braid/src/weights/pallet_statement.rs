@@ -55,21 +55,26 @@ impl<T: frame_system::Config> pallet_statement::WeightInfo for WeightInfo<T> {
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::Statements` (r:1 w:1)
 	/// Proof: `Statement::Statements` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::IdentifierLookup` (r:0 w:1)
 	/// Proof: `Statement::IdentifierLookup` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::Entries` (r:0 w:1)
 	/// Proof: `Statement::Entries` (`max_values`: None, `max_size`: Some(138), added: 2613, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::ValidityWindows` (r:1 w:1)
+	/// Proof: `Statement::ValidityWindows` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Expirations` (r:1 w:1)
+	/// Proof: `Statement::Expirations` (`max_values`: None, `max_size`: Some(49016), added: 51491, mode: `MaxEncodedLen`)
 	fn register() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `757`
-		//  Estimated: `3671`
-		// Minimum execution time: 39_590_000 picoseconds.
-		Weight::from_parts(40_530_000, 0)
-			.saturating_add(Weight::from_parts(0, 3671))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(5))
+		//  Estimated: `57807`
+		// Estimated with both validity bounds set and full expiry buckets.
+		Weight::from_parts(55_530_000, 0)
+			.saturating_add(Weight::from_parts(0, 57807))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(8))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -81,19 +86,28 @@ impl<T: frame_system::Config> pallet_statement::WeightInfo for WeightInfo<T> {
 	/// Proof: `Statement::RevocationList` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::Entries` (r:1 w:1)
 	/// Proof: `Statement::Entries` (`max_values`: None, `max_size`: Some(138), added: 2613, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::RevocationPolicies` (r:1 w:0)
+	/// Proof: `Statement::RevocationPolicies` (`max_values`: None, `max_size`: Some(872), added: 3347, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Suspensions` (r:1 w:0)
+	/// Proof: `Statement::Suspensions` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::IdentifierLookup` (r:0 w:1)
 	/// Proof: `Statement::IdentifierLookup` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::ValidityWindows` (r:1 w:1)
+	/// Proof: `Statement::ValidityWindows` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Expirations` (r:2 w:2)
+	/// Proof: `Statement::Expirations` (`max_values`: None, `max_size`: Some(49016), added: 51491, mode: `MaxEncodedLen`)
 	fn update() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1158`
-		//  Estimated: `3671`
-		// Minimum execution time: 48_350_000 picoseconds.
-		Weight::from_parts(49_530_000, 0)
-			.saturating_add(Weight::from_parts(0, 3671))
-			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(6))
+		//  Estimated: `109298`
+		// Estimated with both validity bounds set and full expiry buckets.
+		Weight::from_parts(79_530_000, 0)
+			.saturating_add(Weight::from_parts(0, 109298))
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().writes(10))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -103,17 +117,20 @@ impl<T: frame_system::Config> pallet_statement::WeightInfo for WeightInfo<T> {
 	/// Proof: `Statement::Statements` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::RevocationList` (r:1 w:1)
 	/// Proof: `Statement::RevocationList` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::RevocationPolicies` (r:1 w:0)
+	/// Proof: `Statement::RevocationPolicies` (`max_values`: None, `max_size`: Some(872), added: 3347, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	fn revoke() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1031`
-		//  Estimated: `3671`
-		// Minimum execution time: 38_210_000 picoseconds.
+		//  Estimated: `16533`
+		// Estimated from the storage accesses, pending benchmarking.
 		Weight::from_parts(38_980_000, 0)
-			.saturating_add(Weight::from_parts(0, 3671))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(Weight::from_parts(0, 16533))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -123,17 +140,20 @@ impl<T: frame_system::Config> pallet_statement::WeightInfo for WeightInfo<T> {
 	/// Proof: `Statement::Statements` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::RevocationList` (r:1 w:1)
 	/// Proof: `Statement::RevocationList` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::RevocationPolicies` (r:1 w:0)
+	/// Proof: `Statement::RevocationPolicies` (`max_values`: None, `max_size`: Some(872), added: 3347, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	fn restore() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1199`
-		//  Estimated: `3671`
-		// Minimum execution time: 39_610_000 picoseconds.
+		//  Estimated: `16533`
+		// Estimated from the storage accesses, pending benchmarking.
 		Weight::from_parts(40_700_000, 0)
-			.saturating_add(Weight::from_parts(0, 3671))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(Weight::from_parts(0, 16533))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -141,22 +161,25 @@ impl<T: frame_system::Config> pallet_statement::WeightInfo for WeightInfo<T> {
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::Statements` (r:1 w:1)
 	/// Proof: `Statement::Statements` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::RevocationPolicies` (r:1 w:0)
+	/// Proof: `Statement::RevocationPolicies` (`max_values`: None, `max_size`: Some(872), added: 3347, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::Entries` (r:2 w:1)
 	/// Proof: `Statement::Entries` (`max_values`: None, `max_size`: Some(138), added: 2613, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::IdentifierLookup` (r:0 w:1)
 	/// Proof: `Statement::IdentifierLookup` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
 	/// The range of component `l` is `[1, 5120]`.
 	fn remove(_l: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1200`
-		//  Estimated: `6216`
-		// Minimum execution time: 62_809_000 picoseconds.
+		//  Estimated: `19145`
+		// Estimated from the storage accesses, pending benchmarking.
 		Weight::from_parts(65_285_526, 0)
-			.saturating_add(Weight::from_parts(0, 6216))
-			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(Weight::from_parts(0, 19145))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(6))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -164,8 +187,10 @@ impl<T: frame_system::Config> pallet_statement::WeightInfo for WeightInfo<T> {
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::Statements` (r:3 w:3)
 	/// Proof: `Statement::Statements` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:3 w:3)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:3 w:3)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:3)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::IdentifierLookup` (r:0 w:3)
 	/// Proof: `Statement::IdentifierLookup` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::Entries` (r:0 w:3)
@@ -173,13 +198,12 @@ impl<T: frame_system::Config> pallet_statement::WeightInfo for WeightInfo<T> {
 	/// The range of component `l` is `[1, 5120]`.
 	fn register_batch(_l: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `757`
-		//  Estimated: `9012`
-		// Minimum execution time: 71_319_000 picoseconds.
+		//  Estimated: `21036`
+		// Estimated from the storage accesses, pending benchmarking.
 		Weight::from_parts(73_983_087, 0)
-			.saturating_add(Weight::from_parts(0, 9012))
+			.saturating_add(Weight::from_parts(0, 21036))
 			.saturating_add(T::DbWeight::get().reads(8))
-			.saturating_add(T::DbWeight::get().writes(13))
+			.saturating_add(T::DbWeight::get().writes(16))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -191,19 +215,22 @@ impl<T: frame_system::Config> pallet_statement::WeightInfo for WeightInfo<T> {
 	/// Proof: `Statement::RevocationList` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::Presentations` (r:1 w:1)
 	/// Proof: `Statement::Presentations` (`max_values`: None, `max_size`: Some(221), added: 2696, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::PresentationsMetadata` (r:0 w:1)
+	/// Proof: `Statement::PresentationsMetadata` (`max_values`: None, `max_size`: Some(917), added: 3392, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::IdentifierLookup` (r:0 w:1)
 	/// Proof: `Statement::IdentifierLookup` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
 	fn add_presentation() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1031`
-		//  Estimated: `3686`
-		// Minimum execution time: 43_220_000 picoseconds.
+		//  Estimated: `15882`
+		// Estimated from the storage accesses, pending benchmarking.
 		Weight::from_parts(44_590_000, 0)
-			.saturating_add(Weight::from_parts(0, 3686))
+			.saturating_add(Weight::from_parts(0, 15882))
 			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes(6))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -211,18 +238,193 @@ impl<T: frame_system::Config> pallet_statement::WeightInfo for WeightInfo<T> {
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::Presentations` (r:1 w:1)
 	/// Proof: `Statement::Presentations` (`max_values`: None, `max_size`: Some(221), added: 2696, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::PresentationsMetadata` (r:0 w:1)
+	/// Proof: `Statement::PresentationsMetadata` (`max_values`: None, `max_size`: Some(917), added: 3392, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::IdentifierLookup` (r:0 w:1)
 	/// Proof: `Statement::IdentifierLookup` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
 	fn remove_presentation() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1145`
-		//  Estimated: `3686`
-		// Minimum execution time: 41_970_000 picoseconds.
+		//  Estimated: `10594`
+		// Estimated from the storage accesses, pending benchmarking.
 		Weight::from_parts(42_910_000, 0)
-			.saturating_add(Weight::from_parts(0, 3686))
+			.saturating_add(Weight::from_parts(0, 10594))
 			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
+	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Delegates` (r:1 w:0)
+	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::RevocationPolicies` (r:0 w:1)
+	/// Proof: `Statement::RevocationPolicies` (`max_values`: None, `max_size`: Some(872), added: 3347, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 25]`.
+	fn set_revocation_policy(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `327883`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(33_410_000, 0)
+			.saturating_add(Weight::from_parts(0, 327883))
+			.saturating_add(Weight::from_parts(612_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
+	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::RevocationPolicies` (r:1 w:1)
+	/// Proof: `Statement::RevocationPolicies` (`max_values`: None, `max_size`: Some(872), added: 3347, mode: `MaxEncodedLen`)
+	fn remove_revocation_policy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `8687`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(32_160_000, 0)
+			.saturating_add(Weight::from_parts(0, 8687))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Statement::RevocationProposals` (r:1 w:1)
+	/// Proof: `Statement::RevocationProposals` (`max_values`: None, `max_size`: Some(948), added: 3423, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
+	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Statements` (r:1 w:1)
+	/// Proof: `Statement::Statements` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::RevocationPolicies` (r:1 w:0)
+	/// Proof: `Statement::RevocationPolicies` (`max_values`: None, `max_size`: Some(872), added: 3347, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::RevocationProposalExpirations` (r:1 w:1)
+	/// Proof: `Statement::RevocationProposalExpirations` (`max_values`: None, `max_size`: Some(49016), added: 51491, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::RevocationList` (r:1 w:1)
+	/// Proof: `Statement::RevocationList` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Entries` (r:1 w:1)
+	/// Proof: `Statement::Entries` (`max_values`: None, `max_size`: Some(138), added: 2613, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::ValidityWindows` (r:1 w:1)
+	/// Proof: `Statement::ValidityWindows` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Expirations` (r:1 w:1)
+	/// Proof: `Statement::Expirations` (`max_values`: None, `max_size`: Some(49016), added: 51491, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Suspensions` (r:1 w:0)
+	/// Proof: `Statement::Suspensions` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::IdentifierLookup` (r:0 w:1)
+	/// Proof: `Statement::IdentifierLookup` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
+	fn approve_revocation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `130685`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(78_640_000, 0)
+			.saturating_add(Weight::from_parts(0, 130685))
+			.saturating_add(T::DbWeight::get().reads(12))
+			.saturating_add(T::DbWeight::get().writes(11))
+	}
+	/// Storage: `Statement::RevocationProposals` (r:1 w:1)
+	/// Proof: `Statement::RevocationProposals` (`max_values`: None, `max_size`: Some(948), added: 3423, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
+	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Statements` (r:1 w:0)
+	/// Proof: `Statement::Statements` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::RevocationProposalExpirations` (r:1 w:1)
+	/// Proof: `Statement::RevocationProposalExpirations` (`max_values`: None, `max_size`: Some(49016), added: 51491, mode: `MaxEncodedLen`)
+	fn cancel_revocation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `62928`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(41_260_000, 0)
+			.saturating_add(Weight::from_parts(0, 62928))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
+	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Statements` (r:1 w:0)
+	/// Proof: `Statement::Statements` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::RevocationList` (r:1 w:0)
+	/// Proof: `Statement::RevocationList` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Suspensions` (r:1 w:1)
+	/// Proof: `Statement::Suspensions` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::SuspensionLifts` (r:1 w:1)
+	/// Proof: `Statement::SuspensionLifts` (`max_values`: None, `max_size`: Some(49016), added: 51491, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn suspend() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `67250`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(44_870_000, 0)
+			.saturating_add(Weight::from_parts(0, 67250))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
+	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Statements` (r:1 w:0)
+	/// Proof: `Statement::Statements` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Suspensions` (r:1 w:1)
+	/// Proof: `Statement::Suspensions` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::SuspensionLifts` (r:1 w:1)
+	/// Proof: `Statement::SuspensionLifts` (`max_values`: None, `max_size`: Some(49016), added: 51491, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn unsuspend() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `64636`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(42_350_000, 0)
+			.saturating_add(Weight::from_parts(0, 64636))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `ChainSpace::Authorizations` (r:2 w:0)
+	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Spaces` (r:2 w:2)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Statements` (r:1 w:1)
+	/// Proof: `Statement::Statements` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::RevocationPolicies` (r:1 w:0)
+	/// Proof: `Statement::RevocationPolicies` (`max_values`: None, `max_size`: Some(872), added: 3347, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::RevocationProposals` (r:1 w:0)
+	/// Proof: `Statement::RevocationProposals` (`max_values`: None, `max_size`: Some(948), added: 3423, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Entries` (r:2 w:0)
+	/// Proof: `Statement::Entries` (`max_values`: None, `max_size`: Some(138), added: 2613, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Presentations` (r:1000 w:999)
+	/// Proof: `Statement::Presentations` (`max_values`: None, `max_size`: Some(221), added: 2696, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::IdentifierLookup` (r:1000 w:2000)
+	/// Proof: `Statement::IdentifierLookup` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 1000]`.
+	fn transfer_space(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `10983 + n * (2696 ±0)`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(73_910_000, 0)
+			.saturating_add(Weight::from_parts(0, 10983))
+			.saturating_add(Weight::from_parts(14_326_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2696).saturating_mul(n.into()))
 	}
 }
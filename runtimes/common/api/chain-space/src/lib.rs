@@ -0,0 +1,39 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API definition for chain spaces.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+pub use pallet_chain_space::{SpaceTreeNode, StatusOf};
+
+sp_api::decl_runtime_apis! {
+	#[api_version(1)]
+	pub trait ChainSpaceApi<SpaceId, SpaceCreator> where
+		SpaceId: Codec,
+		SpaceCreator: Codec,
+	{
+	/// Given a space identifier this returns the space and all of its
+	/// sub-spaces in depth-first order, along with their capacity, reserved
+	/// capacity and usage aggregated over their descendants.
+	fn space_tree(space: SpaceId) -> Option<Vec<SpaceTreeNode<SpaceId, SpaceCreator, StatusOf>>>;
+	}
+}
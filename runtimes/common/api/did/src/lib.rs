@@ -28,6 +28,10 @@ mod did_details;
 mod service_endpoint;
 
 pub use did_details::*;
+pub use pallet_did::did_details::{
+	DidEncryptionKey, DidPublicKey, DidPublicKeyDetails, DidSignature, DidVerificationKey,
+	DidVerificationKeyRelationship,
+};
 pub use service_endpoint::*;
 
 #[derive(Encode, Decode, TypeInfo, Eq, PartialEq)]
@@ -48,6 +52,17 @@ pub struct DidLinkedInfo<
 	pub details: DidDetails<Key, BlockNumber, AccountId>,
 }
 
+/// The state of a DID at the queried block.
+#[derive(Encode, Decode, TypeInfo, Eq, PartialEq)]
+pub enum DidState<LinkedInfo> {
+	/// The DID exists, along with its linked information.
+	Active(LinkedInfo),
+	/// The DID has been deleted and can not be created again.
+	Deactivated,
+	/// The DID has never been created.
+	NotFound,
+}
+
 /// The DidLinkedInfo represented as a byte array.
 ///
 /// This will be returned by the runtime and processed by the client side RPC
@@ -55,8 +70,12 @@ pub struct DidLinkedInfo<
 pub type RawDidLinkedInfo<DidIdentifier, AccountId, Key, BlockNumber> =
 	DidLinkedInfo<DidIdentifier, AccountId, Vec<u8>, Vec<u8>, Vec<u8>, Vec<u8>, Key, BlockNumber>;
 
+/// The state of a DID carrying its `RawDidLinkedInfo` when active.
+pub type RawDidState<DidIdentifier, AccountId, Key, BlockNumber> =
+	DidState<RawDidLinkedInfo<DidIdentifier, AccountId, Key, BlockNumber>>;
+
 sp_api::decl_runtime_apis! {
-	#[api_version(1)]
+	#[api_version(3)]
 	pub trait DidApi<DidIdentifier, AccountId, Key: Ord, BlockNumber: MaxEncodedLen> where
 		DidIdentifier: Codec,
 		AccountId: Codec,
@@ -75,5 +94,23 @@ sp_api::decl_runtime_apis! {
 	/// * public keys stored for the did
 	/// * service endpoints
 	fn query(did: DidIdentifier) -> Option<RawDidLinkedInfo<DidIdentifier, AccountId, Key, BlockNumber>>;
+
+	/// Given a did this returns the same information as `query` when the DID
+	/// exists, and otherwise tells a deleted DID apart from one that has
+	/// never been created.
+	#[api_version(2)]
+	fn resolve(did: DidIdentifier) -> RawDidState<DidIdentifier, AccountId, Key, BlockNumber>;
+
+	/// Given a did, a verification relationship, a payload and a signature
+	/// this returns whether the signature was created with a key that was
+	/// bound to the DID under that relationship at the given block.
+	#[api_version(3)]
+	fn verify_signature_at(
+		did: DidIdentifier,
+		key_relationship: DidVerificationKeyRelationship,
+		payload: Vec<u8>,
+		signature: DidSignature,
+		block_number: BlockNumber,
+	) -> bool;
 	}
 }
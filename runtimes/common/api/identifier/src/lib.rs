@@ -0,0 +1,45 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API definition for identifier timelines.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+
+pub use identifier::{
+	CallTypeOf, EventEntryOf, IdentifierTypeOf, TimelinePage, TimelinePageOf, Timepoint,
+	MAX_TIMELINE_PAGE_SIZE,
+};
+
+sp_api::decl_runtime_apis! {
+	#[api_version(1)]
+	pub trait IdentifierApi<Identifier> where
+		Identifier: Codec,
+	{
+	/// Returns up to `limit` timeline entries of an identifier, oldest first,
+	/// starting at the `cursor` index. The `next` cursor of the returned page
+	/// is `None` once the complete history has been read.
+	fn timeline(
+		identifier: Identifier,
+		id_type: IdentifierTypeOf,
+		cursor: Option<u64>,
+		limit: u32,
+	) -> TimelinePageOf;
+	}
+}
@@ -0,0 +1,42 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+//! Runtime API definition for network scores.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+
+pub use pallet_network_score::{AggregatedEntryOf, RatingTypeOf, ScoreWindow};
+
+sp_api::decl_runtime_apis! {
+	#[api_version(1)]
+	pub trait NetworkScoreApi<EntityIdentifier> where
+		EntityIdentifier: Codec,
+	{
+	/// Given an entity identifier and a rating type this returns the
+	/// aggregated ratings of the entity over the chosen window:
+	/// * all ratings ever recorded
+	/// * the ratings of the most recent epochs
+	/// * the ratings of the most recent epochs, weighted down by their age
+	fn score(entity: EntityIdentifier, rating_type: RatingTypeOf, window: ScoreWindow) -> Option<AggregatedEntryOf>;
+	/// Given an entity identifier this returns the aggregated ratings of the
+	/// entity over the chosen window, combined across all rating categories
+	/// according to the scale and weight of each category.
+	fn combined_score(entity: EntityIdentifier, window: ScoreWindow) -> Option<AggregatedEntryOf>;
+	}
+}
@@ -0,0 +1,56 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API definition for registries and registry entries.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+pub use pallet_entries::{RegistryEntryVersion, StatusOf};
+
+sp_api::decl_runtime_apis! {
+	#[api_version(1)]
+	pub trait RegistriesApi<Identifier, Digest, CreatorId, BlockNumber> where
+		Identifier: Codec,
+		Digest: Codec,
+		CreatorId: Codec,
+		BlockNumber: Codec,
+	{
+	/// Given a digest this returns the registry blob stored against it, if
+	/// any registry still references it.
+	fn registry_blob(digest: Digest) -> Option<Vec<u8>>;
+	/// Given a digest this returns the registry entry blob stored against
+	/// it, if any registry entry still references it.
+	fn registry_entry_blob(digest: Digest) -> Option<Vec<u8>>;
+	/// Given a registry entry identifier and a block number this returns the
+	/// state of the entry as of that block: its digest, whether it was
+	/// revoked, and when and by whom that state was recorded. Returns `None`
+	/// if the entry did not exist at the block, or if its history no longer
+	/// covers the block.
+	fn entry_state_at(
+		registry_entry_id: Identifier,
+		block: BlockNumber,
+	) -> Option<RegistryEntryVersion<Digest, StatusOf, CreatorId>>;
+	/// Given a registry entry identifier this returns the schema the entry is
+	/// pinned to, being the schema of its registry when the entry was created.
+	/// Returns `None` if the entry is not pinned to a schema.
+	fn entry_schema(registry_entry_id: Identifier) -> Option<Identifier>;
+	}
+}
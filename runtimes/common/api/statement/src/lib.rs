@@ -0,0 +1,61 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API definition for statements.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+
+pub use pallet_statement::{
+	PresentationLocation, PresentationMetadata, PresentationTypeOf, StatementEntryInfo,
+	StatementInfo, StatementPresentationInfo, StatementStatus, StatementSuspension,
+	StatementValidity,
+};
+
+sp_api::decl_runtime_apis! {
+	#[api_version(1)]
+	pub trait StatementApi<Identifier, Digest, Creator, BlockNumber> where
+		Identifier: Codec,
+		Digest: Codec,
+		Creator: Codec,
+		BlockNumber: Codec,
+	{
+	/// Given a statement identifier this returns:
+	/// * the latest digest, space and schema of the statement
+	/// * the status of the latest digest
+	/// * the validity window of the statement, if any
+	/// * the suspension placed on the statement, if any
+	/// * every digest anchored against the statement with its status
+	/// * the presentations attached to the statement
+	fn query(identifier: Identifier) -> Option<StatementInfo<Identifier, Digest, Creator, BlockNumber>>;
+
+	/// Given a statement or presentation digest and the space it was anchored
+	/// in, this resolves the statement identifier and returns the same
+	/// information as `query`.
+	fn query_by_digest(digest: Digest, space: Identifier) -> Option<StatementInfo<Identifier, Digest, Creator, BlockNumber>>;
+
+	/// Returns the status of a single digest anchored against a statement.
+	fn digest_status(identifier: Identifier, digest: Digest) -> Option<StatementStatus>;
+
+	/// Returns the presentations attached to a statement along with their
+	/// MIME type, language and location, optionally restricted to a single
+	/// presentation type.
+	fn presentations(identifier: Identifier, presentation_type: Option<PresentationTypeOf>) -> Vec<StatementPresentationInfo<Digest, Creator>>;
+	}
+}
@@ -184,7 +184,7 @@ impl frame_system::Config for Runtime {
 	type SystemWeightInfo = weights::frame_system::WeightInfo<Runtime>;
 	type SS58Prefix = SS58Prefix;
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
-	type MultiBlockMigrator = ();
+	type MultiBlockMigrator = MultiBlockMigrations;
 }
 
 parameter_types! {
@@ -229,6 +229,25 @@ impl pallet_scheduler::Config for Runtime {
 	type Preimages = Preimage;
 }
 
+parameter_types! {
+	pub MbmServiceWeight: Weight = Perbill::from_percent(80) * BlockWeights::get().max_block;
+}
+
+impl pallet_migrations::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type Migrations = MultiBlockMigrationSteps;
+	// Benchmarks need mocked migrations to guarantee that they succeed.
+	#[cfg(feature = "runtime-benchmarks")]
+	type Migrations = pallet_migrations::mock_helpers::MockedMigrations;
+	type CursorMaxLen = ConstU32<65_536>;
+	type IdentifierMaxLen = ConstU32<256>;
+	type MigrationStatusHandler = ();
+	type FailedMigrationHandler = frame_support::migrations::FreezeChainOnFailedMigration;
+	type MaxServiceWeight = MbmServiceWeight;
+	type WeightInfo = pallet_migrations::weights::SubstrateWeight<Runtime>;
+}
+
 parameter_types! {
 	pub const PreimageMaxSize: u32 = 4096 * 1024;
 	pub const PreimageBaseDeposit: Balance = 1 * UNITS;
@@ -506,13 +525,17 @@ impl pallet_identity::Config for Runtime {
 
 parameter_types! {
 	pub const MaxRegistryEntryBlobSize: u32 = 4 * 1024; // 4KB in bytes
+	pub const MaxRegistryEntriesPerBatch: u16 = 1_000;
+	pub const MaxRegistryEntryVersions: u32 = 50;
 }
 
 impl pallet_entries::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type MaxEncodedInputLength = MaxEncodedInputLength;
 	type MaxRegistryEntryBlobSize = MaxRegistryEntryBlobSize;
-	type WeightInfo = ();
+	type MaxRegistryEntriesPerBatch = MaxRegistryEntriesPerBatch;
+	type MaxRegistryEntryVersions = MaxRegistryEntryVersions;
+	type WeightInfo = weights::pallet_entries::WeightInfo<Runtime>;
 }
 
 parameter_types! {
@@ -778,7 +801,6 @@ impl pallet_node_authorization::Config for Runtime {
 parameter_types! {
 	pub const MembershipPeriod: BlockNumber = YEAR;
 	pub const MaxMembersPerBlock: u32 = 1_000;
-	pub const MaxEventsHistory: u32 = u32::MAX;
 }
 
 impl pallet_network_membership::Config for Runtime {
@@ -789,9 +811,7 @@ impl pallet_network_membership::Config for Runtime {
 	type WeightInfo = weights::pallet_network_membership::WeightInfo<Runtime>;
 }
 
-impl identifier::Config for Runtime {
-	type MaxEventsHistory = MaxEventsHistory;
-}
+impl identifier::Config for Runtime {}
 
 impl pallet_runtime_upgrade::Config for Runtime {
 	type SetCodeOrigin = EnsureRootOrCommitteeApproval;
@@ -811,6 +831,25 @@ parameter_types! {
 	pub const MaxServiceUrlLength: u32 = 200;
 	pub const MaxNumberOfTypesPerService: u32 = 1;
 	pub const MaxNumberOfUrlsPerService: u32 = 1;
+	pub const MaxKeyHistoryEntries: u32 = 100;
+	pub const MaxControllersPerDid: u32 = 10;
+	pub const MaxRecoveryDids: u32 = 10;
+	pub const MinRecoveryDelay: BlockNumber = DAYS;
+}
+
+#[cfg(not(test))]
+parameter_types! {
+	/// The block from which anchoring statements and schemas through a DID
+	/// requires its assertion key rather than its authentication key. Unset
+	/// until DIDs have been given time to add an assertion key.
+	pub const AssertionKeyRequiredFrom: Option<BlockNumber> = None;
+}
+
+// Settable in tests, so that the key relationships of anchoring calls can be
+// checked once assertion keys are required.
+#[cfg(test)]
+parameter_types! {
+	pub storage AssertionKeyRequiredFrom: Option<BlockNumber> = None;
 }
 
 impl pallet_did::Config for Runtime {
@@ -838,6 +877,10 @@ impl pallet_did::Config for Runtime {
 	type MaxServiceUrlLength = MaxServiceUrlLength;
 	type MaxNumberOfTypesPerService = MaxNumberOfTypesPerService;
 	type MaxNumberOfUrlsPerService = MaxNumberOfUrlsPerService;
+	type MaxKeyHistoryEntries = MaxKeyHistoryEntries;
+	type MaxControllersPerDid = MaxControllersPerDid;
+	type MaxRecoveryDids = MaxRecoveryDids;
+	type MinRecoveryDelay = MinRecoveryDelay;
 	type WeightInfo = weights::pallet_did::WeightInfo<Runtime>;
 }
 
@@ -862,6 +905,7 @@ impl pallet_did_name::Config for Runtime {
 
 parameter_types! {
 	pub const MaxEncodedSchemaLength: u32 = 15_360;
+	pub const ValidateSchemaStructure: bool = true;
 }
 
 impl pallet_schema::Config for Runtime {
@@ -870,17 +914,20 @@ impl pallet_schema::Config for Runtime {
 	type OriginSuccess = pallet_did::DidRawOrigin<AccountId, DidIdentifier>;
 	type RuntimeEvent = RuntimeEvent;
 	type MaxEncodedSchemaLength = MaxEncodedSchemaLength;
+	type ValidateSchemaStructure = ValidateSchemaStructure;
 	type WeightInfo = weights::pallet_schema::WeightInfo<Runtime>;
 }
 
 impl pallet_schema_accounts::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type MaxEncodedSchemaLength = MaxEncodedSchemaLength;
-	type WeightInfo = ();
+	type WeightInfo = weights::pallet_schema_accounts::WeightInfo<Runtime>;
 }
 
 parameter_types! {
 	pub const MaxSpaceDelegates: u32 = 10_000;
+	pub const MaxSubspaceDepth: u32 = 8;
+	pub const MaxSpaceDelegationExpirations: u32 = 1_000;
 }
 
 impl pallet_chain_space::Config for Runtime {
@@ -891,6 +938,8 @@ impl pallet_chain_space::Config for Runtime {
 	type ChainSpaceOrigin = MoreThanHalfCouncil;
 	type NetworkPermission = NetworkParameters;
 	type MaxSpaceDelegates = MaxSpaceDelegates;
+	type MaxSubspaceDepth = MaxSubspaceDepth;
+	type MaxDelegationExpirationsPerBlock = MaxSpaceDelegationExpirations;
 	type WeightInfo = weights::pallet_chain_space::WeightInfo<Runtime>;
 }
 
@@ -898,19 +947,41 @@ parameter_types! {
 	pub const MaxRegistryBlobSize: u32 = 4 * 1024;
 	pub const MaxEncodedInputLength: u32 = 30;
 	pub const MaxRegistryDelegates: u32 = 10_000;
+	pub const MaxRegistryDelegationExpirations: u32 = 1_000;
+	pub const RegistryBlobBaseDeposit: Balance = 10 * MILLI_UNITS;
+	pub const RegistryBlobByteDeposit: Balance = 1 * MICRO_UNITS;
+	pub const RegistryBlobHoldReason: RuntimeHoldReason =
+		RuntimeHoldReason::Registries(pallet_registries::HoldReason::BlobStorage);
 }
 
 impl pallet_registries::Config for Runtime {
+	// Registries created by accounts before DIDs owned them are managed by the
+	// DID sharing the identifier of the account, see the pallet docs.
+	type RegistryCreatorId = DidIdentifier;
+	type EnsureOrigin = pallet_did::EnsureDidOrigin<DidIdentifier, AccountId>;
+	type OriginSuccess = pallet_did::DidRawOrigin<AccountId, DidIdentifier>;
 	type RuntimeEvent = RuntimeEvent;
 	type MaxRegistryDelegates = MaxRegistryDelegates;
 	type MaxRegistryBlobSize = MaxRegistryBlobSize;
 	type MaxEncodedInputLength = MaxEncodedInputLength;
-	type WeightInfo = ();
+	type MaxDelegationExpirationsPerBlock = MaxRegistryDelegationExpirations;
+	type Consideration = HoldConsideration<
+		AccountId,
+		Balances,
+		RegistryBlobHoldReason,
+		LinearStoragePrice<RegistryBlobBaseDeposit, RegistryBlobByteDeposit, Balance>,
+	>;
+	type WeightInfo = weights::pallet_registries::WeightInfo<Runtime>;
 }
 
 parameter_types! {
 	pub const MaxDigestsPerBatch: u16 = 1_000;
 	pub const MaxRemoveEntries: u16 = 1_000;
+	pub const MaxExpirationsPerBlock: u32 = 1_000;
+	pub const RejectDeprecatedSchemas: bool = true;
+	pub const MaxRevocationApprovers: u32 = 25;
+	pub const RevocationProposalLifetime: BlockNumber = 7 * DAYS;
+	pub const MaxPresentationUriLength: u32 = 512;
 }
 
 impl pallet_statement::Config for Runtime {
@@ -920,6 +991,11 @@ impl pallet_statement::Config for Runtime {
 	type WeightInfo = weights::pallet_statement::WeightInfo<Runtime>;
 	type MaxDigestsPerBatch = MaxDigestsPerBatch;
 	type MaxRemoveEntries = MaxRemoveEntries;
+	type MaxExpirationsPerBlock = MaxExpirationsPerBlock;
+	type RejectDeprecatedSchemas = RejectDeprecatedSchemas;
+	type MaxRevocationApprovers = MaxRevocationApprovers;
+	type RevocationProposalLifetime = RevocationProposalLifetime;
+	type MaxPresentationUriLength = MaxPresentationUriLength;
 }
 
 impl pallet_remark::Config for Runtime {
@@ -933,13 +1009,23 @@ impl pallet_sudo::Config for Runtime {
 	type WeightInfo = weights::pallet_sudo::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+	pub const ScoreEpochDuration: Moment = 24 * 60 * 60 * 1_000;
+	pub const MaxRetainedScoreEpochs: u32 = 366;
+	pub const ScoreEpochDecay: Permill = Permill::from_parts(995_000);
+}
+
 impl pallet_network_score::Config for Runtime {
 	type RatingProviderIdOf = DidIdentifier;
 	type EnsureOrigin = pallet_did::EnsureDidOrigin<DidIdentifier, AccountId>;
 	type OriginSuccess = pallet_did::DidRawOrigin<AccountId, DidIdentifier>;
+	type RatingCategoryOrigin = MoreThanHalfCouncil;
 	type RuntimeEvent = RuntimeEvent;
 	type MaxEncodedValueLength = ConstU32<128>;
 	type MaxRatingValue = ConstU32<50>;
+	type EpochDuration = ScoreEpochDuration;
+	type MaxRetainedEpochs = MaxRetainedScoreEpochs;
+	type EpochDecay = ScoreEpochDecay;
 	type WeightInfo = weights::pallet_network_score::WeightInfo<Runtime>;
 }
 
@@ -1115,6 +1201,9 @@ mod runtime {
 	#[runtime::pallet_index(38)]
 	pub type Contracts = pallet_contracts;
 
+	#[runtime::pallet_index(39)]
+	pub type MultiBlockMigrations = pallet_migrations;
+
 	#[runtime::pallet_index(50)]
 	pub type Identifier = identifier;
 
@@ -1199,9 +1288,30 @@ impl pallet_did::DeriveDidCallAuthorizationVerificationKeyRelationship for Runti
 			RuntimeCall::DidName { .. } => {
 				Ok(pallet_did::DidVerificationKeyRelationship::Authentication)
 			},
+			RuntimeCall::Schema(pallet_schema::Call::create { .. }) => {
+				pallet_did::assertion_key_relationship::<Runtime, AssertionKeyRequiredFrom>()
+			},
+			RuntimeCall::Schema(pallet_schema::Call::supersede { .. }) => {
+				pallet_did::assertion_key_relationship::<Runtime, AssertionKeyRequiredFrom>()
+			},
 			RuntimeCall::Schema { .. } => {
 				Ok(pallet_did::DidVerificationKeyRelationship::Authentication)
 			},
+			RuntimeCall::Statement(pallet_statement::Call::register { .. }) => {
+				pallet_did::assertion_key_relationship::<Runtime, AssertionKeyRequiredFrom>()
+			},
+			RuntimeCall::Statement(pallet_statement::Call::update { .. }) => {
+				pallet_did::assertion_key_relationship::<Runtime, AssertionKeyRequiredFrom>()
+			},
+			RuntimeCall::Statement(pallet_statement::Call::revoke { .. }) => {
+				pallet_did::assertion_key_relationship::<Runtime, AssertionKeyRequiredFrom>()
+			},
+			RuntimeCall::Statement(pallet_statement::Call::approve_revocation { .. }) => {
+				pallet_did::assertion_key_relationship::<Runtime, AssertionKeyRequiredFrom>()
+			},
+			RuntimeCall::Statement(pallet_statement::Call::register_batch { .. }) => {
+				pallet_did::assertion_key_relationship::<Runtime, AssertionKeyRequiredFrom>()
+			},
 			RuntimeCall::Statement { .. } => {
 				Ok(pallet_did::DidVerificationKeyRelationship::Authentication)
 			},
@@ -1238,6 +1348,24 @@ impl pallet_did::DeriveDidCallAuthorizationVerificationKeyRelationship for Runti
 			RuntimeCall::ChainSpace(pallet_chain_space::Call::update_transaction_capacity_sub { .. }) => {
 				Ok(pallet_did::DidVerificationKeyRelationship::Authentication)
 			},
+			RuntimeCall::Registries(pallet_registries::Call::add_delegate { .. }) => {
+				Ok(pallet_did::DidVerificationKeyRelationship::CapabilityDelegation)
+			},
+			RuntimeCall::Registries(pallet_registries::Call::add_admin_delegate { .. }) => {
+				Ok(pallet_did::DidVerificationKeyRelationship::CapabilityDelegation)
+			},
+			RuntimeCall::Registries(pallet_registries::Call::add_delegator { .. }) => {
+				Ok(pallet_did::DidVerificationKeyRelationship::CapabilityDelegation)
+			},
+			RuntimeCall::Registries(pallet_registries::Call::remove_delegate { .. }) => {
+				Ok(pallet_did::DidVerificationKeyRelationship::CapabilityDelegation)
+			},
+			RuntimeCall::Registries { .. } => {
+				Ok(pallet_did::DidVerificationKeyRelationship::Authentication)
+			},
+			RuntimeCall::Entries { .. } => {
+				Ok(pallet_did::DidVerificationKeyRelationship::Authentication)
+			},
 			RuntimeCall::Utility(pallet_utility::Call::batch { calls }) => {
 				single_key_relationship(&calls[..])
 			},
@@ -1291,6 +1419,21 @@ pub type UncheckedExtrinsic =
 /// Extrinsic type that has already been checked.
 pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, RuntimeCall, SignedExtra>;
 
+/// Migrations to apply on runtime upgrade.
+pub type Migrations = (pallet_schema::migrations::v2::MigrateToSchemaDraft<Runtime>,);
+
+/// Migrations that run over several blocks after the runtime upgrade. Extrinsics
+/// other than inherents are paused until they complete.
+pub type MultiBlockMigrationSteps = (
+	identifier::migrations::v2::MigrateToPagedTimeline<Runtime>,
+	pallet_chain_space::migrations::v2::MigrateToHierarchicalUsage<Runtime>,
+	pallet_chain_space::migrations::v3::MigrateToFineGrainedPermissions<Runtime>,
+	pallet_network_score::migrations::v1::MigrateToRatingCategories<Runtime>,
+	pallet_entries::migrations::v2::MigrateToVersionHistory<Runtime>,
+	pallet_entries::migrations::v3::MigrateToPinnedSchemas<Runtime>,
+	pallet_did::migrations::v2::MigrateToKeyHistory<Runtime>,
+);
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -1298,6 +1441,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 /// The payload being signed in transactions.
 pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
@@ -1321,6 +1465,7 @@ mod benches {
 		[pallet_im_online, ImOnline]
 		[pallet_indices, Indices]
 		[pallet_membership, TechnicalMembership]
+		[pallet_migrations, MultiBlockMigrations]
 		[pallet_multisig, Multisig]
 		[pallet_preimage, Preimage]
 		[pallet_remark, Remark]
@@ -1336,6 +1481,9 @@ mod benches {
 		[pallet_did_name, DidName]
 		[pallet_network_membership, NetworkMembership]
 		[pallet_network_score, NetworkScore]
+		[pallet_registries, Registries]
+		[pallet_entries, Entries]
+		[pallet_schema_accounts, SchemaAccounts]
 		[pallet_sudo, Sudo]
 	);
 }
@@ -1586,6 +1734,7 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(3)]
 	impl pallet_did_runtime_api::DidApi<
 		Block,
 		DidIdentifier,
@@ -1637,6 +1786,142 @@ sp_api::impl_runtime_apis! {
 					}
 			})
 		}
+		fn resolve(did: DidIdentifier) -> pallet_did_runtime_api::RawDidState<
+				DidIdentifier,
+				AccountId,
+				Hash,
+				BlockNumber
+			>
+		{
+			if pallet_did::DidBlacklist::<Runtime>::contains_key(&did) {
+				return pallet_did_runtime_api::DidState::Deactivated;
+			}
+			let Some(details) = pallet_did::Did::<Runtime>::get(&did) else {
+				return pallet_did_runtime_api::DidState::NotFound;
+			};
+			let name = pallet_did_name::Names::<Runtime>::get(&did).map(Into::into);
+			let service_endpoints = pallet_did::ServiceEndpoints::<Runtime>::iter_prefix(&did).map(|e| From::from(e.1)).collect();
+
+			pallet_did_runtime_api::DidState::Active(pallet_did_runtime_api::RawDidLinkedInfo {
+				identifier: did.clone(),
+				account: did,
+				name,
+				service_endpoints,
+				details: details.into(),
+			})
+		}
+		fn verify_signature_at(
+			did: DidIdentifier,
+			key_relationship: pallet_did_runtime_api::DidVerificationKeyRelationship,
+			payload: Vec<u8>,
+			signature: pallet_did_runtime_api::DidSignature,
+			block_number: BlockNumber,
+		) -> bool {
+			pallet_did::Pallet::<Runtime>::verify_signature_at(
+				&did,
+				key_relationship,
+				&payload,
+				&signature,
+				block_number,
+			)
+		}
+	}
+
+	impl pallet_statement_runtime_api::StatementApi<
+		Block,
+		Ss58Identifier,
+		Hash,
+		DidIdentifier,
+		BlockNumber
+	> for Runtime {
+		fn query(identifier: Ss58Identifier) -> Option<
+			pallet_statement_runtime_api::StatementInfo<Ss58Identifier, Hash, DidIdentifier, BlockNumber>
+		> {
+			Statement::statement_info(&identifier)
+		}
+
+		fn query_by_digest(digest: Hash, space: Ss58Identifier) -> Option<
+			pallet_statement_runtime_api::StatementInfo<Ss58Identifier, Hash, DidIdentifier, BlockNumber>
+		> {
+			Statement::statement_info_by_digest(&digest, &space)
+		}
+
+		fn digest_status(
+			identifier: Ss58Identifier,
+			digest: Hash
+		) -> Option<pallet_statement_runtime_api::StatementStatus> {
+			Statement::digest_status(&identifier, &digest)
+		}
+
+		fn presentations(
+			identifier: Ss58Identifier,
+			presentation_type: Option<pallet_statement_runtime_api::PresentationTypeOf>
+		) -> Vec<pallet_statement_runtime_api::StatementPresentationInfo<Hash, DidIdentifier>> {
+			Statement::presentations(&identifier, presentation_type)
+		}
+	}
+
+	impl cord_identifier_runtime_api::IdentifierApi<Block, Ss58Identifier> for Runtime {
+		fn timeline(
+			identifier: Ss58Identifier,
+			id_type: cord_identifier_runtime_api::IdentifierTypeOf,
+			cursor: Option<u64>,
+			limit: u32,
+		) -> cord_identifier_runtime_api::TimelinePageOf {
+			Identifier::timeline_page(&identifier, id_type, cursor, limit)
+		}
+	}
+
+	impl pallet_chain_space_runtime_api::ChainSpaceApi<Block, Ss58Identifier, DidIdentifier> for Runtime {
+		fn space_tree(
+			space: Ss58Identifier,
+		) -> Option<Vec<pallet_chain_space_runtime_api::SpaceTreeNode<Ss58Identifier, DidIdentifier, bool>>> {
+			ChainSpace::space_tree(&space)
+		}
+	}
+
+	impl pallet_registries_runtime_api::RegistriesApi<
+		Block,
+		Ss58Identifier,
+		Hash,
+		DidIdentifier,
+		BlockNumber
+	> for Runtime {
+		fn registry_blob(digest: Hash) -> Option<Vec<u8>> {
+			Registries::blob(&digest)
+		}
+
+		fn registry_entry_blob(digest: Hash) -> Option<Vec<u8>> {
+			Entries::blob(&digest)
+		}
+
+		fn entry_state_at(
+			registry_entry_id: Ss58Identifier,
+			block: BlockNumber,
+		) -> Option<pallet_registries_runtime_api::RegistryEntryVersion<Hash, pallet_registries_runtime_api::StatusOf, DidIdentifier>> {
+			Entries::entry_state_at(&registry_entry_id, block)
+		}
+
+		fn entry_schema(registry_entry_id: Ss58Identifier) -> Option<Ss58Identifier> {
+			Entries::pinned_schema(&registry_entry_id)
+		}
+	}
+
+	impl pallet_network_score_runtime_api::NetworkScoreApi<Block, pallet_network_score::EntityIdentifierOf<Runtime>> for Runtime {
+		fn score(
+			entity: pallet_network_score::EntityIdentifierOf<Runtime>,
+			rating_type: pallet_network_score_runtime_api::RatingTypeOf,
+			window: pallet_network_score_runtime_api::ScoreWindow,
+		) -> Option<pallet_network_score_runtime_api::AggregatedEntryOf> {
+			NetworkScore::score(&entity, &rating_type, window)
+		}
+
+		fn combined_score(
+			entity: pallet_network_score::EntityIdentifierOf<Runtime>,
+			window: pallet_network_score_runtime_api::ScoreWindow,
+		) -> Option<pallet_network_score_runtime_api::AggregatedEntryOf> {
+			NetworkScore::combined_score(&entity, window)
+		}
 	}
 
 	impl pallet_transaction_weight_runtime_api::TransactionWeightApi<Block> for Runtime {
@@ -1787,6 +2072,10 @@ sp_api::impl_runtime_apis! {
 mod tests {
 	use super::*;
 	use frame_system::offchain::CreateSignedTransaction;
+	use identifier::{IdentifierCreator, IdentifierType};
+	use pallet_did::{
+		DeriveDidCallAuthorizationVerificationKeyRelationship, DidVerificationKeyRelationship,
+	};
 
 	#[test]
 	fn validate_transaction_submitter_bounds() {
@@ -1809,4 +2098,58 @@ mod tests {
 			size,
 		);
 	}
+	#[test]
+	fn anchoring_calls_require_the_authentication_key_until_assertion_keys_are_required() {
+		let statement_id =
+			Ss58Identifier::create_identifier(&[0u8; 32], IdentifierType::Statement).unwrap();
+		let authorization =
+			Ss58Identifier::create_identifier(&[0u8; 32], IdentifierType::Authorization).unwrap();
+		let anchoring_call =
+			RuntimeCall::Statement(pallet_statement::Call::revoke { statement_id, authorization });
+
+		sp_io::TestExternalities::default().execute_with(|| {
+			assert_eq!(AssertionKeyRequiredFrom::get(), None);
+			assert_eq!(
+				anchoring_call.derive_verification_key_relationship(),
+				Ok(DidVerificationKeyRelationship::Authentication)
+			);
+		});
+	}
+	#[test]
+	fn anchoring_calls_require_the_assertion_key_once_assertion_keys_are_required() {
+		let statement_id =
+			Ss58Identifier::create_identifier(&[0u8; 32], IdentifierType::Statement).unwrap();
+		let authorization =
+			Ss58Identifier::create_identifier(&[0u8; 32], IdentifierType::Authorization).unwrap();
+		let anchoring_calls = [
+			RuntimeCall::Statement(pallet_statement::Call::revoke {
+				statement_id: statement_id.clone(),
+				authorization: authorization.clone(),
+			}),
+			RuntimeCall::Statement(pallet_statement::Call::approve_revocation {
+				statement_id,
+				authorization,
+			}),
+		];
+
+		sp_io::TestExternalities::default().execute_with(|| {
+			AssertionKeyRequiredFrom::set(&Some(10));
+
+			System::set_block_number(9);
+			for call in anchoring_calls.iter() {
+				assert_eq!(
+					call.derive_verification_key_relationship(),
+					Ok(DidVerificationKeyRelationship::Authentication)
+				);
+			}
+
+			System::set_block_number(10);
+			for call in anchoring_calls.iter() {
+				assert_eq!(
+					call.derive_verification_key_relationship(),
+					Ok(DidVerificationKeyRelationship::AssertionMethod)
+				);
+			}
+		});
+	}
 }
@@ -58,21 +58,24 @@ impl<T: frame_system::Config> pallet_network_score::WeightInfo for WeightInfo<T>
 	/// Storage: `NetworkScore::RatingEntries` (r:1 w:1)
 	/// Proof: `NetworkScore::RatingEntries` (`max_values`: None, `max_size`: Some(680), added: 3155, mode: `MaxEncodedLen`)
 	/// Storage: `NetworkScore::AggregateScores` (r:1 w:1)
-	/// Proof: `NetworkScore::AggregateScores` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	/// Proof: `NetworkScore::AggregateScores` (`max_values`: None, `max_size`: Some(174), added: 2649, mode: `MaxEncodedLen`)
+	/// Storage: `NetworkScore::EpochScores` (r:1 w:1)
+	/// Proof: `NetworkScore::EpochScores` (`max_values`: None, `max_size`: Some(8945), added: 11420, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
-	/// The range of component `l` is `[1, 15360]`.
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[1, 128]`.
 	fn register_rating(_l: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `801`
-		//  Estimated: `4145`
-		// Minimum execution time: 48_370_000 picoseconds.
+		//  Estimated: `28336`
+		// Estimated from the storage accesses, pending benchmarking.
 		Weight::from_parts(50_137_334, 0)
-			.saturating_add(Weight::from_parts(0, 4145))
-			.saturating_add(T::DbWeight::get().reads(7))
-			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(Weight::from_parts(0, 28336))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -83,23 +86,25 @@ impl<T: frame_system::Config> pallet_network_score::WeightInfo for WeightInfo<T>
 	/// Storage: `NetworkScore::MessageIdentifiers` (r:1 w:1)
 	/// Proof: `NetworkScore::MessageIdentifiers` (`max_values`: None, `max_size`: Some(236), added: 2711, mode: `MaxEncodedLen`)
 	/// Storage: `NetworkScore::AggregateScores` (r:1 w:1)
-	/// Proof: `NetworkScore::AggregateScores` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	/// Proof: `NetworkScore::AggregateScores` (`max_values`: None, `max_size`: Some(174), added: 2649, mode: `MaxEncodedLen`)
+	/// Storage: `NetworkScore::EpochScores` (r:1 w:1)
+	/// Proof: `NetworkScore::EpochScores` (`max_values`: None, `max_size`: Some(8945), added: 11420, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:2 w:2)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
-	/// The range of component `l` is `[1, 15360]`.
+	/// Storage: `Identifier::TimelineLength` (r:2 w:2)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:2)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[1, 128]`.
 	fn revoke_rating(l: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1329`
-		//  Estimated: `7300`
-		// Minimum execution time: 64_009_000 picoseconds.
+		//  Estimated: `34049`
+		// Estimated from the storage accesses, pending benchmarking.
 		Weight::from_parts(66_046_914, 0)
-			.saturating_add(Weight::from_parts(0, 7300))
-			// Standard Error: 9
+			.saturating_add(Weight::from_parts(0, 34049))
 			.saturating_add(Weight::from_parts(15, 0).saturating_mul(l.into()))
-			.saturating_add(T::DbWeight::get().reads(9))
-			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(9))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -110,20 +115,53 @@ impl<T: frame_system::Config> pallet_network_score::WeightInfo for WeightInfo<T>
 	/// Storage: `NetworkScore::MessageIdentifiers` (r:1 w:1)
 	/// Proof: `NetworkScore::MessageIdentifiers` (`max_values`: None, `max_size`: Some(236), added: 2711, mode: `MaxEncodedLen`)
 	/// Storage: `NetworkScore::AggregateScores` (r:1 w:1)
-	/// Proof: `NetworkScore::AggregateScores` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	/// Proof: `NetworkScore::AggregateScores` (`max_values`: None, `max_size`: Some(174), added: 2649, mode: `MaxEncodedLen`)
+	/// Storage: `NetworkScore::EpochScores` (r:1 w:1)
+	/// Proof: `NetworkScore::EpochScores` (`max_values`: None, `max_size`: Some(8945), added: 11420, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:2 w:2)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
-	/// The range of component `l` is `[1, 15360]`.
+	/// Storage: `Identifier::TimelineLength` (r:2 w:2)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:2)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[1, 128]`.
 	fn revise_rating(_l: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1428`
-		//  Estimated: `7300`
-		// Minimum execution time: 65_800_000 picoseconds.
+		//  Estimated: `34049`
+		// Estimated from the storage accesses, pending benchmarking.
 		Weight::from_parts(68_253_387, 0)
-			.saturating_add(Weight::from_parts(0, 7300))
-			.saturating_add(T::DbWeight::get().reads(9))
-			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(Weight::from_parts(0, 34049))
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(9))
+	}
+	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
+	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Spaces` (r:1 w:0)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `NetworkScore::NextRatingCategory` (r:1 w:1)
+	/// Proof: `NetworkScore::NextRatingCategory` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `NetworkScore::RatingCategories` (r:0 w:1)
+	/// Proof: `NetworkScore::RatingCategories` (`max_values`: None, `max_size`: Some(201), added: 2676, mode: `MaxEncodedLen`)
+	fn register_rating_category() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `5839`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(22_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 5839))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `NetworkScore::NextRatingCategory` (r:1 w:1)
+	/// Proof: `NetworkScore::NextRatingCategory` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `NetworkScore::RatingCategories` (r:0 w:1)
+	/// Proof: `NetworkScore::RatingCategories` (`max_values`: None, `max_size`: Some(201), added: 2676, mode: `MaxEncodedLen`)
+	fn register_network_rating_category() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `499`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 499))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 }
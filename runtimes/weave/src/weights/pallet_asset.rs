@@ -55,19 +55,20 @@ impl<T: frame_system::Config> pallet_asset::WeightInfo for WeightInfo<T> {
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Asset::Assets` (r:1 w:1)
 	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3194), added: 5669, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `Asset::AssetLookup` (r:0 w:1)
 	/// Proof: `Asset::AssetLookup` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
 	fn create() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `719`
-		//  Estimated: `6659`
-		// Minimum execution time: 36_120_000 picoseconds.
+		//  Estimated: `13567`
+		// Estimated from the storage accesses, pending benchmarking.
 		Weight::from_parts(37_030_000, 0)
-			.saturating_add(Weight::from_parts(0, 6659))
+			.saturating_add(Weight::from_parts(0, 13567))
 			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -77,52 +78,55 @@ impl<T: frame_system::Config> pallet_asset::WeightInfo for WeightInfo<T> {
 	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3194), added: 5669, mode: `MaxEncodedLen`)
 	/// Storage: `Asset::Distribution` (r:1 w:1)
 	/// Proof: `Asset::Distribution` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:2 w:2)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:2 w:2)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:2)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `Asset::Issuance` (r:0 w:1)
 	/// Proof: `Asset::Issuance` (`max_values`: None, `max_size`: Some(3330), added: 5805, mode: `MaxEncodedLen`)
 	/// Storage: `Asset::AssetLookup` (r:0 w:1)
 	/// Proof: `Asset::AssetLookup` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
 	fn issue() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `960`
-		//  Estimated: `6659`
-		// Minimum execution time: 50_500_000 picoseconds.
+		//  Estimated: `18599`
+		// Estimated from the storage accesses, pending benchmarking.
 		Weight::from_parts(51_470_000, 0)
-			.saturating_add(Weight::from_parts(0, 6659))
+			.saturating_add(Weight::from_parts(0, 18599))
 			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(7))
+			.saturating_add(T::DbWeight::get().writes(9))
 	}
 	/// Storage: `Asset::Assets` (r:1 w:0)
 	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3194), added: 5669, mode: `MaxEncodedLen`)
 	/// Storage: `Asset::Issuance` (r:1 w:1)
 	/// Proof: `Asset::Issuance` (`max_values`: None, `max_size`: Some(3330), added: 5805, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	fn transfer() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `852`
-		//  Estimated: `6795`
-		// Minimum execution time: 29_210_000 picoseconds.
+		//  Estimated: `14032`
+		// Estimated from the storage accesses, pending benchmarking.
 		Weight::from_parts(30_010_000, 0)
-			.saturating_add(Weight::from_parts(0, 6795))
+			.saturating_add(Weight::from_parts(0, 14032))
 			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Asset::Assets` (r:1 w:0)
 	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3194), added: 5669, mode: `MaxEncodedLen`)
 	/// Storage: `Asset::Issuance` (r:1 w:1)
 	/// Proof: `Asset::Issuance` (`max_values`: None, `max_size`: Some(3330), added: 5805, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	fn status_change() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `852`
-		//  Estimated: `6795`
-		// Minimum execution time: 28_660_000 picoseconds.
+		//  Estimated: `14032`
+		// Estimated from the storage accesses, pending benchmarking.
 		Weight::from_parts(29_540_000, 0)
-			.saturating_add(Weight::from_parts(0, 6795))
+			.saturating_add(Weight::from_parts(0, 14032))
 			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 }
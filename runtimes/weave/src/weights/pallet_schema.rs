@@ -55,19 +55,65 @@ impl<T: frame_system::Config> pallet_schema::WeightInfo for WeightInfo<T> {
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Schema::Schemas` (r:1 w:1)
 	/// Proof: `Schema::Schemas` (`max_values`: None, `max_size`: Some(15542), added: 18017, mode: `MaxEncodedLen`)
-	/// Storage: `Identifier::Identifiers` (r:1 w:1)
-	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// The range of component `l` is `[1, 15360]`.
 	fn create(l: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `719`
-		//  Estimated: `19007`
-		// Minimum execution time: 35_430_000 picoseconds.
+		//  Estimated: `25915`
+		// Estimated from the storage accesses, pending benchmarking.
 		Weight::from_parts(36_938_389, 0)
-			.saturating_add(Weight::from_parts(0, 19007))
-			// Standard Error: 8
-			.saturating_add(Weight::from_parts(3_234, 0).saturating_mul(l.into()))
+			.saturating_add(Weight::from_parts(0, 25915))
+			// Includes 40_000 picoseconds per byte for structural validation of the schema.
+			.saturating_add(Weight::from_parts(43_234, 0).saturating_mul(l.into()))
 			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Schema::Schemas` (r:2 w:1)
+	/// Proof: `Schema::Schemas` (`max_values`: None, `max_size`: Some(15542), added: 18017, mode: `MaxEncodedLen`)
+	/// Storage: `Schema::SchemaVersions` (r:1 w:2)
+	/// Proof: `Schema::SchemaVersions` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
+	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:2 w:2)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:2)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[1, 15360]`.
+	fn supersede(l: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `49117`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(53_602_114, 0)
+			.saturating_add(Weight::from_parts(0, 49117))
+			// Includes 40_000 picoseconds per byte for structural validation of the schema.
+			.saturating_add(Weight::from_parts(43_238, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
+	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Spaces` (r:1 w:0)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `Schema::Schemas` (r:1 w:0)
+	/// Proof: `Schema::Schemas` (`max_values`: None, `max_size`: Some(15542), added: 18017, mode: `MaxEncodedLen`)
+	/// Storage: `Schema::SchemaVersions` (r:1 w:1)
+	/// Proof: `Schema::SchemaVersions` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	/// Proof: `Identifier::TimelineLength` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Timeline` (r:0 w:1)
+	/// Proof: `Identifier::Timeline` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn deprecate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `28542`
+		// Estimated from the storage accesses, pending benchmarking.
+		Weight::from_parts(29_530_000, 0)
+			.saturating_add(Weight::from_parts(0, 28542))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 }
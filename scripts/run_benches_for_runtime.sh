@@ -1,7 +1,11 @@
 #!/bin/bash
 
 # Runs all benchmarks for all pallets, for a given runtime, provided by $1
+# (braid, loom or weave, defaults to loom), and writes the weights to
+# runtimes/<runtime>/src/weights.
 # Should be run on a reference machine to gain accurate benchmarks
+#
+# Usage: ./scripts/run_benches_for_runtime.sh [-b] [-v] [-p pallet_a,pallet_b] [runtime]
 
 while getopts 'bfp:v' flag; do
   case "${flag}" in
@@ -9,6 +13,10 @@ while getopts 'bfp:v' flag; do
     # Skip build.
     skip_build='true'
     ;;
+  p)
+    # Only benchmark the given comma-separated pallets.
+    only_pallets="${OPTARG}"
+    ;;
   v)
     # Echo all executed commands.
     set -x
@@ -20,6 +28,18 @@ while getopts 'bfp:v' flag; do
     ;;
   esac
 done
+shift $((OPTIND - 1))
+
+RUNTIME="${1:-loom}"
+case "$RUNTIME" in
+braid | loom | weave) ;;
+*)
+  echo "Unknown runtime $RUNTIME. Expected one of braid, loom or weave."
+  exit 1
+  ;;
+esac
+CHAIN="dev-node-$RUNTIME"
+WEIGHTS_DIR="./runtimes/$RUNTIME/src/weights"
 
 if [ "$skip_build" != true ]; then
   echo "[+] Compiling benchmarks..."
@@ -30,15 +50,19 @@ fi
 CORD=./target/production/cord
 
 # Load all pallet names in an array.
-PALLETS=($(
-  $CORD benchmark pallet --list --chain="dev" |
-    tail -n+2 |
-    cut -d',' -f1 |
-    sort |
-    uniq
-))
+if [ -n "$only_pallets" ]; then
+  IFS=',' read -r -a PALLETS <<<"$only_pallets"
+else
+  PALLETS=($(
+    $CORD benchmark pallet --list --chain="$CHAIN" |
+      tail -n+2 |
+      cut -d',' -f1 |
+      sort |
+      uniq
+  ))
+fi
 
-echo "[+] Benchmarking ${#PALLETS[@]} pallets for CORD runtime"
+echo "[+] Benchmarking ${#PALLETS[@]} pallets for the $RUNTIME runtime"
 
 # Define the error file.
 ERR_FILE="benchmarking_errors.txt"
@@ -47,7 +71,7 @@ rm -f $ERR_FILE
 
 # Benchmark each pallet.
 for PALLET in "${PALLETS[@]}"; do
-  echo "[+] Benchmarking $PALLET for CORD runtime"
+  echo "[+] Benchmarking $PALLET for the $RUNTIME runtime"
 
   output_file=""
   if [[ $PALLET == *"::"* ]]; then
@@ -57,7 +81,7 @@ for PALLET in "${PALLETS[@]}"; do
 
   OUTPUT=$(
     $CORD benchmark pallet \
-      --chain=dev \
+      --chain="$CHAIN" \
       --steps=50 \
       --repeat=20 \
       --pallet="$PALLET" \
@@ -65,7 +89,7 @@ for PALLET in "${PALLETS[@]}"; do
       --wasm-execution=compiled \
       --heap-pages=4096 \
       --header=./HEADER-GPL3 \
-      --output="./runtime/src/weights/${output_file}" 2>&1
+      --output="$WEIGHTS_DIR/${output_file}" 2>&1
   )
   if [ $? -ne 0 ]; then
     echo "$OUTPUT" >>"$ERR_FILE"
@@ -73,25 +97,28 @@ for PALLET in "${PALLETS[@]}"; do
   fi
 done
 
-# Update the block and extrinsic overhead weights.
-echo "[+] Benchmarking block and extrinsic overheads..."
-OUTPUT=$(
-  $CORD benchmark overhead \
-    --chain="dev" \
-    --wasm-execution=compiled \
-    --weight-path="runtime/constants/src/weights/" \
-    --warmup=10 \
-    --repeat=100 \
-    --header=./HEADER-GPL3
-)
-if [ $? -ne 0 ]; then
-  echo "$OUTPUT" >>"$ERR_FILE"
-  echo "[-] Failed to benchmark the block and extrinsic overheads. Error written to $ERR_FILE; continuing..."
+# Update the block and extrinsic overhead weights, unless only some pallets
+# were asked for.
+if [ -z "$only_pallets" ]; then
+  echo "[+] Benchmarking block and extrinsic overheads..."
+  OUTPUT=$(
+    $CORD benchmark overhead \
+      --chain="$CHAIN" \
+      --wasm-execution=compiled \
+      --weight-path="runtimes/$RUNTIME/constants/src/weights/" \
+      --warmup=10 \
+      --repeat=100 \
+      --header=./HEADER-GPL3
+  )
+  if [ $? -ne 0 ]; then
+    echo "$OUTPUT" >>"$ERR_FILE"
+    echo "[-] Failed to benchmark the block and extrinsic overheads. Error written to $ERR_FILE; continuing..."
+  fi
 fi
 
 echo "[+] Benchmarking the machine..."
 OUTPUT=$(
-  $CORD benchmark machine --chain=dev 2>&1
+  $CORD benchmark machine --chain="$CHAIN" 2>&1
 )
 if [ $? -ne 0 ]; then
   # Do not write the error to the error file since it is not a benchmarking error.
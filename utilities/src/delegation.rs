@@ -0,0 +1,127 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Time-limited and quota-limited delegations, shared by the pallets that
+//! let their members delegate permissions to others.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{traits::Get, BoundedVec};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// Limits attached to a time-limited or quota-limited delegation.
+///
+/// Delegations without limits have no entry of this type and never lapse.
+///
+/// ## Fields
+///
+/// - `expires_at`: The block at which the delegation lapses, if any.
+/// - `quota`: The number of authorized operations the delegate may perform, if limited.
+/// - `used`: The number of authorized operations performed so far.
+#[derive(Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct DelegationLimits<BlockNumber> {
+	pub expires_at: Option<BlockNumber>,
+	pub quota: Option<u32>,
+	pub used: u32,
+}
+
+/// The reasons a delegation is refused because of its limits.
+#[derive(Clone, Copy, RuntimeDebug, PartialEq, Eq)]
+pub enum DelegationLimitsError {
+	/// The expiry is not after the block the delegation is granted in.
+	InvalidExpiry,
+	/// The quota is zero.
+	InvalidQuota,
+	/// The delegation has reached its expiry block.
+	Expired,
+	/// The delegate has used up its quota.
+	QuotaExhausted,
+}
+
+impl<BlockNumber: PartialOrd> DelegationLimits<BlockNumber> {
+	/// Returns the limits of a delegation granted at block `now`, or `None`
+	/// for a delegation without limits.
+	pub fn new(
+		expires_at: Option<BlockNumber>,
+		quota: Option<u32>,
+		now: &BlockNumber,
+	) -> Result<Option<Self>, DelegationLimitsError> {
+		if expires_at.is_none() && quota.is_none() {
+			return Ok(None);
+		}
+		if quota == Some(0) {
+			return Err(DelegationLimitsError::InvalidQuota);
+		}
+		if expires_at.as_ref().map_or(false, |expiry| expiry <= now) {
+			return Err(DelegationLimitsError::InvalidExpiry);
+		}
+
+		Ok(Some(Self { expires_at, quota, used: 0 }))
+	}
+
+	/// Returns `true` if the delegation has lapsed at block `now`.
+	pub fn is_expired(&self, now: &BlockNumber) -> bool {
+		self.expires_at.as_ref().map_or(false, |expiry| now >= expiry)
+	}
+
+	/// Returns `true` if the delegate has used up its quota.
+	pub fn is_exhausted(&self) -> bool {
+		self.quota.map_or(false, |quota| self.used >= quota)
+	}
+
+	/// Returns `true` if the delegation can no longer be used at block `now`.
+	pub fn has_lapsed(&self, now: &BlockNumber) -> bool {
+		self.is_expired(now) || self.is_exhausted()
+	}
+
+	/// Records one use of the delegation at block `now`.
+	///
+	/// Returns `true` if the limits changed and have to be stored again, which
+	/// is the case for every use of a quota-limited delegation.
+	pub fn consume(&mut self, now: &BlockNumber) -> Result<bool, DelegationLimitsError> {
+		if self.is_expired(now) {
+			return Err(DelegationLimitsError::Expired);
+		}
+		if self.is_exhausted() {
+			return Err(DelegationLimitsError::QuotaExhausted);
+		}
+		if self.quota.is_none() {
+			return Ok(false);
+		}
+
+		self.used = self.used.saturating_add(1);
+		Ok(true)
+	}
+}
+
+/// Removes `delegate` from the delegates of a space or registry.
+///
+/// The list is scanned linearly, so callers charge for a list of the maximum
+/// length. Returns `false` if the delegate was not listed.
+pub fn remove_delegate<AccountId: PartialEq, MaxDelegates: Get<u32>>(
+	delegates: &mut BoundedVec<AccountId, MaxDelegates>,
+	delegate: &AccountId,
+) -> bool {
+	match delegates.iter().position(|d| d == delegate) {
+		Some(index) => {
+			delegates.remove(index);
+			true
+		},
+		None => false,
+	}
+}
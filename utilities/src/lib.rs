@@ -20,6 +20,7 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub mod delegation;
 pub mod deposit;
 pub use deposit::{free_deposit, reserve_deposit};
 